@@ -0,0 +1,42 @@
+// `cargo test --benches` and `#[feature(test)]` work only in nightly
+#![cfg(rustc_nightly)]
+#![cfg(feature = "bytes")]
+#![feature(test)]
+
+extern crate test;
+
+extern crate protobuf;
+
+extern crate perftest_bytes;
+
+use perftest_bytes::fast_path;
+use protobuf::Message;
+
+fn make_bytes() -> Vec<u8> {
+    let mut m = fast_path::Flat::new();
+    m.set_a(150);
+    m.set_b(-3);
+    m.set_c(true);
+    m.set_f(7);
+    m.set_d(1.5);
+    let bytes = m.write_to_bytes().unwrap();
+    // fields are merged when message is concatenated,
+    // so parsing time is dominated by fields parsing, not by stream setup
+    bytes.repeat(100)
+}
+
+// `Flat` has `merge_from` fast path
+#[bench]
+fn parse_fast_path(b: &mut test::Bencher) {
+    let bytes = make_bytes();
+    b.iter(|| test::black_box(protobuf::parse_from_bytes::<fast_path::Flat>(&bytes).unwrap()))
+}
+
+// `FlatGeneric` has the same fields, but parsed with generic code
+#[bench]
+fn parse_generic(b: &mut test::Bencher) {
+    let bytes = make_bytes();
+    b.iter(|| {
+        test::black_box(protobuf::parse_from_bytes::<fast_path::FlatGeneric>(&bytes).unwrap())
+    })
+}
@@ -0,0 +1,86 @@
+// `cargo test --benches` and `#[feature(test)]` work only in nightly
+#![cfg(rustc_nightly)]
+#![cfg(feature = "bytes")]
+#![feature(test)]
+
+extern crate test;
+
+extern crate protobuf;
+
+extern crate perftest_bytes;
+
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use perftest_bytes::intern_strings;
+use protobuf::Message;
+
+struct CountingAlloc;
+
+// bytes currently allocated
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+// Many copies of few distinct strings, like labels of metrics
+fn make_bytes() -> Vec<u8> {
+    let mut m = intern_strings::LabelsString::new();
+    for i in 0..10_000 {
+        m.labels.push(format!("some-long-label-value-{}", i % 10));
+    }
+    m.write_to_bytes().unwrap()
+}
+
+// Bytes retained by parsed message
+fn retained_memory<M: Message>(bytes: &[u8]) -> usize {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    let m: M = protobuf::parse_from_bytes(bytes).unwrap();
+    let retained = ALLOCATED.load(Ordering::Relaxed) - before;
+    test::black_box(m);
+    retained
+}
+
+#[test]
+fn memory() {
+    let bytes = make_bytes();
+    let string = retained_memory::<intern_strings::LabelsString>(&bytes);
+    let interned = retained_memory::<intern_strings::LabelsInterned>(&bytes);
+    println!("retained bytes: string: {}, interned: {}", string, interned);
+    assert!(interned < string);
+}
+
+#[bench]
+fn parse_string(b: &mut test::Bencher) {
+    let bytes = make_bytes();
+    b.iter(|| {
+        test::black_box(
+            protobuf::parse_from_bytes::<intern_strings::LabelsString>(&bytes).unwrap(),
+        )
+    })
+}
+
+#[bench]
+fn parse_interned(b: &mut test::Bencher) {
+    let bytes = make_bytes();
+    b.iter(|| {
+        test::black_box(
+            protobuf::parse_from_bytes::<intern_strings::LabelsInterned>(&bytes).unwrap(),
+        )
+    })
+}
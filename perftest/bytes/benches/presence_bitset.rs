@@ -0,0 +1,92 @@
+// `cargo test --benches` and `#[feature(test)]` work only in nightly
+#![cfg(rustc_nightly)]
+#![cfg(feature = "bytes")]
+#![feature(test)]
+
+extern crate test;
+
+extern crate protobuf;
+
+extern crate perftest_bytes;
+
+use std::mem;
+
+use perftest_bytes::messages;
+
+// Set all fields of message, then return sum of all fields
+macro_rules! set_get_all {
+    ($m:expr, $(($set:ident, $get:ident)),*) => {{
+        let m = &mut $m;
+        let mut i = 0;
+        $(
+            i += 1;
+            m.$set(i);
+        )*
+        let mut sum = 0;
+        $(
+            sum += m.$get();
+        )*
+        sum
+    }};
+}
+
+macro_rules! set_get_all_32 {
+    ($m:expr) => {
+        set_get_all!(
+            $m,
+        (set_f0, get_f0),
+        (set_f1, get_f1),
+        (set_f2, get_f2),
+        (set_f3, get_f3),
+        (set_f4, get_f4),
+        (set_f5, get_f5),
+        (set_f6, get_f6),
+        (set_f7, get_f7),
+        (set_f8, get_f8),
+        (set_f9, get_f9),
+        (set_f10, get_f10),
+        (set_f11, get_f11),
+        (set_f12, get_f12),
+        (set_f13, get_f13),
+        (set_f14, get_f14),
+        (set_f15, get_f15),
+        (set_f16, get_f16),
+        (set_f17, get_f17),
+        (set_f18, get_f18),
+        (set_f19, get_f19),
+        (set_f20, get_f20),
+        (set_f21, get_f21),
+        (set_f22, get_f22),
+        (set_f23, get_f23),
+        (set_f24, get_f24),
+        (set_f25, get_f25),
+        (set_f26, get_f26),
+        (set_f27, get_f27),
+        (set_f28, get_f28),
+        (set_f29, get_f29),
+        (set_f30, get_f30),
+        (set_f31, get_f31)
+        )
+    };
+}
+
+#[test]
+fn size_of() {
+    println!(
+        "size_of: bitset: {}, option: {}",
+        mem::size_of::<messages::Presence32Bitset>(),
+        mem::size_of::<messages::Presence32Option>()
+    );
+}
+
+#[bench]
+fn set_get_bitset(b: &mut test::Bencher) {
+    let mut m = messages::Presence32Bitset::new();
+    b.iter(|| test::black_box(set_get_all_32!(test::black_box(&mut m))))
+}
+
+#[bench]
+fn set_get_option(b: &mut test::Bencher) {
+    let mut m = messages::Presence32Option::new();
+    b.iter(|| test::black_box(set_get_all_32!(test::black_box(&mut m))))
+}
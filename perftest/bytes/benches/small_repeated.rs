@@ -0,0 +1,90 @@
+// `cargo test --benches` and `#[feature(test)]` work only in nightly
+#![cfg(rustc_nightly)]
+#![cfg(feature = "bytes")]
+#![cfg(feature = "smallvec")]
+#![feature(test)]
+
+extern crate test;
+
+extern crate protobuf;
+
+extern crate perftest_bytes;
+
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use perftest_bytes::small_repeated;
+use protobuf::Message;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+// Two numbers and one string, below inline capacity of `SmallRepeated`
+fn make_bytes() -> Vec<u8> {
+    let mut m = small_repeated::SmallRepeatedVec::new();
+    m.numbers.push(1);
+    m.numbers.push(2);
+    m.strings.push("s".to_owned());
+    m.write_to_bytes().unwrap()
+}
+
+fn count_allocations<F: FnOnce()>(f: F) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+#[test]
+fn allocations() {
+    let bytes = make_bytes();
+    let vec = count_allocations(|| {
+        test::black_box(
+            protobuf::parse_from_bytes::<small_repeated::SmallRepeatedVec>(&bytes).unwrap(),
+        );
+    });
+    let small_vec = count_allocations(|| {
+        test::black_box(
+            protobuf::parse_from_bytes::<small_repeated::SmallRepeated>(&bytes).unwrap(),
+        );
+    });
+    println!("allocations: vec: {}, small_vec: {}", vec, small_vec);
+    assert!(small_vec < vec);
+}
+
+#[bench]
+fn parse_vec(b: &mut test::Bencher) {
+    let bytes = make_bytes();
+    b.iter(|| {
+        test::black_box(
+            protobuf::parse_from_bytes::<small_repeated::SmallRepeatedVec>(&bytes).unwrap(),
+        )
+    })
+}
+
+#[bench]
+fn parse_small_vec(b: &mut test::Bencher) {
+    let bytes = make_bytes();
+    b.iter(|| {
+        test::black_box(
+            protobuf::parse_from_bytes::<small_repeated::SmallRepeated>(&bytes).unwrap(),
+        )
+    })
+}
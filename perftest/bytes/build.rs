@@ -7,7 +7,12 @@ use std::process;
 fn generate_protos() {
     protoc_rust::Args::new()
         .out_dir("src")
-        .input("src/messages.proto")
+        .inputs(&[
+            "src/messages.proto",
+            "src/small_repeated.proto",
+            "src/intern_strings.proto",
+            "src/fast_path.proto",
+        ])
         .includes(&["src", "../../proto"])
         .run()
         .expect("protoc");
@@ -3,5 +3,11 @@
 extern crate protobuf;
 
 extern crate bytes;
+#[cfg(feature = "smallvec")]
+extern crate smallvec;
 
+pub mod fast_path;
+pub mod intern_strings;
 pub mod messages;
+#[cfg(feature = "smallvec")]
+pub mod small_repeated;
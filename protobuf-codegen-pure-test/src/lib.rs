@@ -14,6 +14,15 @@ extern crate serde;
 extern crate serde_derive;
 #[cfg(feature = "with-serde")]
 extern crate serde_json;
+#[cfg(feature = "with-arbitrary")]
+#[macro_use]
+extern crate arbitrary;
+#[cfg(feature = "with-rayon")]
+extern crate rayon;
+#[cfg(feature = "with-smallvec")]
+extern crate smallvec;
+#[cfg(feature = "with-uuid")]
+extern crate uuid;
 
 mod v2;
 mod v3;
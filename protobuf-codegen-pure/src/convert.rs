@@ -220,6 +220,9 @@ impl<'a> Resolver<'a> {
         input: &[model::ProtobufOption],
     ) -> ConvertResult<protobuf::descriptor::MessageOptions> {
         let mut r = protobuf::descriptor::MessageOptions::new();
+        if let Some(deprecated) = input.by_name_bool("deprecated")? {
+            r.set_deprecated(deprecated);
+        }
         self.custom_options(
             input,
             "google.protobuf.MessageOptions",
@@ -546,15 +549,33 @@ impl<'a> Resolver<'a> {
         }
     }
 
+    fn enum_value_options(
+        &self,
+        input: &[model::ProtobufOption],
+    ) -> ConvertResult<protobuf::descriptor::EnumValueOptions> {
+        let mut r = protobuf::descriptor::EnumValueOptions::new();
+        if let Some(deprecated) = input.by_name_bool("deprecated")? {
+            r.set_deprecated(deprecated);
+        }
+        self.custom_options(
+            input,
+            "google.protobuf.EnumValueOptions",
+            r.mut_unknown_fields(),
+        )?;
+        Ok(r)
+    }
+
     fn enum_value(
         &self,
-        name: &str,
-        number: i32,
-    ) -> protobuf::descriptor::EnumValueDescriptorProto {
+        value: &model::EnumValue,
+    ) -> ConvertResult<protobuf::descriptor::EnumValueDescriptorProto> {
         let mut output = protobuf::descriptor::EnumValueDescriptorProto::new();
-        output.set_name(name.to_owned());
-        output.set_number(number);
+        output.set_name(value.name.clone());
+        output.set_number(value.number);
         output
+            .options
+            .set_message(self.enum_value_options(&value.options)?);
+        Ok(output)
     }
 
     fn enum_options(
@@ -581,8 +602,8 @@ impl<'a> Resolver<'a> {
         output.value = input
             .values
             .iter()
-            .map(|v| self.enum_value(&v.name, v.number))
-            .collect();
+            .map(|v| self.enum_value(v))
+            .collect::<Result<_, _>>()?;
         output
             .options
             .set_message(self.enum_options(&input.options)?);
@@ -695,10 +716,85 @@ impl<'a> Resolver<'a> {
         input: &[model::ProtobufOption],
     ) -> ConvertResult<protobuf::descriptor::FileOptions> {
         let mut r = protobuf::descriptor::FileOptions::new();
+        if let Some(optimize_for) = input.by_name("optimize_for") {
+            use protobuf::descriptor::file_options::OptimizeMode;
+            r.set_optimize_for(match optimize_for {
+                model::ProtobufConstant::Ident(ref mode) if mode == "SPEED" => OptimizeMode::SPEED,
+                model::ProtobufConstant::Ident(ref mode) if mode == "CODE_SIZE" => {
+                    OptimizeMode::CODE_SIZE
+                }
+                model::ProtobufConstant::Ident(ref mode) if mode == "LITE_RUNTIME" => {
+                    OptimizeMode::LITE_RUNTIME
+                }
+                _ => return Err(ConvertError::WrongOptionType),
+            });
+        }
         self.custom_options(input, "google.protobuf.FileOptions", r.mut_unknown_fields())?;
         Ok(r)
     }
 
+    fn method_options(
+        &self,
+        input: &[model::ProtobufOption],
+    ) -> ConvertResult<protobuf::descriptor::MethodOptions> {
+        let mut r = protobuf::descriptor::MethodOptions::new();
+        if let Some(deprecated) = input.by_name_bool("deprecated")? {
+            r.set_deprecated(deprecated);
+        }
+        self.custom_options(input, "google.protobuf.MethodOptions", r.mut_unknown_fields())?;
+        Ok(r)
+    }
+
+    fn method(
+        &self,
+        input: &model::Method,
+    ) -> ConvertResult<protobuf::descriptor::MethodDescriptorProto> {
+        let relative_path = ProtobufRelativePath::empty();
+        let mut output = protobuf::descriptor::MethodDescriptorProto::new();
+        output.set_name(input.name.clone());
+        output.set_input_type(self.resolve_message_or_enum(&input.input_type, &relative_path).0.path);
+        output.set_output_type(self.resolve_message_or_enum(&input.output_type, &relative_path).0.path);
+        if input.client_streaming {
+            output.set_client_streaming(true);
+        }
+        if input.server_streaming {
+            output.set_server_streaming(true);
+        }
+        output
+            .options
+            .set_message(self.method_options(&input.options)?);
+        Ok(output)
+    }
+
+    fn service_options(
+        &self,
+        input: &[model::ProtobufOption],
+    ) -> ConvertResult<protobuf::descriptor::ServiceOptions> {
+        let mut r = protobuf::descriptor::ServiceOptions::new();
+        if let Some(deprecated) = input.by_name_bool("deprecated")? {
+            r.set_deprecated(deprecated);
+        }
+        self.custom_options(input, "google.protobuf.ServiceOptions", r.mut_unknown_fields())?;
+        Ok(r)
+    }
+
+    fn service(
+        &self,
+        input: &model::Service,
+    ) -> ConvertResult<protobuf::descriptor::ServiceDescriptorProto> {
+        let mut output = protobuf::descriptor::ServiceDescriptorProto::new();
+        output.set_name(input.name.clone());
+        output.method = input
+            .methods
+            .iter()
+            .map(|m| self.method(m))
+            .collect::<Result<_, _>>()?;
+        output
+            .options
+            .set_message(self.service_options(&input.options)?);
+        Ok(output)
+    }
+
     fn extension(
         &self,
         input: &model::Extension,
@@ -764,5 +860,11 @@ pub fn file_descriptor(
     }
     output.extension = extensions;
 
+    output.service = input
+        .services
+        .iter()
+        .map(|s| resolver.service(s))
+        .collect::<Result<_, _>>()?;
+
     Ok(output)
 }
@@ -186,6 +186,8 @@ pub struct EnumValue {
     pub name: String,
     /// enum value number
     pub number: i32,
+    /// enum value options
+    pub options: Vec<ProtobufOption>,
 }
 
 /// A protobuf enumerator
@@ -687,13 +687,6 @@ impl<'a> Parser<'a> {
     // Enum definition
 
     // enumValueOption = optionName "=" constant
-    fn next_enum_value_option(&mut self) -> ParserResult<()> {
-        self.next_option_name()?;
-        self.tokenizer.next_symbol_expect_eq('=')?;
-        self.next_constant()?;
-        Ok(())
-    }
-
     // https://github.com/google/protobuf/issues/4561
     fn next_enum_value(&mut self) -> ParserResult<i32> {
         let minus = self.tokenizer.next_symbol_if_eq('-')?;
@@ -714,15 +707,17 @@ impl<'a> Parser<'a> {
         let name = self.tokenizer.next_ident()?.to_owned();
         self.tokenizer.next_symbol_expect_eq('=')?;
         let number = self.next_enum_value()?;
+
+        let mut options = Vec::new();
+
         if self.tokenizer.next_symbol_if_eq('[')? {
-            self.next_enum_value_option()?;
-            while self.tokenizer.next_symbol_if_eq(',')? {
-                self.next_enum_value_option()?;
+            for o in self.next_field_options()? {
+                options.push(o);
             }
             self.tokenizer.next_symbol_expect_eq(']')?;
         }
 
-        Ok(EnumValue { name, number })
+        Ok(EnumValue { name, number, options })
     }
 
     // enum = "enum" enumName enumBody
@@ -948,9 +943,9 @@ impl<'a> Parser<'a> {
         if self.tokenizer.next_ident_if_eq("stream")? {
             let name = self.tokenizer.next_ident()?;
             self.tokenizer.next_symbol_expect_eq('(')?;
-            let input_type = self.tokenizer.next_ident()?;
+            let input_type = self.next_message_or_enum_type()?;
             self.tokenizer.next_symbol_expect_eq(',')?;
-            let output_type = self.tokenizer.next_ident()?;
+            let output_type = self.next_message_or_enum_type()?;
             self.tokenizer.next_symbol_expect_eq(')')?;
             let options = self.next_options_or_colon()?;
             Ok(Some(Method {
@@ -974,12 +969,12 @@ impl<'a> Parser<'a> {
             let name = self.tokenizer.next_ident()?;
             self.tokenizer.next_symbol_expect_eq('(')?;
             let client_streaming = self.tokenizer.next_ident_if_eq("stream")?;
-            let input_type = self.tokenizer.next_ident()?;
+            let input_type = self.next_message_or_enum_type()?;
             self.tokenizer.next_symbol_expect_eq(')')?;
             self.tokenizer.next_ident_expect_eq("returns")?;
             self.tokenizer.next_symbol_expect_eq('(')?;
             let server_streaming = self.tokenizer.next_ident_if_eq("stream")?;
-            let output_type = self.tokenizer.next_ident()?;
+            let output_type = self.next_message_or_enum_type()?;
             self.tokenizer.next_symbol_expect_eq(')')?;
             let options = self.next_options_or_colon()?;
             Ok(Some(Method {
@@ -1205,6 +1200,19 @@ mod test {
         assert_eq!(4, enumeration.values.len());
     }
 
+    #[test]
+    fn test_enum_value_options() {
+        let msg = r#"enum Status {
+                CURRENT = 0;
+                OLD     = 1 [deprecated = true];
+    }"#;
+
+        let enumeration = parse_opt(msg, |p| p.next_enum_opt());
+        assert!(enumeration.values[0].options.is_empty());
+        assert_eq!(1, enumeration.values[1].options.len());
+        assert_eq!("deprecated", enumeration.values[1].options[0].name);
+    }
+
     #[test]
     fn test_ignore() {
         let msg = r#"option optimize_for = SPEED;"#;
@@ -1400,6 +1408,19 @@ mod test {
         assert!(!method.server_streaming);
     }
 
+    #[test]
+    fn test_service_qualified_types() {
+        let msg = r#"service SearchService {
+            rpc Search (.foo.SearchRequest) returns (stream google.protobuf.Empty);
+        }"#;
+        let service = parse_opt(msg, |p| p.next_service_opt());
+
+        let method = &service.methods[0];
+        assert_eq!(".foo.SearchRequest", method.input_type);
+        assert_eq!("google.protobuf.Empty", method.output_type);
+        assert!(method.server_streaming);
+    }
+
     #[test]
     fn test_incorrect_file_descriptor() {
         let msg = r#"
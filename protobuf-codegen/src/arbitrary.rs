@@ -0,0 +1,11 @@
+use code_writer::CodeWriter;
+use Customize;
+
+/// Write `arbitrary` crate attr if `derive_arbitrary` codegen option is set.
+///
+/// Attr is enabled only when `arbitrary` feature of generated code crate is enabled.
+pub fn write_arbitrary_attr(w: &mut CodeWriter, customize: &Customize, attr: &str) {
+    if customize.derive_arbitrary.unwrap_or(false) {
+        w.write_line(&format!("#[cfg_attr(feature = \"arbitrary\", {})]", attr));
+    }
+}
@@ -1,6 +1,7 @@
 // TODO: used by grpc-rust, should move it into separate crate.
 #![doc(hidden)]
 
+use std::borrow::Cow;
 use std::io::Write;
 
 /// Field visibility.
@@ -12,6 +13,65 @@ pub enum Visibility {
 pub struct CodeWriter<'a> {
     writer: &'a mut (Write + 'a),
     indent: String,
+    // keyword emitted for public items
+    pub_keyword: &'static str,
+    // rewrite `::std::` paths to `::core::` and `::alloc::`
+    no_std: bool,
+    // rewrite `::protobuf::` paths to aliases imported by `write_use_preamble`
+    use_preamble: bool,
+}
+
+// `alloc` types, other paths used in generated code are available in `core`
+const NO_STD_ALLOC_PATHS: &[&str] = &["::std::vec::", "::std::string::", "::std::boxed::"];
+
+fn no_std_paths(line: &str) -> String {
+    let mut line = line.to_owned();
+    for path in NO_STD_ALLOC_PATHS {
+        line = line.replace(path, &path.replace("::std::", "::alloc::"));
+    }
+    line.replace("::std::", "::core::")
+}
+
+/// Aliases of `::protobuf` paths imported with `use_preamble`.
+///
+/// Paths are imported under `pb`-prefixed names rather than as is,
+/// so generated `Message`, `RepeatedField` etc. types don't clash with imports.
+/// More specific paths go first.
+pub(crate) const PREAMBLE_ALIASES: &[(&str, &str)] = &[
+    ("::protobuf::rt::", "pb_rt"),
+    ("::protobuf::reflect::", "pb_reflect"),
+    ("::protobuf::types::", "pb_types"),
+    ("::protobuf::wire_format::", "pb_wire_format"),
+    ("::protobuf::", "pb"),
+];
+
+fn preamble_paths(line: &str) -> String {
+    let mut r = String::with_capacity(line.len());
+    let mut rem = line;
+    'outer: while let Some(pos) = rem.find("::protobuf::") {
+        // keep relative paths like `super::protobuf::`
+        let prev = rem[..pos].chars().next_back();
+        let absolute = match prev {
+            Some(c) => !(c.is_alphanumeric() || c == '_' || c == ':'),
+            None => true,
+        };
+        if absolute {
+            for &(path, alias) in PREAMBLE_ALIASES {
+                if rem[pos..].starts_with(path) {
+                    r.push_str(&rem[..pos]);
+                    r.push_str(alias);
+                    r.push_str("::");
+                    rem = &rem[pos + path.len()..];
+                    continue 'outer;
+                }
+            }
+        }
+        let end = pos + "::protobuf::".len();
+        r.push_str(&rem[..end]);
+        rem = &rem[end..];
+    }
+    r.push_str(rem);
+    r
 }
 
 impl<'a> CodeWriter<'a> {
@@ -19,12 +79,61 @@ impl<'a> CodeWriter<'a> {
         CodeWriter {
             writer: writer,
             indent: "".to_string(),
+            pub_keyword: "pub",
+            no_std: false,
+            use_preamble: false,
+        }
+    }
+
+    /// Keyword emitted for public items, `pub` by default.
+    pub fn set_pub_keyword(&mut self, pub_keyword: &'static str) {
+        self.pub_keyword = pub_keyword;
+    }
+
+    /// `pub` or `pub(crate)`
+    pub fn pub_keyword(&self) -> &'static str {
+        self.pub_keyword
+    }
+
+    /// Rewrite `::std::` paths in written lines to `::core::` and `::alloc::` paths.
+    pub fn set_no_std(&mut self, no_std: bool) {
+        self.no_std = no_std;
+    }
+
+    pub fn no_std(&self) -> bool {
+        self.no_std
+    }
+
+    /// Rewrite `::protobuf::` paths in written lines to aliases
+    /// imported by `write_use_preamble`.
+    pub fn set_use_preamble(&mut self, use_preamble: bool) {
+        self.use_preamble = use_preamble;
+    }
+
+    /// Import aliases of `::protobuf` paths if `use_preamble` is set.
+    pub fn write_use_preamble(&mut self) {
+        if !self.use_preamble {
+            return;
+        }
+        // imported paths must not be rewritten
+        self.use_preamble = false;
+        for &(path, alias) in PREAMBLE_ALIASES {
+            self.write_line(&format!("use {} as {};", &path[..path.len() - "::".len()], alias));
         }
+        self.use_preamble = true;
     }
 
     pub fn write_line<S: AsRef<str>>(&mut self, line: S) {
+        let line = if self.use_preamble {
+            Cow::Owned(preamble_paths(line.as_ref()))
+        } else {
+            Cow::Borrowed(line.as_ref())
+        };
         (if line.as_ref().is_empty() {
             self.writer.write_all("\n".as_bytes())
+        } else if self.no_std {
+            let s: String = [&self.indent, &no_std_paths(line.as_ref()), "\n"].concat();
+            self.writer.write_all(s.as_bytes())
         } else {
             let s: String = [self.indent.as_ref(), line.as_ref(), "\n"].concat();
             self.writer.write_all(s.as_bytes())
@@ -58,6 +167,7 @@ impl<'a> CodeWriter<'a> {
         self.write_line("");
         self.write_line("#![allow(box_pointers)]");
         self.write_line("#![allow(dead_code)]");
+        self.write_line("#![allow(deprecated)]");
         self.write_line("#![allow(missing_docs)]");
         self.write_line("#![allow(non_camel_case_types)]");
         self.write_line("#![allow(non_snake_case)]");
@@ -82,6 +192,9 @@ impl<'a> CodeWriter<'a> {
         cb(&mut CodeWriter {
             writer: self.writer,
             indent: format!("{}    ", self.indent),
+            pub_keyword: self.pub_keyword,
+            no_std: self.no_std,
+            use_preamble: self.use_preamble,
         });
     }
 
@@ -93,11 +206,17 @@ impl<'a> CodeWriter<'a> {
         cb(&mut CodeWriter {
             writer: self.writer,
             indent: format!("// {}", self.indent),
+            pub_keyword: self.pub_keyword,
+            no_std: self.no_std,
+            use_preamble: self.use_preamble,
         });
     }
 
     pub fn pub_const(&mut self, name: &str, field_type: &str, init: &str) {
-        self.write_line(&format!("pub const {}: {} = {};", name, field_type, init));
+        self.write_line(&format!(
+            "{} const {}: {} = {};",
+            self.pub_keyword, name, field_type, init
+        ));
     }
 
     pub fn lazy_static(&mut self, name: &str, ty: &str) {
@@ -186,7 +305,7 @@ impl<'a> CodeWriter<'a> {
     where
         F: Fn(&mut CodeWriter),
     {
-        self.expr_block(&format!("pub struct {}", name.as_ref()), cb);
+        self.expr_block(&format!("{} struct {}", self.pub_keyword, name.as_ref()), cb);
     }
 
     pub fn def_struct<S: AsRef<str>, F>(&mut self, name: S, cb: F)
@@ -200,21 +319,21 @@ impl<'a> CodeWriter<'a> {
     where
         F: Fn(&mut CodeWriter),
     {
-        self.expr_block(&format!("pub enum {}", name), cb);
+        self.expr_block(&format!("{} enum {}", self.pub_keyword, name), cb);
     }
 
     pub fn pub_trait<F>(&mut self, name: &str, cb: F)
     where
         F: Fn(&mut CodeWriter),
     {
-        self.expr_block(&format!("pub trait {}", name), cb);
+        self.expr_block(&format!("{} trait {}", self.pub_keyword, name), cb);
     }
 
     pub fn pub_trait_extend<F>(&mut self, name: &str, extend: &str, cb: F)
     where
         F: Fn(&mut CodeWriter),
     {
-        self.expr_block(&format!("pub trait {} : {}", name, extend), cb);
+        self.expr_block(&format!("{} trait {} : {}", self.pub_keyword, name, extend), cb);
     }
 
     pub fn field_entry(&mut self, name: &str, value: &str) {
@@ -226,7 +345,7 @@ impl<'a> CodeWriter<'a> {
     }
 
     pub fn pub_field_decl(&mut self, name: &str, field_type: &str) {
-        self.write_line(&format!("pub {}: {},", name, field_type));
+        self.write_line(&format!("{} {}: {},", self.pub_keyword, name, field_type));
     }
 
     pub fn field_decl_vis(&mut self, vis: Visibility, name: &str, field_type: &str) {
@@ -246,6 +365,10 @@ impl<'a> CodeWriter<'a> {
         self.write_line(&format!("#[allow({})]", v.join(",")));
     }
 
+    pub fn deprecated(&mut self, note: &str) {
+        self.write_line(&format!("#[deprecated(note = \"{}\")]", note));
+    }
+
     pub fn comment(&mut self, comment: &str) {
         if comment.is_empty() {
             self.write_line("//");
@@ -263,7 +386,7 @@ impl<'a> CodeWriter<'a> {
         F: FnOnce(&mut CodeWriter),
     {
         if public {
-            self.expr_block(&format!("pub fn {}", sig), cb);
+            self.expr_block(&format!("{} fn {}", self.pub_keyword, sig), cb);
         } else {
             self.expr_block(&format!("fn {}", sig), cb);
         }
@@ -294,7 +417,10 @@ impl<'a> CodeWriter<'a> {
     where
         F: Fn(&mut CodeWriter),
     {
-        self.expr_block(&format!("pub mod {}", name), cb)
+        self.expr_block(&format!("{} mod {}", self.pub_keyword, name), |w| {
+            w.write_use_preamble();
+            cb(w);
+        })
     }
 
     pub fn while_block<S: AsRef<str>, F>(&mut self, cond: S, cb: F)
@@ -1,6 +1,8 @@
 use protobuf::descriptor::FieldOptions;
+use protobuf::descriptor::FileDescriptorProto;
 use protobuf::descriptor::FileOptions;
 use protobuf::descriptor::MessageOptions;
+use protobuf::prelude::*;
 use protobuf::rustproto;
 
 /// Specifies style of generated code.
@@ -31,8 +33,89 @@ pub struct Customize {
     pub serde_derive: Option<bool>,
     /// When `serde_derive` is set, serde annotations will be guarded with `#[cfg(cfg, ...)]`.
     pub serde_derive_cfg: Option<String>,
+    /// When `serde_derive` is set, fields with default values are skipped when serializing.
+    pub serde_skip_default: Option<bool>,
     /// Enable lite runtime
     pub lite_runtime: Option<bool>,
+    /// Generate a directory with `mod.rs` and a file per top-level message or enum
+    /// instead of single `.rs` file
+    pub split_files: Option<bool>,
+    /// Implement `std::ops::Add` and `std::iter::Sum` for messages with single numeric field
+    pub numeric_wrapper: Option<bool>,
+    /// Implement `PartialEq<str>` and `PartialEq<&str>` for messages with single string field
+    pub string_wrapper: Option<bool>,
+    /// Generate `FooView<'a>` struct for each message `Foo`,
+    /// which borrows strings and bytes from the parsed buffer
+    /// (map and oneof fields are not supported)
+    pub generate_views: Option<bool>,
+    /// Implement `std::str::FromStr` for messages using JSON parser
+    pub json_fromstr: Option<bool>,
+    /// Generate nested messages and enums as top-level types named like `Outer_Inner`
+    /// instead of types in nested mods
+    pub flatten_nested: Option<bool>,
+    /// Generate a trait and methods metadata for each service
+    pub generate_services: Option<bool>,
+    /// Store presence of proto2 singular numeric and bool fields in a bitset
+    /// instead of wrapping each field in `Option`
+    pub presence_bitset: Option<bool>,
+    /// Generate `#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]` for messages and enums
+    ///
+    /// Crate with generated code needs optional `arbitrary` dependency with `derive` feature,
+    /// and `protobuf/with-arbitrary` feature enabled together with it.
+    pub derive_arbitrary: Option<bool>,
+    /// Implement `TryFrom<&serde_json::Value>` for messages using JSON mapping,
+    /// guarded by `serde_derive_cfg` if it is set
+    /// (requires `with-serde-json` feature of `protobuf` crate)
+    pub json_try_from_value: Option<bool>,
+    /// Generate `pub type Timestamp = ::protobuf::well_known_types::Timestamp;`
+    /// aliases for well-known types referenced by the file
+    pub reexport_well_known: Option<bool>,
+    /// Visibility of generated types, their fields and methods
+    pub type_visibility: Option<Visibility>,
+    /// Generate `#[allow(clippy::large_enum_variant)]` for oneof enums
+    /// with a variant holding a not boxed message
+    pub oneof_allow_large_enum_variant: Option<bool>,
+    /// Generate `mod.rs` declaring a module for each generated file
+    pub gen_mod_rs: Option<bool>,
+    /// Use `::core::` and `::alloc::` instead of `::std::` paths in generated code,
+    /// so it can be compiled in `#![no_std]` crate with `extern crate alloc;`
+    /// (map fields, `ip_addr` and `io_write_field` options are not supported)
+    pub no_std: Option<bool>,
+    /// Implement `Debug` for messages using text format printer
+    /// instead of deriving it (default unless `lite_runtime`)
+    pub debug_as_text_format: Option<bool>,
+    /// Store repeated numeric, `bool`, `string`, `bytes` and message fields
+    /// in `smallvec::SmallVec<[T; N]>`, which does not allocate below `N` elements
+    /// (requires `with-smallvec` feature of `protobuf` crate)
+    pub small_repeated: Option<u32>,
+    /// Implement `Hash` and `Eq` for messages, hashing `f32` and `f64` fields by their bits
+    /// (with `-0.0` hashed as `0.0`) and map fields by their length.
+    /// Note `NaN` is not equal to itself, so messages with `NaN` fields cannot be found in maps.
+    pub derive_hash_floats_by_bits: Option<bool>,
+    /// Implement `serde::Serialize` for messages using JSON mapping instead of deriving it,
+    /// guarded by `serde_derive_cfg` if it is set
+    /// (requires `with-serde` feature of `protobuf` crate)
+    pub json_serialize: Option<bool>,
+    /// Generate `into_parts(self)` returning `FooParts` struct with owned values of all fields
+    pub generate_into_parts: Option<bool>,
+    /// Implement `FromStr` for messages using text format parser
+    pub text_format_fromstr: Option<bool>,
+    /// Generate `append_xxx(&mut self, &[T])` appending clones of slice elements
+    /// to repeated fields
+    pub generate_append_slice: Option<bool>,
+    /// How generated code refers to `protobuf` crate items
+    /// (fully-qualified `::protobuf::` paths by default)
+    pub import_style: Option<ImportStyle>,
+    /// Implement `PartialEq`, `Eq`, `PartialOrd` and `Ord` for messages comparing fields in order,
+    /// `f32` and `f64` fields with `total_cmp`, so messages with float fields can be `BTreeMap` keys
+    /// (`-0.0` is less than `0.0`, and `NaN` is equal to itself and greater than infinity,
+    /// or less than negative infinity if negative).
+    /// Unknown fields are not compared.
+    /// Map and oneof fields are not supported; message fields must have this option too.
+    pub float_total_cmp: Option<bool>,
+    /// Store `string` fields as `ArcStr`, and share storage of equal values
+    /// read while parsing a message (e. g. repeated identical labels)
+    pub intern_strings: Option<bool>,
 
     // When adding more options please keep in sync with `parse_from_parameter` below.
     /// Make sure `Customize` is always used with `..Default::default()`
@@ -40,10 +123,46 @@ pub struct Customize {
     pub _future_options: (),
 }
 
+/// Visibility of generated code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    /// `pub`
+    Public,
+    /// `pub(crate)`, generated types are not exported from the crate
+    Crate,
+}
+
+impl Visibility {
+    /// Rust keyword
+    pub(crate) fn keyword(&self) -> &'static str {
+        match *self {
+            Visibility::Public => "pub",
+            Visibility::Crate => "pub(crate)",
+        }
+    }
+}
+
+/// How generated code refers to `protobuf` crate items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportStyle {
+    /// `::protobuf::rt::read_singular_message_into` etc.
+    FullyQualified,
+    /// `use ::protobuf::rt as pb_rt;` etc. at the top of each generated module
+    /// and `pb_rt::read_singular_message_into` etc. in the code.
+    ///
+    /// Imports are aliased with `pb` prefix, so they don't clash with generated types
+    /// (e. g. message named `Message`); messages, enums and nested message mods
+    /// must not be named like aliases (`pb`, `pb_rt`, `pb_reflect`, `pb_types`, `pb_wire_format`).
+    UsePreamble,
+}
+
 #[derive(Debug)]
 pub enum CustomizeParseParameterError {
     EqNotFound,
     CannotParseBool,
+    CannotParseVisibility,
+    CannotParseImportStyle,
+    CannotParseInt,
     UnknownOptionName(String),
 }
 
@@ -85,9 +204,87 @@ impl Customize {
         if let Some(ref v) = that.serde_derive_cfg {
             self.serde_derive_cfg = Some(v.clone());
         }
+        if let Some(v) = that.serde_skip_default {
+            self.serde_skip_default = Some(v);
+        }
         if let Some(v) = that.lite_runtime {
             self.lite_runtime = Some(v);
         }
+        if let Some(v) = that.split_files {
+            self.split_files = Some(v);
+        }
+        if let Some(v) = that.numeric_wrapper {
+            self.numeric_wrapper = Some(v);
+        }
+        if let Some(v) = that.string_wrapper {
+            self.string_wrapper = Some(v);
+        }
+        if let Some(v) = that.generate_views {
+            self.generate_views = Some(v);
+        }
+        if let Some(v) = that.json_fromstr {
+            self.json_fromstr = Some(v);
+        }
+        if let Some(v) = that.flatten_nested {
+            self.flatten_nested = Some(v);
+        }
+        if let Some(v) = that.generate_services {
+            self.generate_services = Some(v);
+        }
+        if let Some(v) = that.presence_bitset {
+            self.presence_bitset = Some(v);
+        }
+        if let Some(v) = that.derive_arbitrary {
+            self.derive_arbitrary = Some(v);
+        }
+        if let Some(v) = that.json_try_from_value {
+            self.json_try_from_value = Some(v);
+        }
+        if let Some(v) = that.reexport_well_known {
+            self.reexport_well_known = Some(v);
+        }
+        if let Some(v) = that.type_visibility {
+            self.type_visibility = Some(v);
+        }
+        if let Some(v) = that.oneof_allow_large_enum_variant {
+            self.oneof_allow_large_enum_variant = Some(v);
+        }
+        if let Some(v) = that.gen_mod_rs {
+            self.gen_mod_rs = Some(v);
+        }
+        if let Some(v) = that.no_std {
+            self.no_std = Some(v);
+        }
+        if let Some(v) = that.debug_as_text_format {
+            self.debug_as_text_format = Some(v);
+        }
+        if let Some(v) = that.small_repeated {
+            self.small_repeated = Some(v);
+        }
+        if let Some(v) = that.derive_hash_floats_by_bits {
+            self.derive_hash_floats_by_bits = Some(v);
+        }
+        if let Some(v) = that.json_serialize {
+            self.json_serialize = Some(v);
+        }
+        if let Some(v) = that.generate_into_parts {
+            self.generate_into_parts = Some(v);
+        }
+        if let Some(v) = that.text_format_fromstr {
+            self.text_format_fromstr = Some(v);
+        }
+        if let Some(v) = that.generate_append_slice {
+            self.generate_append_slice = Some(v);
+        }
+        if let Some(v) = that.import_style {
+            self.import_style = Some(v);
+        }
+        if let Some(v) = that.float_total_cmp {
+            self.float_total_cmp = Some(v);
+        }
+        if let Some(v) = that.intern_strings {
+            self.intern_strings = Some(v);
+        }
     }
 
     /// Update unset fields of self with fields from other customize
@@ -104,6 +301,27 @@ impl Customize {
                 .map_err(|_| CustomizeParseParameterError::CannotParseBool)
         }
 
+        fn parse_u32(v: &str) -> CustomizeParseParameterResult<u32> {
+            v.parse()
+                .map_err(|_| CustomizeParseParameterError::CannotParseInt)
+        }
+
+        fn parse_visibility(v: &str) -> CustomizeParseParameterResult<Visibility> {
+            match v {
+                "pub" => Ok(Visibility::Public),
+                "crate" => Ok(Visibility::Crate),
+                _ => Err(CustomizeParseParameterError::CannotParseVisibility),
+            }
+        }
+
+        fn parse_import_style(v: &str) -> CustomizeParseParameterResult<ImportStyle> {
+            match v {
+                "fully_qualified" => Ok(ImportStyle::FullyQualified),
+                "use_preamble" => Ok(ImportStyle::UsePreamble),
+                _ => Err(CustomizeParseParameterError::CannotParseImportStyle),
+            }
+        }
+
         let mut r = Customize::default();
         for nv in parameter.split_whitespace() {
             let eq = match nv.find('=') {
@@ -136,8 +354,60 @@ impl Customize {
                 r.serde_derive = Some(parse_bool(v)?);
             } else if n == "serde_derive_cfg" {
                 r.serde_derive_cfg = Some(v.to_owned());
+            } else if n == "serde_skip_default" {
+                r.serde_skip_default = Some(parse_bool(v)?);
             } else if n == "lite_runtime" {
                 r.lite_runtime = Some(parse_bool(v)?);
+            } else if n == "split_files" {
+                r.split_files = Some(parse_bool(v)?);
+            } else if n == "numeric_wrapper" {
+                r.numeric_wrapper = Some(parse_bool(v)?);
+            } else if n == "string_wrapper" {
+                r.string_wrapper = Some(parse_bool(v)?);
+            } else if n == "generate_views" {
+                r.generate_views = Some(parse_bool(v)?);
+            } else if n == "json_fromstr" {
+                r.json_fromstr = Some(parse_bool(v)?);
+            } else if n == "flatten_nested" {
+                r.flatten_nested = Some(parse_bool(v)?);
+            } else if n == "generate_services" {
+                r.generate_services = Some(parse_bool(v)?);
+            } else if n == "presence_bitset" {
+                r.presence_bitset = Some(parse_bool(v)?);
+            } else if n == "derive_arbitrary" {
+                r.derive_arbitrary = Some(parse_bool(v)?);
+            } else if n == "json_try_from_value" {
+                r.json_try_from_value = Some(parse_bool(v)?);
+            } else if n == "reexport_well_known" {
+                r.reexport_well_known = Some(parse_bool(v)?);
+            } else if n == "type_visibility" {
+                r.type_visibility = Some(parse_visibility(v)?);
+            } else if n == "oneof_allow_large_enum_variant" {
+                r.oneof_allow_large_enum_variant = Some(parse_bool(v)?);
+            } else if n == "gen_mod_rs" {
+                r.gen_mod_rs = Some(parse_bool(v)?);
+            } else if n == "no_std" {
+                r.no_std = Some(parse_bool(v)?);
+            } else if n == "debug_as_text_format" {
+                r.debug_as_text_format = Some(parse_bool(v)?);
+            } else if n == "small_repeated" {
+                r.small_repeated = Some(parse_u32(v)?);
+            } else if n == "derive_hash_floats_by_bits" {
+                r.derive_hash_floats_by_bits = Some(parse_bool(v)?);
+            } else if n == "json_serialize" {
+                r.json_serialize = Some(parse_bool(v)?);
+            } else if n == "generate_into_parts" {
+                r.generate_into_parts = Some(parse_bool(v)?);
+            } else if n == "text_format_fromstr" {
+                r.text_format_fromstr = Some(parse_bool(v)?);
+            } else if n == "generate_append_slice" {
+                r.generate_append_slice = Some(parse_bool(v)?);
+            } else if n == "import_style" {
+                r.import_style = Some(parse_import_style(v)?);
+            } else if n == "float_total_cmp" {
+                r.float_total_cmp = Some(parse_bool(v)?);
+            } else if n == "intern_strings" {
+                r.intern_strings = Some(parse_bool(v)?);
             } else {
                 return Err(CustomizeParseParameterError::UnknownOptionName(
                     n.to_owned(),
@@ -160,7 +430,33 @@ pub fn customize_from_rustproto_for_message(source: &MessageOptions) -> Customiz
     let singular_field_option = rustproto::exts::singular_field_option.get(source);
     let serde_derive = rustproto::exts::serde_derive.get(source);
     let serde_derive_cfg = rustproto::exts::serde_derive_cfg.get(source);
+    let serde_skip_default = rustproto::exts::serde_skip_default.get(source);
     let lite_runtime = None;
+    let split_files = None;
+    let numeric_wrapper = rustproto::exts::numeric_wrapper.get(source);
+    let string_wrapper = rustproto::exts::string_wrapper.get(source);
+    let generate_views = rustproto::exts::generate_views.get(source);
+    let json_fromstr = rustproto::exts::json_fromstr.get(source);
+    let flatten_nested = None;
+    let generate_services = None;
+    let presence_bitset = rustproto::exts::presence_bitset.get(source);
+    let derive_arbitrary = rustproto::exts::derive_arbitrary.get(source);
+    let json_try_from_value = rustproto::exts::json_try_from_value.get(source);
+    let reexport_well_known = None;
+    let type_visibility = None;
+    let oneof_allow_large_enum_variant = rustproto::exts::oneof_allow_large_enum_variant.get(source);
+    let gen_mod_rs = None;
+    let no_std = None;
+    let debug_as_text_format = rustproto::exts::debug_as_text_format.get(source);
+    let small_repeated = rustproto::exts::small_repeated.get(source);
+    let derive_hash_floats_by_bits = rustproto::exts::derive_hash_floats_by_bits.get(source);
+    let json_serialize = rustproto::exts::json_serialize.get(source);
+    let generate_into_parts = rustproto::exts::generate_into_parts.get(source);
+    let text_format_fromstr = rustproto::exts::text_format_fromstr.get(source);
+    let generate_append_slice = rustproto::exts::generate_append_slice.get(source);
+    let import_style = None;
+    let float_total_cmp = rustproto::exts::float_total_cmp.get(source);
+    let intern_strings = rustproto::exts::intern_strings.get(source);
     Customize {
         expose_oneof,
         expose_fields,
@@ -173,7 +469,33 @@ pub fn customize_from_rustproto_for_message(source: &MessageOptions) -> Customiz
         singular_field_option,
         serde_derive,
         serde_derive_cfg,
+        serde_skip_default,
         lite_runtime,
+        split_files,
+        numeric_wrapper,
+        string_wrapper,
+        generate_views,
+        json_fromstr,
+        flatten_nested,
+        generate_services,
+        presence_bitset,
+        derive_arbitrary,
+        json_try_from_value,
+        reexport_well_known,
+        type_visibility,
+        oneof_allow_large_enum_variant,
+        gen_mod_rs,
+        no_std,
+        debug_as_text_format,
+        small_repeated,
+        derive_hash_floats_by_bits,
+        json_serialize,
+        generate_into_parts,
+        text_format_fromstr,
+        generate_append_slice,
+        import_style,
+        float_total_cmp,
+        intern_strings,
         _future_options: (),
     }
 }
@@ -191,7 +513,33 @@ pub fn customize_from_rustproto_for_field(source: &FieldOptions) -> Customize {
     let singular_field_option = rustproto::exts::singular_field_option_field.get(source);
     let serde_derive = None;
     let serde_derive_cfg = None;
+    let serde_skip_default = None;
     let lite_runtime = None;
+    let split_files = None;
+    let numeric_wrapper = None;
+    let string_wrapper = None;
+    let generate_views = None;
+    let json_fromstr = None;
+    let flatten_nested = None;
+    let generate_services = None;
+    let presence_bitset = None;
+    let derive_arbitrary = None;
+    let json_try_from_value = None;
+    let reexport_well_known = None;
+    let type_visibility = None;
+    let oneof_allow_large_enum_variant = None;
+    let gen_mod_rs = None;
+    let no_std = None;
+    let debug_as_text_format = None;
+    let small_repeated = rustproto::exts::small_repeated_field.get(source);
+    let derive_hash_floats_by_bits = None;
+    let json_serialize = None;
+    let generate_into_parts = None;
+    let text_format_fromstr = None;
+    let generate_append_slice = rustproto::exts::generate_append_slice_field.get(source);
+    let import_style = None;
+    let float_total_cmp = None;
+    let intern_strings = rustproto::exts::intern_strings_field.get(source);
     Customize {
         expose_oneof,
         expose_fields,
@@ -204,7 +552,33 @@ pub fn customize_from_rustproto_for_field(source: &FieldOptions) -> Customize {
         singular_field_option,
         serde_derive,
         serde_derive_cfg,
+        serde_skip_default,
         lite_runtime,
+        split_files,
+        numeric_wrapper,
+        string_wrapper,
+        generate_views,
+        json_fromstr,
+        flatten_nested,
+        generate_services,
+        presence_bitset,
+        derive_arbitrary,
+        json_try_from_value,
+        reexport_well_known,
+        type_visibility,
+        oneof_allow_large_enum_variant,
+        gen_mod_rs,
+        no_std,
+        debug_as_text_format,
+        small_repeated,
+        derive_hash_floats_by_bits,
+        json_serialize,
+        generate_into_parts,
+        text_format_fromstr,
+        generate_append_slice,
+        import_style,
+        float_total_cmp,
+        intern_strings,
         _future_options: (),
     }
 }
@@ -221,7 +595,39 @@ pub fn customize_from_rustproto_for_file(source: &FileOptions) -> Customize {
     let singular_field_option = rustproto::exts::singular_field_option_all.get(source);
     let serde_derive = rustproto::exts::serde_derive_all.get(source);
     let serde_derive_cfg = rustproto::exts::serde_derive_cfg_all.get(source);
+    let serde_skip_default = rustproto::exts::serde_skip_default_all.get(source);
     let lite_runtime = rustproto::exts::lite_runtime_all.get(source);
+    let split_files = rustproto::exts::split_files_all.get(source);
+    let numeric_wrapper = None;
+    let string_wrapper = None;
+    let generate_views = rustproto::exts::generate_views_all.get(source);
+    let json_fromstr = rustproto::exts::json_fromstr_all.get(source);
+    let flatten_nested = rustproto::exts::flatten_nested_all.get(source);
+    let generate_services = rustproto::exts::generate_services_all.get(source);
+    let presence_bitset = rustproto::exts::presence_bitset_all.get(source);
+    let derive_arbitrary = rustproto::exts::derive_arbitrary_all.get(source);
+    let json_try_from_value = rustproto::exts::json_try_from_value_all.get(source);
+    let reexport_well_known = rustproto::exts::reexport_well_known_all.get(source);
+    let type_visibility = rustproto::exts::crate_visibility_all
+        .get(source)
+        .map(|crate_visibility| match crate_visibility {
+            true => Visibility::Crate,
+            false => Visibility::Public,
+        });
+    let oneof_allow_large_enum_variant =
+        rustproto::exts::oneof_allow_large_enum_variant_all.get(source);
+    let gen_mod_rs = None;
+    let no_std = None;
+    let debug_as_text_format = rustproto::exts::debug_as_text_format_all.get(source);
+    let small_repeated = rustproto::exts::small_repeated_all.get(source);
+    let derive_hash_floats_by_bits = rustproto::exts::derive_hash_floats_by_bits_all.get(source);
+    let json_serialize = rustproto::exts::json_serialize_all.get(source);
+    let generate_into_parts = rustproto::exts::generate_into_parts_all.get(source);
+    let text_format_fromstr = rustproto::exts::text_format_fromstr_all.get(source);
+    let generate_append_slice = rustproto::exts::generate_append_slice_all.get(source);
+    let import_style = None;
+    let float_total_cmp = rustproto::exts::float_total_cmp_all.get(source);
+    let intern_strings = rustproto::exts::intern_strings_all.get(source);
     Customize {
         expose_oneof,
         expose_fields,
@@ -234,7 +640,42 @@ pub fn customize_from_rustproto_for_file(source: &FileOptions) -> Customize {
         singular_field_option,
         serde_derive,
         serde_derive_cfg,
+        serde_skip_default,
         lite_runtime,
+        split_files,
+        numeric_wrapper,
+        string_wrapper,
+        generate_views,
+        json_fromstr,
+        flatten_nested,
+        generate_services,
+        presence_bitset,
+        derive_arbitrary,
+        json_try_from_value,
+        reexport_well_known,
+        type_visibility,
+        oneof_allow_large_enum_variant,
+        gen_mod_rs,
+        no_std,
+        debug_as_text_format,
+        small_repeated,
+        derive_hash_floats_by_bits,
+        json_serialize,
+        generate_into_parts,
+        text_format_fromstr,
+        generate_append_slice,
+        import_style,
+        float_total_cmp,
+        intern_strings,
         _future_options: (),
     }
 }
+
+/// Customize for given file: invocation options updated with options specified in the file
+pub(crate) fn customize_for_file(customize: &Customize, file: &FileDescriptorProto) -> Customize {
+    let mut customize = customize.clone();
+    customize.update_with(&customize_from_rustproto_for_file(
+        file.options.get_message(),
+    ));
+    customize
+}
@@ -7,6 +7,7 @@ use protobuf::prelude::*;
 use super::code_writer::*;
 use super::customize::Customize;
 use serde;
+use arbitrary;
 use scope::{EnumWithScope, EnumValueWithContext};
 use scope::RootScope;
 use scope::WithScope;
@@ -121,6 +122,10 @@ impl<'a> EnumGen<'a> {
         self.write_impl_default(w);
         w.write_line("");
         self.write_impl_value(w);
+        w.write_line("");
+        self.write_impl_from_for_str(w);
+        w.write_line("");
+        self.write_impl_eq_enum_or_unknown(w);
     }
 
     fn write_struct(&self, w: &mut CodeWriter) {
@@ -132,6 +137,8 @@ impl<'a> EnumGen<'a> {
         }
         derive.push("Eq");
         derive.push("Debug");
+        // enums are always `Eq` and `Hash`,
+        // `PartialEq` and `Hash` of enums with aliases are implemented by value
         if !self.allow_alias() {
             derive.push("Hash");
         } else {
@@ -139,9 +146,20 @@ impl<'a> EnumGen<'a> {
         }
         w.derive(&derive);
         serde::write_serde_attr(w, &self.customize, "derive(Serialize, Deserialize)");
+        arbitrary::write_arbitrary_attr(w, &self.customize, "derive(Arbitrary)");
+        if self.enum_with_scope.en.options.get_message().get_deprecated() {
+            w.deprecated(&format!("enum `{}` is deprecated", self.enum_with_scope.name_absolute()));
+        }
         let ref type_name = self.type_name;
-        w.expr_block(&format!("pub enum {}", type_name), |w| {
+        w.expr_block(&format!("{} enum {}", w.pub_keyword(), type_name), |w| {
             for value in self.values_all() {
+                if value.value.proto.options.get_message().get_deprecated() {
+                    w.deprecated(&format!(
+                        "enum value `{}.{}` is deprecated",
+                        self.enum_with_scope.name_absolute(),
+                        value.value.proto.get_name()
+                    ));
+                }
                 if self.allow_alias() {
                     w.write_line(&format!(
                         "{}, // {}",
@@ -191,6 +209,18 @@ impl<'a> EnumGen<'a> {
                 });
             });
 
+            w.write_line("");
+            w.def_fn("name(&self) -> &'static str", |w| {
+                w.match_expr("*self", |w| {
+                    for value in self.values_all() {
+                        w.case_expr(
+                            &format!("{}", value.rust_name_outer()),
+                            &format!("\"{}\"", value.value.proto.get_name()),
+                        );
+                    }
+                });
+            });
+
             w.write_line("");
             w.def_fn(&format!("values() -> &'static [Self]"), |w| {
                 w.write_line(&format!("static values: &'static [{}] = &[", type_name));
@@ -226,6 +256,18 @@ impl<'a> EnumGen<'a> {
         )
     }
 
+    fn write_impl_from_for_str(&self, w: &mut CodeWriter) {
+        w.impl_for_block(
+            &format!("::std::convert::From<{}>", self.type_name),
+            "&'static str",
+            |w| {
+                w.def_fn(&format!("from(value: {}) -> &'static str", self.type_name), |w| {
+                    w.write_line("::protobuf::ProtobufEnum::name(&value)");
+                });
+            },
+        );
+    }
+
     fn write_impl_eq(&self, w: &mut CodeWriter) {
         assert!(self.allow_alias());
         w.impl_for_block("::std::cmp::PartialEq", &format!("{}", self.type_name), |w| {
@@ -235,6 +277,27 @@ impl<'a> EnumGen<'a> {
         });
     }
 
+    fn write_impl_eq_enum_or_unknown(&self, w: &mut CodeWriter) {
+        w.impl_for_block(
+            &format!(
+                "::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<{}>>",
+                self.type_name
+            ),
+            &format!("{}", self.type_name),
+            |w| {
+                w.def_fn(
+                    &format!(
+                        "eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<{}>) -> bool",
+                        self.type_name
+                    ),
+                    |w| {
+                        w.write_line("other == self");
+                    },
+                );
+            },
+        );
+    }
+
     fn write_impl_hash(&self, w: &mut CodeWriter) {
         assert!(self.allow_alias());
         w.impl_for_block("::std::hash::Hash", &format!("{}", self.type_name), |w| {
@@ -22,6 +22,7 @@ impl<'a> ExtGen<'a> {
             &FileAndMod {
                 file: self.file.get_name().to_owned(),
                 relative_mod: RustRelativePath::from("exts"),
+                in_split_file: false,
             },
             self.root_scope)
     }
@@ -43,6 +44,7 @@ impl<'a> ExtGen<'a> {
                 &FileAndMod {
                     file: self.file.get_name().to_owned(),
                     relative_mod: RustRelativePath::from("exts"),
+                    in_split_file: false,
                 },
                 self.root_scope,
             );
@@ -0,0 +1,58 @@
+//! Resolution of features of files with `syntax = "editions"`.
+
+use protobuf::descriptor::feature_set;
+use protobuf::descriptor::Edition;
+use protobuf::descriptor::FeatureSet;
+use protobuf::prelude::*;
+
+use scope::FieldWithContext;
+
+/// Features used by codegen, resolved for a single field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FieldFeatures {
+    pub field_presence: feature_set::FieldPresence,
+    pub repeated_field_encoding: feature_set::RepeatedFieldEncoding,
+}
+
+impl FieldFeatures {
+    fn edition_defaults(edition: Edition) -> FieldFeatures {
+        match edition {
+            Edition::EDITION_2023 | Edition::EDITION_2024 => FieldFeatures {
+                field_presence: feature_set::FieldPresence::EXPLICIT,
+                repeated_field_encoding: feature_set::RepeatedFieldEncoding::PACKED,
+            },
+            Edition::EDITION_PROTO2 => FieldFeatures {
+                field_presence: feature_set::FieldPresence::EXPLICIT,
+                repeated_field_encoding: feature_set::RepeatedFieldEncoding::EXPANDED,
+            },
+            Edition::EDITION_PROTO3 => FieldFeatures {
+                field_presence: feature_set::FieldPresence::IMPLICIT,
+                repeated_field_encoding: feature_set::RepeatedFieldEncoding::PACKED,
+            },
+            Edition::EDITION_UNKNOWN => panic!("edition is not specified"),
+        }
+    }
+
+    // features explicitly set in more specific scope override inherited features
+    fn merge_from(&mut self, features: &FeatureSet) {
+        if features.has_field_presence() {
+            self.field_presence = features.get_field_presence();
+        }
+        if features.has_repeated_field_encoding() {
+            self.repeated_field_encoding = features.get_repeated_field_encoding();
+        }
+    }
+
+    /// Resolve features of a field: edition defaults are overridden
+    /// by features of the file, containing messages and the field itself
+    pub fn resolve(field: &FieldWithContext) -> FieldFeatures {
+        let file = field.message.scope.get_file_descriptor();
+        let mut r = FieldFeatures::edition_defaults(file.get_edition());
+        r.merge_from(file.options.get_message().features.get_message());
+        for message in field.containing_messages().into_iter().rev() {
+            r.merge_from(message.options.get_message().features.get_message());
+        }
+        r.merge_from(field.field.options.get_message().features.get_message());
+        r
+    }
+}
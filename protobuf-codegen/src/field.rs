@@ -2,7 +2,9 @@ use protobuf::prelude::*;
 
 use protobuf::descriptor::*;
 use protobuf::rt;
+use protobuf::rustproto;
 use rust;
+use serde;
 use ProtobufAbsolutePath;
 use protobuf::text_format;
 use protobuf::text_format::lexer::float;
@@ -27,6 +29,7 @@ use scope::FieldWithContext;
 use scope::RootScope;
 use scope::WithScope;
 use syntax::Syntax;
+use features::FieldFeatures;
 use file_and_mod::FileAndMod;
 
 
@@ -40,15 +43,26 @@ fn type_is_copy(field_type: field_descriptor_proto::Type) -> bool {
 }
 
 trait FieldDescriptorProtoTypeExt {
-    fn read(&self, is: &str, primitive_type_variant: PrimitiveTypeVariant) -> String;
+    fn read(
+        &self,
+        is: &str,
+        field_name: &str,
+        primitive_type_variant: PrimitiveTypeVariant,
+    ) -> String;
     fn is_s_varint(&self) -> bool;
 }
 
 impl FieldDescriptorProtoTypeExt for field_descriptor_proto::Type {
-    fn read(&self, is: &str, primitive_type_variant: PrimitiveTypeVariant) -> String {
+    fn read(
+        &self,
+        is: &str,
+        field_name: &str,
+        primitive_type_variant: PrimitiveTypeVariant,
+    ) -> String {
         match *self {
             field_descriptor_proto::Type::TYPE_ENUM => {
-                format!("{}.read_enum_or_unknown()", is)
+                // field name is used in error when unknown enum values are rejected
+                format!("{}.read_enum_or_unknown_for_field({:?})", is, field_name)
             }
             _ => {
                 match primitive_type_variant {
@@ -60,6 +74,7 @@ impl FieldDescriptorProtoTypeExt for field_descriptor_proto::Type {
                         };
                         format!("{}.read_carllerche_{}()", is, protobuf_name)
                     }
+                    PrimitiveTypeVariant::Interned => format!("{}.read_interned_string()", is),
                 }
             }
         }
@@ -141,6 +156,31 @@ fn field_type_size(field_type: field_descriptor_proto::Type) -> Option<u32> {
     }
 }
 
+/// Field types which presence can be stored in message bitset
+fn is_presence_bitset_type(field_type: field_descriptor_proto::Type) -> bool {
+    match field_type {
+        field_descriptor_proto::Type::TYPE_DOUBLE
+        | field_descriptor_proto::Type::TYPE_FLOAT
+        | field_descriptor_proto::Type::TYPE_INT32
+        | field_descriptor_proto::Type::TYPE_INT64
+        | field_descriptor_proto::Type::TYPE_UINT32
+        | field_descriptor_proto::Type::TYPE_UINT64
+        | field_descriptor_proto::Type::TYPE_SINT32
+        | field_descriptor_proto::Type::TYPE_SINT64
+        | field_descriptor_proto::Type::TYPE_FIXED32
+        | field_descriptor_proto::Type::TYPE_FIXED64
+        | field_descriptor_proto::Type::TYPE_SFIXED32
+        | field_descriptor_proto::Type::TYPE_SFIXED64
+        | field_descriptor_proto::Type::TYPE_BOOL => true,
+        _ => false,
+    }
+}
+
+/// Field types which can be encoded as packed repeated fields
+fn is_packable_type(field_type: field_descriptor_proto::Type) -> bool {
+    is_presence_bitset_type(field_type) || field_type == field_descriptor_proto::Type::TYPE_ENUM
+}
+
 /// Optional fields can be stored are `Option<T>`, `SingularField<T>` or `SingularPtrField<T>`.
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum OptionKind {
@@ -231,6 +271,13 @@ pub enum SingularFieldFlag {
     },
     // proto3
     WithoutFlag,
+    // proto2 numeric or bool field with `presence_bitset` option:
+    // value is stored unwrapped, and presence is stored in message bitset
+    WithBit {
+        required: bool,
+        // index in message bitset, assigned by message
+        bit: u32,
+    },
 }
 
 impl SingularFieldFlag {
@@ -238,6 +285,7 @@ impl SingularFieldFlag {
         match *self {
             SingularFieldFlag::WithFlag { required, .. } => required,
             SingularFieldFlag::WithoutFlag => false,
+            SingularFieldFlag::WithBit { required, .. } => required,
         }
     }
 }
@@ -254,16 +302,19 @@ impl<'a> SingularField<'a> {
             SingularFieldFlag::WithFlag { option_kind, .. } => {
                 option_kind.wrap_element(self.elem.rust_storage_elem_type(reference))
             }
-            SingularFieldFlag::WithoutFlag => self.elem.rust_storage_elem_type(reference),
+            SingularFieldFlag::WithoutFlag | SingularFieldFlag::WithBit { .. } => {
+                self.elem.rust_storage_elem_type(reference)
+            }
         }
     }
 }
 
-/// Repeated field can be `Vec<T>` or `RepeatedField<T>`.
+/// Repeated field can be `Vec<T>`, `RepeatedField<T>` or `SmallVec<[T; N]>`.
 #[derive(Eq, PartialEq, Copy, Clone)]
 pub enum RepeatedFieldKind {
     Vec,
     RepeatedField,
+    SmallVec(u32),
 }
 
 impl RepeatedFieldKind {
@@ -272,6 +323,7 @@ impl RepeatedFieldKind {
         match self {
             RepeatedFieldKind::Vec => RustType::Vec(element_type),
             RepeatedFieldKind::RepeatedField => RustType::RepeatedField(element_type),
+            RepeatedFieldKind::SmallVec(n) => RustType::SmallVec(*n, element_type),
         }
     }
 }
@@ -281,12 +333,16 @@ pub(crate) struct RepeatedField<'a> {
     pub elem: FieldElem<'a>,
     pub packed: bool,
     pub repeated_field_vec: bool,
+    // inline capacity of `SmallVec` storing this field
+    pub small_repeated: Option<u32>,
 }
 
 impl<'a> RepeatedField<'a> {
     fn kind(&self) -> RepeatedFieldKind {
-        if !self.elem.is_copy()
-            && self.elem.primitive_type_variant() != PrimitiveTypeVariant::Carllerche
+        if let Some(n) = self.small_repeated {
+            RepeatedFieldKind::SmallVec(n)
+        } else if !self.elem.is_copy()
+            && self.elem.primitive_type_variant() == PrimitiveTypeVariant::Default
             && !self.repeated_field_vec
         {
             RepeatedFieldKind::RepeatedField
@@ -424,6 +480,11 @@ impl<'a> FieldElem<'a> {
                 PrimitiveTypeVariant::Carllerche,
             ) => RustType::Bytes,
             FieldElem::Primitive(.., PrimitiveTypeVariant::Carllerche) => unreachable!(),
+            FieldElem::Primitive(
+                field_descriptor_proto::Type::TYPE_STRING,
+                PrimitiveTypeVariant::Interned,
+            ) => RustType::ArcStr,
+            FieldElem::Primitive(.., PrimitiveTypeVariant::Interned) => unreachable!(),
             FieldElem::Group => RustType::Group,
             FieldElem::Message(ref m) => m.rust_type(reference),
             FieldElem::Enum(ref en) => en.enum_or_unknown_rust_type(reference),
@@ -459,6 +520,19 @@ impl<'a> FieldElem<'a> {
             _ => PrimitiveTypeVariant::Default,
         }
     }
+
+    // expression to shrink element referenced by `&mut` variable,
+    // `None` if element does not own a buffer
+    fn shrink_to_fit_expr(&self, var: &str) -> Option<String> {
+        match *self {
+            FieldElem::Message(..) => Some(format!("::protobuf::Message::shrink_to_fit({})", var)),
+            FieldElem::Primitive(field_descriptor_proto::Type::TYPE_STRING, PrimitiveTypeVariant::Default) |
+            FieldElem::Primitive(field_descriptor_proto::Type::TYPE_BYTES, PrimitiveTypeVariant::Default) => {
+                Some(format!("{}.shrink_to_fit()", var))
+            }
+            _ => None,
+        }
+    }
 }
 
 fn field_elem<'a>(
@@ -510,6 +584,14 @@ fn field_elem<'a>(
     } else if field.field.has_field_type() {
         let carllerche_for_bytes = customize.carllerche_bytes_for_bytes.unwrap_or(false);
         let carllerche_for_string = customize.carllerche_bytes_for_string.unwrap_or(false);
+        let intern_strings = customize.intern_strings.unwrap_or(false);
+        if carllerche_for_string && intern_strings {
+            panic!(
+                "field {} of message {}: `intern_strings` cannot be used with `carllerche_bytes_for_string`",
+                field.field.get_name(),
+                field.message.name_to_package()
+            );
+        }
 
         let elem = match field.field.get_field_type() {
             field_descriptor_proto::Type::TYPE_STRING if carllerche_for_string => {
@@ -518,6 +600,10 @@ fn field_elem<'a>(
                     PrimitiveTypeVariant::Carllerche,
                 )
             }
+            field_descriptor_proto::Type::TYPE_STRING if intern_strings => FieldElem::Primitive(
+                field_descriptor_proto::Type::TYPE_STRING,
+                PrimitiveTypeVariant::Interned,
+            ),
             field_descriptor_proto::Type::TYPE_BYTES if carllerche_for_bytes => FieldElem::Primitive(
                 field_descriptor_proto::Type::TYPE_BYTES,
                 PrimitiveTypeVariant::Carllerche,
@@ -568,6 +654,14 @@ pub(crate) struct FieldGen<'a> {
     pub generate_accessors: bool,
     pub generate_getter: bool,
     customize: Customize,
+    // `::std::net::IpAddr` etc. for `bytes` field with `ip_addr` option
+    ip_addr: Option<String>,
+    // `bytes` field with `uuid` option
+    uuid: bool,
+    // message field with `lazy` option is stored as `LazyMessage`
+    lazy: bool,
+    // singular message field with `serde_flatten` option
+    serde_flatten: bool,
 }
 
 impl<'a> FieldGen<'a> {
@@ -586,22 +680,56 @@ impl<'a> FieldGen<'a> {
 
         let syntax = field.message.scope.file_scope.syntax();
 
-        let field_may_have_custo_default_value = syntax == Syntax::PROTO2
+        let features = match syntax {
+            Syntax::EDITIONS => Some(FieldFeatures::resolve(&field)),
+            Syntax::PROTO2 | Syntax::PROTO3 => None,
+        };
+
+        // singular non-message field value can be distinguished from unset field
+        let explicit_presence = match features {
+            Some(features) => {
+                features.field_presence != feature_set::FieldPresence::IMPLICIT
+            }
+            None => syntax == Syntax::PROTO2,
+        };
+
+        let required = field.field.get_label() == field_descriptor_proto::Label::LABEL_REQUIRED
+            || features.map_or(false, |features| {
+                features.field_presence == feature_set::FieldPresence::LEGACY_REQUIRED
+            });
+
+        let field_may_have_custo_default_value = explicit_presence
             && field.field.get_label() != field_descriptor_proto::Label::LABEL_REPEATED
             && field.field.get_field_type() != field_descriptor_proto::Type::TYPE_MESSAGE;
 
+        let lazy = rustproto::exts::lazy
+            .get(field.field.options.get_message())
+            .unwrap_or(false);
+
         let default_expose_field = !field_may_have_custo_default_value;
-        let expose_field = customize.expose_fields.unwrap_or(default_expose_field);
+        // `LazyMessage` is accessed directly, accessors are not generated
+        let expose_field = customize.expose_fields.unwrap_or(default_expose_field) || lazy;
 
         let default_generate_accessors = !expose_field;
-        let generate_accessors = customize
+        let generate_accessors = (customize
             .generate_accessors
             .unwrap_or(default_generate_accessors)
-            || field.is_oneof();
+            || field.is_oneof())
+            && !lazy;
 
         let default_generate_getter = generate_accessors || field_may_have_custo_default_value;
         let generate_getter =
-            customize.generate_getter.unwrap_or(default_generate_getter) || field.is_oneof();
+            (customize.generate_getter.unwrap_or(default_generate_getter) || field.is_oneof())
+                && !lazy;
+
+        // field value is meaningless without presence bit
+        let presence_bit = field.field.get_label() != field_descriptor_proto::Label::LABEL_REPEATED
+            && !field.is_oneof()
+            && explicit_presence
+            && customize.presence_bitset.unwrap_or(false)
+            && is_presence_bitset_type(field.field.get_field_type());
+        let generate_accessors = generate_accessors || presence_bit;
+        let generate_getter = generate_getter || presence_bit;
 
         let kind = if field.field.get_label() == field_descriptor_proto::Label::LABEL_REPEATED {
             match (elem, true) {
@@ -613,21 +741,36 @@ impl<'a> FieldGen<'a> {
                 }),
                 // regular repeated field
                 (elem, _) => FieldKind::Repeated(RepeatedField {
+                    small_repeated: if !lazy && supports_small_repeated(&field, &elem) {
+                        customize.small_repeated
+                    } else {
+                        None
+                    },
                     elem,
-                    packed: field.field.options.get_message().get_packed(),
+                    packed: match features {
+                        Some(features) => {
+                            features.repeated_field_encoding
+                                == feature_set::RepeatedFieldEncoding::PACKED
+                                && is_packable_type(field.field.get_field_type())
+                        }
+                        None => field.field.options.get_message().get_packed(),
+                    },
                     repeated_field_vec: customize.repeated_field_vec.unwrap_or(false),
                 }),
             }
         } else if let Some(oneof) = field.oneof() {
             FieldKind::Oneof(OneofField::parse(&oneof, &field, elem))
         } else {
-            let flag = if field.message.scope.file_scope.syntax() == Syntax::PROTO3
+            let flag = if !explicit_presence
                 && field.field.get_field_type() != field_descriptor_proto::Type::TYPE_MESSAGE
             {
                 SingularFieldFlag::WithoutFlag
+            } else if presence_bit {
+                SingularFieldFlag::WithBit {
+                    required,
+                    bit: 0,
+                }
             } else {
-                let required =
-                    field.field.get_label() == field_descriptor_proto::Label::LABEL_REQUIRED;
                 let option_kind = match field.field.get_field_type() {
                     field_descriptor_proto::Type::TYPE_MESSAGE => {
                         if customize.singular_field_option_box.unwrap_or(false) {
@@ -655,10 +798,32 @@ impl<'a> FieldGen<'a> {
             FieldKind::Singular(SingularField { elem, flag })
         };
 
+        let ip_addr = rustproto::exts::ip_addr
+            .get(field.field.options.get_message())
+            .map(|name| ip_addr_rust_type(&field, &kind, &name));
+
+        let uuid = rustproto::exts::uuid
+            .get(field.field.options.get_message())
+            .unwrap_or(false);
+        if uuid {
+            check_uuid_field(&field, &kind, ip_addr.is_some());
+        }
+
+        if lazy {
+            check_lazy_field(&field, &kind);
+        }
+
+        let serde_flatten = rustproto::exts::serde_flatten
+            .get(field.field.options.get_message())
+            .unwrap_or(false);
+        if serde_flatten {
+            check_serde_flatten_field(&field, &kind, lazy);
+        }
+
         FieldGen {
             root_scope,
             syntax: field.message.get_scope().file_scope.syntax(),
-            rust_name: rust_field_name_for_protobuf_field_name(&field.field.get_name()),
+            rust_name: field.rust_name(),
             proto_type: field.field.get_field_type(),
             wire_type: field_type_wire_type(field.field.get_field_type()),
             proto_field: field,
@@ -667,9 +832,21 @@ impl<'a> FieldGen<'a> {
             generate_accessors,
             generate_getter,
             customize,
+            ip_addr,
+            uuid,
+            lazy,
+            serde_flatten,
         }
     }
 
+    pub fn is_lazy(&self) -> bool {
+        self.lazy
+    }
+
+    pub fn intern_strings(&self) -> bool {
+        self.customize.intern_strings.unwrap_or(false)
+    }
+
     // for message level
     fn get_file_and_mod(&self) -> FileAndMod {
         self.proto_field.message.scope.get_file_and_mod()
@@ -686,6 +863,103 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    /// Presence of this field is stored in message bitset
+    pub fn has_presence_bit(&self) -> bool {
+        self.presence_bit().is_some()
+    }
+
+    fn presence_bit(&self) -> Option<u32> {
+        match self.kind {
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithBit { bit, .. },
+                ..
+            }) => Some(bit),
+            _ => None,
+        }
+    }
+
+    /// Assign index in message presence bitset
+    pub fn set_presence_bit(&mut self, index: u32) {
+        match self.kind {
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithBit { ref mut bit, .. },
+                ..
+            }) => *bit = index,
+            _ => unreachable!(),
+        }
+    }
+
+    // expression which is true if presence bit of this field is set
+    fn self_presence_bit_is_set(&self) -> String {
+        self.presence_bit_is_set("self")
+    }
+
+    // presence bit of this field in `obj` message
+    fn presence_bit_is_set(&self, obj: &str) -> String {
+        let bit = self.presence_bit().unwrap();
+        format!(
+            "{}.presence_bits[{}] & (1 << {}) != 0",
+            obj,
+            bit / 32,
+            bit % 32
+        )
+    }
+
+    fn write_self_presence_bit_set(&self, w: &mut CodeWriter) {
+        let bit = self.presence_bit().unwrap();
+        w.write_line(&format!(
+            "self.presence_bits[{}] |= 1 << {};",
+            bit / 32,
+            bit % 32
+        ));
+    }
+
+    fn write_self_presence_bit_clear(&self, w: &mut CodeWriter) {
+        let bit = self.presence_bit().unwrap();
+        w.write_line(&format!(
+            "self.presence_bits[{}] &= !(1 << {});",
+            bit / 32,
+            bit % 32
+        ));
+    }
+
+    // singular or oneof `bytes` field stored as `bytes::Bytes`
+    fn is_singular_carllerche_bytes(&self) -> bool {
+        match self.kind {
+            FieldKind::Singular(SingularField { ref elem, .. })
+            | FieldKind::Oneof(OneofField { ref elem, .. }) => match *elem {
+                FieldElem::Primitive(
+                    field_descriptor_proto::Type::TYPE_BYTES,
+                    PrimitiveTypeVariant::Carllerche,
+                ) => true,
+                _ => false,
+            },
+            FieldKind::Repeated(..) | FieldKind::Map(..) => false,
+        }
+    }
+
+    /// Field or its map value is stored as `bytes::Bytes`
+    /// Field has `ip_addr` option
+    pub fn is_ip_addr(&self) -> bool {
+        self.ip_addr.is_some()
+    }
+
+    pub fn is_carllerche_bytes(&self) -> bool {
+        let elem = match self.kind {
+            FieldKind::Singular(SingularField { ref elem, .. })
+            | FieldKind::Repeated(RepeatedField { ref elem, .. })
+            | FieldKind::Oneof(OneofField { ref elem, .. })
+            | FieldKind::Map(MapField { value: ref elem, .. }) => elem,
+        };
+        match *elem {
+            FieldElem::Primitive(
+                field_descriptor_proto::Type::TYPE_BYTES,
+                PrimitiveTypeVariant::Carllerche,
+            ) => true,
+            _ => false,
+        }
+    }
+
     fn is_repeated_not_map(&self) -> bool {
         match self.kind {
             FieldKind::Repeated(..) => true,
@@ -693,6 +967,13 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    fn is_map(&self) -> bool {
+        match self.kind {
+            FieldKind::Map(..) => true,
+            _ => false,
+        }
+    }
+
     fn is_repeated_or_map(&self) -> bool {
         match self.kind {
             FieldKind::Repeated(..) | FieldKind::Map(..) => true,
@@ -727,6 +1008,9 @@ impl<'a> FieldGen<'a> {
     // type of field in struct
     pub fn full_storage_type(&self, reference: &FileAndMod) -> RustType {
         match self.kind {
+            FieldKind::Repeated(RepeatedField { ref elem, .. }) if self.lazy => {
+                RustType::LazyPacked(elem.protobuf_type_gen(reference).rust_type())
+            }
             FieldKind::Repeated(ref repeated) => repeated.rust_type(reference),
             FieldKind::Map(MapField {
                 ref key, ref value, ..
@@ -734,6 +1018,9 @@ impl<'a> FieldGen<'a> {
                 Box::new(key.rust_storage_elem_type(reference)),
                 Box::new(value.rust_storage_elem_type(reference)),
             ),
+            FieldKind::Singular(SingularField { ref elem, .. }) if self.lazy => {
+                RustType::LazyMessage(Box::new(elem.rust_storage_elem_type(reference)))
+            }
             FieldKind::Singular(ref singular) => singular.rust_storage_type(reference),
             FieldKind::Oneof(..) => unreachable!(),
         }
@@ -961,7 +1248,7 @@ impl<'a> FieldGen<'a> {
     pub fn reconstruct_def(&self) -> String {
         let prefix = match (self.proto_field.field.get_label(), self.syntax) {
             (field_descriptor_proto::Label::LABEL_REPEATED, _) => "repeated ",
-            (_, Syntax::PROTO3) => "",
+            (_, Syntax::PROTO3) | (_, Syntax::EDITIONS) => "",
             (field_descriptor_proto::Label::LABEL_OPTIONAL, _) => "optional ",
             (field_descriptor_proto::Label::LABEL_REQUIRED, _) => "required ",
         };
@@ -987,15 +1274,20 @@ impl<'a> FieldGen<'a> {
 
     fn accessor_fn_repeated(&self, repeated_field: &RepeatedField) -> AccessorFn {
         let RepeatedField { ref elem, .. } = repeated_field;
+        let mut type_params = vec![elem.lib_protobuf_type(&self.get_file_and_mod())];
         let coll = match self.full_storage_type(&self.proto_field.message.scope.get_file_and_mod()) {
             RustType::Vec(..) => "vec",
             RustType::RepeatedField(..) => "repeated_field",
+            RustType::SmallVec(..) => {
+                type_params.push("_".to_owned());
+                "small_vec"
+            }
             _ => unreachable!(),
         };
         let name = format!("make_{}_accessor", coll);
         AccessorFn {
             name: name,
-            type_params: vec![elem.lib_protobuf_type(&self.get_file_and_mod())],
+            type_params,
             callback_params: self.make_accessor_fns_lambda(),
         }
     }
@@ -1091,6 +1383,16 @@ impl<'a> FieldGen<'a> {
 
     fn accessor_fn(&self) -> AccessorFn {
         match self.kind {
+            FieldKind::Singular(SingularField { ref elem, .. }) if self.lazy => AccessorFn {
+                name: "make_lazy_message_accessor".to_owned(),
+                type_params: vec![elem.rust_storage_elem_type(&self.get_file_and_mod()).to_string()],
+                callback_params: self.make_accessor_fns_lambda(),
+            },
+            FieldKind::Repeated(RepeatedField { ref elem, .. }) if self.lazy => AccessorFn {
+                name: "make_lazy_packed_accessor".to_owned(),
+                type_params: vec![elem.protobuf_type_gen(&self.get_file_and_mod()).rust_type()],
+                callback_params: self.make_accessor_fns_lambda(),
+            },
             FieldKind::Repeated(ref repeated_field) => self.accessor_fn_repeated(repeated_field),
             FieldKind::Map(ref map_field) => self.accessor_fn_map(map_field),
             FieldKind::Singular(SingularField {
@@ -1101,6 +1403,14 @@ impl<'a> FieldGen<'a> {
                 ref elem,
                 flag: SingularFieldFlag::WithFlag { option_kind, .. },
             }) => self.accessor_fn_singular_with_flag(elem, option_kind),
+            FieldKind::Singular(SingularField {
+                ref elem,
+                flag: SingularFieldFlag::WithBit { .. },
+            }) => AccessorFn {
+                name: "make_oneof_copy_has_get_set_accessors".to_owned(),
+                type_params: vec![elem.lib_protobuf_type(&self.get_file_and_mod())],
+                callback_params: self.make_accessor_fns_has_get_set(),
+            },
             FieldKind::Oneof(ref oneof) => self.accessor_fn_oneof(oneof),
         }
     }
@@ -1185,6 +1495,187 @@ impl<'a> FieldGen<'a> {
             _ => {
                 let clear_expr = self.full_storage_type(&self.proto_field.message.scope.get_file_and_mod()).clear(&self.self_field());
                 w.write_line(&format!("{};", clear_expr));
+                if self.has_presence_bit() {
+                    self.write_self_presence_bit_clear(w);
+                }
+            }
+        }
+    }
+
+    // Write `Hash::hash` part for this field,
+    // values of equal fields must be hashed equally
+    pub fn write_hash(&self, w: &mut CodeWriter) {
+        if self.lazy {
+            return;
+        }
+        match self.kind {
+            // `HashMap` is not `Hash`, and its iteration order is not stable
+            FieldKind::Map(..) => {
+                w.write_line(&format!(
+                    "::std::hash::Hash::hash(&{}.len(), state);",
+                    self.self_field()
+                ));
+            }
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithoutFlag,
+                ..
+            })
+            | FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithBit { .. },
+                ..
+            }) => {
+                self.write_hash_value(w, &self.self_field(), false);
+            }
+            _ => {
+                self.write_for_self_field(w, "v", |w, _v_type| {
+                    self.write_hash_value(w, "v", true);
+                });
+            }
+        }
+    }
+
+    fn write_hash_value(&self, w: &mut CodeWriter, v: &str, v_is_ref: bool) {
+        let deref = if v_is_ref { "*" } else { "" };
+        match self.proto_type {
+            field_descriptor_proto::Type::TYPE_FLOAT => w.write_line(&format!(
+                "::std::hash::Hash::hash(&::protobuf::rt::f32_hash_bits({}{}), state);",
+                deref, v
+            )),
+            field_descriptor_proto::Type::TYPE_DOUBLE => w.write_line(&format!(
+                "::std::hash::Hash::hash(&::protobuf::rt::f64_hash_bits({}{}), state);",
+                deref, v
+            )),
+            _ => {
+                let r = if v_is_ref { "" } else { "&" };
+                w.write_line(&format!("::std::hash::Hash::hash({}{}, state);", r, v));
+            }
+        }
+    }
+
+    // wrapper of `f32` or `f64` value ordered with `total_cmp`
+    fn total_ord_wrapper(&self) -> Option<&'static str> {
+        match self.proto_type {
+            field_descriptor_proto::Type::TYPE_FLOAT => Some("::protobuf::rt::TotalOrdF32"),
+            field_descriptor_proto::Type::TYPE_DOUBLE => Some("::protobuf::rt::TotalOrdF64"),
+            _ => None,
+        }
+    }
+
+    // `Ord` value (or iterator for repeated field) this field of `obj` is compared by
+    fn total_cmp_key(&self, obj: &str) -> String {
+        let field = format!("{}.{}", obj, self.rust_name);
+        let wrapper = self.total_ord_wrapper();
+        match self.kind {
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithoutFlag,
+                ..
+            }) => match wrapper {
+                Some(wrapper) => format!("{}({})", wrapper, field),
+                None => field,
+            },
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithBit { .. },
+                ..
+            }) => format!(
+                "if {} {{ ::std::option::Option::Some({}) }} else {{ ::std::option::Option::None }}",
+                self.presence_bit_is_set(obj),
+                match wrapper {
+                    Some(wrapper) => format!("{}({})", wrapper, field),
+                    None => format!("&{}", field),
+                }
+            ),
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { .. },
+                ..
+            }) => match wrapper {
+                Some(wrapper) => format!("{}.map({})", field, wrapper),
+                None => format!("{}.as_ref()", field),
+            },
+            FieldKind::Repeated(..) => match wrapper {
+                Some(wrapper) => format!("{}.iter().map(|v| {}(*v))", field, wrapper),
+                None => format!("{}.iter()", field),
+            },
+            FieldKind::Map(..) | FieldKind::Oneof(..) => unreachable!(),
+        }
+    }
+
+    // Compare this field in `Ord` implementation generated with `float_total_cmp` option,
+    // returning if fields are not equal
+    pub fn write_total_cmp(&self, w: &mut CodeWriter) {
+        let cmp = match self.kind {
+            FieldKind::Repeated(..) => "::std::iter::Iterator::cmp",
+            _ => "::std::cmp::Ord::cmp",
+        };
+        let (a, b) = (self.total_cmp_key("self"), self.total_cmp_key("other"));
+        let (a, b) = match self.kind {
+            FieldKind::Repeated(..) => (a, b),
+            _ => (format!("&{}", a), format!("&{}", b)),
+        };
+        w.match_block(&format!("{}({}, {})", cmp, a, b), |w| {
+            w.case_expr("::std::cmp::Ordering::Equal", "{}");
+            w.case_expr("r", "return r");
+        });
+    }
+
+    pub fn write_shrink_to_fit(&self, w: &mut CodeWriter) {
+        if self.lazy {
+            return;
+        }
+        match self.kind {
+            FieldKind::Singular(SingularField { ref elem, flag: SingularFieldFlag::WithFlag { .. } }) => {
+                let shrink_fn = match *elem {
+                    FieldElem::Message(ref m) => format!(
+                        "shrink_singular_message_to_fit::<{}, _>",
+                        m.rust_name_relative(&self.get_file_and_mod())
+                    ),
+                    FieldElem::Primitive(field_descriptor_proto::Type::TYPE_STRING, PrimitiveTypeVariant::Default) => {
+                        "shrink_singular_string_to_fit".to_owned()
+                    }
+                    FieldElem::Primitive(field_descriptor_proto::Type::TYPE_BYTES, PrimitiveTypeVariant::Default) => {
+                        "shrink_singular_bytes_to_fit".to_owned()
+                    }
+                    _ => return,
+                };
+                w.write_line(&format!("::protobuf::rt::{}(&mut {});", shrink_fn, self.self_field()));
+            }
+            // numbers and bools
+            FieldKind::Singular(SingularField { flag: SingularFieldFlag::WithBit { .. }, .. }) => {}
+            FieldKind::Singular(SingularField { ref elem, flag: SingularFieldFlag::WithoutFlag }) => {
+                if let Some(shrink) = elem.shrink_to_fit_expr(&self.self_field()) {
+                    w.write_line(&format!("{};", shrink));
+                }
+            }
+            FieldKind::Repeated(RepeatedField { ref elem, .. }) => {
+                w.write_line(&format!("{}.shrink_to_fit();", self.self_field()));
+                if let Some(shrink) = elem.shrink_to_fit_expr("v") {
+                    w.for_stmt(&format!("{}.iter_mut()", self.self_field()), "v", |w| {
+                        w.write_line(&format!("{};", shrink));
+                    });
+                }
+            }
+            FieldKind::Map(MapField { ref value, .. }) => {
+                w.write_line(&format!("{}.shrink_to_fit();", self.self_field()));
+                if let Some(shrink) = value.shrink_to_fit_expr("v") {
+                    w.for_stmt(&format!("{}.values_mut()", self.self_field()), "v", |w| {
+                        w.write_line(&format!("{};", shrink));
+                    });
+                }
+            }
+            FieldKind::Oneof(ref o) => {
+                // boxed only if message is recursive
+                let var = if o.boxed { "&mut **v" } else { "v" };
+                if let Some(shrink) = o.elem.shrink_to_fit_expr(var) {
+                    w.if_let_stmt(
+                        &format!(
+                            "::std::option::Option::Some({}(ref mut v))",
+                            o.variant_path(&self.proto_field.message.scope.rust_path_to_file().clone().into_path())
+                        ),
+                        &format!("self.{}", o.oneof_field_name),
+                        |w| {
+                            w.write_line(&format!("{};", shrink));
+                        },
+                    );
+                }
             }
         }
     }
@@ -1280,6 +1771,9 @@ impl<'a> FieldGen<'a> {
 
     fn self_field_is_some(&self) -> String {
         assert!(self.is_singular());
+        if self.has_presence_bit() {
+            return self.self_presence_bit_is_set();
+        }
         format!("{}.is_some()", self.self_field())
     }
 
@@ -1290,6 +1784,9 @@ impl<'a> FieldGen<'a> {
 
     fn self_field_is_none(&self) -> String {
         assert!(self.is_singular());
+        if self.has_presence_bit() {
+            return format!("!({})", self.self_presence_bit_is_set());
+        }
         format!("{}.is_none()", self.self_field())
     }
 
@@ -1324,6 +1821,8 @@ impl<'a> FieldGen<'a> {
                 FieldKind::Singular(SingularField { ref flag, .. }) => match *flag {
                     SingularFieldFlag::WithFlag { .. } => Visibility::Default,
                     SingularFieldFlag::WithoutFlag => Visibility::Public,
+                    // value must be consistent with presence bit
+                    SingularFieldFlag::WithBit { .. } => Visibility::Default,
                 },
                 FieldKind::Map(..) => Visibility::Public,
                 FieldKind::Oneof(..) => unreachable!(),
@@ -1331,61 +1830,405 @@ impl<'a> FieldGen<'a> {
         }
     }
 
-    pub fn write_struct_field(&self, w: &mut CodeWriter) {
-        if self.proto_type == field_descriptor_proto::Type::TYPE_GROUP {
-            w.comment(&format!("{}: <group>", &self.rust_name));
-        } else {
-            let vis = self.visibility();
-            w.field_decl_vis(
-                vis,
-                self.rust_name.get(),
-                &self.full_storage_type(&self.proto_field.message.scope.get_file_and_mod()).to_string(),
-            );
+    fn is_deprecated(&self) -> bool {
+        self.proto_field.field.options.get_message().get_deprecated()
+    }
+
+    /// Write `#[deprecated]` attribute if field is marked `deprecated` in `.proto` file
+    pub fn write_deprecated(&self, w: &mut CodeWriter) {
+        if self.is_deprecated() {
+            w.deprecated(&format!(
+                "field `{}.{}` is deprecated",
+                self.proto_field.message.name_absolute(),
+                self.proto_field.name()
+            ));
         }
     }
 
-    fn write_if_let_self_field_is_some<F>(&self, s: &SingularField, w: &mut CodeWriter, cb: F)
-    where
-        F: Fn(&RustValueTyped, &mut CodeWriter),
-    {
-        match s {
-            SingularField {
-                flag: SingularFieldFlag::WithFlag { option_kind, .. },
-                ref elem,
-            } => {
-                let var = "v";
-                let ref_prefix = match elem.rust_storage_elem_type(&self.proto_field.message.scope.get_file_and_mod()).is_copy() {
-                    true => "",
-                    false => "",
-                };
-                let as_option = self.self_field_as_option(elem, *option_kind);
-                w.if_let_stmt(
-                    &format!("Some({}{})", ref_prefix, var),
-                    &as_option.value,
-                    |w| {
-                        let v = RustValueTyped {
-                            value: var.to_owned(),
-                            rust_type: as_option.rust_type.elem_type(),
-                        };
-                        cb(&v, w);
-                    },
-                );
-            }
-            SingularField {
+    /// Singular field of numeric type, which can be used in `numeric_wrapper` message
+    pub fn is_singular_numeric(&self) -> bool {
+        if !self.is_singular() {
+            return false;
+        }
+        match self.proto_type {
+            field_descriptor_proto::Type::TYPE_DOUBLE
+            | field_descriptor_proto::Type::TYPE_FLOAT
+            | field_descriptor_proto::Type::TYPE_INT32
+            | field_descriptor_proto::Type::TYPE_INT64
+            | field_descriptor_proto::Type::TYPE_UINT32
+            | field_descriptor_proto::Type::TYPE_UINT64
+            | field_descriptor_proto::Type::TYPE_SINT32
+            | field_descriptor_proto::Type::TYPE_SINT64
+            | field_descriptor_proto::Type::TYPE_FIXED32
+            | field_descriptor_proto::Type::TYPE_FIXED64
+            | field_descriptor_proto::Type::TYPE_SFIXED32
+            | field_descriptor_proto::Type::TYPE_SFIXED64 => true,
+            _ => false,
+        }
+    }
+
+    /// Add field value of `other` message to this field value
+    pub fn write_numeric_wrapper_add(&self, other: &str, w: &mut CodeWriter) {
+        match self.kind {
+            FieldKind::Singular(SingularField {
                 flag: SingularFieldFlag::WithoutFlag,
-                ref elem,
-            } => match *elem {
-                FieldElem::Primitive(field_descriptor_proto::Type::TYPE_STRING, ..)
-                | FieldElem::Primitive(field_descriptor_proto::Type::TYPE_BYTES, ..) => {
-                    w.if_stmt(format!("!{}.is_empty()", self.self_field()), |w| {
-                        let v = RustValueTyped {
-                            value: self.self_field(),
-                            rust_type: self.full_storage_type(&self.proto_field.message.scope.get_file_and_mod()),
-                        };
-                        cb(&v, w);
-                    });
-                }
-                _ => {
+                ..
+            }) => {
+                w.write_line(&format!("self.{0} += {1}.{0};", self.rust_name, other));
+            }
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { .. },
+                ..
+            }) => {
+                let default_value = self.get_xxx_default_value_rust();
+                w.write_line(&format!(
+                    "self.{0} = ::std::option::Option::Some(self.{0}.unwrap_or({2}) + {1}.{0}.unwrap_or({2}));",
+                    self.rust_name,
+                    other,
+                    default_value
+                ));
+            }
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithBit { .. },
+                ..
+            }) => {
+                w.write_line(&format!(
+                    "let v = self.get_{0}() + {1}.get_{0}();",
+                    self.rust_name, other
+                ));
+                w.write_line(&format!("self.set_{}(v);", self.rust_name));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Singular string field, which can be used in `string_wrapper` message
+    // Type of element of this field in message view
+    fn view_elem_type(&self, elem: &FieldElem) -> String {
+        let reference = self.get_file_and_mod();
+        match *elem {
+            FieldElem::Primitive(field_descriptor_proto::Type::TYPE_STRING, ..) => "&'a str".to_owned(),
+            FieldElem::Primitive(field_descriptor_proto::Type::TYPE_BYTES, ..) => "&'a [u8]".to_owned(),
+            FieldElem::Message(ref m) => {
+                if m.message.get_scope().get_file_descriptor().get_package() == "google.protobuf" {
+                    panic!(
+                        "field {} of message {}: views are not generated for type {}",
+                        self.proto_field.name(),
+                        self.proto_field.message.name_to_package(),
+                        m.message.name_absolute()
+                    );
+                }
+                let mut view = m.rust_name_relative(&reference);
+                view.ident = RustIdent::from(format!("{}View", view.ident));
+                format!("{}<'a>", view)
+            }
+            _ => elem.rust_storage_elem_type(&reference).to_string(),
+        }
+    }
+
+    fn view_unsupported(&self, what: &str) -> ! {
+        panic!(
+            "field {} of message {}: {} are not supported in views",
+            self.proto_field.name(),
+            self.proto_field.message.name_to_package(),
+            what
+        );
+    }
+
+    /// Type of this field in message view
+    pub fn view_type(&self) -> String {
+        if self.lazy {
+            self.view_unsupported("lazy fields");
+        }
+        match self.kind {
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithoutFlag,
+                ref elem,
+            }) => self.view_elem_type(elem),
+            FieldKind::Singular(SingularField {
+                elem: FieldElem::Group,
+                ..
+            })
+            | FieldKind::Repeated(RepeatedField {
+                elem: FieldElem::Group,
+                ..
+            }) => self.view_unsupported("groups"),
+            FieldKind::Singular(SingularField {
+                elem: ref elem @ FieldElem::Message(..),
+                ..
+            }) => format!(
+                "::std::option::Option<::std::boxed::Box<{}>>",
+                self.view_elem_type(elem)
+            ),
+            FieldKind::Singular(SingularField { ref elem, .. }) => {
+                format!("::std::option::Option<{}>", self.view_elem_type(elem))
+            }
+            FieldKind::Repeated(RepeatedField { ref elem, .. }) => {
+                format!("::std::vec::Vec<{}>", self.view_elem_type(elem))
+            }
+            FieldKind::Map(..) => self.view_unsupported("map fields"),
+            FieldKind::Oneof(..) => self.view_unsupported("oneof fields"),
+        }
+    }
+
+    fn write_view_merge_from_singular(
+        &self,
+        s: &SingularField,
+        wire_type_var: &str,
+        w: &mut CodeWriter,
+    ) {
+        match s.elem {
+            FieldElem::Primitive(field_descriptor_proto::Type::TYPE_STRING, ..)
+            | FieldElem::Primitive(field_descriptor_proto::Type::TYPE_BYTES, ..) => {
+                let singular_or_proto3 = match s.flag {
+                    SingularFieldFlag::WithFlag { .. } => "singular",
+                    SingularFieldFlag::WithoutFlag => "singular_proto3",
+                    SingularFieldFlag::WithBit { .. } => unreachable!(),
+                };
+                w.write_line(&format!(
+                    "::protobuf::rt::read_{}_borrowed_{}_into({}, is, &mut self.{})?;",
+                    singular_or_proto3,
+                    protobuf_name(self.proto_type),
+                    wire_type_var,
+                    self.rust_name,
+                ));
+            }
+            FieldElem::Message(..) => {
+                w.write_line(&format!(
+                    "::protobuf::rt::read_singular_message_view_into({}, is, &mut self.{})?;",
+                    wire_type_var, self.rust_name,
+                ));
+            }
+            FieldElem::Group => self.view_unsupported("groups"),
+            // view has no presence bitset, so field is `Option` in view
+            _ if self.has_presence_bit() => {
+                self.write_assert_wire_type(wire_type_var, w);
+                w.write_line(&format!(
+                    "self.{} = ::std::option::Option::Some({}?);",
+                    self.rust_name,
+                    self.proto_type.read(
+                        "is",
+                        self.proto_field.name(),
+                        s.elem.primitive_type_variant()
+                    )
+                ));
+            }
+            // scalars and enums are read same way as in message
+            _ => self.write_merge_from_singular(s, wire_type_var, w),
+        }
+    }
+
+    fn write_view_merge_from_repeated(
+        &self,
+        r: &RepeatedField,
+        wire_type_var: &str,
+        w: &mut CodeWriter,
+    ) {
+        match r.elem {
+            FieldElem::Primitive(field_descriptor_proto::Type::TYPE_STRING, ..)
+            | FieldElem::Primitive(field_descriptor_proto::Type::TYPE_BYTES, ..) => {
+                w.write_line(&format!(
+                    "::protobuf::rt::read_repeated_borrowed_{}_into({}, is, &mut self.{})?;",
+                    protobuf_name(self.proto_type),
+                    wire_type_var,
+                    self.rust_name,
+                ));
+            }
+            FieldElem::Message(..) => {
+                w.write_line(&format!(
+                    "::protobuf::rt::read_repeated_message_view_into({}, is, &mut self.{})?;",
+                    wire_type_var, self.rust_name,
+                ));
+            }
+            FieldElem::Group => self.view_unsupported("groups"),
+            _ => self.write_merge_from_repeated(wire_type_var, w),
+        }
+    }
+
+    // Write `merge_from` part of message view for this field
+    pub fn write_view_merge_from_field(&self, wire_type_var: &str, w: &mut CodeWriter) {
+        match self.kind {
+            FieldKind::Singular(ref s) => self.write_view_merge_from_singular(s, wire_type_var, w),
+            FieldKind::Repeated(ref r) => self.write_view_merge_from_repeated(r, wire_type_var, w),
+            FieldKind::Map(..) => self.view_unsupported("map fields"),
+            FieldKind::Oneof(..) => self.view_unsupported("oneof fields"),
+        }
+    }
+
+    /// Type of this field in `FooParts` struct returned by `into_parts`
+    pub fn parts_type(&self) -> RustType {
+        let reference = self.get_file_and_mod();
+        match self.kind {
+            // lazy message is not parsed here, because parsing may fail
+            FieldKind::Singular(..) if self.lazy => self.full_storage_type(&reference),
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithoutFlag,
+                ref elem,
+            }) => elem.rust_storage_elem_type(&reference),
+            FieldKind::Singular(SingularField { ref elem, .. }) => {
+                RustType::Option(Box::new(elem.rust_storage_elem_type(&reference)))
+            }
+            FieldKind::Repeated(..) | FieldKind::Map(..) => self.full_storage_type(&reference),
+            FieldKind::Oneof(..) => unreachable!(),
+        }
+    }
+
+    /// Expression moving value of this field out of `self` in `into_parts`
+    pub fn parts_value(&self) -> String {
+        match self.kind {
+            FieldKind::Singular(..) if self.lazy => self.self_field(),
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { option_kind, .. },
+                ..
+            }) => match option_kind {
+                OptionKind::Option => self.self_field(),
+                OptionKind::OptionBox => format!("{}.map(|v| *v)", self.self_field()),
+                OptionKind::SingularField | OptionKind::SingularPtrField => {
+                    format!("{}.into_option()", self.self_field())
+                }
+            },
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithBit { .. },
+                ..
+            }) => format!(
+                "if {} {{ ::std::option::Option::Some({}) }} else {{ ::std::option::Option::None }}",
+                self.self_presence_bit_is_set(),
+                self.self_field()
+            ),
+            FieldKind::Singular(..) | FieldKind::Repeated(..) | FieldKind::Map(..) => {
+                self.self_field()
+            }
+            FieldKind::Oneof(..) => unreachable!(),
+        }
+    }
+
+    pub fn is_singular_string(&self) -> bool {
+        self.is_singular() && self.proto_type == field_descriptor_proto::Type::TYPE_STRING
+    }
+
+    /// Singular `Vec<u8>` field, which can be used in `io_write_field` message option
+    pub fn is_singular_bytes_vec(&self) -> bool {
+        match self.kind {
+            FieldKind::Singular(SingularField {
+                elem: FieldElem::Primitive(
+                    field_descriptor_proto::Type::TYPE_BYTES,
+                    PrimitiveTypeVariant::Default,
+                ),
+                ..
+            }) => !self.lazy && self.ip_addr.is_none() && !self.uuid,
+            _ => false,
+        }
+    }
+
+    /// Append `buf` to this field of `self`
+    pub fn write_io_write_append(&self, buf: &str, w: &mut CodeWriter) {
+        let s = match self.kind {
+            FieldKind::Singular(ref s) => s,
+            _ => unreachable!(),
+        };
+        w.block("let v: &mut ::std::vec::Vec<u8> = {", "};", |w| {
+            self.write_message_field_mut_singular(s, w);
+        });
+        w.write_line(&format!("v.extend_from_slice({});", buf));
+    }
+
+    /// Expression of type `&str` for the value of this field of `self`
+    pub fn string_wrapper_as_str(&self) -> String {
+        match self.kind {
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithoutFlag,
+                ..
+            }) => format!("&*self.{}", self.rust_name),
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { .. },
+                ..
+            }) => format!(
+                "self.{}.as_ref().map(|v| &**v).unwrap_or({})",
+                self.rust_name,
+                self.get_xxx_default_value_rust()
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn write_struct_field(&self, w: &mut CodeWriter) {
+        if self.proto_type == field_descriptor_proto::Type::TYPE_GROUP {
+            w.comment(&format!("{}: <group>", &self.rust_name));
+        } else {
+            let vis = self.visibility();
+            let storage_type = self.full_storage_type(&self.proto_field.message.scope.get_file_and_mod());
+            self.write_deprecated(w);
+            if self.serde_flatten {
+                // unset field is serialized as nothing, so `skip_serializing_if` is not needed
+                serde::write_serde_attr(w, &self.customize, "serde(flatten)");
+            } else if self.customize.serde_skip_default.unwrap_or(false) {
+                serde::write_serde_attr(
+                    w,
+                    &self.customize,
+                    &format!(
+                        "serde(skip_serializing_if = \"{}\")",
+                        storage_type.serde_skip_serializing_if()
+                    ),
+                );
+            }
+            w.field_decl_vis(vis, self.rust_name.get(), &storage_type.to_string());
+        }
+    }
+
+    fn write_if_let_self_field_is_some<F>(&self, s: &SingularField, w: &mut CodeWriter, cb: F)
+    where
+        F: Fn(&RustValueTyped, &mut CodeWriter),
+    {
+        match s {
+            SingularField {
+                flag: SingularFieldFlag::WithFlag { option_kind, .. },
+                ref elem,
+            } => {
+                let var = "v";
+                let ref_prefix = match elem.rust_storage_elem_type(&self.proto_field.message.scope.get_file_and_mod()).is_copy() {
+                    true => "",
+                    false => "",
+                };
+                let as_option = self.self_field_as_option(elem, *option_kind);
+                w.if_let_stmt(
+                    &format!("Some({}{})", ref_prefix, var),
+                    &as_option.value,
+                    |w| {
+                        let v = RustValueTyped {
+                            value: var.to_owned(),
+                            rust_type: as_option.rust_type.elem_type(),
+                        };
+                        cb(&v, w);
+                    },
+                );
+            }
+            SingularField {
+                flag: SingularFieldFlag::WithBit { .. },
+                ..
+            } => {
+                w.if_stmt(self.self_presence_bit_is_set(), |w| {
+                    let v = RustValueTyped {
+                        value: self.self_field(),
+                        rust_type: self.full_storage_type(&self.proto_field.message.scope.get_file_and_mod()),
+                    };
+                    cb(&v, w);
+                });
+            }
+            SingularField {
+                flag: SingularFieldFlag::WithoutFlag,
+                ref elem,
+            } => match *elem {
+                FieldElem::Primitive(field_descriptor_proto::Type::TYPE_STRING, ..)
+                | FieldElem::Primitive(field_descriptor_proto::Type::TYPE_BYTES, ..) => {
+                    w.if_stmt(format!("!{}.is_empty()", self.self_field()), |w| {
+                        let v = RustValueTyped {
+                            value: self.self_field(),
+                            rust_type: self.full_storage_type(&self.proto_field.message.scope.get_file_and_mod()),
+                        };
+                        cb(&v, w);
+                    });
+                }
+                _ => {
                     w.if_stmt(
                         format!(
                             "{} != {}",
@@ -1469,6 +2312,13 @@ impl<'a> FieldGen<'a> {
             } => {
                 self.write_self_field_assign(w, value);
             }
+            &SingularField {
+                flag: SingularFieldFlag::WithBit { .. },
+                ..
+            } => {
+                self.write_self_field_assign(w, value);
+                self.write_self_presence_bit_set(w);
+            }
         }
     }
 
@@ -1485,6 +2335,11 @@ impl<'a> FieldGen<'a> {
             SingularFieldFlag::WithFlag { option_kind, .. } => {
                 option_kind.wrap_value(&converted.value)
             }
+            SingularFieldFlag::WithBit { .. } => {
+                self.write_self_field_assign(w, &converted.value);
+                self.write_self_presence_bit_set(w);
+                return;
+            }
         };
         self.write_self_field_assign(w, &wrapped);
     }
@@ -1539,6 +2394,13 @@ impl<'a> FieldGen<'a> {
                             }
                         }
                     }
+                    SingularFieldFlag::WithBit { .. } => {
+                        self.write_self_field_assign_some(
+                            w,
+                            singular,
+                            &singular.elem.rust_storage_elem_type(&self.proto_field.message.scope.get_file_and_mod()).default_value_typed().value,
+                        );
+                    }
                     SingularFieldFlag::WithoutFlag => {
                         unimplemented!()
                     }
@@ -1614,10 +2476,30 @@ impl<'a> FieldGen<'a> {
     ) {
         let carllerche = match r.elem.primitive_type_variant() {
             PrimitiveTypeVariant::Carllerche => "carllerche_",
+            PrimitiveTypeVariant::Interned => "interned_",
             PrimitiveTypeVariant::Default => "",
         };
+        if let Some(ref ip_addr) = self.ip_addr {
+            w.write_line(&format!(
+                "::protobuf::rt::read_repeated_ip_addr_into::<{}, _>(wire_type, is, &mut self.{})?;",
+                ip_addr, self.rust_name,
+            ));
+            return;
+        }
+        if self.uuid {
+            w.write_line(&format!(
+                "::protobuf::rt::read_repeated_uuid_into(wire_type, is, &mut self.{})?;",
+                self.rust_name,
+            ));
+            return;
+        }
         let type_name_for_fn = protobuf_name(self.proto_type);
         let into_what_suffix = match *r {
+            RepeatedField {
+                elem: FieldElem::Message(..),
+                small_repeated: Some(..),
+                ..
+            } => "_small_vec",
             RepeatedField {
                 elem: FieldElem::Message(..),
                 repeated_field_vec,
@@ -1651,13 +2533,32 @@ impl<'a> FieldGen<'a> {
                 flag: SingularFieldFlag::WithoutFlag,
                 ..
             } => "singular_proto3",
+            SingularField {
+                flag: SingularFieldFlag::WithBit { .. },
+                ..
+            } => unreachable!(),
         };
+        if let Some(ref ip_addr) = self.ip_addr {
+            w.write_line(&format!(
+                "::protobuf::rt::read_{}_ip_addr_into::<{}>(wire_type, is, &mut self.{})?;",
+                singular_or_proto3, ip_addr, self.rust_name,
+            ));
+            return;
+        }
+        if self.uuid {
+            w.write_line(&format!(
+                "::protobuf::rt::read_{}_uuid_into(wire_type, is, &mut self.{})?;",
+                singular_or_proto3, self.rust_name,
+            ));
+            return;
+        }
         let type_params = match s.elem {
             FieldElem::Message(ref m, ..) => format!("::<{}, _>", m.rust_name_relative(&self.get_file_and_mod())),
             _ => "".to_owned(),
         };
         let carllerche = match s.elem.primitive_type_variant() {
             PrimitiveTypeVariant::Carllerche => "carllerche_",
+            PrimitiveTypeVariant::Interned => "interned_",
             PrimitiveTypeVariant::Default => "",
         };
         let type_name_for_fn = protobuf_name(self.proto_type);
@@ -1705,7 +2606,11 @@ impl<'a> FieldGen<'a> {
         self.write_assert_wire_type(wire_type_var, w);
 
         let typed = RustValueTyped {
-            value: format!("{}?", self.proto_type.read("is", o.elem.primitive_type_variant())),
+            value: format!("{}?", self.proto_type.read(
+                "is",
+                self.proto_field.name(),
+                o.elem.primitive_type_variant()
+            )),
             rust_type: self.full_storage_iter_elem_type(&self.proto_field.message.scope.get_file_and_mod()),
         };
 
@@ -1747,12 +2652,73 @@ impl<'a> FieldGen<'a> {
             }
             _ => {
                 self.write_assert_wire_type(wire_type_var, w);
-                let read_proc = format!("{}?", self.proto_type.read("is", s.elem.primitive_type_variant()));
+                let read_proc = format!(
+                    "{}?",
+                    self.proto_type.read(
+                        "is",
+                        self.proto_field.name(),
+                        s.elem.primitive_type_variant()
+                    )
+                );
                 self.write_self_field_assign_some(w, s, &read_proc);
             }
         }
     }
 
+    /// Tag of singular scalar field encoded in single byte,
+    /// which can be parsed without dispatch by field number
+    pub fn merge_from_fast_path_tag(&self) -> Option<u32> {
+        let number = self.proto_field.number();
+        match self.kind {
+            FieldKind::Singular(..)
+                if !self.lazy
+                    && number <= 15
+                    && (is_presence_bitset_type(self.proto_type)
+                        || self.proto_type == field_descriptor_proto::Type::TYPE_ENUM) =>
+            {
+                Some(wire_format::Tag::make(number, self.wire_type).value())
+            }
+            _ => None,
+        }
+    }
+
+    /// Field can be parsed and serialized with message reflection,
+    /// as generated for files with `optimize_for = CODE_SIZE`
+    pub fn supports_reflection_codec(&self) -> bool {
+        if self.proto_type == field_descriptor_proto::Type::TYPE_GROUP
+            || self.lazy
+            || self.ip_addr.is_some()
+            || self.uuid
+        {
+            return false;
+        }
+        match self.kind {
+            // reflection codec takes packed encoding from field options
+            FieldKind::Repeated(RepeatedField { packed, .. }) => {
+                packed == self.proto_field.field.options.get_message().get_packed()
+            }
+            _ => true,
+        }
+    }
+
+    /// Write `merge_from` part for this field when tag matched `merge_from_fast_path_tag`,
+    /// so wire type is not checked
+    pub fn write_merge_from_fast_path(&self, w: &mut CodeWriter) {
+        let s = match self.kind {
+            FieldKind::Singular(ref s) => s,
+            _ => unreachable!(),
+        };
+        let read_proc = format!(
+            "{}?",
+            self.proto_type.read(
+                "is",
+                self.proto_field.name(),
+                s.elem.primitive_type_variant()
+            )
+        );
+        self.write_self_field_assign_some(w, s, &read_proc);
+    }
+
     // Write `merge_from` part for this repeated field
     fn write_merge_from_repeated(&self, wire_type_var: &str, w: &mut CodeWriter) {
         let field = match self.kind {
@@ -1768,9 +2734,18 @@ impl<'a> FieldGen<'a> {
             }
             FieldElem::Enum(..) => {
                 w.write_line(&format!(
-                    "::protobuf::rt::read_repeated_enum_or_unknown_into({}, is, &mut self.{})?",
+                    "::protobuf::rt::read_repeated_enum_or_unknown_field_into({}, is, &mut self.{}, {:?})?",
                     wire_type_var,
                     self.rust_name,
+                    self.proto_field.name(),
+                ));
+            }
+            _ if field.small_repeated.is_some() => {
+                w.write_line(&format!(
+                    "::protobuf::rt::read_repeated_into_small_vec::<{}, _>({}, is, &mut self.{})?;",
+                    field.elem.lib_protobuf_type(&self.get_file_and_mod()),
+                    wire_type_var,
+                    self.rust_name
                 ));
             }
             _ => {
@@ -1786,6 +2761,22 @@ impl<'a> FieldGen<'a> {
 
     // Write `merge_from` part for this field
     pub fn write_merge_from_field(&self, wire_type_var: &str, w: &mut CodeWriter) {
+        if self.lazy && self.is_repeated_packed() {
+            w.write_line(&format!(
+                "::protobuf::rt::read_repeated_lazy_packed_into({}, is, &mut {})?;",
+                wire_type_var,
+                self.self_field()
+            ));
+            return;
+        }
+        if self.lazy {
+            w.write_line(&format!(
+                "::protobuf::rt::read_singular_lazy_message_into({}, is, &mut {})?;",
+                wire_type_var,
+                self.self_field()
+            ));
+            return;
+        }
         match self.kind {
             FieldKind::Oneof(ref f) => self.write_merge_from_oneof(&f, wire_type_var, w),
             FieldKind::Map(..) => self.write_merge_from_map(w),
@@ -1839,6 +2830,24 @@ impl<'a> FieldGen<'a> {
     }
 
     pub fn write_message_write_field(&self, w: &mut CodeWriter) {
+        if self.lazy && self.is_repeated_packed() {
+            w.write_line(&format!(
+                "::protobuf::rt::write_lazy_packed_field({}, &{}, os)?;",
+                self.proto_field.number(),
+                self.self_field()
+            ));
+            return;
+        }
+        if self.lazy {
+            w.if_stmt(self.self_field_is_some(), |w| {
+                w.write_line(&format!(
+                    "::protobuf::rt::write_lazy_message_field_with_cached_size({}, &{}, os)?;",
+                    self.proto_field.number(),
+                    self.self_field()
+                ));
+            });
+            return;
+        }
         match self.kind {
             FieldKind::Singular(ref s) => {
                 self.write_if_let_self_field_is_some(s, w, |v, w| {
@@ -1892,6 +2901,22 @@ impl<'a> FieldGen<'a> {
     }
 
     pub fn write_message_compute_field_size(&self, sum_var: &str, w: &mut CodeWriter) {
+        if self.lazy && self.is_repeated_packed() {
+            w.write_line(&format!(
+                "{} += ::protobuf::rt::lazy_packed_size({}, &{});",
+                sum_var,
+                self.proto_field.number(),
+                self.self_field()
+            ));
+            return;
+        }
+        if self.lazy {
+            w.if_stmt(self.self_field_is_some(), |w| {
+                let field_type = self.full_storage_type(&self.get_file_and_mod());
+                self.write_element_size(w, &self.self_field(), &field_type, sum_var);
+            });
+            return;
+        }
         match self.kind {
             FieldKind::Singular(ref s) => {
                 self.write_if_let_self_field_is_some(s, w, |v, w| {
@@ -1949,7 +2974,9 @@ impl<'a> FieldGen<'a> {
 
     fn write_message_field_get_singular_message(&self, s: &SingularField, w: &mut CodeWriter) {
         match s.flag {
-            SingularFieldFlag::WithoutFlag => unimplemented!(),
+            SingularFieldFlag::WithoutFlag | SingularFieldFlag::WithBit { .. } => {
+                unimplemented!()
+            }
             SingularFieldFlag::WithFlag { option_kind, .. } => {
                 let self_field = self.self_field();
                 let ref field_type_name = self.elem().rust_storage_elem_type(&self.proto_field.message.scope.get_file_and_mod());
@@ -1969,6 +2996,7 @@ impl<'a> FieldGen<'a> {
             SingularFieldFlag::WithoutFlag => {
                 w.write_line(&format!("self.{}.enum_value_or_default()", self.rust_name));
             }
+            SingularFieldFlag::WithBit { .. } => unreachable!(),
             SingularFieldFlag::WithFlag { .. } => {
                 w.match_expr(&self.self_field(), |w| {
                     let default_value = self.get_xxx_default_value_rust();
@@ -2023,6 +3051,17 @@ impl<'a> FieldGen<'a> {
                                 .into_target(&get_xxx_return_type, &self_field),
                         );
                     }
+                    &SingularField {
+                        flag: SingularFieldFlag::WithBit { .. },
+                        ..
+                    } => {
+                        w.write_line(&format!(
+                            "if {} {{ {} }} else {{ {} }}",
+                            self.self_presence_bit_is_set(),
+                            self_field,
+                            get_xxx_default_value_rust
+                        ));
+                    }
                 }
             }
         }
@@ -2053,6 +3092,7 @@ impl<'a> FieldGen<'a> {
         let get_xxx_return_type = self.get_xxx_return_type();
         let fn_def = format!("get_{}(&self) -> {}", self.rust_name, get_xxx_return_type);
 
+        self.write_deprecated(w);
         w.pub_fn(&fn_def, |w| match self.kind {
             FieldKind::Oneof(ref o) => {
                 self.write_message_field_get_oneof(o, w);
@@ -2078,6 +3118,10 @@ impl<'a> FieldGen<'a> {
                 flag: SingularFieldFlag::WithoutFlag,
                 ..
             }) => false,
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithBit { .. },
+                ..
+            }) => true,
             FieldKind::Oneof(..) => true,
         }
     }
@@ -2085,6 +3129,10 @@ impl<'a> FieldGen<'a> {
     fn has_mut(&self) -> bool {
         match self.kind {
             FieldKind::Repeated(..) | FieldKind::Map(..) => true,
+            // `Bytes` is immutable, so it can only be replaced with `set_`
+            FieldKind::Singular(..) | FieldKind::Oneof(..) if self.is_singular_carllerche_bytes() => {
+                false
+            }
             // TODO: string should be public, and mut is not needed
             FieldKind::Singular(..) | FieldKind::Oneof(..) => !self.elem_type_is_copy(),
         }
@@ -2103,6 +3151,7 @@ impl<'a> FieldGen<'a> {
     }
 
     fn write_message_field_has(&self, w: &mut CodeWriter) {
+        self.write_deprecated(w);
         w.pub_fn(&format!("{}(&self) -> bool", self.has_name()), |w| {
             match self.kind {
                 FieldKind::Oneof(ref oneof) => {
@@ -2127,9 +3176,25 @@ impl<'a> FieldGen<'a> {
         let set_xxx_param_type = self.set_xxx_param_type(&self.proto_field.message.scope.get_file_and_mod());
         w.comment("Param is passed by value, moved");
         let ref name = self.rust_name;
+        let carllerche_bytes = self.is_singular_carllerche_bytes();
+        if carllerche_bytes {
+            w.comment("`mut_` is not generated for `Bytes` field, use this function to update it");
+        }
+        let fn_def = if carllerche_bytes {
+            format!(
+                "set_{}<V: ::std::convert::Into<{}>>(&mut self, v: V)",
+                name, set_xxx_param_type
+            )
+        } else {
+            format!("set_{}(&mut self, v: {})", name, set_xxx_param_type)
+        };
+        self.write_deprecated(w);
         w.pub_fn(
-            &format!("set_{}(&mut self, v: {})", name, set_xxx_param_type),
+            &fn_def,
             |w| {
+                if carllerche_bytes {
+                    w.write_line("let v = v.into();");
+                }
                 let value_typed = RustValueTyped {
                     value: "v".to_owned(),
                     rust_type: set_xxx_param_type.clone(),
@@ -2152,6 +3217,35 @@ impl<'a> FieldGen<'a> {
         );
     }
 
+    // Setter storing raw enum value, which is preserved even if unknown
+    fn write_message_field_set_i32(&self, w: &mut CodeWriter) {
+        let ref name = self.rust_name;
+        let value_typed = RustValueTyped {
+            value: "v".to_owned(),
+            rust_type: RustType::Int(true, 32),
+        };
+        self.write_deprecated(w);
+        w.pub_fn(&format!("set_{}_i32(&mut self, v: i32)", name), |w| {
+            match self.kind {
+                FieldKind::Oneof(ref oneof) => {
+                    let v = value_typed.rust_type.into_target(
+                        &oneof.rust_type(&self.proto_field.message.scope.get_file_and_mod()),
+                        "v",
+                    );
+                    w.write_line(&format!(
+                        "self.{} = ::std::option::Option::Some({}({}))",
+                        oneof.oneof_field_name,
+                        oneof.variant_path(&self.proto_field.message.scope.rust_path_to_file().clone().into_path()),
+                        v
+                    ));
+                }
+                _ => {
+                    self.write_self_field_assign_value(w, &value_typed);
+                }
+            }
+        });
+    }
+
     fn write_message_field_mut_singular(&self, s: &SingularField, w: &mut CodeWriter) {
         match s {
             SingularField {
@@ -2169,6 +3263,11 @@ impl<'a> FieldGen<'a> {
                 flag: SingularFieldFlag::WithoutFlag,
                 ..
             } => w.write_line(&format!("&mut {}", self.self_field())),
+            // `mut_` is not generated for numbers and bools
+            SingularField {
+                flag: SingularFieldFlag::WithBit { .. },
+                ..
+            } => unreachable!(),
         }
     }
 
@@ -2184,6 +3283,7 @@ impl<'a> FieldGen<'a> {
             }
             _ => panic!("not a ref: {}", mut_xxx_return_type),
         };
+        self.write_deprecated(w);
         w.pub_fn(&fn_def, |w| {
             match self.kind {
                 FieldKind::Repeated(..) | FieldKind::Map(..) => {
@@ -2287,12 +3387,18 @@ impl<'a> FieldGen<'a> {
                 self.self_field(),
                 self.full_storage_type(&self.proto_field.message.scope.get_file_and_mod()).default_value()
             )),
+            // `take_` is not generated for numbers and bools
+            SingularField {
+                flag: SingularFieldFlag::WithBit { .. },
+                ..
+            } => unreachable!(),
         }
     }
 
     fn write_message_field_take(&self, w: &mut CodeWriter) {
         let take_xxx_return_type = self.take_xxx_return_type(&self.proto_field.message.scope.get_file_and_mod());
         w.comment("Take field");
+        self.write_deprecated(w);
         w.pub_fn(
             &format!(
                 "take_{}(&mut self) -> {}",
@@ -2312,6 +3418,192 @@ impl<'a> FieldGen<'a> {
         );
     }
 
+    // field with `lazy` option stored as `LazyPacked`
+    fn write_message_field_iter_lazy_packed(&self, w: &mut CodeWriter) {
+        let field_type = self.full_storage_type(&self.get_file_and_mod());
+        let iter_type = match field_type {
+            RustType::LazyPacked(ref t) => format!("::protobuf::LazyPackedIter<{}>", t),
+            _ => unreachable!(),
+        };
+        self.write_deprecated(w);
+        w.pub_fn(&format!("iter_{}(&self) -> {}", self.rust_name, iter_type), |w| {
+            w.write_line(&format!("self.{}.iter()", self.rust_name));
+        });
+    }
+
+    fn write_message_field_ip_addr_accessors(&self, ip_addr: &str, w: &mut CodeWriter) {
+        let from_bytes = format!("<{} as ::protobuf::rt::IpAddrBytes>::from_bytes", ip_addr);
+        let to_bytes = format!("<{} as ::protobuf::rt::IpAddrBytes>::to_bytes", ip_addr);
+        match self.kind {
+            FieldKind::Singular(ref s) => {
+                w.write_line("");
+                self.write_deprecated(w);
+                w.pub_fn(
+                    &format!("get_{}_ip_addr(&self) -> ::std::option::Option<{}>", self.rust_name, ip_addr),
+                    |w| match s.flag {
+                        SingularFieldFlag::WithFlag { .. } => w.write_line(&format!(
+                            "self.{}.as_ref().and_then(|v| {}(v))",
+                            self.rust_name, from_bytes
+                        )),
+                        SingularFieldFlag::WithoutFlag => {
+                            w.write_line(&format!("{}(&self.{})", from_bytes, self.rust_name))
+                        }
+                        // `ip_addr` fields are `bytes`
+                        SingularFieldFlag::WithBit { .. } => unreachable!(),
+                    },
+                );
+                w.write_line("");
+                self.write_deprecated(w);
+                w.pub_fn(
+                    &format!("set_{}_ip_addr(&mut self, v: {})", self.rust_name, ip_addr),
+                    |w| {
+                        let value = format!("{}(&v)", to_bytes);
+                        self.write_self_field_assign_some(w, s, &value);
+                    },
+                );
+            }
+            FieldKind::Repeated(..) => {
+                w.write_line("");
+                self.write_deprecated(w);
+                w.pub_fn(
+                    &format!(
+                        "get_{}_ip_addrs(&self) -> ::std::vec::Vec<{}>",
+                        self.rust_name, ip_addr
+                    ),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.{}.iter().filter_map(|v| {}(v)).collect()",
+                            self.rust_name, from_bytes
+                        ));
+                    },
+                );
+                w.write_line("");
+                self.write_deprecated(w);
+                w.pub_fn(
+                    &format!("set_{}_ip_addrs(&mut self, v: &[{}])", self.rust_name, ip_addr),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.{} = v.iter().map({}).collect();",
+                            self.rust_name, to_bytes
+                        ));
+                    },
+                );
+            }
+            FieldKind::Map(..) | FieldKind::Oneof(..) => unreachable!(),
+        }
+    }
+
+    fn write_message_field_uuid_accessors(&self, w: &mut CodeWriter) {
+        let from_bytes = "::protobuf::rt::uuid_from_bytes";
+        match self.kind {
+            FieldKind::Singular(ref s) => {
+                w.write_line("");
+                self.write_deprecated(w);
+                w.pub_fn(
+                    &format!("get_{}_uuid(&self) -> ::std::option::Option<::uuid::Uuid>", self.rust_name),
+                    |w| match s.flag {
+                        SingularFieldFlag::WithFlag { .. } => w.write_line(&format!(
+                            "self.{}.as_ref().and_then(|v| {}(v))",
+                            self.rust_name, from_bytes
+                        )),
+                        SingularFieldFlag::WithoutFlag => {
+                            w.write_line(&format!("{}(&self.{})", from_bytes, self.rust_name))
+                        }
+                        // `uuid` fields are `bytes`
+                        SingularFieldFlag::WithBit { .. } => unreachable!(),
+                    },
+                );
+                w.write_line("");
+                self.write_deprecated(w);
+                w.pub_fn(
+                    &format!("set_{}_uuid(&mut self, v: ::uuid::Uuid)", self.rust_name),
+                    |w| {
+                        self.write_self_field_assign_some(w, s, "v.as_bytes().to_vec()");
+                    },
+                );
+            }
+            FieldKind::Repeated(..) => {
+                w.write_line("");
+                self.write_deprecated(w);
+                w.pub_fn(
+                    &format!(
+                        "get_{}_uuids(&self) -> ::std::vec::Vec<::uuid::Uuid>",
+                        self.rust_name
+                    ),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.{}.iter().filter_map(|v| {}(v)).collect()",
+                            self.rust_name, from_bytes
+                        ));
+                    },
+                );
+                w.write_line("");
+                self.write_deprecated(w);
+                w.pub_fn(
+                    &format!("set_{}_uuids(&mut self, v: &[::uuid::Uuid])", self.rust_name),
+                    |w| {
+                        w.write_line(&format!(
+                            "self.{} = v.iter().map(|v| v.as_bytes().to_vec()).collect();",
+                            self.rust_name
+                        ));
+                    },
+                );
+            }
+            FieldKind::Map(..) | FieldKind::Oneof(..) => unreachable!(),
+        }
+    }
+
+    // field with `generate_append_slice` option
+    fn write_message_field_append_slice(&self, w: &mut CodeWriter) {
+        let elem_type = match self.kind {
+            FieldKind::Repeated(RepeatedField { ref elem, .. }) => {
+                elem.rust_storage_elem_type(&self.get_file_and_mod())
+            }
+            _ => unreachable!(),
+        };
+        self.write_deprecated(w);
+        w.pub_fn(
+            &format!(
+                "append_{}(&mut self, values: &[{}])",
+                self.rust_name, elem_type
+            ),
+            |w| {
+                w.for_stmt("values", "v", |w| {
+                    w.write_line(&format!(
+                        "self.{}.push(::std::clone::Clone::clone(v));",
+                        self.rust_name
+                    ));
+                });
+            },
+        );
+    }
+
+    // map field setter collecting `(key, value)` pairs
+    fn write_message_field_set_from_iter(&self, w: &mut CodeWriter) {
+        let (key, value) = match self.kind {
+            FieldKind::Map(MapField {
+                ref key, ref value, ..
+            }) => (
+                key.rust_storage_elem_type(&self.get_file_and_mod()),
+                value.rust_storage_elem_type(&self.get_file_and_mod()),
+            ),
+            _ => unreachable!(),
+        };
+        self.write_deprecated(w);
+        w.pub_fn(
+            &format!(
+                "set_{}_from_iter<I: ::std::iter::IntoIterator<Item = ({}, {})>>(&mut self, v: I)",
+                self.rust_name, key, value
+            ),
+            |w| {
+                w.write_line(&format!(
+                    "self.{} = ::std::iter::FromIterator::from_iter(v);",
+                    self.rust_name
+                ));
+            },
+        );
+    }
+
     pub fn write_message_single_field_accessors(&self, w: &mut CodeWriter) {
         if self.generate_accessors || self.generate_getter {
             w.write_line("");
@@ -2324,12 +3616,34 @@ impl<'a> FieldGen<'a> {
             self.write_message_field_get(w);
         }
 
+        if let Some(ref ip_addr) = self.ip_addr {
+            self.write_message_field_ip_addr_accessors(ip_addr, w);
+        }
+
+        if self.uuid {
+            self.write_message_field_uuid_accessors(w);
+        }
+
+        if self.lazy && self.is_repeated_packed() {
+            w.write_line("");
+            self.write_message_field_iter_lazy_packed(w);
+        }
+
+        if self.is_repeated_not_map()
+            && !self.lazy
+            && self.customize.generate_append_slice.unwrap_or(false)
+        {
+            w.write_line("");
+            self.write_message_field_append_slice(w);
+        }
+
         if !self.generate_accessors {
             return;
         }
 
         w.write_line("");
         let clear_field_func = self.clear_field_func();
+        self.write_deprecated(w);
         w.pub_fn(&format!("{}(&mut self)", clear_field_func), |w| {
             self.write_clear(w);
         });
@@ -2342,6 +3656,16 @@ impl<'a> FieldGen<'a> {
         w.write_line("");
         self.write_message_field_set(w);
 
+        if self.is_map() {
+            w.write_line("");
+            self.write_message_field_set_from_iter(w);
+        }
+
+        if self.proto_type == field_descriptor_proto::Type::TYPE_ENUM && !self.is_repeated_or_map() {
+            w.write_line("");
+            self.write_message_field_set_i32(w);
+        }
+
         if self.has_mut() {
             w.write_line("");
             self.write_message_field_mut(w);
@@ -2354,6 +3678,125 @@ impl<'a> FieldGen<'a> {
     }
 }
 
+// `lazy` option is only supported for singular message fields
+// and packed repeated scalar fields
+fn check_lazy_field(field: &FieldWithContext, kind: &FieldKind) {
+    match *kind {
+        FieldKind::Singular(SingularField {
+            elem: FieldElem::Message(..),
+            ..
+        }) => {}
+        FieldKind::Repeated(RepeatedField { packed: true, .. })
+            if is_packable_type(field.field.get_field_type()) => {}
+        _ => panic!(
+            "field {} of message {}: `lazy` option is only supported for \
+             singular message fields and packed repeated scalar fields",
+            field.name(),
+            field.message.name_to_package()
+        ),
+    }
+}
+
+fn check_serde_flatten_field(field: &FieldWithContext, kind: &FieldKind, lazy: bool) {
+    match *kind {
+        FieldKind::Singular(SingularField {
+            elem: FieldElem::Message(..),
+            ..
+        }) if !lazy => {}
+        _ => panic!(
+            "field {} of message {}: `serde_flatten` option is only supported for \
+             singular message fields without `lazy` option",
+            field.name(),
+            field.message.name_to_package()
+        ),
+    }
+}
+
+// `small_repeated` option is ignored for enum fields
+// and fields stored with `Bytes`, `Chars` or IP address types
+fn supports_small_repeated(field: &FieldWithContext, elem: &FieldElem) -> bool {
+    match *elem {
+        FieldElem::Message(..) | FieldElem::Primitive(_, PrimitiveTypeVariant::Default) => {
+            rustproto::exts::ip_addr
+                .get(field.field.options.get_message())
+                .is_none()
+        }
+        _ => false,
+    }
+}
+
+fn check_uuid_field(field: &FieldWithContext, kind: &FieldKind, ip_addr: bool) {
+    if ip_addr {
+        panic!(
+            "field {} of message {}: `uuid` and `ip_addr` options cannot be used together",
+            field.name(),
+            field.message.name_to_package()
+        );
+    }
+    match *kind {
+        FieldKind::Singular(SingularField {
+            elem:
+                FieldElem::Primitive(
+                    field_descriptor_proto::Type::TYPE_BYTES,
+                    PrimitiveTypeVariant::Default,
+                ),
+            ..
+        })
+        | FieldKind::Repeated(RepeatedField {
+            elem:
+                FieldElem::Primitive(
+                    field_descriptor_proto::Type::TYPE_BYTES,
+                    PrimitiveTypeVariant::Default,
+                ),
+            ..
+        }) => {}
+        _ => panic!(
+            "field {} of message {}: `uuid` option is only supported for \
+             singular and repeated `bytes` fields",
+            field.name(),
+            field.message.name_to_package()
+        ),
+    }
+}
+
+// Rust type of `bytes` field with `ip_addr` option
+fn ip_addr_rust_type(field: &FieldWithContext, kind: &FieldKind, name: &str) -> String {
+    match name {
+        "IpAddr" | "Ipv4Addr" | "Ipv6Addr" => {}
+        _ => panic!(
+            "field {} of message {}: unknown IP address type in `ip_addr` option: {}",
+            field.name(),
+            field.message.name_to_package(),
+            name
+        ),
+    }
+    match *kind {
+        FieldKind::Singular(SingularField {
+            elem:
+                FieldElem::Primitive(
+                    field_descriptor_proto::Type::TYPE_BYTES,
+                    PrimitiveTypeVariant::Default,
+                ),
+            ..
+        })
+        | FieldKind::Repeated(RepeatedField {
+            elem:
+                FieldElem::Primitive(
+                    field_descriptor_proto::Type::TYPE_BYTES,
+                    PrimitiveTypeVariant::Default,
+                ),
+            ..
+        }) => {}
+        _ => panic!(
+            "field {} of message {}: `ip_addr` option is only supported for \
+             singular and repeated `bytes` fields",
+            field.name(),
+            field.message.name_to_package()
+        ),
+    }
+    format!("::std::net::{}", name)
+}
+
 pub(crate) fn rust_field_name_for_protobuf_field_name(name: &str) -> RustIdent {
     if rust::is_rust_keyword(name) {
         return RustIdent::new(&format!("field_{}", name))
@@ -1,6 +1,20 @@
 use rust_name::RustRelativePath;
+use rust_name::RustIdent;
 
 pub(crate) struct FileAndMod {
     pub file: String,
     pub relative_mod: RustRelativePath,
+    /// Code is generated in a per-message file, which is a submodule of the file module
+    pub in_split_file: bool,
+}
+
+impl FileAndMod {
+    /// Path from current mod to the mod of the generated file
+    pub fn path_to_file_mod(&self) -> RustRelativePath {
+        let mut path = self.relative_mod.to_reverse();
+        if self.in_split_file {
+            path.push_ident(RustIdent::super_ident());
+        }
+        path
+    }
 }
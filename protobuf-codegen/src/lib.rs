@@ -3,6 +3,7 @@ extern crate proc_macro;
 
 use std::collections::hash_map::HashMap;
 use std::fmt::Write as FmtWrite;
+use std::fs;
 use std::fs::File;
 use std::io;
 use std::io::Write;
@@ -19,6 +20,7 @@ mod customize;
 mod enums;
 mod file;
 mod extensions;
+mod features;
 mod field;
 mod rust_name;
 mod protobuf_name;
@@ -27,6 +29,8 @@ mod message;
 mod oneof;
 mod rust_types_values;
 mod serde;
+mod arbitrary;
+mod service;
 mod well_known_types;
 pub mod case_convert;
 mod file_descriptor;
@@ -37,25 +41,36 @@ pub(crate) mod syntax;
 pub(crate) mod strx;
 pub(crate) mod rust;
 
-use customize::customize_from_rustproto_for_file;
+use customize::customize_for_file;
 pub use customize::Customize;
+pub use customize::ImportStyle;
+pub use customize::Visibility;
 
 pub mod code_writer;
 
 use self::code_writer::CodeWriter;
+use self::code_writer::PREAMBLE_ALIASES;
 use self::enums::*;
 use self::extensions::*;
 use self::message::*;
 #[doc(hidden)]
 pub use amend_io_error_util::amend_io_error;
 use map::map_entry;
+use well_known_types::referenced_well_known_types;
 use scope::RootScope;
+use service::ServiceGen;
 use scope::FileScope;
+use scope::MessageOrEnumWithScope;
+use scope::WithScope;
 use file::proto_path_to_rust_mod;
+use rust_name::RustIdent;
+use case_convert::snake_case;
 
 pub use protobuf_name::ProtobufIdent;
 pub use protobuf_name::ProtobufAbsolutePath;
 pub use protobuf_name::ProtobufRelativePath;
+pub use scope::UnresolvedType;
+pub use scope::UnresolvedTypesError;
 
 
 fn escape_byte(s: &mut String, b: u8) {
@@ -79,6 +94,9 @@ fn escape_byte(s: &mut String, b: u8) {
 
 fn write_file_descriptor_data(file: &FileDescriptorProto, w: &mut CodeWriter) {
     let fdp_bytes = file.write_to_bytes().unwrap();
+    // descriptor data is not code, it must not be rewritten
+    let no_std = w.no_std();
+    w.set_no_std(false);
     w.write_line("static file_descriptor_proto_data: &'static [u8] = b\"\\");
     w.indented(|w| {
         const MAX_LINE_LEN: usize = 72;
@@ -107,6 +125,7 @@ fn write_file_descriptor_data(file: &FileDescriptorProto, w: &mut CodeWriter) {
         }
     });
     w.write_line("\";");
+    w.set_no_std(no_std);
     w.write_line("");
     w.lazy_static(
         "file_descriptor_proto_lazy",
@@ -130,22 +149,142 @@ fn write_file_descriptor_data(file: &FileDescriptorProto, w: &mut CodeWriter) {
     );
 }
 
+fn split_file_mod_name(message_or_enum_name: &str) -> RustIdent {
+    RustIdent::from(format!("{}_pb", snake_case(message_or_enum_name)))
+}
+
+fn gen_split_file<F>(
+    file_mod: &RustIdent,
+    split_mod: &RustIdent,
+    pub_keyword: &'static str,
+    no_std: bool,
+    use_preamble: bool,
+    cb: F,
+) -> compiler_plugin::GenResult
+where
+    F: Fn(&mut CodeWriter),
+{
+    let mut v = Vec::new();
+
+    {
+        let mut w = CodeWriter::new(&mut v);
+        w.set_pub_keyword(pub_keyword);
+        w.set_no_std(no_std);
+        w.set_use_preamble(use_preamble);
+
+        w.write_generated_by("rust-protobuf", env!("CARGO_PKG_VERSION"));
+        w.write_line("");
+        // split files are a single logical module, so paths are resolved relative to the parent
+        w.write_line("use super::*;");
+        w.write_use_preamble();
+        w.write_line("");
+        cb(&mut w);
+    }
+
+    compiler_plugin::GenResult {
+        name: format!("{}/{}.rs", file_mod, split_mod),
+        content: v,
+    }
+}
+
+// flattened names of nested messages and enums must not conflict
+// with other messages and enums of the file, or their mods
+fn check_flatten_nested_names(file_scope: &FileScope) {
+    let mut type_names: HashMap<String, String> = HashMap::new();
+    let mut mod_names: HashMap<String, String> = HashMap::new();
+    for message_or_enum in file_scope.find_messages_and_enums() {
+        if let MessageOrEnumWithScope::Message(ref m) = message_or_enum {
+            // map entries are not generated
+            if map_entry(m).is_some() {
+                continue;
+            }
+            // mod is generated only for oneofs
+            if !m.message.oneof_decl.is_empty() {
+                let mod_name = format!("{}", m.mod_name());
+                if let Some(prev) = mod_names.insert(mod_name.clone(), m.name_to_package()) {
+                    panic!(
+                        "flattened mod name {} of {} conflicts with {} in {}",
+                        mod_name,
+                        m.name_to_package(),
+                        prev,
+                        file_scope.file_descriptor.get_name()
+                    );
+                }
+            }
+        }
+        let type_name = format!("{}", message_or_enum.rust_name());
+        if let Some(prev) = type_names.insert(type_name.clone(), message_or_enum.name_to_package()) {
+            panic!(
+                "flattened name {} of {} conflicts with {} in {}",
+                type_name,
+                message_or_enum.name_to_package(),
+                prev,
+                file_scope.file_descriptor.get_name()
+            );
+        }
+    }
+}
+
+// imports written with `ImportStyle::UsePreamble` must not conflict
+// with messages and enums of the file, or their mods
+fn check_preamble_alias_names(file_scope: &FileScope) {
+    for message_or_enum in file_scope.find_messages_and_enums() {
+        let mut names = vec![format!("{}", message_or_enum.rust_name())];
+        if let MessageOrEnumWithScope::Message(ref m) = message_or_enum {
+            names.push(format!("{}", m.mod_name()));
+        }
+        for name in names {
+            assert!(
+                !PREAMBLE_ALIASES.iter().any(|&(_, alias)| alias == name),
+                "{} of {} conflicts with `import_style=use_preamble` import in {}",
+                name,
+                message_or_enum.name_to_package(),
+                file_scope.file_descriptor.get_name()
+            );
+        }
+    }
+}
+
+// aliases of referenced well-known types, unless file declares types with the same name
+fn write_well_known_type_aliases(file_scope: &FileScope, root_scope: &RootScope, w: &mut CodeWriter) {
+    let file = file_scope.file_descriptor;
+    for name in referenced_well_known_types(file_scope, root_scope) {
+        if file.message_type.iter().any(|m| m.get_name() == name)
+            || file.enum_type.iter().any(|e| e.get_name() == name)
+        {
+            continue;
+        }
+        w.write_line("");
+        w.write_line(&format!(
+            "{} type {} = ::protobuf::well_known_types::{};",
+            w.pub_keyword(),
+            name,
+            name
+        ));
+    }
+}
+
 fn gen_file(
     file: &FileDescriptorProto,
     _files_map: &HashMap<&Path, &FileDescriptorProto>,
     root_scope: &RootScope,
     customize: &Customize,
-) -> Option<compiler_plugin::GenResult> {
+) -> Vec<compiler_plugin::GenResult> {
     // TODO: use it
-    let mut customize = customize.clone();
-    // options specified in invocation have precedence over options specified in file
-    customize.update_with(&customize_from_rustproto_for_file(
-        file.options.get_message(),
-    ));
+    let customize = customize_for_file(customize, file);
+
+    let split_files = customize.split_files.unwrap_or(false);
+    let flatten_nested = customize.flatten_nested.unwrap_or(false);
 
-    let scope = FileScope {
+    let file_scope = FileScope {
         file_descriptor: file,
-    }.to_scope();
+        split_files,
+        flatten_nested,
+    };
+    if flatten_nested {
+        check_flatten_nested_names(&file_scope);
+    }
+    let scope = file_scope.to_scope();
     let lite_runtime = customize.lite_runtime.unwrap_or_else(|| {
         file
             .options
@@ -154,23 +293,103 @@ fn gen_file(
             == file_options::OptimizeMode::LITE_RUNTIME
     });
 
+    let file_mod = proto_path_to_rust_mod(file.get_name());
+    let pub_keyword = customize
+        .type_visibility
+        .unwrap_or(Visibility::Public)
+        .keyword();
+    let no_std = customize.no_std.unwrap_or(false);
+    let use_preamble = customize.import_style == Some(ImportStyle::UsePreamble);
+    if use_preamble {
+        check_preamble_alias_names(&file_scope);
+    }
+
+    let mut results = Vec::new();
+
     let mut v = Vec::new();
 
     {
         let mut w = CodeWriter::new(&mut v);
+        w.set_pub_keyword(pub_keyword);
+        w.set_no_std(no_std);
+        w.set_use_preamble(use_preamble);
 
         w.write_generated_by("rust-protobuf", env!("CARGO_PKG_VERSION"));
 
-        for message in &scope.get_messages() {
-            // ignore map entries, because they are not used in map fields
-            if map_entry(message).is_none() {
+        w.write_line("");
+        w.write_line("// fails to compile if generated code and protobuf runtime versions differ");
+        w.write_line(&format!(
+            "const _PROTOBUF_VERSION_CHECK: () = ::protobuf::{};",
+            protobuf::VERSION_IDENT
+        ));
+
+        if use_preamble {
+            w.write_line("");
+            w.write_use_preamble();
+        }
+
+        if customize.reexport_well_known.unwrap_or(false) {
+            write_well_known_type_aliases(&file_scope, root_scope, &mut w);
+        }
+
+        // ignore map entries, because they are not used in map fields
+        let messages: Vec<_> = scope
+            .get_messages()
+            .into_iter()
+            .filter(|m| map_entry(m).is_none())
+            .collect();
+        let enums = scope.get_enums();
+
+        if split_files {
+            let mut split_mods = Vec::new();
+            for message in &messages {
+                let split_mod = split_file_mod_name(message.message.get_name());
+                results.push(gen_split_file(&file_mod, &split_mod, pub_keyword, no_std, use_preamble, |w| {
+                    MessageGen::new(message, &root_scope, &customize).write(w);
+                }));
+                split_mods.push(split_mod);
+            }
+            for enum_type in &enums {
+                let split_mod = split_file_mod_name(enum_type.en.get_name());
+                results.push(gen_split_file(&file_mod, &split_mod, pub_keyword, no_std, use_preamble, |w| {
+                    EnumGen::new(enum_type, &customize, root_scope).write(w);
+                }));
+                split_mods.push(split_mod);
+            }
+
+            for split_mod in &split_mods {
+                assert!(
+                    !messages.iter().any(|m| m.mod_name() == *split_mod),
+                    "split file mod {} conflicts with nested message mod in {}",
+                    split_mod, file.get_name()
+                );
+                w.write_line("");
+                w.write_line(format!("mod {};", split_mod));
+                w.write_line(format!("{} use self::{}::*;", w.pub_keyword(), split_mod));
+            }
+        } else {
+            for message in &messages {
                 w.write_line("");
                 MessageGen::new(message, &root_scope, &customize).write(&mut w);
             }
+            for enum_type in &enums {
+                w.write_line("");
+                EnumGen::new(enum_type, &customize, root_scope).write(&mut w);
+            }
         }
-        for enum_type in &scope.get_enums() {
-            w.write_line("");
-            EnumGen::new(enum_type, &customize, root_scope).write(&mut w);
+
+        if customize.generate_services.unwrap_or(false) {
+            for service in &file.service {
+                let service_gen = ServiceGen::new(service, file, root_scope);
+                assert!(
+                    !file.message_type.iter().any(|m| m.get_name() == service_gen.trait_name())
+                        && !file.enum_type.iter().any(|e| e.get_name() == service_gen.trait_name()),
+                    "service {} conflicts with message or enum in {}",
+                    service.get_name(), file.get_name()
+                );
+                w.write_line("");
+                service_gen.write(&mut w);
+            }
         }
 
         write_extensions(file, &root_scope, &mut w);
@@ -181,36 +400,114 @@ fn gen_file(
         }
     }
 
-    Some(compiler_plugin::GenResult {
-        name: format!("{}.rs", proto_path_to_rust_mod(file.get_name())),
+    let name = if split_files {
+        format!("{}/mod.rs", file_mod)
+    } else {
+        format!("{}.rs", file_mod)
+    };
+
+    results.insert(0, compiler_plugin::GenResult {
+        name,
         content: v,
-    })
+    });
+
+    results
+}
+
+// generated files are placed in the same directory regardless of proto file directory,
+// so mod names of all files must be distinct
+fn gen_mod_rs(files: &[&FileDescriptorProto], customize: &Customize) -> compiler_plugin::GenResult {
+    let mut v = Vec::new();
+
+    {
+        let mut w = CodeWriter::new(&mut v);
+        w.write_line(format!(
+            "// This file is generated by rust-protobuf {}. Do not edit",
+            env!("CARGO_PKG_VERSION")
+        ));
+        w.write_line("// @generated");
+        w.write_line("");
+
+        let mut mods: Vec<(RustIdent, &str)> = Vec::new();
+        for file in files {
+            let file_mod = proto_path_to_rust_mod(file.get_name());
+            if let Some(&(_, other)) = mods.iter().find(|&&(ref m, _)| *m == file_mod) {
+                panic!(
+                    "files {} and {} are both generated as mod {}",
+                    other,
+                    file.get_name(),
+                    file_mod
+                );
+            }
+            let pub_keyword = customize_for_file(customize, file)
+                .type_visibility
+                .unwrap_or(Visibility::Public)
+                .keyword();
+            w.write_line(format!("{} mod {};", pub_keyword, file_mod));
+            mods.push((file_mod, file.get_name()));
+        }
+    }
+
+    compiler_plugin::GenResult {
+        name: "mod.rs".to_owned(),
+        content: v,
+    }
 }
 
 // This function is also used externally by cargo plugin
 // https://github.com/plietar/rust-protobuf-build
 // So be careful changing its signature.
-pub fn gen(
+/// Generate code, or return an error if files to generate reference types
+/// which are not found in `file_descriptors`.
+pub fn try_gen(
     file_descriptors: &[FileDescriptorProto],
     files_to_generate: &[PathBuf],
     customize: &Customize,
-) -> Vec<compiler_plugin::GenResult> {
+) -> Result<Vec<compiler_plugin::GenResult>, UnresolvedTypesError> {
     let root_scope = RootScope {
         file_descriptors: file_descriptors,
+        customize,
     };
 
-    let mut results: Vec<compiler_plugin::GenResult> = Vec::new();
     let files_map: HashMap<&Path, &FileDescriptorProto> =
         file_descriptors.iter().map(|f| (Path::new(f.get_name()), f)).collect();
 
-    for file_name in files_to_generate {
-        let file = files_map.get(file_name.as_path()).expect(&format!(
-            "file not found in file descriptors: {:?}, files: {:?}",
-            file_name, files_map.keys()
-        ));
+    let files: Vec<&FileDescriptorProto> = files_to_generate
+        .iter()
+        .map(|file_name| {
+            *files_map.get(file_name.as_path()).expect(&format!(
+                "file not found in file descriptors: {:?}, files: {:?}",
+                file_name,
+                files_map.keys()
+            ))
+        }).collect();
+
+    let mut unresolved = Vec::new();
+    for file in &files {
+        if let Err(e) = root_scope.check_resolved(file) {
+            unresolved.extend(e.types);
+        }
+    }
+    if !unresolved.is_empty() {
+        return Err(UnresolvedTypesError { types: unresolved });
+    }
+
+    let mut results: Vec<compiler_plugin::GenResult> = Vec::new();
+    for file in &files {
         results.extend(gen_file(file, &files_map, &root_scope, customize));
     }
-    results
+    if customize.gen_mod_rs.unwrap_or(false) {
+        results.push(gen_mod_rs(&files, customize));
+    }
+    Ok(results)
+}
+
+pub fn gen(
+    file_descriptors: &[FileDescriptorProto],
+    files_to_generate: &[PathBuf],
+    customize: &Customize,
+) -> Vec<compiler_plugin::GenResult> {
+    try_gen(file_descriptors, files_to_generate, customize).unwrap_or_else(|e| panic!("{}", e))
 }
 
 pub fn gen_and_write(
@@ -219,11 +516,16 @@ pub fn gen_and_write(
     out_dir: &Path,
     customize: &Customize,
 ) -> io::Result<()> {
-    let results = gen(file_descriptors, files_to_generate, customize);
+    let results = try_gen(file_descriptors, files_to_generate, customize)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
     for r in &results {
         let mut file_path = out_dir.to_owned();
         file_path.push(&r.name);
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| amend_io_error(e, format!("failed to create {:?}", parent)))?;
+        }
         let mut file_writer = File::create(&file_path)
             .map_err(|e| amend_io_error(e, format!("failed to create {:?}", file_path)))?;
         file_writer
@@ -243,3 +545,976 @@ pub fn protoc_gen_rust_main() {
         gen(r.file_descriptors, r.files_to_generate, &customize)
     });
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_files() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo/bar.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        for name in &["FirstMessage", "SecondMessage"] {
+            let mut message = DescriptorProto::new();
+            message.set_name(name.to_string());
+            file.message_type.push(message);
+        }
+        let mut en = EnumDescriptorProto::new();
+        en.set_name("SomeEnum".to_owned());
+        let mut value = EnumValueDescriptorProto::new();
+        value.set_name("ZERO".to_owned());
+        en.value.push(value);
+        file.enum_type.push(en);
+
+        let customize = Customize {
+            split_files: Some(true),
+            ..Default::default()
+        };
+        let results = gen(&[file], &[PathBuf::from("foo/bar.proto")], &customize);
+
+        let names: Vec<&str> = results.iter().map(|r| &r.name[..]).collect();
+        assert_eq!(
+            vec![
+                "bar/mod.rs",
+                "bar/first_message_pb.rs",
+                "bar/second_message_pb.rs",
+                "bar/some_enum_pb.rs",
+            ],
+            names
+        );
+
+        let mod_rs = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(mod_rs.contains("mod first_message_pb;\npub use self::first_message_pb::*;\n"));
+        assert!(!mod_rs.contains("pub struct FirstMessage"));
+    }
+
+    #[test]
+    #[should_panic(expected = "exactly one singular numeric field")]
+    fn test_numeric_wrapper_non_numeric_field() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Wrapper".to_owned());
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("value".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_STRING);
+        message.field.push(field);
+        file.message_type.push(message);
+
+        let customize = Customize {
+            numeric_wrapper: Some(true),
+            ..Default::default()
+        };
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    #[should_panic(expected = "exactly one singular string field")]
+    fn test_string_wrapper_non_string_field() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Id".to_owned());
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("value".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_INT32);
+        message.field.push(field);
+        file.message_type.push(message);
+
+        let customize = Customize {
+            string_wrapper: Some(true),
+            ..Default::default()
+        };
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    #[should_panic(expected = "`json_fromstr` option requires reflection")]
+    fn test_json_fromstr_lite_runtime() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Config".to_owned());
+        file.message_type.push(message);
+
+        let customize = Customize {
+            json_fromstr: Some(true),
+            lite_runtime: Some(true),
+            ..Default::default()
+        };
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "message Config: `text_format_fromstr` and `json_fromstr` options cannot be used together"
+    )]
+    fn test_text_format_fromstr_json_fromstr() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Config".to_owned());
+        file.message_type.push(message);
+
+        let customize = Customize {
+            json_fromstr: Some(true),
+            text_format_fromstr: Some(true),
+            ..Default::default()
+        };
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    #[should_panic(expected = "flattened name Outer_Inner of Outer.Inner conflicts with Outer_Inner")]
+    fn test_flatten_nested_name_conflict() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut outer = DescriptorProto::new();
+        outer.set_name("Outer".to_owned());
+        let mut inner = DescriptorProto::new();
+        inner.set_name("Inner".to_owned());
+        outer.nested_type.push(inner);
+        file.message_type.push(outer);
+        let mut flat = DescriptorProto::new();
+        flat.set_name("Outer_Inner".to_owned());
+        file.message_type.push(flat);
+
+        let customize = Customize {
+            flatten_nested: Some(true),
+            ..Default::default()
+        };
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    #[should_panic(expected = "service Foo conflicts with message or enum in foo.proto")]
+    fn test_generate_services_name_conflict() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        file.message_type.push(message);
+        let mut method = MethodDescriptorProto::new();
+        method.set_name("Get".to_owned());
+        method.set_input_type(".Foo".to_owned());
+        method.set_output_type(".Foo".to_owned());
+        let mut service = ServiceDescriptorProto::new();
+        service.set_name("Foo".to_owned());
+        service.method.push(method);
+        file.service.push(service);
+
+        let customize = Customize {
+            generate_services: Some(true),
+            ..Default::default()
+        };
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    #[should_panic(expected = "message Foo has `presence_bitset` option, but it has field named `presence_bits`")]
+    fn test_presence_bitset_field_name_conflict() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto2".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        for (number, name) in vec!["value", "presence_bits"].into_iter().enumerate() {
+            let mut field = FieldDescriptorProto::new();
+            field.set_name(name.to_owned());
+            field.set_number(number as i32 + 1);
+            field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+            field.set_field_type(field_descriptor_proto::Type::TYPE_INT32);
+            message.field.push(field);
+        }
+        file.message_type.push(message);
+
+        let customize = Customize {
+            presence_bitset: Some(true),
+            ..Default::default()
+        };
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    #[should_panic(expected = "message Foo cannot be converted to Bar: field value does not match")]
+    fn test_convertible_to_field_type_mismatch() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        for &(name, field_type) in &[
+            ("Foo", field_descriptor_proto::Type::TYPE_INT32),
+            ("Bar", field_descriptor_proto::Type::TYPE_INT64),
+        ] {
+            let mut message = DescriptorProto::new();
+            message.set_name(name.to_owned());
+            let mut field = FieldDescriptorProto::new();
+            field.set_name("value".to_owned());
+            field.set_number(1);
+            field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+            field.set_field_type(field_type);
+            message.field.push(field);
+            file.message_type.push(message);
+        }
+        // `(rustproto.convertible_to) = "Bar"`
+        file.message_type[0]
+            .options
+            .set_default()
+            .mut_unknown_fields()
+            .add_length_delimited(17043, b"Bar".to_vec());
+
+        gen(&[file], &[PathBuf::from("foo.proto")], &Customize::default());
+    }
+
+    #[test]
+    fn test_unresolved_import() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        file.dependency.push("bar.proto".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("bar".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_MESSAGE);
+        field.set_type_name(".bar.Bar".to_owned());
+        message.field.push(field);
+        file.message_type.push(message);
+
+        let error = match try_gen(&[file], &[PathBuf::from("foo.proto")], &Customize::default()) {
+            Ok(_) => panic!("expecting error"),
+            Err(e) => e,
+        };
+        assert_eq!(
+            vec![UnresolvedType {
+                type_name: ProtobufAbsolutePath::from(".bar.Bar"),
+                referenced_from: "foo.proto".to_owned(),
+            }],
+            error.types
+        );
+        assert_eq!(
+            "types not found in file descriptors (are imported files included?): \
+             `.bar.Bar` referenced from `foo.proto`;",
+            format!("{}", error)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "field value of message Foo: oneof fields are not supported in views")]
+    fn test_generate_views_oneof() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        let mut oneof = OneofDescriptorProto::new();
+        oneof.set_name("one".to_owned());
+        message.oneof_decl.push(oneof);
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("value".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_STRING);
+        field.set_oneof_index(0);
+        message.field.push(field);
+        file.message_type.push(message);
+
+        let customize = Customize {
+            generate_views: Some(true),
+            ..Default::default()
+        };
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "field value of message Foo: `derive_arbitrary` is not supported with `carllerche_bytes_for_bytes`"
+    )]
+    fn test_derive_arbitrary_carllerche_bytes() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("value".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_REPEATED);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_BYTES);
+        message.field.push(field);
+        file.message_type.push(message);
+
+        let customize = Customize {
+            derive_arbitrary: Some(true),
+            carllerche_bytes_for_bytes: Some(true),
+            ..Default::default()
+        };
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    fn test_json_try_from_value() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Config".to_owned());
+        file.message_type.push(message);
+
+        let customize = Customize {
+            json_try_from_value: Some(true),
+            serde_derive_cfg: Some("serde".to_owned()),
+            ..Default::default()
+        };
+        let results = gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(content.contains(
+            "#[cfg(serde)]\nimpl<'a> ::std::convert::TryFrom<&'a ::serde_json::Value> for Config {"
+        ));
+    }
+
+
+    #[test]
+    fn test_json_serialize() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Config".to_owned());
+        file.message_type.push(message);
+
+        let customize = Customize {
+            json_serialize: Some(true),
+            serde_derive: Some(true),
+            serde_derive_cfg: Some("serde".to_owned()),
+            ..Default::default()
+        };
+        let results = gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(content.contains("#[cfg_attr(serde, derive(Deserialize))]\npub struct Config {"));
+        assert!(content.contains("#[cfg(serde)]\nimpl ::serde::Serialize for Config {"));
+    }
+    #[test]
+    #[should_panic(
+        expected = "field type of message Foo: `rust_name` option value `match` is not a valid Rust identifier"
+    )]
+    fn test_rust_name_keyword() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("type".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_INT32);
+        // `(rustproto.rust_name) = "match"`
+        field
+            .options
+            .set_default()
+            .mut_unknown_fields()
+            .add_length_delimited(17052, b"match".to_vec());
+        message.field.push(field);
+        file.message_type.push(message);
+
+        gen(&[file], &[PathBuf::from("foo.proto")], &Customize::default());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "field name of message Foo: `serde_flatten` option is only supported for singular message fields"
+    )]
+    fn test_serde_flatten_not_message() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("name".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_STRING);
+        // `(rustproto.serde_flatten) = true`
+        field
+            .options
+            .set_default()
+            .mut_unknown_fields()
+            .add_varint(17063, 1);
+        message.field.push(field);
+        file.message_type.push(message);
+
+        gen(&[file], &[PathBuf::from("foo.proto")], &Customize::default());
+    }
+
+    #[test]
+    fn test_editions_field_presence() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("editions".to_owned());
+        file.set_edition(Edition::EDITION_2023);
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        for (number, name, presence) in &[
+            (1, "explicit", None),
+            (2, "implicit", Some(feature_set::FieldPresence::IMPLICIT)),
+        ] {
+            let mut field = FieldDescriptorProto::new();
+            field.set_name(name.to_string());
+            field.set_number(*number);
+            field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+            field.set_field_type(field_descriptor_proto::Type::TYPE_INT32);
+            if let Some(presence) = *presence {
+                field
+                    .options
+                    .set_default()
+                    .features
+                    .set_default()
+                    .set_field_presence(presence);
+            }
+            message.field.push(field);
+        }
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("values".to_owned());
+        field.set_number(3);
+        field.set_label(field_descriptor_proto::Label::LABEL_REPEATED);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_INT32);
+        message.field.push(field);
+        file.message_type.push(message);
+
+        let results = gen(&[file], &[PathBuf::from("foo.proto")], &Customize::default());
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(content.contains("explicit: ::std::option::Option<i32>,"));
+        assert!(content.contains("pub implicit: i32,"));
+        assert!(content.contains("::protobuf::rt::vec_packed_varint_size(3, &self.values)"));
+    }
+
+    #[test]
+    fn test_reexport_well_known() {
+        let mut well_known = FileDescriptorProto::new();
+        well_known.set_name("google/protobuf/wkt.proto".to_owned());
+        well_known.set_package("google.protobuf".to_owned());
+        for name in &["Timestamp", "Duration"] {
+            let mut message = DescriptorProto::new();
+            message.set_name(name.to_string());
+            well_known.message_type.push(message);
+        }
+
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        file.dependency.push("google/protobuf/wkt.proto".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Event".to_owned());
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("time".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_MESSAGE);
+        field.set_type_name(".google.protobuf.Timestamp".to_owned());
+        message.field.push(field);
+        file.message_type.push(message);
+
+        let customize = Customize {
+            reexport_well_known: Some(true),
+            ..Default::default()
+        };
+        let results = gen(&[well_known, file], &[PathBuf::from("foo.proto")], &customize);
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(content.contains(
+            "pub type Timestamp = ::protobuf::well_known_types::Timestamp;"
+        ));
+        assert!(!content.contains("pub type Duration"));
+    }
+
+    #[test]
+    fn test_type_visibility_crate() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Outer".to_owned());
+        let mut nested = DescriptorProto::new();
+        nested.set_name("Inner".to_owned());
+        message.nested_type.push(nested);
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("inner".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_MESSAGE);
+        field.set_type_name(".Outer.Inner".to_owned());
+        message.field.push(field);
+        file.message_type.push(message);
+        let mut en = EnumDescriptorProto::new();
+        en.set_name("Color".to_owned());
+        let mut value = EnumValueDescriptorProto::new();
+        value.set_name("RED".to_owned());
+        value.set_number(0);
+        en.value.push(value);
+        file.enum_type.push(en);
+
+        let customize = Customize::parse_from_parameter("type_visibility=crate").unwrap();
+        assert_eq!(Some(Visibility::Crate), customize.type_visibility);
+        let results = gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(content.contains("pub(crate) struct Outer {"));
+        assert!(content.contains("pub(crate) mod outer {"));
+        assert!(content.contains("    pub(crate) struct Inner {"));
+        assert!(content.contains("pub(crate) enum Color {"));
+        assert!(content.contains("pub(crate) fn file_descriptor_proto()"));
+        assert!(!content.lines().any(|l| l.trim_start().starts_with("pub ")));
+    }
+
+    #[test]
+    fn test_oneof_allow_large_enum_variant() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut big = DescriptorProto::new();
+        big.set_name("Big".to_owned());
+        file.message_type.push(big);
+        for &(name, second_type) in &[
+            ("Large", field_descriptor_proto::Type::TYPE_MESSAGE),
+            ("Small", field_descriptor_proto::Type::TYPE_STRING),
+        ] {
+            let mut message = DescriptorProto::new();
+            message.set_name(name.to_owned());
+            let mut oneof = OneofDescriptorProto::new();
+            oneof.set_name("value".to_owned());
+            message.oneof_decl.push(oneof);
+            let mut field = FieldDescriptorProto::new();
+            field.set_name("flag".to_owned());
+            field.set_number(1);
+            field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+            field.set_field_type(field_descriptor_proto::Type::TYPE_BOOL);
+            field.set_oneof_index(0);
+            message.field.push(field);
+            let mut field = FieldDescriptorProto::new();
+            field.set_name("other".to_owned());
+            field.set_number(2);
+            field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+            field.set_field_type(second_type);
+            if second_type == field_descriptor_proto::Type::TYPE_MESSAGE {
+                field.set_type_name(".Big".to_owned());
+            }
+            field.set_oneof_index(0);
+            message.field.push(field);
+            file.message_type.push(message);
+        }
+
+        let customize =
+            Customize::parse_from_parameter("oneof_allow_large_enum_variant=true").unwrap();
+        let results = gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(content.contains(
+            "#[allow(clippy::large_enum_variant)]\n    pub enum Value {\n        flag(bool),\n        other(super::Big),"
+        ));
+        assert_eq!(1, content.matches("clippy::large_enum_variant").count());
+    }
+
+    #[test]
+    fn test_gen_mod_rs() {
+        let names = ["foo.proto", "dir/bar.proto", "dir/sub/baz.proto"];
+        let files: Vec<FileDescriptorProto> = names
+            .iter()
+            .map(|name| {
+                let mut file = FileDescriptorProto::new();
+                file.set_name(name.to_string());
+                file.set_syntax("proto3".to_owned());
+                file
+            }).collect();
+        let paths: Vec<PathBuf> = names.iter().map(PathBuf::from).collect();
+
+        let customize = Customize::parse_from_parameter("gen_mod_rs=true").unwrap();
+        let results = gen(&files, &paths, &customize);
+        let names: Vec<&str> = results.iter().map(|r| &r.name[..]).collect();
+        assert_eq!(vec!["foo.rs", "bar.rs", "baz.rs", "mod.rs"], names);
+        let mod_rs = String::from_utf8(results[3].content.clone()).unwrap();
+        assert!(mod_rs.ends_with("\npub mod foo;\npub mod bar;\npub mod baz;\n"));
+    }
+
+    #[test]
+    #[should_panic(expected = "files a/foo.proto and b/foo.proto are both generated as mod foo")]
+    fn test_gen_mod_rs_conflict() {
+        let names = ["a/foo.proto", "b/foo.proto"];
+        let files: Vec<FileDescriptorProto> = names
+            .iter()
+            .map(|name| {
+                let mut file = FileDescriptorProto::new();
+                file.set_name(name.to_string());
+                file
+            }).collect();
+        let paths: Vec<PathBuf> = names.iter().map(PathBuf::from).collect();
+
+        let customize = Customize {
+            gen_mod_rs: Some(true),
+            ..Default::default()
+        };
+        gen(&files, &paths, &customize);
+    }
+
+    #[test]
+    fn test_no_std() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto2".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        for &(name, label, field_type) in &[
+            ("name", field_descriptor_proto::Label::LABEL_OPTIONAL, field_descriptor_proto::Type::TYPE_STRING),
+            ("values", field_descriptor_proto::Label::LABEL_REPEATED, field_descriptor_proto::Type::TYPE_INT32),
+            ("next", field_descriptor_proto::Label::LABEL_OPTIONAL, field_descriptor_proto::Type::TYPE_MESSAGE),
+        ] {
+            let mut field = FieldDescriptorProto::new();
+            field.set_name(name.to_owned());
+            field.set_number(message.field.len() as i32 + 1);
+            field.set_label(label);
+            field.set_field_type(field_type);
+            if field_type == field_descriptor_proto::Type::TYPE_MESSAGE {
+                field.set_type_name(".Foo".to_owned());
+            }
+            message.field.push(field);
+        }
+        file.message_type.push(message);
+
+        let customize = Customize::parse_from_parameter("no_std=true").unwrap();
+        let results = gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(content.contains("name: ::protobuf::SingularField<::alloc::string::String>,"));
+        assert!(content.contains("pub values: ::alloc::vec::Vec<i32>,"));
+        assert!(content.contains("impl ::core::clone::Clone for Foo"));
+        assert!(content.contains("::alloc::boxed::Box<::protobuf::Message>"));
+        assert!(!content.contains("::std::"));
+    }
+
+    #[test]
+    #[should_panic(expected = "field m of message Foo: map fields are not supported with `no_std`")]
+    fn test_no_std_map() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        let mut entry = DescriptorProto::new();
+        entry.set_name("MEntry".to_owned());
+        let mut options = MessageOptions::new();
+        options.set_map_entry(true);
+        entry.options = ::protobuf::SingularPtrField::some(options);
+        for &(name, number) in &[("key", 1), ("value", 2)] {
+            let mut field = FieldDescriptorProto::new();
+            field.set_name(name.to_owned());
+            field.set_number(number);
+            field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+            field.set_field_type(field_descriptor_proto::Type::TYPE_INT32);
+            entry.field.push(field);
+        }
+        message.nested_type.push(entry);
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("m".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_REPEATED);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_MESSAGE);
+        field.set_type_name(".Foo.MEntry".to_owned());
+        message.field.push(field);
+        file.message_type.push(message);
+
+        let customize = Customize {
+            no_std: Some(true),
+            ..Default::default()
+        };
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    fn test_debug_as_text_format() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        file.message_type.push(message);
+
+        let results = gen(&[file.clone()], &[PathBuf::from("foo.proto")], &Customize::default());
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(content.contains("::protobuf::text_format::fmt(self, f)"));
+
+        let customize = Customize::parse_from_parameter("debug_as_text_format=false").unwrap();
+        let results = gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(content.contains("#[derive(PartialEq,Default,Debug)]"));
+        assert!(!content.contains("::protobuf::text_format::fmt(self, f)"));
+    }
+
+    #[test]
+    #[should_panic(expected = "`debug_as_text_format` option requires reflection")]
+    fn test_debug_as_text_format_lite_runtime() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        file.message_type.push(message);
+
+        let customize = Customize {
+            debug_as_text_format: Some(true),
+            lite_runtime: Some(true),
+            ..Default::default()
+        };
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    fn test_small_repeated() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut en = EnumDescriptorProto::new();
+        en.set_name("Color".to_owned());
+        let mut value = EnumValueDescriptorProto::new();
+        value.set_name("RED".to_owned());
+        value.set_number(0);
+        en.value.push(value);
+        file.enum_type.push(en);
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("numbers".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_REPEATED);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_INT32);
+        message.field.push(field);
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("colors".to_owned());
+        field.set_number(2);
+        field.set_label(field_descriptor_proto::Label::LABEL_REPEATED);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_ENUM);
+        field.set_type_name(".Color".to_owned());
+        message.field.push(field);
+        file.message_type.push(message);
+
+        let customize = Customize::parse_from_parameter("small_repeated=3").unwrap();
+        assert_eq!(Some(3), customize.small_repeated);
+        let results = gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(content.contains("numbers: ::smallvec::SmallVec<[i32; 3]>,"));
+        assert!(content.contains("::protobuf::rt::read_repeated_into_small_vec::"));
+        assert!(content.contains("make_small_vec_accessor::"));
+        // enum fields are not stored in `SmallVec`
+        assert!(content.contains("colors: ::std::vec::Vec<"));
+    }
+
+    #[test]
+    fn test_import_style_use_preamble() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto2".to_owned());
+        // would conflict with unaliased `use protobuf::Message;`
+        let mut message = DescriptorProto::new();
+        message.set_name("Message".to_owned());
+        let mut inner = DescriptorProto::new();
+        inner.set_name("Inner".to_owned());
+        message.nested_type.push(inner);
+        for &(name, label, field_type, type_name) in &[
+            ("inner", field_descriptor_proto::Label::LABEL_OPTIONAL, field_descriptor_proto::Type::TYPE_MESSAGE, ".Message.Inner"),
+            ("names", field_descriptor_proto::Label::LABEL_REPEATED, field_descriptor_proto::Type::TYPE_STRING, ""),
+        ] {
+            let mut field = FieldDescriptorProto::new();
+            field.set_name(name.to_owned());
+            field.set_number(message.field.len() as i32 + 1);
+            field.set_label(label);
+            field.set_field_type(field_type);
+            if !type_name.is_empty() {
+                field.set_type_name(type_name.to_owned());
+            }
+            message.field.push(field);
+        }
+        file.message_type.push(message);
+
+        let customize = Customize::parse_from_parameter("import_style=use_preamble").unwrap();
+        assert_eq!(Some(ImportStyle::UsePreamble), customize.import_style);
+        let results = gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(content.contains("\nuse ::protobuf::rt as pb_rt;\n"));
+        assert!(content.contains("\nuse ::protobuf as pb;\n"));
+        assert!(content.contains("pub inner: pb::SingularPtrField<message::Inner>,"));
+        assert!(content.contains("impl pb::Message for Message {"));
+        assert!(content.contains("pb_rt::read_repeated_string_into(wire_type, is, &mut self.names)?;"));
+        assert!(content.contains("pub mod message {\n    use ::protobuf::rt as pb_rt;\n"));
+        assert!(content
+            .lines()
+            .filter(|l| l.contains("::protobuf::"))
+            .all(|l| l.trim_start().starts_with("use ::protobuf::")));
+    }
+
+    #[test]
+    #[should_panic(expected = "pb_rt of PbRt conflicts with `import_style=use_preamble` import in foo.proto")]
+    fn test_import_style_use_preamble_conflict() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("PbRt".to_owned());
+        let mut nested = DescriptorProto::new();
+        nested.set_name("Nested".to_owned());
+        message.nested_type.push(nested);
+        file.message_type.push(message);
+
+        let customize = Customize {
+            import_style: Some(ImportStyle::UsePreamble),
+            ..Default::default()
+        };
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    #[should_panic(expected = "field m of message Foo: map fields are not supported with `float_total_cmp`")]
+    fn test_float_total_cmp_map() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        let mut entry = DescriptorProto::new();
+        entry.set_name("MEntry".to_owned());
+        let mut options = MessageOptions::new();
+        options.set_map_entry(true);
+        entry.options = ::protobuf::SingularPtrField::some(options);
+        for &(name, number) in &[("key", 1), ("value", 2)] {
+            let mut field = FieldDescriptorProto::new();
+            field.set_name(name.to_owned());
+            field.set_number(number);
+            field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+            field.set_field_type(field_descriptor_proto::Type::TYPE_DOUBLE);
+            entry.field.push(field);
+        }
+        message.nested_type.push(entry);
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("m".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_REPEATED);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_MESSAGE);
+        field.set_type_name(".Foo.MEntry".to_owned());
+        message.field.push(field);
+        file.message_type.push(message);
+
+        let customize = Customize::parse_from_parameter("float_total_cmp=true").unwrap();
+        gen(&[file], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    fn test_version_check() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+
+        let results = gen(&[file], &[PathBuf::from("foo.proto")], &Customize::default());
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        let version_ident = format!("VERSION_{}", env!("CARGO_PKG_VERSION").replace(".", "_"));
+        assert!(content.contains(&format!(
+            "const _PROTOBUF_VERSION_CHECK: () = ::protobuf::{};",
+            version_ident.replace("-", "_").to_uppercase()
+        )));
+    }
+
+    fn intern_strings_file() -> FileDescriptorProto {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("name".to_owned());
+        field.set_number(1);
+        field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_STRING);
+        message.field.push(field);
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("tags".to_owned());
+        field.set_number(2);
+        field.set_label(field_descriptor_proto::Label::LABEL_REPEATED);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_STRING);
+        message.field.push(field);
+        file.message_type.push(message);
+        file
+    }
+
+    #[test]
+    fn test_intern_strings() {
+        let customize = Customize::parse_from_parameter("intern_strings=true").unwrap();
+        assert_eq!(Some(true), customize.intern_strings);
+        let results = gen(&[intern_strings_file()], &[PathBuf::from("foo.proto")], &customize);
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(content.contains("name: ::protobuf::ArcStr,"));
+        assert!(content.contains("tags: ::std::vec::Vec<::protobuf::ArcStr>,"));
+        assert!(content.contains("::protobuf::rt::read_singular_proto3_interned_string_into("));
+        assert!(content.contains("::protobuf::rt::read_repeated_interned_string_into("));
+        assert!(content.contains("pub fn freeze(self) -> ::std::sync::Arc<Foo> {"));
+
+        let results = gen(&[intern_strings_file()], &[PathBuf::from("foo.proto")], &Customize::default());
+        let content = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(!content.contains("fn freeze("));
+    }
+
+    #[test]
+    #[should_panic(expected = "field name of message Foo: `intern_strings` cannot be used with `carllerche_bytes_for_string`")]
+    fn test_intern_strings_carllerche() {
+        let customize =
+            Customize::parse_from_parameter("intern_strings=true carllerche_bytes_for_string=true")
+                .unwrap();
+        gen(&[intern_strings_file()], &[PathBuf::from("foo.proto")], &customize);
+    }
+
+    #[test]
+    fn test_optimize_for_code_size() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.set_syntax("proto2".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        for (number, (name, field_type)) in vec![
+            ("a", field_descriptor_proto::Type::TYPE_INT32),
+            ("b", field_descriptor_proto::Type::TYPE_SINT64),
+            ("c", field_descriptor_proto::Type::TYPE_STRING),
+            ("d", field_descriptor_proto::Type::TYPE_BYTES),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let mut field = FieldDescriptorProto::new();
+            field.set_name(name.to_owned());
+            field.set_number(number as i32 + 1);
+            field.set_label(field_descriptor_proto::Label::LABEL_OPTIONAL);
+            field.set_field_type(field_type);
+            message.field.push(field);
+        }
+        file.message_type.push(message);
+
+        let results = gen(&[file.clone()], &[PathBuf::from("foo.proto")], &Customize::default());
+        let speed = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(!speed.contains("::protobuf::reflect::rt::merge_from_reflect(self, is)"));
+
+        file.options
+            .set_default()
+            .set_optimize_for(file_options::OptimizeMode::CODE_SIZE);
+        let results = gen(&[file], &[PathBuf::from("foo.proto")], &Customize::default());
+        let code_size = String::from_utf8(results[0].content.clone()).unwrap();
+        assert!(code_size.contains("::protobuf::reflect::rt::merge_from_reflect(self, is)"));
+        assert!(code_size.contains("::protobuf::reflect::rt::compute_size_reflect(self)"));
+        assert!(code_size
+            .contains("::protobuf::reflect::rt::write_to_with_cached_sizes_reflect(self, os)"));
+        assert!(code_size.len() < speed.len());
+    }
+}
@@ -1,4 +1,5 @@
 use protobuf::descriptor::*;
+use protobuf::rustproto;
 
 use protobuf::prelude::*;
 
@@ -12,6 +13,8 @@ use map::map_entry;
 use oneof::OneofGen;
 use oneof::OneofVariantGen;
 use serde;
+use arbitrary;
+use scope::MessageOrEnumWithScope;
 use scope::MessageWithScope;
 use scope::WithScope;
 use scope::RootScope;
@@ -21,6 +24,7 @@ use case_convert::snake_case;
 use rust::is_rust_keyword;
 use file_descriptor::file_descriptor_proto_expr;
 use file_and_mod::FileAndMod;
+use ProtobufAbsolutePath;
 
 
 /// Message info for codegen
@@ -30,6 +34,9 @@ pub(crate) struct MessageGen<'a> {
     type_name: RustIdentWithPath,
     pub fields: Vec<FieldGen<'a>>,
     pub lite_runtime: bool,
+    // `merge_from`, `compute_size` and `write_to_with_cached_sizes` use reflection
+    code_size: bool,
+    debug_as_text_format: bool,
     customize: Customize,
 }
 
@@ -44,11 +51,83 @@ impl<'a> MessageGen<'a> {
             message.message.options.get_message(),
         ));
 
-        let fields: Vec<_> = message
+        let mut fields: Vec<_> = message
             .fields()
             .into_iter()
             .map(|field| FieldGen::parse(field, root_scope, &customize))
             .collect();
+        let mut presence_bits = 0;
+        for field in &mut fields {
+            if field.has_presence_bit() {
+                field.set_presence_bit(presence_bits);
+                presence_bits += 1;
+            }
+        }
+        if presence_bits != 0 {
+            assert!(
+                fields.iter().all(|f| f.rust_name.get() != "presence_bits"),
+                "message {} has `presence_bitset` option, \
+                 but it has field named `presence_bits`",
+                message.name_to_package()
+            );
+        }
+        for (i, field) in fields.iter().enumerate() {
+            if let Some(other) = fields[..i].iter().find(|f| f.rust_name == field.rust_name) {
+                panic!(
+                    "message {}: fields {} and {} have the same Rust name `{}`",
+                    message.name_to_package(),
+                    other.proto_field.name(),
+                    field.proto_field.name(),
+                    field.rust_name
+                );
+            }
+        }
+        if customize.derive_arbitrary.unwrap_or(false) {
+            for field in &fields {
+                assert!(
+                    !field.is_carllerche_bytes(),
+                    "field {} of message {}: `derive_arbitrary` is not supported \
+                     with `carllerche_bytes_for_bytes`",
+                    field.proto_field.name(),
+                    message.name_to_package()
+                );
+            }
+        }
+        if customize.no_std.unwrap_or(false) {
+            for field in &fields {
+                let unsupported = match field.kind {
+                    FieldKind::Map(..) => Some("map fields are"),
+                    _ if field.is_ip_addr() => Some("`ip_addr` option is"),
+                    _ => None,
+                };
+                if let Some(unsupported) = unsupported {
+                    panic!(
+                        "field {} of message {}: {} not supported with `no_std`",
+                        field.proto_field.name(),
+                        message.name_to_package(),
+                        unsupported
+                    );
+                }
+            }
+        }
+        if customize.float_total_cmp.unwrap_or(false) {
+            for field in &fields {
+                let unsupported = match field.kind {
+                    FieldKind::Map(..) => Some("map fields are"),
+                    FieldKind::Oneof(..) => Some("oneof fields are"),
+                    _ if field.is_lazy() => Some("`lazy` option is"),
+                    _ => None,
+                };
+                if let Some(unsupported) = unsupported {
+                    panic!(
+                        "field {} of message {}: {} not supported with `float_total_cmp`",
+                        field.proto_field.name(),
+                        message.name_to_package(),
+                        unsupported
+                    );
+                }
+            }
+        }
         let lite_runtime = customize.lite_runtime.unwrap_or_else(|| {
             message
                 .get_file_descriptor()
@@ -57,12 +136,32 @@ impl<'a> MessageGen<'a> {
                 .get_optimize_for()
                 == file_options::OptimizeMode::LITE_RUNTIME
         });
+        // messages with fields reflection codec cannot handle
+        // are generated as with `optimize_for = SPEED`
+        let code_size = !lite_runtime
+            && message
+                .get_file_descriptor()
+                .options
+                .get_message()
+                .get_optimize_for()
+                == file_options::OptimizeMode::CODE_SIZE
+            && fields.iter().all(|f| f.supports_reflection_codec());
+        let debug_as_text_format = customize.debug_as_text_format.unwrap_or(!lite_runtime);
+        if debug_as_text_format && lite_runtime {
+            panic!(
+                "message {}: `debug_as_text_format` option requires reflection, \
+                 so it cannot be used with `lite_runtime`",
+                message.name_to_package()
+            );
+        }
         MessageGen {
             message: message,
             root_scope: root_scope,
             type_name: message.rust_name().to_path(),
             fields: fields,
             lite_runtime,
+            code_size,
+            debug_as_text_format,
             customize,
         }
     }
@@ -71,6 +170,18 @@ impl<'a> MessageGen<'a> {
         self.message.scope.get_file_and_mod()
     }
 
+    // number of `u32` words in presence bitset
+    fn presence_bits_words(&self) -> usize {
+        let bits = self.fields.iter().filter(|f| f.has_presence_bit()).count();
+        (bits + 31) / 32
+    }
+
+    // `freeze` is generated for messages sharing interned strings
+    fn intern_strings(&self) -> bool {
+        self.customize.intern_strings.unwrap_or(false)
+            || self.fields.iter().any(|f| f.intern_strings())
+    }
+
     fn expose_oneof(&self) -> bool {
         self.customize.expose_oneof.unwrap_or(true)
     }
@@ -162,6 +273,10 @@ impl<'a> MessageGen<'a> {
 
     fn write_write_to_with_cached_sizes(&self, w: &mut CodeWriter) {
         w.def_fn("write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()>", |w| {
+            if self.code_size {
+                w.write_line("::protobuf::reflect::rt::write_to_with_cached_sizes_reflect(self, os)");
+                return;
+            }
             // To have access to its methods but not polute the name space.
             for f in self.fields_except_oneof_and_group() {
                 f.write_message_write_field(w);
@@ -202,6 +317,12 @@ impl<'a> MessageGen<'a> {
         // there are unused variables in oneof
         w.allow(&["unused_variables"]);
         w.def_fn("compute_size(&self) -> u32", |w| {
+            if self.code_size {
+                w.write_line("let my_size = ::protobuf::reflect::rt::compute_size_reflect(self);");
+                w.write_line("self.cached_size.set(my_size);");
+                w.write_line("my_size");
+                return;
+            }
             // To have access to its methods but not polute the name space.
             w.write_line("let mut my_size = 0;");
             for field in self.fields_except_oneof_and_group() {
@@ -224,6 +345,57 @@ impl<'a> MessageGen<'a> {
         }
     }
 
+    // like field accessors, oneof accessors are always generated
+    fn write_oneof_accessors(&self, w: &mut CodeWriter) {
+        for oneof in self.oneofs() {
+            let field_name = oneof.oneof.field_name();
+            w.write_line("");
+            w.comment(&format!("oneof {}", oneof.oneof.oneof.get_name()));
+            w.write_line("");
+            w.pub_fn(&format!("clear_{}(&mut self)", field_name), |w| {
+                w.write_line(&format!("self.{} = ::std::option::Option::None;", field_name));
+            });
+            w.write_line("");
+            w.comment("Take oneof value, leaving `None`");
+            w.pub_fn(
+                &format!("take_{}(&mut self) -> {}", field_name, oneof.full_storage_type()),
+                |w| {
+                    w.write_line(&format!("self.{}.take()", field_name));
+                },
+            );
+            for variant in oneof.variants_except_group() {
+                let variant_name = &variant.field.rust_name;
+                let path = variant.path(&self.get_file_and_mod());
+                w.write_line("");
+                w.pub_fn(&format!("is_{}(&self) -> bool", variant_name), |w| {
+                    w.match_expr(&format!("self.{}", field_name), |w| {
+                        w.case_expr(&format!("::std::option::Option::Some({}(..))", path), "true");
+                        w.case_expr("_", "false");
+                    });
+                });
+                w.write_line("");
+                let elem_type = variant.elem_rust_type(&self.get_file_and_mod());
+                w.pub_fn(
+                    &format!(
+                        "{}_ref(&self) -> {}",
+                        variant_name,
+                        RustType::Option(Box::new(RustType::Ref(Box::new(elem_type))))
+                    ),
+                    |w| {
+                        w.match_expr(&format!("self.{}", field_name), |w| {
+                            let v = if variant.is_boxed() { "&**v" } else { "v" };
+                            w.case_expr(
+                                &format!("::std::option::Option::Some({}(ref v))", path),
+                                &format!("::std::option::Option::Some({})", v),
+                            );
+                            w.case_expr("_", "::std::option::Option::None");
+                        });
+                    },
+                );
+            }
+        }
+    }
+
     fn write_impl_self(&self, w: &mut CodeWriter) {
         w.impl_self_block(&format!("{}", self.type_name), |w| {
             // TODO: new should probably be a part of Message trait
@@ -231,7 +403,15 @@ impl<'a> MessageGen<'a> {
                 w.write_line("::std::default::Default::default()");
             });
 
+            if self.intern_strings() {
+                w.write_line("");
+                w.pub_fn(&format!("freeze(self) -> ::std::sync::Arc<{}>", self.type_name), |w| {
+                    w.write_line("::std::sync::Arc::new(self)");
+                });
+            }
+
             self.write_field_accessors(w);
+            self.write_oneof_accessors(w);
         });
     }
 
@@ -251,21 +431,71 @@ impl<'a> MessageGen<'a> {
         );
     }
 
+    // Fields of message with only singular scalar fields with single byte tags,
+    // or `None` if message needs generic `merge_from` loop
+    fn merge_from_fast_path_fields(&self) -> Option<Vec<(u32, &FieldGen)>> {
+        if self.fields.is_empty() {
+            return None;
+        }
+        self.fields
+            .iter()
+            .map(|f| f.merge_from_fast_path_tag().map(|tag| (tag, f)))
+            .collect()
+    }
+
+    fn write_merge_from_field_number_match(&self, w: &mut CodeWriter) {
+        w.if_stmt("!is.field_allowed(field_number)", |w| {
+            w.write_line("::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;");
+            w.write_line("is.record_field_offset(field_number, field_start);");
+            w.write_line("continue;");
+        });
+        w.match_block("field_number", |w| {
+            for f in &self.fields_except_group() {
+                let number = f.proto_field.number();
+                w.case_block(number.to_string(), |w| {
+                    f.write_merge_from_field("wire_type", w);
+                });
+            }
+            w.case_block("_", |w| {
+                w.write_line("::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;");
+            });
+        });
+        w.write_line("is.record_field_offset(field_number, field_start);");
+    }
+
     fn write_merge_from(&self, w: &mut CodeWriter) {
         w.def_fn(&format!("merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()>"), |w| {
+            if self.code_size {
+                w.write_line("::protobuf::reflect::rt::merge_from_reflect(self, is)");
+                return;
+            }
             w.while_block("!is.eof()?", |w| {
-                w.write_line(&format!("let (field_number, wire_type) = is.read_tag_unpack()?;"));
-                w.match_block("field_number", |w| {
-                    for f in &self.fields_except_group() {
-                        let number = f.proto_field.number();
-                        w.case_block(number.to_string(), |w| {
-                            f.write_merge_from_field("wire_type", w);
+                // start of field for `CodedInputStream::record_field_offset`
+                w.write_line("let field_start = is.pos();");
+                match self.merge_from_fast_path_fields() {
+                    Some(fields) => {
+                        // tags with expected wire types are matched directly,
+                        // everything else is handled as in generic loop
+                        w.write_line("let tag = is.read_raw_varint32()?;");
+                        w.match_block("tag", |w| {
+                            for &(tag, f) in &fields {
+                                let number = f.proto_field.number();
+                                w.case_block(format!("{} if is.field_allowed({})", tag, number), |w| {
+                                    f.write_merge_from_fast_path(w);
+                                    w.write_line(&format!("is.record_field_offset({}, field_start);", number));
+                                });
+                            }
+                            w.case_block("_", |w| {
+                                w.write_line("let (field_number, wire_type) = ::protobuf::rt::unpack_tag(tag)?;");
+                                self.write_merge_from_field_number_match(w);
+                            });
                         });
                     }
-                    w.case_block("_", |w| {
-                        w.write_line("::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;");
-                    });
-                });
+                    None => {
+                        w.write_line(&format!("let (field_number, wire_type) = is.read_tag_unpack()?;"));
+                        self.write_merge_from_field_number_match(w);
+                    }
+                }
             });
             w.write_line("::std::result::Result::Ok(())");
         });
@@ -320,6 +550,13 @@ impl<'a> MessageGen<'a> {
                     continue;
                 }
 
+                if f.is_lazy() {
+                    w.if_stmt(format!("!self.{}.is_initialized()", f.rust_name), |w| {
+                        w.write_line("return false;");
+                    });
+                    continue;
+                }
+
                 // TODO:
                 // if message is declared in this file and has no message fields,
                 // we could skip the check here
@@ -357,12 +594,27 @@ impl<'a> MessageGen<'a> {
             w.def_fn(&format!("new() -> {}", self.type_name), |w| {
                 w.write_line(&format!("{}::new()", self.type_name));
             });
+            w.write_line("");
+            w.def_fn("clone_box(&self) -> ::std::boxed::Box<::protobuf::Message>", |w| {
+                w.write_line("::std::boxed::Box::new(::std::clone::Clone::clone(self))");
+            });
             if !self.lite_runtime {
                 w.write_line("");
                 self.write_descriptor_static(w);
             }
             w.write_line("");
             self.write_default_instance(w);
+            w.write_line("");
+            self.write_shrink_to_fit(w);
+        });
+    }
+
+    fn write_shrink_to_fit(&self, w: &mut CodeWriter) {
+        w.def_fn("shrink_to_fit(&mut self)", |w| {
+            for f in self.fields_except_group() {
+                f.write_shrink_to_fit(w);
+            }
+            w.write_line("self.unknown_fields.shrink_to_fit();");
         });
     }
 
@@ -374,6 +626,433 @@ impl<'a> MessageGen<'a> {
         );
     }
 
+    fn write_impl_convertible(&self, w: &mut CodeWriter) {
+        w.impl_for_block(
+            "::protobuf::ProtobufConvertible",
+            &format!("{}", self.type_name),
+            |_w| {},
+        );
+    }
+
+    fn numeric_wrapper_field(&self) -> &FieldGen {
+        if self.fields.len() != 1 || !self.fields[0].is_singular_numeric() {
+            panic!(
+                "message {} has `numeric_wrapper` option, \
+                 but it does not have exactly one singular numeric field",
+                self.message.name_to_package()
+            );
+        }
+        &self.fields[0]
+    }
+
+    fn write_impl_add(&self, w: &mut CodeWriter) {
+        let field = self.numeric_wrapper_field();
+        w.impl_for_block("::std::ops::Add", &format!("{}", self.type_name), |w| {
+            w.write_line(&format!("type Output = {};", self.type_name));
+            w.write_line("");
+            w.def_fn(
+                &format!("add(mut self, other: {}) -> {}", self.type_name, self.type_name),
+                |w| {
+                    field.write_numeric_wrapper_add("other", w);
+                    w.write_line("self");
+                },
+            );
+        });
+    }
+
+    fn write_impl_sum(&self, w: &mut CodeWriter) {
+        w.impl_for_block("::std::iter::Sum", &format!("{}", self.type_name), |w| {
+            w.def_fn(
+                &format!(
+                    "sum<I: ::std::iter::Iterator<Item = {}>>(iter: I) -> {}",
+                    self.type_name, self.type_name
+                ),
+                |w| {
+                    w.write_line("iter.fold(::std::default::Default::default(), ::std::ops::Add::add)");
+                },
+            );
+        });
+    }
+
+    fn string_wrapper_field(&self) -> &FieldGen {
+        if self.fields.len() != 1 || !self.fields[0].is_singular_string() {
+            panic!(
+                "message {} has `string_wrapper` option, \
+                 but it does not have exactly one singular string field",
+                self.message.name_to_package()
+            );
+        }
+        &self.fields[0]
+    }
+
+    fn write_impl_partial_eq_str(&self, w: &mut CodeWriter) {
+        let field = self.string_wrapper_field();
+        w.impl_for_block("::std::cmp::PartialEq<str>", &format!("{}", self.type_name), |w| {
+            w.def_fn("eq(&self, other: &str) -> bool", |w| {
+                w.write_line(&format!("{} == other", field.string_wrapper_as_str()));
+            });
+        });
+        w.write_line("");
+        w.impl_args_for_block(
+            &["'a"],
+            "::std::cmp::PartialEq<&'a str>",
+            &format!("{}", self.type_name),
+            |w| {
+                w.def_fn("eq(&self, other: &&'a str) -> bool", |w| {
+                    w.write_line("<Self as ::std::cmp::PartialEq<str>>::eq(self, *other)");
+                });
+            },
+        );
+    }
+
+    fn write_impl_from_str(&self, w: &mut CodeWriter) {
+        if self.lite_runtime {
+            panic!(
+                "message {}: `json_fromstr` option requires reflection, \
+                 so it cannot be used with `lite_runtime`",
+                self.message.name_to_package()
+            );
+        }
+        w.impl_for_block("::std::str::FromStr", &format!("{}", self.type_name), |w| {
+            w.write_line("type Err = ::protobuf::json::ParseErrorWithLoc;");
+            w.write_line("");
+            w.def_fn(
+                &format!(
+                    "from_str(s: &str) -> ::std::result::Result<{}, ::protobuf::json::ParseErrorWithLoc>",
+                    self.type_name
+                ),
+                |w| {
+                    w.write_line("::protobuf::json::parse_from_str(s)");
+                },
+            );
+        });
+    }
+
+    fn write_impl_text_format_from_str(&self, w: &mut CodeWriter) {
+        if self.lite_runtime {
+            panic!(
+                "message {}: `text_format_fromstr` option requires reflection, \
+                 so it cannot be used with `lite_runtime`",
+                self.message.name_to_package()
+            );
+        }
+        if self.customize.json_fromstr.unwrap_or(false) {
+            panic!(
+                "message {}: `text_format_fromstr` and `json_fromstr` options \
+                 cannot be used together",
+                self.message.name_to_package()
+            );
+        }
+        w.impl_for_block("::std::str::FromStr", &format!("{}", self.type_name), |w| {
+            w.write_line("type Err = ::protobuf::text_format::ParseErrorWithLoc;");
+            w.write_line("");
+            w.def_fn(
+                &format!(
+                    "from_str(s: &str) -> ::std::result::Result<{}, ::protobuf::text_format::ParseErrorWithLoc>",
+                    self.type_name
+                ),
+                |w| {
+                    w.write_line("::protobuf::text_format::parse_from_str(s)");
+                },
+            );
+        });
+    }
+
+    fn write_impl_try_from_json_value(&self, w: &mut CodeWriter) {
+        if self.lite_runtime {
+            panic!(
+                "message {}: `json_try_from_value` option requires reflection, \
+                 so it cannot be used with `lite_runtime`",
+                self.message.name_to_package()
+            );
+        }
+        if let Some(ref cfg) = self.customize.serde_derive_cfg {
+            w.write_line(&format!("#[cfg({})]", cfg));
+        }
+        w.impl_args_for_block(
+            &["'a"],
+            "::std::convert::TryFrom<&'a ::serde_json::Value>",
+            &format!("{}", self.type_name),
+            |w| {
+                w.write_line("type Error = ::protobuf::json::ParseErrorWithLoc;");
+                w.write_line("");
+                w.def_fn(
+                    &format!(
+                        "try_from(json: &'a ::serde_json::Value) -> ::std::result::Result<{}, ::protobuf::json::ParseErrorWithLoc>",
+                        self.type_name
+                    ),
+                    |w| {
+                        w.write_line("::protobuf::json::parse_from_value(json)");
+                    },
+                );
+            },
+        );
+    }
+
+    fn write_impl_json_serialize(&self, w: &mut CodeWriter) {
+        if self.lite_runtime {
+            panic!(
+                "message {}: `json_serialize` option requires reflection, \
+                 so it cannot be used with `lite_runtime`",
+                self.message.name_to_package()
+            );
+        }
+        if let Some(ref cfg) = self.customize.serde_derive_cfg {
+            w.write_line(&format!("#[cfg({})]", cfg));
+        }
+        w.impl_for_block("::serde::Serialize", &format!("{}", self.type_name), |w| {
+            w.def_fn(
+                "serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>",
+                |w| {
+                    w.write_line("::protobuf::json::serialize(self, serializer)");
+                },
+            );
+        });
+    }
+
+    // field specified in `io_write_field` option
+    fn io_write_field(&self) -> Option<&FieldGen> {
+        let name = rustproto::exts::io_write_field.get(self.message.message.options.get_message())?;
+        if self.customize.no_std.unwrap_or(false) {
+            panic!(
+                "message {}: `io_write_field` is not supported with `no_std`",
+                self.message.name_to_package()
+            );
+        }
+        match self.fields.iter().find(|f| f.proto_field.name() == name) {
+            Some(field) if field.is_singular_bytes_vec() => Some(field),
+            Some(..) => panic!(
+                "message {} has `io_write_field` option, \
+                 but field {} is not a singular `bytes` field stored as `Vec<u8>`",
+                self.message.name_to_package(),
+                name
+            ),
+            None => panic!(
+                "message {} has `io_write_field` option, but field {} is not found",
+                self.message.name_to_package(),
+                name
+            ),
+        }
+    }
+
+    fn write_impl_io_write(&self, field: &FieldGen, w: &mut CodeWriter) {
+        w.impl_for_block("::std::io::Write", &format!("{}", self.type_name), |w| {
+            w.def_fn("write(&mut self, buf: &[u8]) -> ::std::io::Result<usize>", |w| {
+                field.write_io_write_append("buf", w);
+                w.write_line("::std::result::Result::Ok(buf.len())");
+            });
+            w.write_line("");
+            w.def_fn("flush(&mut self) -> ::std::io::Result<()>", |w| {
+                w.write_line("::std::result::Result::Ok(())");
+            });
+        });
+    }
+
+    // message specified in `convertible_to` option
+    fn convertible_to(&self) -> Option<MessageWithScope<'a>> {
+        let name = rustproto::exts::convertible_to.get(self.message.message.options.get_message())?;
+        let target = match self
+            .root_scope
+            .find_message_or_enum_opt(&ProtobufAbsolutePath::from_path_maybe_dot(&name))
+        {
+            Some(MessageOrEnumWithScope::Message(m)) => m,
+            _ => panic!(
+                "message {} has `convertible_to` option, but message {} is not found",
+                self.message.name_to_package(),
+                name
+            ),
+        };
+        self.check_convertible_to(&target);
+        Some(target)
+    }
+
+    fn check_convertible_to(&self, target: &MessageWithScope) {
+        let fail = |reason: &str| -> ! {
+            panic!(
+                "message {} cannot be converted to {}: {}",
+                self.message.name_to_package(),
+                target.name_to_package(),
+                reason
+            )
+        };
+        if self.message.get_scope().file_scope.syntax() != target.get_scope().file_scope.syntax() {
+            fail("messages have different syntax");
+        }
+        if !self.message.message.oneof_decl.is_empty() || !target.message.oneof_decl.is_empty() {
+            fail("oneofs are not supported");
+        }
+        if self.message.message.field.len() != target.message.field.len() {
+            fail("messages have different number of fields");
+        }
+        for field in &self.message.message.field {
+            if !target.message.field.iter().any(|t| self.convertible_field(field, t)) {
+                fail(&format!("field {} does not match", field.get_name()));
+            }
+        }
+    }
+
+    fn convertible_field(&self, a: &FieldDescriptorProto, b: &FieldDescriptorProto) -> bool {
+        if a.get_number() != b.get_number()
+            || a.get_name() != b.get_name()
+            || a.get_label() != b.get_label()
+            || a.get_field_type() != b.get_field_type()
+        {
+            return false;
+        }
+        if a.get_type_name() == b.get_type_name() {
+            return true;
+        }
+        // map entries are nested messages, so their names are different
+        let find_map_entry = |type_name: &str| match self
+            .root_scope
+            .find_message_or_enum_opt(&ProtobufAbsolutePath::from(type_name))
+        {
+            Some(MessageOrEnumWithScope::Message(ref m))
+                if m.message.options.get_message().get_map_entry() => Some(m.message),
+            _ => None,
+        };
+        match (find_map_entry(a.get_type_name()), find_map_entry(b.get_type_name())) {
+            (Some(a), Some(b)) => {
+                a.field.len() == b.field.len()
+                    && a.field.iter().zip(&b.field).all(|(a, b)| self.convertible_field(a, b))
+            }
+            _ => false,
+        }
+    }
+
+    fn write_impl_from_for_convertible_to(&self, target: &MessageWithScope, w: &mut CodeWriter) {
+        if self.presence_bits_words() != 0 {
+            panic!(
+                "message {}: `convertible_to` is not supported with `presence_bitset`",
+                self.message.name_to_package()
+            );
+        }
+        let target_type = message_or_enum_to_rust_relative(target, &self.get_file_and_mod());
+        w.impl_for_block(
+            &format!("::std::convert::From<{}>", self.type_name),
+            &format!("{}", target_type),
+            |w| {
+                w.def_fn(&format!("from(v: {}) -> {}", self.type_name, target_type), |w| {
+                    w.expr_block(&format!("{}", target_type), |w| {
+                        for field in self.fields_except_group() {
+                            w.write_line(&format!("{0}: v.{0},", field.rust_name));
+                        }
+                        w.write_line("unknown_fields: v.unknown_fields,");
+                        w.write_line("cached_size: ::std::default::Default::default(),");
+                    });
+                });
+            },
+        );
+    }
+
+    fn view_name(&self) -> String {
+        format!("{}View", self.type_name)
+    }
+
+    fn write_view_struct(&self, w: &mut CodeWriter) {
+        w.derive(&["Clone", "Default", "Debug", "PartialEq"]);
+        w.pub_struct(&format!("{}<'a>", self.view_name()), |w| {
+            for field in self.fields_except_group() {
+                w.pub_field_decl(field.rust_name.get(), &field.view_type());
+            }
+            w.field_decl("_marker", "::std::marker::PhantomData<&'a ()>");
+        });
+    }
+
+    fn write_view_impl_self(&self, w: &mut CodeWriter) {
+        w.expr_block(&format!("impl<'a> {}<'a>", self.view_name()), |w| {
+            w.pub_fn(
+                &format!("parse(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<{}<'a>>", self.view_name()),
+                |w| {
+                    w.write_line("::protobuf::parse_view_from_bytes(bytes)");
+                },
+            );
+        });
+    }
+
+    fn write_view_impl_message_view(&self, w: &mut CodeWriter) {
+        w.impl_args_for_block(
+            &["'a"],
+            "::protobuf::MessageView<'a>",
+            &format!("{}<'a>", self.view_name()),
+            |w| {
+                w.def_fn("merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'a>) -> ::protobuf::ProtobufResult<()>", |w| {
+                    w.while_block("!is.eof()?", |w| {
+                        w.write_line("let (field_number, wire_type) = is.read_tag_unpack()?;");
+                        w.match_block("field_number", |w| {
+                            for f in &self.fields_except_group() {
+                                let number = f.proto_field.number();
+                                w.case_block(number.to_string(), |w| {
+                                    f.write_view_merge_from_field("wire_type", w);
+                                });
+                            }
+                            w.case_block("_", |w| {
+                                w.write_line("::protobuf::rt::skip_unknown_or_group(wire_type, is)?;");
+                            });
+                        });
+                    });
+                    w.write_line("::std::result::Result::Ok(())");
+                });
+            },
+        );
+    }
+
+    // `FooView<'a>` struct which borrows strings and bytes from parsed buffer
+    fn write_view(&self, w: &mut CodeWriter) {
+        self.write_view_struct(w);
+        w.write_line("");
+        self.write_view_impl_self(w);
+        w.write_line("");
+        self.write_view_impl_message_view(w);
+    }
+
+    fn parts_name(&self) -> String {
+        format!("{}Parts", self.type_name)
+    }
+
+    fn write_parts_struct(&self, w: &mut CodeWriter) {
+        let mut derive = vec!["Clone", "Default", "Debug"];
+        if self.supports_derive_partial_eq() {
+            derive.push("PartialEq");
+        }
+        w.derive(&derive);
+        w.pub_struct(&self.parts_name(), |w| {
+            for field in self.fields_except_oneof_and_group() {
+                w.pub_field_decl(field.rust_name.get(), &field.parts_type().to_string());
+            }
+            for oneof in self.oneofs() {
+                w.pub_field_decl(
+                    &oneof.oneof.field_name().to_string(),
+                    &oneof.full_storage_type().to_string(),
+                );
+            }
+        });
+    }
+
+    fn write_impl_into_parts(&self, w: &mut CodeWriter) {
+        w.impl_self_block(&format!("{}", self.type_name), |w| {
+            w.comment("Consume message returning values of all fields, unknown fields are dropped");
+            w.pub_fn(&format!("into_parts(self) -> {}", self.parts_name()), |w| {
+                w.expr_block(&self.parts_name(), |w| {
+                    for field in self.fields_except_oneof_and_group() {
+                        w.field_entry(field.rust_name.get(), &field.parts_value());
+                    }
+                    for oneof in self.oneofs() {
+                        let name = oneof.oneof.field_name().to_string();
+                        w.field_entry(&name, &format!("self.{}", name));
+                    }
+                });
+            });
+        });
+    }
+
+    // `FooParts` struct with owned values of all fields returned by `Foo::into_parts`
+    fn write_parts(&self, w: &mut CodeWriter) {
+        self.write_parts_struct(w);
+        w.write_line("");
+        self.write_impl_into_parts(w);
+    }
+
     fn write_impl_show(&self, w: &mut CodeWriter) {
         w.impl_for_block("::std::fmt::Debug", &format!("{}", self.type_name), |w| {
             w.def_fn(
@@ -396,23 +1075,142 @@ impl<'a> MessageGen<'a> {
         });
     }
 
+    // names of all struct fields including special fields
+    fn struct_field_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .fields_except_oneof_and_group()
+            .into_iter()
+            .map(|f| f.rust_name.get().to_owned())
+            .collect();
+        for oneof in self.oneofs() {
+            names.push(oneof.oneof.field_name().to_string());
+        }
+        if self.presence_bits_words() != 0 {
+            names.push("presence_bits".to_owned());
+        }
+        names.push("unknown_fields".to_owned());
+        names.push("cached_size".to_owned());
+        names
+    }
+
+    // Not derived to override `clone_from` which reuses allocations of fields
+    fn write_impl_clone(&self, w: &mut CodeWriter) {
+        let names = self.struct_field_names();
+        w.impl_for_block("::std::clone::Clone", &format!("{}", self.type_name), |w| {
+            w.def_fn(&format!("clone(&self) -> {}", self.type_name), |w| {
+                w.expr_block(&format!("{}", self.type_name), |w| {
+                    for name in &names {
+                        w.field_entry(name, &format!("::std::clone::Clone::clone(&self.{})", name));
+                    }
+                });
+            });
+            w.write_line("");
+            w.def_fn(&format!("clone_from(&mut self, source: &{})", self.type_name), |w| {
+                for name in &names {
+                    w.write_line(&format!(
+                        "::std::clone::Clone::clone_from(&mut self.{}, &source.{});",
+                        name, name
+                    ));
+                }
+            });
+        });
+    }
+
+    // `Eq` is not reflexive for `NaN` values of float fields,
+    // but it is needed to use messages as map keys
+    fn write_impl_eq(&self, w: &mut CodeWriter) {
+        w.write_line(&format!("impl ::std::cmp::Eq for {} {{}}", self.type_name));
+    }
+
+    // Not derived because `f32` and `f64` are not `Eq` and `Ord`;
+    // `PartialEq` must be consistent with `Ord`, so it is not derived either
+    fn write_impl_total_cmp(&self, w: &mut CodeWriter) {
+        let fields = self.fields_except_group();
+        w.impl_for_block("::std::cmp::PartialEq", &format!("{}", self.type_name), |w| {
+            w.def_fn(&format!("eq(&self, other: &{}) -> bool", self.type_name), |w| {
+                w.write_line("::std::cmp::Ord::cmp(self, other) == ::std::cmp::Ordering::Equal");
+            });
+        });
+        w.write_line("");
+        w.write_line(&format!("impl ::std::cmp::Eq for {} {{}}", self.type_name));
+        w.write_line("");
+        w.impl_for_block("::std::cmp::PartialOrd", &format!("{}", self.type_name), |w| {
+            w.def_fn(
+                &format!(
+                    "partial_cmp(&self, other: &{}) -> ::std::option::Option<::std::cmp::Ordering>",
+                    self.type_name
+                ),
+                |w| {
+                    w.write_line("::std::option::Option::Some(::std::cmp::Ord::cmp(self, other))");
+                },
+            );
+        });
+        w.write_line("");
+        w.impl_for_block("::std::cmp::Ord", &format!("{}", self.type_name), |w| {
+            let other = if fields.is_empty() { "_other" } else { "other" };
+            w.def_fn(
+                &format!("cmp(&self, {}: &{}) -> ::std::cmp::Ordering", other, self.type_name),
+                |w| {
+                    for f in &fields {
+                        f.write_total_cmp(w);
+                    }
+                    w.write_line("::std::cmp::Ordering::Equal");
+                },
+            );
+        });
+    }
+
+    // Not derived because `f32` and `f64` are not `Hash`
+    fn write_impl_hash(&self, w: &mut CodeWriter) {
+        let fields = self.fields_except_group();
+        let state = if fields.is_empty() { "_state" } else { "state" };
+        w.impl_for_block("::std::hash::Hash", &format!("{}", self.type_name), |w| {
+            w.def_fn(
+                &format!("hash<H: ::std::hash::Hasher>(&self, {}: &mut H)", state),
+                |w| {
+                    for f in &fields {
+                        f.write_hash(w);
+                    }
+                },
+            );
+        });
+    }
+
     fn supports_derive_partial_eq(&self) -> bool {
         // There's stack overflow in the compiler when struct has too many fields
         // https://github.com/rust-lang/rust/issues/40119
         self.fields.len() <= 500
     }
 
+    fn float_total_cmp(&self) -> bool {
+        self.customize.float_total_cmp.unwrap_or(false)
+    }
+
     fn write_struct(&self, w: &mut CodeWriter) {
         let mut derive = Vec::new();
-        if self.supports_derive_partial_eq() {
+        // `PartialEq` is implemented with `write_impl_total_cmp`
+        if self.supports_derive_partial_eq() && !self.float_total_cmp() {
             derive.push("PartialEq");
         }
-        derive.extend(&["Clone", "Default"]);
-        if self.lite_runtime {
+        derive.push("Default");
+        if !self.debug_as_text_format {
             derive.push("Debug");
         }
         w.derive(&derive);
-        serde::write_serde_attr(w, &self.customize, "derive(Serialize, Deserialize)");
+        if self.customize.json_serialize.unwrap_or(false) {
+            // `Serialize` is implemented with `write_impl_json_serialize`
+            serde::write_serde_attr(w, &self.customize, "derive(Deserialize)");
+        } else {
+            serde::write_serde_attr(w, &self.customize, "derive(Serialize, Deserialize)");
+        }
+        if self.customize.serde_skip_default.unwrap_or(false) {
+            // fields skipped on serialization are missing in input of deserialization
+            serde::write_serde_attr(w, &self.customize, "serde(default)");
+        }
+        arbitrary::write_arbitrary_attr(w, &self.customize, "derive(Arbitrary)");
+        if self.message.message.options.get_message().get_deprecated() {
+            w.deprecated(&format!("message `{}` is deprecated", self.message.name_absolute()));
+        }
         w.pub_struct(&format!("{}", self.type_name), |w| {
             if !self.fields_except_oneof().is_empty() {
                 w.comment("message fields");
@@ -427,6 +1225,13 @@ impl<'a> MessageGen<'a> {
                         true => Visibility::Public,
                         false => Visibility::Default,
                     };
+                    if self.customize.serde_skip_default.unwrap_or(false) {
+                        serde::write_serde_attr(
+                            w,
+                            &self.customize,
+                            "serde(skip_serializing_if = \"::std::option::Option::is_none\")",
+                        );
+                    }
                     w.field_decl_vis(
                         vis,
                         &oneof.oneof.field_name().to_string(),
@@ -434,10 +1239,16 @@ impl<'a> MessageGen<'a> {
                 }
             }
             w.comment("special fields");
+            if self.presence_bits_words() != 0 {
+                w.comment("presence of fields stored in bitset");
+                w.field_decl("presence_bits", &format!("[u32; {}]", self.presence_bits_words()));
+            }
 
             serde::write_serde_attr(w, &self.customize, "serde(skip)");
+            arbitrary::write_arbitrary_attr(w, &self.customize, "arbitrary(default)");
             w.pub_field_decl("unknown_fields", "::protobuf::UnknownFields");
             serde::write_serde_attr(w, &self.customize, "serde(skip)");
+            arbitrary::write_arbitrary_attr(w, &self.customize, "arbitrary(default)");
             w.pub_field_decl("cached_size", "::protobuf::CachedSize");
         });
     }
@@ -473,7 +1284,7 @@ impl<'a> MessageGen<'a> {
         w.write_line("");
         self.write_impl_default_for_amp(w);
 
-        if !self.supports_derive_partial_eq() {
+        if !self.supports_derive_partial_eq() && !self.float_total_cmp() {
             w.write_line("");
             self.write_dummy_impl_partial_eq(w);
         }
@@ -484,14 +1295,75 @@ impl<'a> MessageGen<'a> {
         self.write_impl_message(w);
         w.write_line("");
         self.write_impl_clear(w);
-        if !self.lite_runtime {
+        w.write_line("");
+        self.write_impl_clone(w);
+        if self.float_total_cmp() {
+            w.write_line("");
+            self.write_impl_total_cmp(w);
+        }
+        if self.customize.derive_hash_floats_by_bits.unwrap_or(false) {
+            // hash of float bits is consistent with `total_cmp` equality too
+            if !self.float_total_cmp() {
+                w.write_line("");
+                self.write_impl_eq(w);
+            }
+            w.write_line("");
+            self.write_impl_hash(w);
+        }
+        if self.debug_as_text_format {
             w.write_line("");
             self.write_impl_show(w);
         }
         w.write_line("");
         self.write_impl_value(w);
+        if !self.lite_runtime {
+            w.write_line("");
+            self.write_impl_convertible(w);
+        }
+        if self.customize.numeric_wrapper.unwrap_or(false) {
+            w.write_line("");
+            self.write_impl_add(w);
+            w.write_line("");
+            self.write_impl_sum(w);
+        }
+        if self.customize.string_wrapper.unwrap_or(false) {
+            w.write_line("");
+            self.write_impl_partial_eq_str(w);
+        }
+        if let Some(target) = self.convertible_to() {
+            w.write_line("");
+            self.write_impl_from_for_convertible_to(&target, w);
+        }
+        if let Some(field) = self.io_write_field() {
+            w.write_line("");
+            self.write_impl_io_write(field, w);
+        }
+        if self.customize.json_fromstr.unwrap_or(false) {
+            w.write_line("");
+            self.write_impl_from_str(w);
+        }
+        if self.customize.text_format_fromstr.unwrap_or(false) {
+            w.write_line("");
+            self.write_impl_text_format_from_str(w);
+        }
+        if self.customize.json_try_from_value.unwrap_or(false) {
+            w.write_line("");
+            self.write_impl_try_from_json_value(w);
+        }
+        if self.customize.json_serialize.unwrap_or(false) {
+            w.write_line("");
+            self.write_impl_json_serialize(w);
+        }
+        if self.customize.generate_views.unwrap_or(false) {
+            w.write_line("");
+            self.write_view(w);
+        }
+        if self.customize.generate_into_parts.unwrap_or(false) {
+            w.write_line("");
+            self.write_parts(w);
+        }
 
-        let mod_name = message_name_to_nested_mod_name(&self.message.message.get_name());
+        let mod_name = self.message.mod_name();
 
         let oneofs = self.oneofs();
         let nested_messages: Vec<_> = self.message.to_scope().get_messages()
@@ -503,6 +1375,29 @@ impl<'a> MessageGen<'a> {
             .collect();
         let nested_enums = self.message.to_scope().get_enums();
 
+        if self.message.scope.file_scope.flatten_nested {
+            if !oneofs.is_empty() {
+                w.write_line("");
+                w.pub_mod(mod_name.get(), |w| {
+                    for oneof in &oneofs {
+                        w.write_line("");
+                        oneof.write(w);
+                    }
+                });
+            }
+
+            // nested types are written next to this message
+            for nested in &nested_messages {
+                w.write_line("");
+                MessageGen::new(nested, self.root_scope, &self.customize).write(w);
+            }
+            for enum_type in &nested_enums {
+                w.write_line("");
+                EnumGen::new(enum_type, &self.customize, self.root_scope).write(w);
+            }
+            return;
+        }
+
         if !oneofs.is_empty() || !nested_messages.is_empty() || !nested_enums.is_empty() {
             w.write_line("");
             w.pub_mod(mod_name.get(), |w| {
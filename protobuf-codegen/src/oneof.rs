@@ -11,6 +11,7 @@ use scope::WithScope;
 use rust_types_values::RustType;
 use rust_types_values::make_path;
 use serde;
+use arbitrary;
 use Customize;
 use rust_name::{RustIdent, RustIdentWithPath, RustPath};
 use file_and_mod::FileAndMod;
@@ -96,6 +97,15 @@ impl<'a> OneofVariantGen<'a> {
         self.oneof_field.rust_type(reference)
     }
 
+    /// Variant type without `Box` of recursive message
+    pub fn elem_rust_type(&self, reference: &FileAndMod) -> RustType {
+        self.oneof_field.elem.rust_storage_elem_type(reference)
+    }
+
+    pub fn is_boxed(&self) -> bool {
+        self.oneof_field.boxed
+    }
+
     pub fn path(&self, reference: &FileAndMod) -> RustPath {
         RustPath::from(format!("{}::{}",
             self.oneof.type_name_relative(&reference.relative_mod.clone().into_path()),
@@ -159,12 +169,30 @@ impl<'a> OneofGen<'a> {
         file_and_mod
     }
 
+    /// Oneof has a message variant stored inline, which may be much larger
+    /// than other variants (boxed recursive messages are pointer-sized).
+    fn has_large_variant(&self) -> bool {
+        let variants = self.variants_except_group();
+        variants.len() > 1
+            && variants.iter().any(|v| match v.oneof_field.elem {
+                FieldElem::Message(..) => !v.is_boxed(),
+                _ => false,
+            })
+    }
+
     fn write_enum(&self, w: &mut CodeWriter) {
         let derive = vec!["Clone", "PartialEq", "Debug"];
         w.derive(&derive);
+        if self.customize.oneof_allow_large_enum_variant.unwrap_or(false)
+            && self.has_large_variant()
+        {
+            w.allow(&["clippy::large_enum_variant"]);
+        }
         serde::write_serde_attr(w, &self.customize, "derive(Serialize, Deserialize)");
+        arbitrary::write_arbitrary_attr(w, &self.customize, "derive(Arbitrary)");
         w.pub_enum(&self.oneof.rust_name().ident.to_string(), |w| {
             for variant in self.variants_except_group() {
+                variant.field.write_deprecated(w);
                 w.write_line(&format!(
                     "{}({}),",
                     variant.field.rust_name,
@@ -180,9 +208,66 @@ impl<'a> OneofGen<'a> {
         });
     }
 
+    // message field is `Option` and defaults to `None`,
+    // so this is only used when the enum is used as standalone value
+    fn write_impl_default(&self, w: &mut CodeWriter) {
+        let variants = self.variants_except_group();
+        let first_variant = match variants.first() {
+            Some(variant) => variant,
+            None => return,
+        };
+        w.write_line("");
+        w.impl_for_block("::std::default::Default", self.oneof.rust_name().ident.to_string(), |w| {
+            w.def_fn("default() -> Self", |w| {
+                w.write_line(&format!(
+                    "{}::{}(::std::default::Default::default())",
+                    self.oneof.rust_name().ident,
+                    first_variant.field.rust_name
+                ));
+            });
+        });
+    }
+
+    // oneof with a single message variant is a wrapper of that message,
+    // so it can be converted to and from the message without loss
+    fn write_impl_from_single_message(&self, w: &mut CodeWriter) {
+        let variants = self.variants_except_group();
+        if variants.len() != 1 {
+            return;
+        }
+        let variant = &variants[0];
+        match variant.oneof_field.elem {
+            FieldElem::Message(..) => {}
+            _ => return,
+        }
+        let enum_name = self.oneof.rust_name().ident.to_string();
+        let message_type = variant.elem_rust_type(&self.get_file_and_mod()).to_string();
+        let (wrap, unwrap) = if variant.is_boxed() {
+            ("::std::boxed::Box::new(v)", "*v")
+        } else {
+            ("v", "v")
+        };
+        w.write_line("");
+        w.impl_for_block(format!("::std::convert::From<{}>", message_type), &enum_name, |w| {
+            w.def_fn(&format!("from(v: {}) -> Self", message_type), |w| {
+                w.write_line(&format!("{}::{}({})", enum_name, variant.field.rust_name, wrap));
+            });
+        });
+        w.write_line("");
+        w.impl_for_block(format!("::std::convert::From<{}>", enum_name), &message_type, |w| {
+            w.def_fn(&format!("from(v: {}) -> Self", enum_name), |w| {
+                w.match_expr("v", |w| {
+                    w.case_expr(format!("{}::{}(v)", enum_name, variant.field.rust_name), unwrap);
+                });
+            });
+        });
+    }
+
     pub fn write(&self, w: &mut CodeWriter) {
         self.write_enum(w);
         w.write_line("");
         self.write_impl_oneof(w);
+        self.write_impl_default(w);
+        self.write_impl_from_single_message(w);
     }
 }
@@ -58,6 +58,18 @@ pub fn is_rust_keyword(ident: &str) -> bool {
     RUST_KEYWORDS.contains(&ident)
 }
 
+/// Identifier usable as field or function name: ASCII only, not a keyword
+pub fn is_rust_identifier(ident: &str) -> bool {
+    let mut chars = ident.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    ident != "_"
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !is_rust_keyword(ident)
+}
+
 fn hex_digit(value: u32) -> char {
     if value < 10 {
         (b'0' + value as u8) as char
@@ -116,4 +128,15 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_is_rust_identifier() {
+        assert!(is_rust_identifier("ty"));
+        assert!(is_rust_identifier("_x1"));
+        assert!(!is_rust_identifier(""));
+        assert!(!is_rust_identifier("_"));
+        assert!(!is_rust_identifier("1x"));
+        assert!(!is_rust_identifier("a-b"));
+        assert!(!is_rust_identifier("type"));
+    }
+
 }
@@ -31,7 +31,13 @@ pub(crate) enum RustType {
     Option(Box<RustType>),
     SingularField(Box<RustType>),
     SingularPtrField(Box<RustType>),
+    // protobuf::LazyMessage<T>
+    LazyMessage(Box<RustType>),
+    // protobuf::LazyPacked<T>, param is protobuf type
+    LazyPacked(String),
     RepeatedField(Box<RustType>),
+    // smallvec::SmallVec<[T; N]>
+    SmallVec(u32, Box<RustType>),
     // Box<T>
     Uniq(Box<RustType>),
     // &T
@@ -48,6 +54,8 @@ pub(crate) enum RustType {
     Bytes,
     // chars::Chars
     Chars,
+    // arc_str::ArcStr
+    ArcStr,
     // group
     Group,
 }
@@ -74,9 +82,16 @@ impl fmt::Display for RustType {
             RustType::SingularPtrField(ref param) => {
                 write!(f, "::protobuf::SingularPtrField<{}>", **param)
             }
+            RustType::LazyMessage(ref param) => {
+                write!(f, "::protobuf::LazyMessage<{}>", **param)
+            }
+            RustType::LazyPacked(ref param) => write!(f, "::protobuf::LazyPacked<{}>", param),
             RustType::RepeatedField(ref param) => {
                 write!(f, "::protobuf::RepeatedField<{}>", **param)
             }
+            RustType::SmallVec(n, ref param) => {
+                write!(f, "::smallvec::SmallVec<[{}; {}]>", **param, n)
+            }
             RustType::Uniq(ref param) => write!(f, "::std::boxed::Box<{}>", **param),
             RustType::Ref(ref param) => write!(f, "&{}", **param),
             RustType::Message(ref name)
@@ -88,6 +103,7 @@ impl fmt::Display for RustType {
             RustType::Group => write!(f, "<group>"),
             RustType::Bytes => write!(f, "::bytes::Bytes"),
             RustType::Chars => write!(f, "::protobuf::Chars"),
+            RustType::ArcStr => write!(f, "::protobuf::ArcStr"),
         }
     }
 }
@@ -187,6 +203,22 @@ impl RustType {
         }
     }
 
+    // function to be used in `#[serde(skip_serializing_if = "...")]`
+    pub fn serde_skip_serializing_if(&self) -> &'static str {
+        match *self {
+            RustType::Option(..) => "::std::option::Option::is_none",
+            RustType::SingularField(..) => "::protobuf::SingularField::is_none",
+            RustType::SingularPtrField(..) => "::protobuf::SingularPtrField::is_none",
+            RustType::LazyMessage(..) => "::protobuf::LazyMessage::is_none",
+            RustType::LazyPacked(..) => "::protobuf::LazyPacked::is_empty",
+            RustType::Vec(..) => "::std::vec::Vec::is_empty",
+            RustType::RepeatedField(..) => "::protobuf::RepeatedField::is_empty",
+            RustType::SmallVec(..) => "::smallvec::SmallVec::is_empty",
+            RustType::HashMap(..) => "::std::collections::HashMap::is_empty",
+            _ => "::protobuf::rt::is_default",
+        }
+    }
+
     // default value for type
     pub fn default_value(&self) -> String {
         match *self {
@@ -200,10 +232,14 @@ impl RustType {
             RustType::String => "::std::string::String::new()".to_string(),
             RustType::Bytes => "::bytes::Bytes::new()".to_string(),
             RustType::Chars => "::protobuf::Chars::new()".to_string(),
+            RustType::ArcStr => "::protobuf::ArcStr::new()".to_string(),
             RustType::Option(..) => "::std::option::Option::None".to_string(),
             RustType::SingularField(..) => "::protobuf::SingularField::none()".to_string(),
             RustType::SingularPtrField(..) => "::protobuf::SingularPtrField::none()".to_string(),
+            RustType::LazyMessage(..) => "::protobuf::LazyMessage::new()".to_string(),
+            RustType::LazyPacked(..) => "::protobuf::LazyPacked::new()".to_string(),
             RustType::RepeatedField(..) => "::protobuf::RepeatedField::new()".to_string(),
+            RustType::SmallVec(..) => "::smallvec::SmallVec::new()".to_string(),
             RustType::Message(ref name) => format!("{}::new()", name),
             RustType::Ref(ref m) if m.is_message() => match **m {
                 RustType::Message(ref name) => {
@@ -233,10 +269,13 @@ impl RustType {
             | RustType::Bytes
             | RustType::String
             | RustType::RepeatedField(..)
+            | RustType::SmallVec(..)
             | RustType::SingularField(..)
             | RustType::SingularPtrField(..)
+            | RustType::LazyMessage(..)
+            | RustType::LazyPacked(..)
             | RustType::HashMap(..) => format!("{}.clear()", v),
-            RustType::Chars => format!("::protobuf::Clear::clear(&mut {})", v),
+            RustType::Chars | RustType::ArcStr => format!("::protobuf::Clear::clear(&mut {})", v),
             RustType::Bool
             | RustType::Float(..)
             | RustType::Int(..)
@@ -280,6 +319,9 @@ impl RustType {
             (&RustType::Chars, &RustType::Ref(ref t)) if **t == RustType::Str => {
                 return Ok(format!("&{}", v))
             }
+            (&RustType::ArcStr, &RustType::Ref(ref t)) if **t == RustType::Str => {
+                return Ok(format!("&{}", v))
+            }
             (&RustType::Ref(ref t1), &RustType::Ref(ref t2)) if t1.is_string() && t2.is_str() => {
                 return Ok(format!("&{}", v))
             }
@@ -294,6 +336,11 @@ impl RustType {
                        _ => false,
                     // TODO: from_static
                    } => return Ok(format!("<::protobuf::Chars as ::std::convert::From<_>>::from({}.to_owned())", v)),
+            (&RustType::Ref(ref t1), &RustType::ArcStr)
+                if match **t1 {
+                       RustType::Str => true,
+                       _ => false,
+                   } => return Ok(format!("<::protobuf::ArcStr as ::std::convert::From<_>>::from({})", v)),
             (&RustType::Ref(ref t1), &RustType::Vec(ref t2))
                 if match (&**t1, &**t2) {
                        (&RustType::Slice(ref x), ref y) => **x == **y,
@@ -307,6 +354,11 @@ impl RustType {
                        RustType::Slice(ref y) => x == y,
                        _ => false,
                    } => return Ok(format!("&{}", v)),
+            (&RustType::SmallVec(_, ref x), &RustType::Ref(ref t))
+                if match **t {
+                       RustType::Slice(ref y) => x == y,
+                       _ => false,
+                   } => return Ok(format!("&{}", v)),
             (&RustType::Bytes, &RustType::Ref(ref t))
                 if match **t {
                        RustType::Slice(ref y) => **y == RustType::u8(),
@@ -315,6 +367,7 @@ impl RustType {
             (&RustType::Ref(ref t1), &RustType::Ref(ref t2))
                 if match (&**t1, &**t2) {
                        (&RustType::Vec(ref x), &RustType::Slice(ref y)) => x == y,
+                       (&RustType::SmallVec(_, ref x), &RustType::Slice(ref y)) => x == y,
                        _ => false,
                    } => return Ok(format!("&{}", v)),
             (&RustType::Enum(..), &RustType::Int(true, 32)) => {
@@ -336,6 +389,9 @@ impl RustType {
             (&RustType::Enum(ref f, ..), &RustType::EnumOrUnknown(ref t, ..)) if f == t => {
                 return Ok(format!("::protobuf::ProtobufEnumOrUnknown::new({})", v))
             }
+            (&RustType::Int(true, 32), &RustType::EnumOrUnknown(..)) => {
+                return Ok(format!("::protobuf::ProtobufEnumOrUnknown::from_i32({})", v))
+            }
             _ => (),
         };
 
@@ -351,8 +407,10 @@ impl RustType {
     /// Type to view data of this type
     pub fn ref_type(&self) -> RustType {
         RustType::Ref(Box::new(match self {
-            &RustType::String | &RustType::Chars => RustType::Str,
-            &RustType::Vec(ref p) | &RustType::RepeatedField(ref p) => RustType::Slice(p.clone()),
+            &RustType::String | &RustType::Chars | &RustType::ArcStr => RustType::Str,
+            &RustType::Vec(ref p)
+            | &RustType::RepeatedField(ref p)
+            | &RustType::SmallVec(_, ref p) => RustType::Slice(p.clone()),
             &RustType::Bytes => RustType::Slice(Box::new(RustType::u8())),
             &RustType::Message(ref p) => RustType::Message(p.clone()),
             &RustType::Uniq(ref p) => RustType::Uniq(p.clone()),
@@ -375,6 +433,7 @@ impl RustType {
             &RustType::Vec(ref ty)
             | &RustType::Option(ref ty)
             | &RustType::RepeatedField(ref ty)
+            | &RustType::SmallVec(_, ref ty)
             | &RustType::SingularField(ref ty)
             | &RustType::SingularPtrField(ref ty) => RustType::Ref(ty.clone()),
             x => panic!("cannot iterate {}", x),
@@ -520,7 +579,7 @@ pub(crate) fn message_or_enum_to_rust_relative(
             message_or_enum.rust_name_to_file()
         ))
     } else {
-        current.relative_mod.to_reverse()
+        current.path_to_file_mod()
             .into_path()
             .append_ident(RustIdent::super_ident())
             .append_with_ident(message_or_enum.rust_name_with_file())
@@ -541,6 +600,8 @@ pub(crate) fn type_name_to_rust_relative(
 pub enum PrimitiveTypeVariant {
     Default,
     Carllerche,
+    // `string` stored as `ArcStr` and interned when parsing
+    Interned,
 }
 
 pub enum _CarllercheBytesType {
@@ -572,6 +633,11 @@ impl ProtobufTypeGen {
                 PrimitiveTypeVariant::Carllerche,
             ) => format!("::protobuf::types::ProtobufTypeCarllercheChars"),
             &ProtobufTypeGen::Primitive(.., PrimitiveTypeVariant::Carllerche) => unreachable!(),
+            &ProtobufTypeGen::Primitive(
+                field_descriptor_proto::Type::TYPE_STRING,
+                PrimitiveTypeVariant::Interned,
+            ) => format!("::protobuf::types::ProtobufTypeArcStr"),
+            &ProtobufTypeGen::Primitive(.., PrimitiveTypeVariant::Interned) => unreachable!(),
             &ProtobufTypeGen::Message(ref name) => {
                 format!("::protobuf::types::ProtobufTypeMessage<{}>", name)
             }
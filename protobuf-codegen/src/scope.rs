@@ -4,6 +4,9 @@ use protobuf::descriptor::EnumDescriptorProto;
 use protobuf::descriptor::EnumValueDescriptorProto;
 use protobuf::descriptor::FieldDescriptorProto;
 use protobuf::descriptor::OneofDescriptorProto;
+use protobuf::descriptor::field_descriptor_proto;
+use protobuf::rustproto;
+use protobuf::prelude::*;
 use rust_name::RustIdent;
 use rust_name::RustRelativePath;
 use rust_name::RustIdentWithPath;
@@ -18,10 +21,52 @@ use protobuf_name::ProtobufRelativePath;
 use protobuf_name::ProtobufIdent;
 use field::rust_field_name_for_protobuf_field_name;
 use file_and_mod::FileAndMod;
+use customize::customize_for_file;
+use Customize;
+
+use std::error::Error;
+use std::fmt;
+
+
+/// Type referenced from a `.proto` file, which is not found in file descriptors
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnresolvedType {
+    /// Fully qualified name of the referenced type
+    pub type_name: ProtobufAbsolutePath,
+    /// Name of the file where the type is referenced
+    pub referenced_from: String,
+}
+
+/// Some types referenced from files to generate cannot be resolved.
+///
+/// Usually it means that imported files are not included in file descriptors,
+/// e. g. `protoc` is invoked without `--include_imports` flag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnresolvedTypesError {
+    pub types: Vec<UnresolvedType>,
+}
+
+impl fmt::Display for UnresolvedTypesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "types not found in file descriptors (are imported files included?):")?;
+        for t in &self.types {
+            write!(f, " `{}` referenced from `{}`;", t.type_name, t.referenced_from)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for UnresolvedTypesError {
+    fn description(&self) -> &str {
+        "types not found in file descriptors"
+    }
+}
 
 
 pub(crate) struct RootScope<'a> {
     pub file_descriptors: &'a [FileDescriptorProto],
+    /// Options specified in codegen invocation
+    pub customize: &'a Customize,
 }
 
 impl<'a> RootScope<'a> {
@@ -30,6 +75,10 @@ impl<'a> RootScope<'a> {
             .iter()
             .map(|fd| FileScope {
                 file_descriptor: fd,
+                split_files: false,
+                flatten_nested: customize_for_file(self.customize, fd)
+                    .flatten_nested
+                    .unwrap_or(false),
             }).collect()
     }
 
@@ -50,20 +99,72 @@ impl<'a> RootScope<'a> {
     }
 
     // find message or enum by fully qualified name
-    pub fn find_message_or_enum(&'a self, fqn: &ProtobufAbsolutePath) -> MessageOrEnumWithScope<'a> {
+    pub fn find_message_or_enum_opt(&'a self, fqn: &ProtobufAbsolutePath) -> Option<MessageOrEnumWithScope<'a>> {
         assert!(!fqn.is_empty());
         self.packages()
             .into_iter()
             .flat_map(|p| {
                 p.find_message_or_enum_abs(fqn)
             }).next()
-            .expect(&format!("enum not found by name: {}", fqn))
+    }
+
+    // find message or enum by fully qualified name
+    // (names are checked with `check_resolved` before codegen)
+    pub fn find_message_or_enum(&'a self, fqn: &ProtobufAbsolutePath) -> MessageOrEnumWithScope<'a> {
+        self.find_message_or_enum_opt(fqn)
+            .expect(&format!("message or enum not found by name: {}", fqn))
+    }
+
+    // check that all types referenced from given file can be found
+    pub fn check_resolved(&'a self, file: &'a FileDescriptorProto) -> Result<(), UnresolvedTypesError> {
+        let mut type_names = Vec::new();
+        for ext in &file.extension {
+            type_names.push(ext.get_extendee());
+            if ext.has_type_name() && ext.get_field_type() != field_descriptor_proto::Type::TYPE_GROUP {
+                type_names.push(ext.get_type_name());
+            }
+        }
+        FileScope { file_descriptor: file, split_files: false, flatten_nested: false }.to_scope().walk_scopes(|scope| {
+            for message in scope.get_message_descriptors() {
+                for field in &message.field {
+                    if field.has_type_name() && field.get_field_type() != field_descriptor_proto::Type::TYPE_GROUP {
+                        type_names.push(field.get_type_name());
+                    }
+                }
+            }
+        });
+
+        let mut types = Vec::new();
+        for type_name in type_names {
+            let type_name = ProtobufAbsolutePath::from(type_name);
+            if self.find_message_or_enum_opt(&type_name).is_none() {
+                let unresolved = UnresolvedType {
+                    type_name,
+                    referenced_from: file.get_name().to_owned(),
+                };
+                if !types.contains(&unresolved) {
+                    types.push(unresolved);
+                }
+            }
+        }
+
+        if types.is_empty() {
+            Ok(())
+        } else {
+            Err(UnresolvedTypesError { types })
+        }
     }
 }
 
 #[derive(Clone, Debug)]
 pub(crate) struct FileScope<'a> {
     pub file_descriptor: &'a FileDescriptorProto,
+    /// Top-level messages and enums are generated in separate files,
+    /// which are submodules of the file module
+    pub split_files: bool,
+    /// Nested messages and enums are generated as top-level types
+    /// named like `Outer_Inner`
+    pub flatten_nested: bool,
 }
 
 impl<'a> FileScope<'a> {
@@ -108,7 +209,7 @@ impl<'a> FileScope<'a> {
     }
 
     // find all messages in given file descriptor
-    pub fn _find_messages(&self) -> Vec<MessageWithScope<'a>> {
+    pub fn find_messages(&self) -> Vec<MessageWithScope<'a>> {
         let mut r = Vec::new();
 
         self.to_scope().walk_scopes(|scope| {
@@ -219,10 +320,22 @@ impl<'a> Scope<'a> {
     }
 
     pub fn rust_path_to_file(&self) -> RustRelativePath {
+        if self.file_scope.flatten_nested {
+            return RustRelativePath::from_components(Vec::new());
+        }
         RustRelativePath::from_components(
             self.path.iter().map(|m| message_name_to_nested_mod_name(m.get_name())))
     }
 
+    // prefix of names of messages and enums in this scope
+    // when nested types are flattened, e. g. `Outer_Inner_`
+    fn flat_name_prefix(&self) -> String {
+        if !self.file_scope.flatten_nested {
+            return String::new();
+        }
+        self.path.iter().map(|m| format!("{}_", m.get_name())).collect()
+    }
+
     pub fn path_str(&self) -> String {
         let v: Vec<&str> = self.path.iter().map(|m| m.get_name()).collect();
         v.join(".")
@@ -251,6 +364,7 @@ impl<'a> Scope<'a> {
         FileAndMod {
             file: self.file_scope.file_descriptor.get_name().to_owned(),
             relative_mod: self.rust_path_to_file(),
+            in_split_file: self.file_scope.split_files,
         }
     }
 }
@@ -287,7 +401,11 @@ pub(crate) trait WithScope<'a> {
 
     // rust type name of this descriptor
     fn rust_name(&self) -> RustIdent {
-        let mut rust_name = capitalize(self.get_name().get());
+        let mut rust_name = capitalize(&format!(
+            "{}{}",
+            self.get_scope().flat_name_prefix(),
+            self.get_name().get()
+        ));
 
         if is_rust_keyword(&rust_name) {
             rust_name.insert_str(0, self.escape_prefix());
@@ -365,7 +483,11 @@ impl<'a> MessageWithScope<'a> {
     }
 
     pub fn mod_name(&self) -> RustIdent {
-        message_name_to_nested_mod_name(self.message.get_name())
+        message_name_to_nested_mod_name(&format!(
+            "{}{}",
+            self.scope.flat_name_prefix(),
+            self.message.get_name()
+        ))
     }
 }
 
@@ -478,8 +600,23 @@ impl<'a> FieldWithContext<'a> {
         self.field.get_number() as u32
     }
 
+    /// Field name in generated code, specified by `rust_name` option
+    /// or derived from proto field name
     pub fn rust_name(&self) -> RustIdent {
-        rust_field_name_for_protobuf_field_name(self.name())
+        match rustproto::exts::rust_name.get(self.field.options.get_message()) {
+            Some(name) => {
+                assert!(
+                    rust::is_rust_identifier(&name),
+                    "field {} of message {}: `rust_name` option value `{}` \
+                     is not a valid Rust identifier",
+                    self.name(),
+                    self.message.name_to_package(),
+                    name
+                );
+                RustIdent::new(&name)
+            }
+            None => rust_field_name_for_protobuf_field_name(self.name()),
+        }
     }
 
     /// Shortcut
@@ -488,7 +625,7 @@ impl<'a> FieldWithContext<'a> {
     }
 
     // From field to file root
-    pub fn _containing_messages(&self) -> Vec<&'a DescriptorProto> {
+    pub fn containing_messages(&self) -> Vec<&'a DescriptorProto> {
         let mut r = Vec::new();
         r.push(self.message.message);
         r.extend(self.message.scope.path.iter().rev());
@@ -518,9 +655,9 @@ impl<'a> OneofWithContext<'a> {
     pub fn rust_name(&self) -> RustIdentWithPath {
         // TODO: escape name
         let type_name = RustIdent::from(capitalize(self.oneof.get_name()));
-        self.message.to_scope().rust_path_to_file()
-            .into_path()
-            .with_ident(type_name)
+        let mut path = self.message.scope.rust_path_to_file();
+        path.push_ident(self.message.mod_name());
+        path.into_path().with_ident(type_name)
     }
 
     pub fn variants(&'a self) -> Vec<OneofVariantWithContext<'a>> {
@@ -0,0 +1,171 @@
+use protobuf::descriptor::*;
+use protobuf::prelude::*;
+
+use case_convert::snake_case;
+use code_writer::CodeWriter;
+use file_and_mod::FileAndMod;
+use rust;
+use rust_name::RustIdentWithPath;
+use rust_name::RustRelativePath;
+use rust_types_values::type_name_to_rust_relative;
+use scope::RootScope;
+use ProtobufAbsolutePath;
+
+// Transport-agnostic service: a trait with a method per RPC and methods metadata
+pub(crate) struct ServiceGen<'a> {
+    service: &'a ServiceDescriptorProto,
+    file: &'a FileDescriptorProto,
+    root_scope: &'a RootScope<'a>,
+}
+
+impl<'a> ServiceGen<'a> {
+    pub fn new(
+        service: &'a ServiceDescriptorProto,
+        file: &'a FileDescriptorProto,
+        root_scope: &'a RootScope<'a>,
+    ) -> ServiceGen<'a> {
+        ServiceGen {
+            service,
+            file,
+            root_scope,
+        }
+    }
+
+    // trait name
+    pub fn trait_name(&self) -> &str {
+        self.service.get_name()
+    }
+
+    // name of `ServiceInfo` constant
+    pub fn info_const_name(&self) -> String {
+        format!("{}_SERVICE", snake_case(self.service.get_name()).to_uppercase())
+    }
+
+    fn full_name(&self) -> String {
+        if self.file.get_package().is_empty() {
+            self.service.get_name().to_owned()
+        } else {
+            format!("{}.{}", self.file.get_package(), self.service.get_name())
+        }
+    }
+
+    fn message_rust_name(&self, type_name: &str) -> RustIdentWithPath {
+        type_name_to_rust_relative(
+            &ProtobufAbsolutePath::from(type_name),
+            &FileAndMod {
+                file: self.file.get_name().to_owned(),
+                relative_mod: RustRelativePath::from_components(Vec::new()),
+                in_split_file: false,
+            },
+            self.root_scope,
+        )
+    }
+
+    fn method_rust_name(method: &MethodDescriptorProto) -> String {
+        let name = snake_case(method.get_name());
+        if rust::is_rust_keyword(&name) {
+            format!("{}_rpc", name)
+        } else {
+            name
+        }
+    }
+
+    // `google.protobuf.Empty` in non-streaming position is passed as `()`
+    fn is_empty(type_name: &str, streaming: bool) -> bool {
+        !streaming && type_name == ".google.protobuf.Empty"
+    }
+
+    fn write_trait_method(&self, method: &MethodDescriptorProto, w: &mut CodeWriter) {
+        let input = self.message_rust_name(method.get_input_type());
+        let output = self.message_rust_name(method.get_output_type());
+        let input = if method.get_client_streaming() {
+            format!("::std::boxed::Box<::std::iter::Iterator<Item = {}>>", input)
+        } else {
+            format!("{}", input)
+        };
+        let output = if Self::is_empty(method.get_output_type(), method.get_server_streaming()) {
+            "()".to_owned()
+        } else if method.get_server_streaming() {
+            format!("::std::boxed::Box<::std::iter::Iterator<Item = {}>>", output)
+        } else {
+            format!("{}", output)
+        };
+        let params = if Self::is_empty(method.get_input_type(), method.get_client_streaming()) {
+            "&self".to_owned()
+        } else {
+            format!("&self, request: {}", input)
+        };
+        if method.options.get_message().get_deprecated() {
+            w.deprecated(&format!(
+                "method `{}.{}` is deprecated",
+                self.full_name(),
+                method.get_name()
+            ));
+        }
+        w.fn_def(&format!(
+            "{}({}) -> ::std::result::Result<{}, Self::Error>",
+            Self::method_rust_name(method),
+            params,
+            output
+        ));
+    }
+
+    fn write_trait(&self, w: &mut CodeWriter) {
+        w.comment(&format!("Service `{}`", self.full_name()));
+        if self.service.options.get_message().get_deprecated() {
+            w.deprecated(&format!("service `{}` is deprecated", self.full_name()));
+        }
+        w.pub_trait(self.trait_name(), |w| {
+            w.comment("Error returned by service methods");
+            w.write_line("type Error;");
+            for method in &self.service.method {
+                w.write_line("");
+                self.write_trait_method(method, w);
+            }
+        });
+    }
+
+    fn write_method_info(&self, method: &MethodDescriptorProto, w: &mut CodeWriter) {
+        let full_type_name = |n: &str| n.trim_start_matches('.').to_owned();
+        w.block("::protobuf::service::MethodInfo {", "},", |w| {
+            w.field_entry("name", &format!("{:?}", method.get_name()));
+            w.field_entry(
+                "path",
+                &format!("{:?}", format!("/{}/{}", self.full_name(), method.get_name())),
+            );
+            w.field_entry(
+                "input_type",
+                &format!("{:?}", full_type_name(method.get_input_type())),
+            );
+            w.field_entry(
+                "output_type",
+                &format!("{:?}", full_type_name(method.get_output_type())),
+            );
+            w.field_entry("client_streaming", &format!("{}", method.get_client_streaming()));
+            w.field_entry("server_streaming", &format!("{}", method.get_server_streaming()));
+        });
+    }
+
+    fn write_info(&self, w: &mut CodeWriter) {
+        let decl = format!(
+            "{} const {}: ::protobuf::service::ServiceInfo = ::protobuf::service::ServiceInfo",
+            w.pub_keyword(),
+            self.info_const_name()
+        );
+        w.stmt_block(decl, |w| {
+            w.field_entry("name", &format!("{:?}", self.service.get_name()));
+            w.field_entry("full_name", &format!("{:?}", self.full_name()));
+            w.block("methods: &[", "],", |w| {
+                for method in &self.service.method {
+                    self.write_method_info(method, w);
+                }
+            });
+        });
+    }
+
+    pub fn write(&self, w: &mut CodeWriter) {
+        self.write_trait(w);
+        w.write_line("");
+        self.write_info(w);
+    }
+}
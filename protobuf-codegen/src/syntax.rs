@@ -2,6 +2,7 @@
 pub enum Syntax {
     PROTO2,
     PROTO3,
+    EDITIONS,
 }
 
 impl Syntax {
@@ -9,6 +10,7 @@ impl Syntax {
         match s {
             "" | "proto2" => Syntax::PROTO2,
             "proto3" => Syntax::PROTO3,
+            "editions" => Syntax::EDITIONS,
             _ => panic!("unsupported syntax value: {:?}", s),
         }
     }
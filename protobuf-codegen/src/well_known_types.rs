@@ -1,5 +1,12 @@
+use std::collections::BTreeSet;
+
+use protobuf::descriptor::FieldDescriptorProto;
+
 use protobuf_name::ProtobufAbsolutePath;
 use protobuf_name::ProtobufRelativePath;
+use scope::FileScope;
+use scope::RootScope;
+use scope::WithScope;
 
 
 static NAMES: &'static [&'static str] = &[
@@ -52,6 +59,50 @@ pub fn is_well_known_type_full(name: &ProtobufAbsolutePath) -> Option<ProtobufRe
     }
 }
 
+/// Names of well-known types referenced by fields or extensions of given file,
+/// which are generated as `::protobuf::well_known_types::X` paths.
+pub(crate) fn referenced_well_known_types(
+    file_scope: &FileScope,
+    root_scope: &RootScope,
+) -> BTreeSet<String> {
+    let file = file_scope.file_descriptor;
+    let messages = file_scope.find_messages();
+    let fields = messages
+        .iter()
+        .flat_map(|m| m.message.field.iter())
+        .chain(file.extension.iter());
+
+    let mut r = BTreeSet::new();
+    for field in fields {
+        if let Some(name) = referenced_well_known_type(field, file.get_name(), root_scope) {
+            r.insert(name);
+        }
+    }
+    r
+}
+
+fn referenced_well_known_type(
+    field: &FieldDescriptorProto,
+    file_name: &str,
+    root_scope: &RootScope,
+) -> Option<String> {
+    if field.get_type_name().is_empty() {
+        return None;
+    }
+    let type_name = ProtobufAbsolutePath::from(field.get_type_name());
+    let name = is_well_known_type_full(&type_name)?;
+    // TODO: dotted names
+    if name.path.contains('.') {
+        return None;
+    }
+    // well-known types files refer to types in the same file by local names
+    let message_or_enum = root_scope.find_message_or_enum(&type_name);
+    if message_or_enum.get_file_descriptor().get_name() == file_name {
+        return None;
+    }
+    Some(name.path)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -118,9 +118,9 @@ pub fn gen_mod_rs_in_dir(dir: &str) {
     writeln!(mod_rs, "// generated by {}", module_path!()).expect("write");
     writeln!(mod_rs, "").expect("write");
 
-    let rs_files = glob_simple(&format!("{}/*.rs", dir));
+    let mut mod_names = Vec::new();
 
-    for rs in rs_files {
+    for rs in glob_simple(&format!("{}/*.rs", dir)) {
         let file_name = Path::new(&rs)
             .file_name()
             .expect("file_name")
@@ -130,11 +130,32 @@ pub fn gen_mod_rs_in_dir(dir: &str) {
             continue;
         }
         assert!(file_name.ends_with(".rs"));
-        let mod_name = &file_name[..file_name.len() - ".rs".len()];
+        mod_names.push(file_name[..file_name.len() - ".rs".len()].to_owned());
+    }
+
+    // generated with `split_files` option
+    for mod_rs in glob_simple(&format!("{}/*/mod.rs", dir)) {
+        let mod_dir = Path::new(&mod_rs).parent().expect("parent");
+        let mod_name = mod_dir
+            .file_name()
+            .expect("file_name")
+            .to_str()
+            .expect("file_name");
+        mod_names.push(mod_name.to_owned());
+    }
+
+    mod_names.sort();
 
+    for mod_name in &mod_names {
         if mod_name.contains("carllerche") {
             writeln!(mod_rs, r#"#[cfg(feature = "with-bytes")]"#).expect("write carllerche");
         }
+        if mod_name.contains("small_repeated") {
+            writeln!(mod_rs, r#"#[cfg(feature = "with-smallvec")]"#).expect("write small_repeated");
+        }
+        if mod_name.contains("uuid") {
+            writeln!(mod_rs, r#"#[cfg(feature = "with-uuid")]"#).expect("write uuid");
+        }
         writeln!(mod_rs, "mod {};", mod_name).expect("write");
     }
 
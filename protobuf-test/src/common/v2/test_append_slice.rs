@@ -0,0 +1,28 @@
+use super::test_append_slice_pb::*;
+
+#[test]
+fn test_append_int() {
+    let mut m = TestAppendSlice::new();
+    m.set_ints(vec![1]);
+    m.append_ints(&[2, 3]);
+    m.append_ints(&[]);
+    assert_eq!(&[1, 2, 3], m.get_ints());
+
+    m.append_vec_ints(&[4, 5]);
+    assert_eq!(&[4, 5], m.get_vec_ints());
+}
+
+#[test]
+fn test_append_clones() {
+    let mut item = Item::new();
+    item.set_name("aa".to_owned());
+    let items = vec![item];
+
+    let mut m = TestAppendSlice::new();
+    m.append_strings(&["x".to_owned(), "y".to_owned()]);
+    m.append_items(&items);
+    m.append_items(&items);
+    assert_eq!(&["x".to_owned(), "y".to_owned()], m.get_strings());
+    assert_eq!(2, m.get_items().len());
+    assert_eq!("aa", m.get_items()[1].get_name());
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "with-arbitrary")]
+
+use arbitrary::Arbitrary;
+use arbitrary::Unstructured;
+
+use protobuf::*;
+
+use super::test_arbitrary_pb::*;
+
+#[test]
+fn arbitrary_message_round_trip() {
+    let data: Vec<u8> = (0..1000u32).map(|i| (i * 7919 % 251) as u8).collect();
+    let mut u = Unstructured::new(&data);
+    for _ in 0..10 {
+        let m = ArbitraryMessage::arbitrary(&mut u).expect("arbitrary");
+        let bytes = m.write_to_bytes().expect("write_to_bytes");
+        let parsed: ArbitraryMessage = parse_from_bytes(&bytes).expect("parse_from_bytes");
+        assert_eq!(m, parsed);
+    }
+}
+
+#[test]
+fn arbitrary_from_empty_input() {
+    let mut u = Unstructured::new(&[]);
+    let m = ArbitraryMessage::arbitrary(&mut u).expect("arbitrary");
+    assert_eq!(m, parse_from_bytes(&m.write_to_bytes().unwrap()).unwrap());
+}
@@ -19,6 +19,24 @@ fn test2() {
     test_serialize_deserialize("12 07 74 65 73 74 69 6e 67", &test2);
 }
 
+#[test]
+fn test_write_to_vec_reuse() {
+    let mut test2 = Test2::new();
+    test2.set_b("testing".to_string());
+    let mut v = Vec::new();
+    test2.write_to_vec_reuse(&mut v).unwrap();
+    assert_eq!(decode_hex("12 07 74 65 73 74 69 6e 67"), v);
+
+    let capacity = v.capacity();
+    let ptr = v.as_ptr();
+    let mut test1 = Test1::new();
+    test1.set_a(150);
+    test1.write_to_vec_reuse(&mut v).unwrap();
+    assert_eq!(decode_hex("08 96 01"), v);
+    assert_eq!(capacity, v.capacity());
+    assert_eq!(ptr, v.as_ptr());
+}
+
 #[test]
 fn test3() {
     let mut test1 = Test1::new();
@@ -120,6 +138,15 @@ fn test_types_singular() {
     test_serialize_deserialize_no_hex(&message);
 }
 
+#[test]
+fn test_bytes_field_mut() {
+    let mut message = TestTypesSingular::new();
+    message.mut_bytes_field().extend_from_slice(&[33u8, 34]);
+    message.mut_bytes_field()[1] = 35;
+    assert_eq!(&[33u8, 35], message.get_bytes_field());
+    test_serialize_deserialize("7a 02 21 23", &message);
+}
+
 #[test]
 fn test_types_repeated() {
     let mut message = TestTypesRepeated::new();
@@ -215,6 +242,36 @@ fn test_enum_descriptor() {
     assert_eq!("GREEN", d.value_by_name("GREEN").unwrap().name());
 }
 
+#[test]
+fn test_enum_descriptor_values() {
+    let d = TestEnumDescriptor::enum_descriptor_static();
+    let values: Vec<(&str, i32)> = d.values().iter().map(|v| (v.name(), v.value())).collect();
+    assert_eq!(
+        vec![("UNDEFINED", 0), ("RED", 1), ("BLUE", 2), ("GREEN", 3)],
+        values
+    );
+    assert_eq!(2, d.value_by_name("BLUE").unwrap().value());
+    assert_eq!("GREEN", d.value_by_number(3).unwrap().name());
+    assert_eq!(Some(TestEnumDescriptor::GREEN), d.value_by_number(3).unwrap().cast());
+    assert!(d.value_by_name("YELLOW").is_none());
+    assert!(d.value_by_number(4).is_none());
+}
+
+#[test]
+fn test_enum_or_unknown_eq_enum() {
+    let known = ProtobufEnumOrUnknown::new(TestEnumDescriptor::BLUE);
+    assert!(known == TestEnumDescriptor::BLUE);
+    assert!(TestEnumDescriptor::BLUE == known);
+    assert!(known != TestEnumDescriptor::RED);
+    assert!(TestEnumDescriptor::RED != known);
+
+    let unknown = ProtobufEnumOrUnknown::<TestEnumDescriptor>::from_i32(17);
+    for &e in TestEnumDescriptor::values() {
+        assert!(unknown != e);
+        assert!(e != unknown);
+    }
+}
+
 #[test]
 fn test_invalid_tag() {
     // 01 is invalid tag, because field number for that tag would be 0
@@ -1,5 +1,7 @@
 use bytes::Bytes;
 use protobuf::Chars;
+use protobuf::CodedInputStream;
+use protobuf::Message;
 
 use super::test_carllerche_bytes_pb::*;
 
@@ -23,3 +25,38 @@ fn test() {
 
     test_serialize_deserialize_no_hex(&m);
 }
+
+#[test]
+fn test_set_into() {
+    let mut m = TestCarllercheBytes::new();
+    m.set_b1(&b"aabb"[..]);
+    assert_eq!(b"aabb", m.get_b1());
+    m.set_b1(vec![1, 2]);
+    assert_eq!(&[1, 2], m.get_b1());
+    m.set_ob("ccdd");
+    assert_eq!(b"ccdd", m.get_ob());
+}
+
+#[test]
+fn test_parse_zero_copy() {
+    // short values are copied to `Bytes` inline storage
+    let mut m = TestCarllercheBytes::new();
+    m.set_b1(Bytes::from(vec![b'a'; 100]));
+    m.set_s1(Chars::from(String::from_utf8(vec![b'c'; 100]).unwrap()));
+    let bytes = Bytes::from(m.write_to_bytes().unwrap());
+
+    let mut parsed = TestCarllercheBytes::new();
+    {
+        let mut is = CodedInputStream::from_carllerche_bytes(&bytes);
+        parsed.merge_from(&mut is).unwrap();
+    }
+    assert_eq!(m, parsed);
+
+    // fields point into the input buffer
+    let start = bytes.as_ptr() as usize;
+    let end = start + bytes.len();
+    let b1 = parsed.get_b1().as_ptr() as usize;
+    assert!(b1 >= start && b1 < end);
+    let s1 = parsed.get_s1().as_ptr() as usize;
+    assert!(s1 >= start && s1 < end);
+}
@@ -0,0 +1,49 @@
+use protobuf::Message;
+
+use super::test_clone_from_pb::*;
+
+fn item(name: &str, chunks: &[&[u8]]) -> Item {
+    let mut item = Item::new();
+    item.set_name(name.to_owned());
+    for chunk in chunks {
+        item.mut_chunks().push(chunk.to_vec());
+    }
+    item
+}
+
+fn batch(id: &str, n: usize) -> Batch {
+    let mut batch = Batch::new();
+    batch.set_id(id.to_owned());
+    batch.set_first(item(id, &[b"first"]));
+    for i in 0..n {
+        batch.mut_items().push(item(&format!("{}{}", id, i), &[b"ab", b"cd"]));
+    }
+    batch.mut_counts().insert(id.to_owned(), n as i32);
+    batch
+}
+
+#[test]
+fn test_clone_from() {
+    let mut target = batch("target", 3);
+    target.set_text("text".to_owned());
+
+    let mut source = batch("source", 2);
+    source.set_item(item("oneof", &[b"x"]));
+    target.clone_from(&source);
+    assert_eq!(source, target);
+
+    let mut source = batch("bigger", 5);
+    source.mut_unknown_fields().add_varint(100, 1);
+    target.clone_from(&source);
+    assert_eq!(source, target);
+    assert_eq!(source.get_unknown_fields(), target.get_unknown_fields());
+}
+
+#[test]
+fn test_clone_from_empty() {
+    let mut target = batch("target", 3);
+    let source = Batch::new();
+    target.clone_from(&source);
+    assert_eq!(source, target);
+    assert_eq!(0, target.get_items().len());
+}
@@ -0,0 +1,21 @@
+use protobuf::Message;
+
+use super::test_convertible_to_pb::*;
+
+#[test]
+fn test_from() {
+    let mut v1 = UserV1::new();
+    v1.set_name("Alice".to_owned());
+    v1.set_age(30);
+    v1.mut_emails().push("alice@example.com".to_owned());
+    v1.mut_address().set_city("Paris".to_owned());
+    v1.set_role(Role::ADMIN);
+    v1.mut_counters().insert("logins".to_owned(), 7);
+    v1.mut_unknown_fields().add_varint(100, 1);
+
+    let bytes = v1.write_to_bytes().unwrap();
+
+    let v2 = UserV2::from(v1);
+    assert_eq!(bytes, v2.write_to_bytes().unwrap());
+    assert_eq!(Some(1), v2.get_unknown_fields().get(100).map(|f| f.varint.len()));
+}
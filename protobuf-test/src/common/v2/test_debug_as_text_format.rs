@@ -0,0 +1,23 @@
+use super::test_debug_as_text_format_pb::*;
+
+#[test]
+fn test_debug() {
+    let mut endpoint = Endpoint::new();
+    endpoint.set_host("a".to_owned());
+    endpoint.set_port(80);
+    let mut config = Config::new();
+    config.set_name("prod".to_owned());
+    config.mut_endpoints().push(endpoint);
+    assert_eq!(
+        "name: \"prod\" endpoints {host: \"a\" port: 80}",
+        format!("{:?}", config)
+    );
+}
+
+#[test]
+fn test_debug_derived() {
+    let mut derived = Derived::new();
+    derived.set_name("x".to_owned());
+    let debug = format!("{:?}", derived);
+    assert!(debug.starts_with("Derived {"), "{}", debug);
+}
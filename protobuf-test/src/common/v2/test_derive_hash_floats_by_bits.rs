@@ -0,0 +1,46 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use super::test_derive_hash_floats_by_bits_pb::*;
+
+fn hash<T: Hash>(t: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    t.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_negative_zero() {
+    let mut a = Point::new();
+    a.set_x(0.0);
+    a.set_y(0.0);
+    a.mut_zs().push(0.0);
+    a.set_distance(0.0);
+    let mut b = Point::new();
+    b.set_x(-0.0);
+    b.set_y(-0.0);
+    b.mut_zs().push(-0.0);
+    b.set_distance(-0.0);
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+}
+
+#[test]
+fn test_hash_set() {
+    let mut a = Point::new();
+    a.set_x(1.5);
+    a.set_label("a".to_owned());
+    a.set_color(Color::GREEN);
+    a.mut_parent().set_y(2.5);
+    a.mut_weights().insert("w".to_owned(), 0.5);
+    let mut b = a.clone();
+    b.set_x(2.5);
+
+    let mut set = HashSet::new();
+    set.insert(a.clone());
+    assert!(set.contains(&a));
+    assert!(!set.contains(&b));
+    assert_eq!(hash(&Empty::new()), hash(&Empty::new()));
+}
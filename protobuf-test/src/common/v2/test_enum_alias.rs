@@ -26,3 +26,13 @@ fn test_enum_in_message() {
     m.set_en(EnumWithAlias::A);
     test_serialize_deserialize("08 0a", &m);
 }
+
+#[test]
+fn test_enum_hash_set() {
+    let mut set = ::std::collections::HashSet::new();
+    set.insert(EnumWithAlias::A);
+    set.insert(EnumWithAlias::A_AGAIN);
+    set.insert(EnumWithAlias::B);
+    assert_eq!(2, set.len());
+    assert!(set.contains(&EnumWithAlias::A_AGAIN));
+}
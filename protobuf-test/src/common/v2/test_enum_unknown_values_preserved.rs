@@ -1,5 +1,6 @@
 use super::test_enum_unknown_values_preserved_pb::*;
 
+use protobuf::error::WireError;
 use protobuf::*;
 use protobuf_test_common::*;
 
@@ -17,3 +18,66 @@ fn unknown_values_preserved() {
 
     test_serialize_deserialize("08 1e", &old);
 }
+
+#[test]
+fn unknown_values_rejected() {
+    let bytes = hex::decode_hex("08 1e");
+
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    let mut old = OldMessage::new();
+    old.merge_from(&mut is).expect("lenient by default");
+    assert_eq!(bytes, old.write_to_bytes().unwrap());
+
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    is.set_reject_unknown_enum_values(true);
+    let mut old = OldMessage::new();
+    match old.merge_from(&mut is) {
+        Err(ProtobufError::WireError(WireError::UnknownEnumValue { field, value })) => {
+            assert_eq!("eee", field);
+            assert_eq!(30, value);
+        }
+        r => panic!("unexpected: {:?}", r),
+    }
+
+    // known values are accepted
+    let bytes = hex::decode_hex("08 0a");
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    is.set_reject_unknown_enum_values(true);
+    let mut old = OldMessage::new();
+    old.merge_from(&mut is).expect("parse");
+    assert_eq!(OldEnum::A_OLD, old.get_eee());
+}
+
+#[test]
+fn unknown_repeated_values_rejected() {
+    let mut new = NewMessage::new();
+    new.mut_rrr().push(ProtobufEnumOrUnknown::new(NewEnum::A));
+    new.mut_rrr().push(ProtobufEnumOrUnknown::new(NewEnum::C));
+    let bytes = new.write_to_bytes().unwrap();
+
+    let old: OldMessage = parse_from_bytes(&bytes).expect("parse");
+    assert_eq!(2, old.get_rrr().len());
+
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    is.set_reject_unknown_enum_values(true);
+    let mut old = OldMessage::new();
+    match old.merge_from(&mut is) {
+        Err(ProtobufError::WireError(WireError::UnknownEnumValue { field, value })) => {
+            assert_eq!("rrr", field);
+            assert_eq!(30, value);
+        }
+        r => panic!("unexpected: {:?}", r),
+    }
+}
+
+#[test]
+fn unknown_value_set_from_i32() {
+    let mut old = OldMessage::new();
+    old.set_eee_i32(30);
+    assert_eq!(OldEnum::UNKNOWN_OLD, old.get_eee());
+
+    test_serialize_deserialize("08 1e", &old);
+
+    let new: NewMessage = parse_from_bytes(&old.write_to_bytes().unwrap()).expect("parse");
+    assert_eq!(NewEnum::C, new.get_eee());
+}
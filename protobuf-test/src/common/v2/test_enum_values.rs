@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use protobuf::*;
 
 use super::test_enum_values_pb::*;
@@ -13,3 +15,43 @@ fn test_enum_values() {
     ];
     assert_eq!(expected, TestEnumValuesEnum::values());
 }
+
+#[test]
+fn test_enum_name() {
+    assert_eq!("WINTER", TestEnumValuesEnum::WINTER.name());
+    let s: &'static str = TestEnumValuesEnum::AUTUMN.into();
+    assert_eq!("AUTUMN", s);
+}
+
+#[test]
+fn test_enum_or_unknown_name() {
+    assert_eq!(
+        Some("SPRING"),
+        ProtobufEnumOrUnknown::new(TestEnumValuesEnum::SPRING).name()
+    );
+    assert_eq!(
+        None,
+        ProtobufEnumOrUnknown::<TestEnumValuesEnum>::from_i32(17).name()
+    );
+}
+
+#[test]
+fn test_enum_hash_set() {
+    let mut set = HashSet::new();
+    set.insert(TestEnumValuesEnum::WINTER);
+    set.insert(TestEnumValuesEnum::SUMMER);
+    set.insert(TestEnumValuesEnum::WINTER);
+    assert_eq!(2, set.len());
+    assert!(set.contains(&TestEnumValuesEnum::SUMMER));
+    assert!(!set.contains(&TestEnumValuesEnum::SPRING));
+}
+
+#[test]
+fn test_enum_or_unknown_hash_set() {
+    let mut set = HashSet::new();
+    set.insert(ProtobufEnumOrUnknown::new(TestEnumValuesEnum::WINTER));
+    set.insert(ProtobufEnumOrUnknown::<TestEnumValuesEnum>::from_i32(11));
+    set.insert(ProtobufEnumOrUnknown::<TestEnumValuesEnum>::from_i32(100));
+    assert_eq!(2, set.len());
+    assert!(set.contains(&ProtobufEnumOrUnknown::from_i32(100)));
+}
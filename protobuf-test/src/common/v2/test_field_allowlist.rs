@@ -0,0 +1,49 @@
+use protobuf::CodedInputStream;
+use protobuf::Message;
+
+use super::test_field_allowlist_pb::*;
+
+fn outer() -> Outer {
+    let mut outer = Outer::new();
+    outer.set_id(10);
+    outer.set_name("nn".to_owned());
+    outer.mut_inner().set_a(20);
+    outer.mut_inner().set_b("bb".to_owned());
+    outer
+}
+
+fn parse_with_allowlist(bytes: &[u8], field_numbers: &[u32]) -> Outer {
+    let mut is = CodedInputStream::from_bytes(bytes);
+    is.set_field_allowlist(field_numbers);
+    let mut parsed = Outer::new();
+    parsed.merge_from(&mut is).expect("merge_from");
+    parsed
+}
+
+#[test]
+fn test_parse_only_allowed() {
+    let bytes = outer().write_to_bytes().unwrap();
+
+    let parsed = parse_with_allowlist(&bytes, &[1]);
+    assert_eq!(10, parsed.get_id());
+    assert_eq!("", parsed.get_name());
+    assert!(!parsed.has_inner());
+    assert!(parsed.get_unknown_fields().get(2).is_some());
+    assert!(parsed.get_unknown_fields().get(3).is_some());
+
+    assert_eq!(bytes, parsed.write_to_bytes().unwrap());
+}
+
+#[test]
+fn test_nested_parsed_completely() {
+    let bytes = outer().write_to_bytes().unwrap();
+
+    let parsed = parse_with_allowlist(&bytes, &[3]);
+    assert_eq!(0, parsed.get_id());
+    assert_eq!(20, parsed.get_inner().get_a());
+    assert_eq!("bb", parsed.get_inner().get_b());
+
+    // known fields are written before unknown fields, so compare messages
+    let reparsed: Outer = protobuf::parse_from_bytes(&parsed.write_to_bytes().unwrap()).unwrap();
+    assert_eq!(outer(), reparsed);
+}
@@ -0,0 +1,65 @@
+use protobuf::CodedInputStream;
+use protobuf::Message;
+
+use super::test_field_offsets_pb::*;
+
+fn outer() -> Outer {
+    let mut outer = Outer::new();
+    outer.set_id(10);
+    outer.set_secret("password".to_owned());
+    outer.mut_inner().set_a(20);
+    outer.mut_inner().set_b("bb".to_owned());
+    outer.mut_tags().push("x".to_owned());
+    outer.mut_tags().push("yz".to_owned());
+    outer
+}
+
+fn parse_with_offsets(bytes: &[u8], field_numbers: &[u32]) -> Vec<(u32, u64, u64)> {
+    let mut offsets = Vec::new();
+    {
+        let mut is = CodedInputStream::from_bytes(bytes);
+        is.set_field_offset_collector(field_numbers, &mut offsets);
+        let mut parsed = Outer::new();
+        parsed.merge_from(&mut is).expect("merge_from");
+        assert_eq!(outer(), parsed);
+    }
+    offsets
+}
+
+#[test]
+fn test_record_offset() {
+    let bytes = outer().write_to_bytes().unwrap();
+
+    // nested field 2 is not recorded
+    let offsets = parse_with_offsets(&bytes, &[2]);
+    assert_eq!(vec![(2, 2, 10)], offsets);
+
+    // tag, length and content
+    let (_, start, len) = offsets[0];
+    assert_eq!(b"\x12\x08password", &bytes[start as usize..(start + len) as usize]);
+
+    // zeroed out field is still parsed
+    let mut redacted = bytes.clone();
+    for b in &mut redacted[(start + 2) as usize..(start + len) as usize] {
+        *b = 0;
+    }
+    let parsed: Outer = protobuf::parse_from_bytes(&redacted).unwrap();
+    assert_eq!("\0\0\0\0\0\0\0\0", parsed.get_secret());
+}
+
+#[test]
+fn test_record_offset_repeated() {
+    let bytes = outer().write_to_bytes().unwrap();
+
+    let offsets = parse_with_offsets(&bytes, &[4, 3]);
+    assert_eq!(3, offsets.len());
+    assert_eq!(3, offsets[0].0);
+    let tags: Vec<&[u8]> = offsets[1..]
+        .iter()
+        .map(|&(field_number, start, len)| {
+            assert_eq!(4, field_number);
+            &bytes[start as usize..(start + len) as usize]
+        })
+        .collect();
+    assert_eq!(vec![&b"\x22\x01x"[..], &b"\x22\x02yz"[..]], tags);
+}
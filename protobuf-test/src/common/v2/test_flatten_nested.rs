@@ -0,0 +1,45 @@
+use protobuf::*;
+
+use protobuf_test_common::*;
+
+use super::test_flatten_nested_pb::*;
+
+fn leaf(value: i32) -> Outer_Inner_Leaf {
+    let mut leaf = Outer_Inner_Leaf::new();
+    leaf.set_value(value);
+    leaf
+}
+
+#[test]
+fn test_flat_names() {
+    assert_eq!(
+        "test_flatten_nested.Outer.Inner.Leaf",
+        Outer_Inner_Leaf::descriptor_static().full_name()
+    );
+    assert_eq!(
+        "test_flatten_nested.Outer.Inner",
+        Outer_Inner::descriptor_static().full_name()
+    );
+    assert_eq!(1, Outer_Inner_Kind::LEAF.value());
+}
+
+#[test]
+fn test_references() {
+    let mut inner = Outer_Inner::new();
+    inner.set_leaf(leaf(1));
+    inner.set_leaf_choice(leaf(2));
+    match inner.choice {
+        Some(outer_inner::Choice::leaf_choice(ref l)) => assert_eq!(2, l.get_value()),
+        ref c => panic!("{:?}", c),
+    }
+
+    let mut outer = Outer::new();
+    outer.set_inner(inner);
+    outer.mut_leaves().push(leaf(3));
+    outer.mut_leaves_by_name().insert("four".to_owned(), leaf(4));
+    test_serialize_deserialize_no_hex(&outer);
+
+    let mut holder = LeafHolder::new();
+    holder.set_leaf(leaf(5));
+    test_serialize_deserialize("0a 02 08 05", &holder);
+}
@@ -0,0 +1,78 @@
+use std::collections::BTreeSet;
+use std::f32;
+use std::f64;
+
+use super::test_float_total_cmp_pb::*;
+
+fn sample(value: f64) -> Sample {
+    let mut sample = Sample::new();
+    sample.set_value(value);
+    sample
+}
+
+#[test]
+fn test_sort_nan() {
+    let values = vec![
+        f64::NAN,
+        1.0,
+        -f64::NAN,
+        0.0,
+        f64::INFINITY,
+        -0.0,
+        f64::NEG_INFINITY,
+    ];
+    let mut samples: Vec<Sample> = values.into_iter().map(sample).collect();
+    samples.sort();
+
+    let sorted: Vec<f64> = samples.iter().map(|s| s.get_value()).collect();
+    assert!(sorted[0].is_nan() && sorted[0].is_sign_negative());
+    assert_eq!(
+        vec![f64::NEG_INFINITY, -0.0, 0.0, 1.0, f64::INFINITY],
+        sorted[1..6].to_vec()
+    );
+    assert!(sorted[2].is_sign_negative());
+    assert!(sorted[6].is_nan() && sorted[6].is_sign_positive());
+}
+
+#[test]
+fn test_eq() {
+    assert_eq!(sample(f64::NAN), sample(f64::NAN));
+    assert_ne!(sample(0.0), sample(-0.0));
+
+    let mut a = sample(1.0);
+    a.mut_weights().push(f32::NAN);
+    let mut b = a.clone();
+    assert_eq!(a, b);
+    b.mut_weights().push(0.0);
+    assert!(a < b);
+
+    // fields are compared in order
+    a.set_name("b".to_owned());
+    b.set_name("a".to_owned());
+    assert!(a > b);
+
+    // nested messages are compared too
+    let mut c = a.clone();
+    c.mut_next().set_value(f64::NAN);
+    assert!(a < c);
+}
+
+#[test]
+fn test_btree_set() {
+    let mut set = BTreeSet::new();
+    assert!(set.insert(sample(f64::NAN)));
+    assert!(set.insert(sample(1.0)));
+    assert!(!set.insert(sample(f64::NAN)));
+    assert!(set.contains(&sample(f64::NAN)));
+    assert_eq!(2, set.len());
+}
+
+#[test]
+fn test_presence_bits() {
+    let mut a = SampleWithPresenceBits::new();
+    let b = SampleWithPresenceBits::new();
+    a.set_value(f32::NAN);
+    assert!(a > b);
+    a.clear_value();
+    assert_eq!(a, b);
+}
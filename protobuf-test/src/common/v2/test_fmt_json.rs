@@ -323,3 +323,25 @@ fn test_reflect() {
         test_json_message(&*m);
     }
 }
+
+#[test]
+fn test_stream() {
+    let messages: Vec<TestTypes> = (1..4)
+        .map(|i| {
+            let mut m = TestTypes::new();
+            m.set_int32_singular(i);
+            m.set_string_singular(format!("s{}]", i));
+            m
+        })
+        .collect();
+
+    let mut writer = json::StreamWriter::new(Vec::new());
+    for m in &messages {
+        writer.write_message(m).expect("write_message");
+    }
+    let bytes = writer.finish().expect("finish");
+
+    let reader = json::StreamReader::<_, TestTypes>::new(&bytes[..]);
+    let read: Vec<TestTypes> = reader.map(|r| r.expect("read")).collect();
+    assert_eq!(messages, read);
+}
@@ -0,0 +1,76 @@
+use super::test_generate_services_pb::*;
+
+struct GreeterImpl;
+
+impl Greeter for GreeterImpl {
+    type Error = String;
+
+    fn say_hello(&self, request: HelloRequest) -> Result<HelloReply, String> {
+        if request.get_name().is_empty() {
+            return Err("empty name".to_owned());
+        }
+        let mut reply = HelloReply::new();
+        reply.set_message(format!("Hello, {}", request.get_name()));
+        Ok(reply)
+    }
+
+    fn say_hellos(
+        &self,
+        request: Box<Iterator<Item = HelloRequest>>,
+    ) -> Result<Box<Iterator<Item = HelloReply>>, String> {
+        let replies: Result<Vec<_>, _> = request.map(|r| self.say_hello(r)).collect();
+        Ok(Box::new(replies?.into_iter()))
+    }
+
+    fn ping(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+fn request(name: &str) -> HelloRequest {
+    let mut request = HelloRequest::new();
+    request.set_name(name.to_owned());
+    request
+}
+
+#[test]
+fn test_trait() {
+    let greeter = GreeterImpl;
+    assert_eq!(
+        "Hello, a",
+        greeter.say_hello(request("a")).unwrap().get_message()
+    );
+    assert!(greeter.say_hello(request("")).is_err());
+
+    let requests = vec![request("a"), request("b")];
+    let replies: Vec<_> = greeter
+        .say_hellos(Box::new(requests.into_iter()))
+        .unwrap()
+        .map(|r| r.get_message().to_owned())
+        .collect();
+    assert_eq!(vec!["Hello, a", "Hello, b"], replies);
+
+    assert_eq!(Ok(()), greeter.ping());
+}
+
+#[test]
+fn test_service_info() {
+    assert_eq!("Greeter", GREETER_SERVICE.name);
+    assert_eq!("test_generate_services.Greeter", GREETER_SERVICE.full_name);
+    assert_eq!(3, GREETER_SERVICE.methods.len());
+
+    let say_hello = GREETER_SERVICE.method_by_name("SayHello").unwrap();
+    assert_eq!("/test_generate_services.Greeter/SayHello", say_hello.path);
+    assert_eq!("test_generate_services.HelloRequest", say_hello.input_type);
+    assert_eq!("test_generate_services.HelloReply", say_hello.output_type);
+    assert!(!say_hello.client_streaming);
+    assert!(!say_hello.server_streaming);
+
+    let say_hellos = GREETER_SERVICE.method_by_name("SayHellos").unwrap();
+    assert!(say_hellos.client_streaming);
+    assert!(say_hellos.server_streaming);
+
+    let ping = GREETER_SERVICE.method_by_name("Ping").unwrap();
+    assert_eq!("google.protobuf.Empty", ping.input_type);
+    assert_eq!("google.protobuf.Empty", ping.output_type);
+}
@@ -0,0 +1,64 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+use protobuf::Message;
+
+use super::test_hash_stable_pb::*;
+
+fn hash<M: Message>(m: &M) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    m.hash_stable(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_map_order() {
+    let keys: Vec<String> = (0..20).map(|i| format!("k{}", i)).collect();
+
+    let mut a = Item::new();
+    for (i, k) in keys.iter().enumerate() {
+        a.mut_counts().insert(k.clone(), i as i32);
+    }
+
+    let mut b = Item::new();
+    for (i, k) in keys.iter().enumerate().rev() {
+        b.mut_counts().insert(k.clone(), i as i32);
+    }
+
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+
+    b.mut_counts().insert("k0".to_owned(), 100);
+    assert_ne!(hash(&a), hash(&b));
+}
+
+#[test]
+fn test_negative_zero() {
+    let mut a = Item::new();
+    a.set_weight(0.0);
+    let mut b = Item::new();
+    b.set_weight(-0.0);
+    assert_eq!(hash(&a), hash(&b));
+}
+
+#[test]
+fn test_unknown_fields_ignored() {
+    let mut a = Item::new();
+    a.set_name("a".to_owned());
+    let mut b = a.clone();
+    b.mut_unknown_fields().add_varint(100, 1);
+    assert_eq!(hash(&a), hash(&b));
+}
+
+#[test]
+fn test_nested() {
+    let mut a = Item::new();
+    a.mut_children().push(Item::new());
+    a.mut_children()[0].set_name("x".to_owned());
+
+    let mut b = Item::new();
+    b.mut_children().push(Item::new());
+    b.set_name("x".to_owned());
+
+    assert_ne!(hash(&a), hash(&b));
+}
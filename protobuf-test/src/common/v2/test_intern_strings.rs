@@ -0,0 +1,58 @@
+use protobuf::Message;
+
+use super::test_intern_strings_pb::*;
+
+fn label(key: &str, value: &str) -> Label {
+    let mut label = Label::new();
+    label.set_key(key.into());
+    label.set_value(value.into());
+    label
+}
+
+// singular field getters return `&str` both in proto2 and proto3,
+// so storage sharing is checked by comparing string pointers
+fn same_storage(a: &str, b: &str) -> bool {
+    a.as_ptr() == b.as_ptr()
+}
+
+#[test]
+fn test_equal_strings_share_storage() {
+    let mut labels = Labels::new();
+    labels.set_name("env".into());
+    labels.mut_tags().push("prod".into());
+    labels.mut_tags().push("prod".into());
+    labels.mut_labels().push(label("env", "prod"));
+    labels.mut_labels().push(label("env", "prod"));
+    labels.mut_attributes().insert("env".into(), "prod".into());
+    labels.set_text("prod".into());
+
+    let parsed: Labels = protobuf::parse_from_bytes(&labels.write_to_bytes().unwrap()).unwrap();
+    assert_eq!(labels, parsed);
+
+    let prod = &parsed.get_tags()[0];
+    assert!(same_storage(prod, &parsed.get_tags()[1]));
+    for label in parsed.get_labels() {
+        assert!(same_storage(prod, label.get_value()));
+        assert!(same_storage(parsed.get_name(), label.get_key()));
+    }
+    let (key, value) = parsed.get_attributes().iter().next().unwrap();
+    assert!(same_storage(parsed.get_name(), key));
+    assert!(same_storage(prod, value));
+}
+
+#[test]
+fn test_strings_not_shared_between_parses() {
+    let bytes = label("env", "prod").write_to_bytes().unwrap();
+    let a: Label = protobuf::parse_from_bytes(&bytes).unwrap();
+    let b: Label = protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(a, b);
+    assert!(!same_storage(a.get_key(), b.get_key()));
+}
+
+#[test]
+fn test_freeze() {
+    let frozen = label("env", "prod").freeze();
+    let shared = frozen.clone();
+    assert_eq!("prod", shared.get_value());
+    assert_eq!(label("env", "prod"), *frozen);
+}
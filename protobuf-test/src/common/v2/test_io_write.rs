@@ -0,0 +1,19 @@
+use std::io::Write;
+
+use super::test_io_write_pb::*;
+
+#[test]
+fn test_write() {
+    let mut buffer = Buffer::new();
+    write!(buffer, "{}-{}", 1, "a").unwrap();
+    buffer.write_all(b"bc").unwrap();
+    buffer.flush().unwrap();
+    assert_eq!(b"1-abc", buffer.get_data());
+}
+
+#[test]
+fn test_write_empty() {
+    let mut buffer = Buffer::new();
+    buffer.write_all(b"").unwrap();
+    assert_eq!(b"", buffer.get_data());
+}
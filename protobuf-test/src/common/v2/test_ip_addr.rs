@@ -0,0 +1,86 @@
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+
+use protobuf::error::WireError;
+use protobuf::*;
+
+use protobuf_test_common::*;
+
+use super::test_ip_addr_pb::*;
+
+#[test]
+fn test_v4() {
+    let mut host = Host::new();
+    host.set_address_ip_addr(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)));
+    host.set_v4_ip_addr(Ipv4Addr::new(10, 0, 0, 1));
+    test_serialize_deserialize("0a 04 c0 a8 00 01 12 04 0a 00 00 01", &host);
+
+    let host: Host = parse_from_bytes(&host.write_to_bytes().unwrap()).unwrap();
+    assert_eq!(
+        Some(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1))),
+        host.get_address_ip_addr()
+    );
+    assert_eq!(Some(Ipv4Addr::new(10, 0, 0, 1)), host.get_v4_ip_addr());
+    assert_eq!(None, host.get_v6_ip_addr());
+}
+
+#[test]
+fn test_v6() {
+    let mut host = Host::new();
+    host.set_address_ip_addr(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)));
+    host.set_v6_ip_addr(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1));
+    host.set_aliases_ip_addrs(&[
+        IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+        IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)),
+    ]);
+
+    let host: Host = parse_from_bytes(&host.write_to_bytes().unwrap()).unwrap();
+    assert_eq!(
+        Some(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))),
+        host.get_address_ip_addr()
+    );
+    assert_eq!(None, host.get_v4_ip_addr());
+    assert_eq!(
+        Some(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)),
+        host.get_v6_ip_addr()
+    );
+    assert_eq!(
+        vec![
+            IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+            IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1))
+        ],
+        host.get_aliases_ip_addrs()
+    );
+}
+
+fn parse_host_error(host_bytes: &HostBytes) -> WireError {
+    match parse_from_bytes::<Host>(&host_bytes.write_to_bytes().unwrap()) {
+        Err(ProtobufError::WireError(e)) => e,
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn test_incorrect_length() {
+    let mut host_bytes = HostBytes::new();
+    host_bytes.set_address(vec![1, 2, 3, 4, 5]);
+    match parse_host_error(&host_bytes) {
+        WireError::IncorrectIpAddrLength(5) => {}
+        e => panic!("{:?}", e),
+    }
+
+    let mut host_bytes = HostBytes::new();
+    host_bytes.set_v4(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1).octets().to_vec());
+    match parse_host_error(&host_bytes) {
+        WireError::IncorrectIpAddrLength(16) => {}
+        e => panic!("{:?}", e),
+    }
+
+    let mut host_bytes = HostBytes::new();
+    host_bytes.mut_aliases().push(Vec::new());
+    match parse_host_error(&host_bytes) {
+        WireError::IncorrectIpAddrLength(0) => {}
+        e => panic!("{:?}", e),
+    }
+}
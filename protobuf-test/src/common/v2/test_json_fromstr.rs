@@ -0,0 +1,20 @@
+use super::test_json_fromstr_pb::*;
+
+#[test]
+fn test_parse() {
+    let config: Config =
+        "{\"name\": \"prod\", \"endpoints\": [{\"host\": \"a\", \"port\": 80}], \"verbose\": true}"
+            .parse()
+            .unwrap();
+    assert_eq!("prod", config.get_name());
+    assert_eq!(1, config.get_endpoints().len());
+    assert_eq!("a", config.get_endpoints()[0].get_host());
+    assert_eq!(80, config.get_endpoints()[0].get_port());
+    assert_eq!(true, config.get_verbose());
+}
+
+#[test]
+fn test_parse_error() {
+    assert!("{\"name\": 1}".parse::<Config>().is_err());
+    assert!("{\"unknown\": 1}".parse::<Endpoint>().is_err());
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "with-serde")]
+
+use protobuf::well_known_types::Duration;
+use protobuf::well_known_types::StringValue;
+
+use serde_json::json;
+
+use super::test_json_serialize_pb::*;
+
+#[test]
+fn test_serialize() {
+    let mut item = Item::new();
+    item.set_item_name("pen".to_owned());
+    let mut timeout = Duration::new();
+    timeout.seconds = 3;
+    let mut note = StringValue::new();
+    note.value = "fragile".to_owned();
+
+    let mut order = Order::new();
+    order.set_order_id(12345678901);
+    order.set_quantity(2);
+    order.set_color(Color::BLUE);
+    order.set_payload(b"ab".to_vec());
+    order.mut_items().push(item);
+    order.mut_totals().insert("eur".to_owned(), 10);
+    order.set_timeout(timeout);
+    order.set_note(note);
+    order.set_ratio(::std::f64::NAN);
+
+    let expected = json!({
+        "orderId": "12345678901",
+        "quantity": 2,
+        "color": "BLUE",
+        "payload": "YWI=",
+        "items": [{"itemName": "pen"}],
+        "totals": {"eur": "10"},
+        "timeout": "3.000000000s",
+        "note": "fragile",
+        "ratio": "NaN",
+    });
+    assert_eq!(expected, serde_json::to_value(&order).unwrap());
+}
+
+#[test]
+fn test_serialize_empty() {
+    assert_eq!(json!({}), serde_json::to_value(&Order::new()).unwrap());
+}
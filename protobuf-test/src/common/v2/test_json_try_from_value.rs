@@ -0,0 +1,36 @@
+#![cfg(feature = "with-serde")]
+
+use std::convert::TryFrom;
+
+use serde_json::json;
+
+use super::test_json_try_from_value_pb::*;
+
+#[test]
+fn test_try_from() {
+    let value = json!({
+        "name": "api",
+        "endpoints": [{"host": "a", "port": 80}, {"host": "b"}],
+        "level": "HIGH",
+        "limits": {"rps": "100"},
+    });
+    let service = Service::try_from(&value).unwrap();
+    assert_eq!("api", service.get_name());
+    assert_eq!(2, service.get_endpoints().len());
+    assert_eq!("a", service.get_endpoints()[0].get_host());
+    assert_eq!(80, service.get_endpoints()[0].get_port());
+    assert_eq!("b", service.get_endpoints()[1].get_host());
+    assert_eq!(Level::HIGH, service.get_level());
+    assert_eq!(Some(&100), service.get_limits().get("rps"));
+
+    // same as parsing JSON string
+    let parsed: Service = protobuf::json::parse_from_str(&value.to_string()).unwrap();
+    assert_eq!(parsed, service);
+}
+
+#[test]
+fn test_try_from_error() {
+    assert!(Service::try_from(&json!({"name": 1})).is_err());
+    assert!(Service::try_from(&json!([])).is_err());
+    assert!(Endpoint::try_from(&json!({"unknown": 1})).is_err());
+}
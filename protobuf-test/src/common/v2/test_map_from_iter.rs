@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+use super::test_map_from_iter_pb::*;
+
+fn value(text: &str) -> Value {
+    let mut value = Value::new();
+    value.set_text(text.to_owned());
+    value
+}
+
+#[test]
+fn test_set_from_vec_of_pairs() {
+    let mut m = WithMaps::new();
+    m.set_counts_from_iter(vec![("a".to_owned(), 1), ("b".to_owned(), 2)]);
+
+    let mut expected = HashMap::new();
+    expected.insert("a".to_owned(), 1);
+    expected.insert("b".to_owned(), 2);
+    assert_eq!(&expected, m.get_counts());
+}
+
+#[test]
+fn test_set_from_iter_replaces_entries() {
+    let mut m = WithMaps::new();
+    m.mut_values().insert(1, value("one"));
+    m.set_values_from_iter((2..4).map(|i| (i, value(&i.to_string()))));
+
+    assert_eq!(2, m.get_values().len());
+    assert_eq!(None, m.get_values().get(&1));
+    assert_eq!(Some(&value("3")), m.get_values().get(&3));
+}
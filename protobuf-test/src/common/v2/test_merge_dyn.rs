@@ -0,0 +1,41 @@
+use protobuf::*;
+
+use super::test_merge_dyn_pb::*;
+
+#[test]
+fn test_merge_dyn() {
+    let mut a = Point::new();
+    a.set_x(10);
+    a.mut_tags().push("a".to_owned());
+
+    let mut b = Point::new();
+    b.set_y(20);
+    b.mut_tags().push("b".to_owned());
+    b.mut_next().set_y(2);
+
+    let mut boxed_a: Box<Message> = Box::new(a);
+    let boxed_b: Box<Message> = Box::new(b);
+    boxed_a.merge_dyn(&*boxed_b).unwrap();
+
+    let merged = boxed_a.downcast_ref::<Point>().unwrap();
+    assert_eq!(10, merged.get_x());
+    assert_eq!(20, merged.get_y());
+    assert_eq!(&["a".to_owned(), "b".to_owned()][..], merged.get_tags());
+    assert_eq!(2, merged.get_next().get_y());
+}
+
+#[test]
+fn test_merge_dyn_type_mismatch() {
+    let mut other = Other::new();
+    other.set_x(10);
+
+    let mut point: Box<Message> = Box::new(Point::new());
+    match point.merge_dyn(&other) {
+        Err(ProtobufError::MessageTypeMismatch { expected, actual }) => {
+            assert_eq!("test_merge_dyn.Point", expected);
+            assert_eq!("test_merge_dyn.Other", actual);
+        }
+        r => panic!("{:?}", r),
+    }
+    assert_eq!(&Point::new(), point.downcast_ref::<Point>().unwrap());
+}
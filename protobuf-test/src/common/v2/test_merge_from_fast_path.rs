@@ -0,0 +1,55 @@
+use protobuf::*;
+
+use protobuf_test_common::hex::decode_hex;
+use protobuf_test_common::*;
+
+use super::test_merge_from_fast_path_pb::*;
+
+#[test]
+fn test_fields() {
+    let mut m = Flat::new();
+    m.set_a(150);
+    m.set_b(-1);
+    m.set_c(true);
+    m.set_e(FlatEnum::ONE);
+    m.set_f(7);
+    m.set_d(1.0);
+    test_serialize_deserialize(
+        "08 96 01 10 01 18 01 20 01 2d 07 00 00 00 79 00 00 00 00 00 00 f0 3f",
+        &m,
+    );
+}
+
+#[test]
+fn test_unknown_fields() {
+    // field 6 is unknown, field 16 has two byte tag
+    let bytes = decode_hex("30 05 08 01 80 01 02");
+    let m: Flat = parse_from_bytes(&bytes).unwrap();
+    assert_eq!(1, m.get_a());
+    assert!(m.get_unknown_fields().get(6).is_some());
+    assert!(m.get_unknown_fields().get(16).is_some());
+}
+
+#[test]
+fn test_unexpected_wire_type() {
+    // field 1 is length-delimited
+    assert!(parse_from_bytes::<Flat>(&decode_hex("0a 00")).is_err());
+}
+
+#[test]
+fn test_incorrect_tag() {
+    // field number 0
+    assert!(parse_from_bytes::<Flat>(&decode_hex("00 00")).is_err());
+}
+
+#[test]
+fn test_field_allowlist() {
+    let bytes = decode_hex("08 01 10 02");
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    is.set_field_allowlist(&[2]);
+    let mut m = Flat::new();
+    m.merge_from(&mut is).unwrap();
+    assert_eq!(0, m.get_a());
+    assert_eq!(1, m.get_b());
+    assert!(m.get_unknown_fields().get(1).is_some());
+}
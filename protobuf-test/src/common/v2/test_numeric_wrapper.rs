@@ -0,0 +1,28 @@
+use super::test_numeric_wrapper_pb::*;
+
+fn bytes(count: u64) -> Bytes {
+    let mut bytes = Bytes::new();
+    bytes.set_count(count);
+    bytes
+}
+
+fn seconds(value: f64) -> Seconds {
+    let mut seconds = Seconds::new();
+    seconds.set_value(value);
+    seconds
+}
+
+#[test]
+fn test_add() {
+    assert_eq!(bytes(30), bytes(10) + bytes(20));
+    assert_eq!(seconds(1.5), seconds(1.0) + seconds(0.5));
+}
+
+#[test]
+fn test_sum() {
+    let all = vec![bytes(1), bytes(2), Bytes::new(), bytes(4)];
+    assert_eq!(7, all.into_iter().sum::<Bytes>().get_count());
+
+    let empty: Vec<Seconds> = Vec::new();
+    assert_eq!(0.0, empty.into_iter().sum::<Seconds>().get_value());
+}
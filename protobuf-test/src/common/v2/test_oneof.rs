@@ -1,3 +1,5 @@
+use protobuf::Message;
+
 use protobuf_test_common::*;
 
 use super::test_oneof_pb::*;
@@ -61,3 +63,69 @@ fn test_types() {
     t(|o| o.set_enum_field(EnumForOneof::A));
     t(|o| o.mut_message_field().set_f(22));
 }
+
+#[test]
+fn test_take_oneof() {
+    let mut test_message = TestOneof::new();
+    test_message.set_s("s".to_owned());
+    test_message.set_string_field("abc".to_owned());
+
+    match test_message.take_one() {
+        Some(test_oneof::One::string_field(s)) => assert_eq!("abc", s),
+        v => panic!("{:?}", v),
+    }
+    assert_eq!(None, test_message.one);
+    assert!(!test_message.has_string_field());
+    assert_eq!(None, test_message.take_one());
+    assert_eq!("s", test_message.get_s());
+}
+
+#[test]
+fn test_clear_oneof() {
+    let mut test_message = TestOneof::new();
+    test_message.set_int32_field(10);
+    test_message.clear_one();
+    assert!(!test_message.has_int32_field());
+    assert_eq!(None, test_message.one);
+}
+
+#[test]
+fn test_oneof_variant_predicates() {
+    let mut test_message = TestOneof::new();
+    assert!(!test_message.is_string_field());
+    assert_eq!(None, test_message.string_field_ref());
+
+    test_message.set_string_field("text".to_owned());
+    assert!(test_message.is_string_field());
+    assert!(!test_message.is_bytes_field());
+    assert_eq!(Some(&"text".to_owned()), test_message.string_field_ref());
+    assert_eq!(None, test_message.bytes_field_ref());
+
+    test_message.mut_message_field().set_f(22);
+    assert!(!test_message.is_string_field());
+    assert!(test_message.is_message_field());
+    assert_eq!(Some(22), test_message.message_field_ref().map(|m| m.get_f()));
+}
+
+#[test]
+fn test_oneof_default() {
+    assert_eq!(test_oneof::One::double_field(0.0), test_oneof::One::default());
+    assert_eq!(None, TestOneof::new().one);
+}
+
+#[test]
+fn test_oneof_descriptor() {
+    let descriptor = TestOneof::descriptor_static();
+    assert_eq!(1, descriptor.oneofs().len());
+    let oneof = &descriptor.oneofs()[0];
+    assert_eq!("one", oneof.name());
+    assert_eq!(17, oneof.fields().len());
+    assert_eq!("double_field", oneof.fields()[0].name());
+    assert!(descriptor == oneof.containing_message());
+
+    let int32_field = descriptor.field_by_name("int32_field").unwrap();
+    let string_field = descriptor.field_by_name("string_field").unwrap();
+    assert_eq!(Some(oneof), int32_field.containing_oneof());
+    assert_eq!(Some(oneof), string_field.containing_oneof());
+    assert_eq!(None, descriptor.field_by_name("s").unwrap().containing_oneof());
+}
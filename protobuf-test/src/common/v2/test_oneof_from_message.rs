@@ -0,0 +1,33 @@
+use super::test_oneof_from_message_pb::*;
+
+fn payload(data: &str) -> Payload {
+    let mut payload = Payload::new();
+    payload.set_data(data.to_owned());
+    payload
+}
+
+#[test]
+fn test_from_message() {
+    let kind = wrapper::Kind::from(payload("abc"));
+    assert_eq!(wrapper::Kind::payload(payload("abc")), kind);
+
+    let mut wrapper = Wrapper::new();
+    wrapper.kind = Some(payload("def").into());
+    assert_eq!("def", wrapper.get_payload().get_data());
+}
+
+#[test]
+fn test_into_message() {
+    let kind = wrapper::Kind::payload(payload("abc"));
+    let message: Payload = kind.into();
+    assert_eq!(payload("abc"), message);
+}
+
+#[test]
+fn test_recursive() {
+    let mut inner = Recursive::new();
+    inner.set_depth(1);
+    let next = recursive::Next::from(inner.clone());
+    assert_eq!(recursive::Next::recursive(Box::new(inner.clone())), next);
+    assert_eq!(inner, Recursive::from(next));
+}
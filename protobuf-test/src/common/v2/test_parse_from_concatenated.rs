@@ -0,0 +1,53 @@
+use protobuf::parse_from_bytes;
+use protobuf::parse_from_concatenated;
+use protobuf::Message;
+
+use super::test_parse_from_concatenated_pb::*;
+
+fn first() -> Outer {
+    let mut m = Outer::new();
+    m.set_id(1);
+    m.set_name("first".to_owned());
+    m.set_count(10);
+    m.mut_tags().push("a".to_owned());
+    m
+}
+
+fn second() -> Outer {
+    let mut m = Outer::new();
+    m.set_id(2);
+    m.set_name("second".to_owned());
+    m.mut_tags().push("b".to_owned());
+    m.mut_tags().push("c".to_owned());
+    m
+}
+
+fn merged() -> Outer {
+    let mut m = Outer::new();
+    m.set_id(2);
+    m.set_name("second".to_owned());
+    m.set_count(10);
+    m.mut_tags().push("a".to_owned());
+    m.mut_tags().push("b".to_owned());
+    m.mut_tags().push("c".to_owned());
+    m
+}
+
+#[test]
+fn test_parse_from_concatenated() {
+    let first = first().write_to_bytes().unwrap();
+    let second = second().write_to_bytes().unwrap();
+
+    let parsed: Outer = parse_from_concatenated(&[&first[..], &second[..]]).unwrap();
+    assert_eq!(merged(), parsed);
+
+    let mut concatenated = first.clone();
+    concatenated.extend_from_slice(&second);
+    let parsed: Outer = parse_from_bytes(&concatenated).unwrap();
+    assert_eq!(merged(), parsed);
+}
+
+#[test]
+fn test_parse_from_concatenated_empty() {
+    assert_eq!(Empty::new(), parse_from_concatenated(&[]).unwrap());
+}
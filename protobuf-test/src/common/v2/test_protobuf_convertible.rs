@@ -0,0 +1,43 @@
+use protobuf::parse_from_bytes;
+use protobuf::ProtobufConvertible;
+
+use super::test_protobuf_convertible_pb::*;
+
+// user-defined format, implemented for all generated messages
+trait ToTaggedBytes {
+    fn to_tagged_bytes(&self) -> Vec<u8>;
+}
+
+impl<M: ProtobufConvertible> ToTaggedBytes for M {
+    fn to_tagged_bytes(&self) -> Vec<u8> {
+        let mut r = M::descriptor_static().full_name().as_bytes().to_vec();
+        r.push(b':');
+        r.extend(self.write_to_bytes().unwrap());
+        r
+    }
+}
+
+fn from_tagged_bytes<M: ProtobufConvertible>(bytes: &[u8]) -> M {
+    let name = M::descriptor_static().full_name();
+    assert!(bytes.starts_with(name.as_bytes()));
+    assert_eq!(b':', bytes[name.len()]);
+    parse_from_bytes(&bytes[name.len() + 1..]).unwrap()
+}
+
+#[test]
+fn test_generic_conversion() {
+    let mut point = Point::new();
+    point.set_x(10);
+    point.set_y(20);
+    let bytes = point.to_tagged_bytes();
+    assert!(bytes.starts_with(b"test_protobuf_convertible.Point:"));
+    assert_eq!(point, from_tagged_bytes::<Point>(&bytes));
+
+    let mut polygon = Polygon::new();
+    polygon.set_name("triangle".to_owned());
+    polygon.mut_points().push(point.clone());
+    polygon.mut_points().push(Point::new());
+    let bytes = polygon.to_tagged_bytes();
+    assert!(bytes.starts_with(b"test_protobuf_convertible.Polygon:"));
+    assert_eq!(polygon, from_tagged_bytes::<Polygon>(&bytes));
+}
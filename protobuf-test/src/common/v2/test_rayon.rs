@@ -0,0 +1,47 @@
+#![cfg(feature = "with-rayon")]
+
+use rayon::prelude::*;
+
+use protobuf::Clear;
+
+use super::test_rayon_pb::*;
+
+fn items(values: &[i32]) -> Items {
+    let mut m = Items::new();
+    for &value in values {
+        let mut item = Item::new();
+        item.set_value(value);
+        m.items.push(item);
+    }
+    m
+}
+
+#[test]
+fn test_par_iter_sum() {
+    let values: Vec<i32> = (0..1000).collect();
+    let m = items(&values);
+    let sum: i64 = m.items.par_iter().map(|item| item.get_value() as i64).sum();
+    assert_eq!(499500, sum);
+}
+
+#[test]
+fn test_par_iter_skips_cleared() {
+    let mut m = items(&[1, 2, 3]);
+    m.clear();
+    let mut item = Item::new();
+    item.set_value(7);
+    m.items.push(item);
+    let sum: i32 = m.items.par_iter().map(|item| item.get_value()).sum();
+    assert_eq!(7, sum);
+}
+
+#[test]
+fn test_par_iter_mut() {
+    let mut m = items(&[1, 2, 3]);
+    m.items.par_iter_mut().for_each(|item| {
+        let value = item.get_value();
+        item.set_value(value * 10)
+    });
+    let values: Vec<i32> = m.items.iter().map(|item| item.get_value()).collect();
+    assert_eq!(vec![10, 20, 30], values);
+}
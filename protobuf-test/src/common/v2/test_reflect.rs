@@ -2,6 +2,7 @@ use protobuf::reflect::FieldDescriptor;
 use protobuf::reflect::ReflectValueBox;
 use protobuf::Message;
 use protobuf::ProtobufEnum;
+use protobuf::ProtobufError;
 
 use super::test_reflect_pb::*;
 use protobuf::reflect::RuntimeFieldType;
@@ -30,6 +31,39 @@ fn test_get_sub_message_via_reflection() {
     );
 }
 
+#[test]
+fn test_clone_box() {
+    let mut m = M::new();
+    m.mut_sub_m().set_n(42);
+    let boxed: Box<Message> = Box::new(m);
+
+    let cloned = boxed.clone_box();
+    let descriptor = M::descriptor_static();
+    assert!(descriptor.deep_eq(&*boxed, &*cloned));
+    let sub_m = descriptor.field_by_name("sub_m").unwrap().get_message(&*cloned);
+    assert_eq!(42, sub_m.descriptor().field_by_name("n").unwrap().get_i32(sub_m));
+
+    let cloned_vec = vec![boxed].clone();
+    assert!(descriptor.deep_eq(&*cloned, &*cloned_vec[0]));
+}
+
+#[test]
+fn test_downcast_clone() {
+    let mut m = M::new();
+    m.mut_sub_m().set_n(42);
+
+    let sub_m_field = m.descriptor().field_by_name("sub_m").unwrap();
+    let sub_m = sub_m_field.get_singular_field_or_default(&m);
+    assert_eq!(42, sub_m.downcast_clone::<SubM>().unwrap().get_n());
+    assert_eq!(None, sub_m.downcast_clone::<M>());
+
+    let n_field = SubM::descriptor_static().field_by_name("n").unwrap();
+    let n = n_field.get_singular_field_or_default(m.get_sub_m());
+    assert_eq!(Some(42), n.downcast_clone::<i32>());
+    assert_eq!(None, n.downcast_clone::<u32>());
+    assert_eq!(Some(42), n.to_box().downcast_clone::<i32>());
+}
+
 #[test]
 fn test_singular_basic() {
     let mut message = TestTypesSingular::new();
@@ -43,6 +77,93 @@ fn test_singular_basic() {
     assert_eq!(true, bool_field.get_bool(&message));
 }
 
+#[test]
+fn test_try_set_singular_field() {
+    let mut message = TestTypesSingular::new();
+    let descriptor = message.descriptor();
+
+    let int32_field = descriptor.field_by_name("int32_field").unwrap();
+    int32_field
+        .try_set_singular_field(&mut message, ReflectValueBox::I32(5))
+        .unwrap();
+    assert_eq!(5, int32_field.get_i32(&message));
+
+    for value in vec![
+        ReflectValueBox::String("5".to_owned()),
+        ReflectValueBox::I64(6),
+    ] {
+        match int32_field.try_set_singular_field(&mut message, value) {
+            Err(ProtobufError::FieldValueTypeMismatch { field }) => {
+                assert_eq!("int32_field", field)
+            }
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+    assert_eq!(5, int32_field.get_i32(&message));
+
+    let message_field = descriptor.field_by_name("message_field").unwrap();
+    assert!(message_field
+        .try_set_singular_field(&mut message, ReflectValueBox::Message(Box::new(M::new())))
+        .is_err());
+    assert!(!message_field.has_field(&message));
+
+    for field in descriptor.fields() {
+        let value = value_for_runtime_type(field.singular_runtime_type());
+        field.try_set_singular_field(&mut message, value).unwrap();
+    }
+}
+
+#[test]
+fn test_value_box_constructors() {
+    let mut message = TestTypesSingular::new();
+    let descriptor = message.descriptor();
+    let enum_descriptor = TestReflectEnum::enum_descriptor_static();
+
+    let mut sub_m = SubM::new();
+    sub_m.set_n(17);
+
+    let values: Vec<(&str, ReflectValueBox)> = vec![
+        ("double_field", 1.5f64.into()),
+        ("float_field", 2.5f32.into()),
+        ("int32_field", (-3i32).into()),
+        ("int64_field", (-4i64).into()),
+        ("uint32_field", 5u32.into()),
+        ("uint64_field", 6u64.into()),
+        ("sint32_field", (-7i32).into()),
+        ("sint64_field", (-8i64).into()),
+        ("fixed32_field", 9u32.into()),
+        ("fixed64_field", 10u64.into()),
+        ("sfixed32_field", (-11i32).into()),
+        ("sfixed64_field", (-12i64).into()),
+        ("bool_field", true.into()),
+        ("string_field", "thirteen".into()),
+        ("bytes_field", (&b"fourteen"[..]).into()),
+        ("enum_field", ReflectValueBox::from_enum(enum_descriptor, 2).unwrap()),
+        ("message_field", ReflectValueBox::from(Box::new(sub_m.clone()) as Box<Message>)),
+    ];
+    assert_eq!(descriptor.fields().len(), values.len());
+
+    for (name, value) in values {
+        let field = descriptor.field_by_name(name).unwrap();
+        field
+            .try_set_singular_field(&mut message, value.clone())
+            .unwrap();
+        assert_eq!(value, field.get_singular_field_or_default(&message));
+        match value.clone().into_value() {
+            Ok(v) => assert_eq!(value, v),
+            Err(v) => assert_eq!(value, v),
+        }
+    }
+
+    assert_eq!(-3, message.get_int32_field());
+    assert_eq!("thirteen", message.get_string_field());
+    assert_eq!(b"fourteen", message.get_bytes_field());
+    assert_eq!(TestReflectEnum::BLUE, message.get_enum_field());
+    assert_eq!(&sub_m, message.get_message_field());
+
+    assert_eq!(None, ReflectValueBox::from_enum(enum_descriptor, 100));
+}
+
 fn test_singular_field(message: &mut Message, field: &FieldDescriptor) {
     assert!(!field.has_field(message));
 
@@ -0,0 +1,24 @@
+use protobuf::reflect::lookup_message;
+use protobuf::reflect::register_message;
+use protobuf::Message;
+
+use super::test_registry_pb::*;
+
+#[test]
+fn test_register_and_lookup() {
+    register_message::<Foo>();
+    assert!(!register_message::<Foo>());
+
+    let descriptor = lookup_message("test_registry.Foo").unwrap();
+    assert_eq!("test_registry.Foo", descriptor.full_name());
+
+    // v2 and v3 tests register different types with the same name,
+    // the first registered is kept
+    let message = descriptor.new_instance();
+    assert_eq!(Foo::descriptor_static().full_name(), message.descriptor().full_name());
+}
+
+#[test]
+fn test_lookup_unknown() {
+    assert!(lookup_message("test_registry.Bar").is_none());
+}
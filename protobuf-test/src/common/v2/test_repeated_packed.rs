@@ -1,5 +1,7 @@
 use super::test_repeated_packed_pb::*;
 
+use protobuf::error::WireError;
+use protobuf::*;
 use protobuf_test_common::*;
 
 // varint field number = 4
@@ -79,3 +81,31 @@ fn test_issue_281() {
     test.values = (0..100).collect();
     test_serialize_deserialize_no_hex(&test);
 }
+
+fn merge_with_max_repeated_len<M: Message>(hex: &str, max: usize) -> ProtobufResult<M> {
+    let bytes = hex::decode_hex(hex);
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    is.set_max_repeated_len(max);
+    let mut message = M::new();
+    message.merge_from(&mut is)?;
+    Ok(message)
+}
+
+#[test]
+fn test_max_repeated_len() {
+    let packed: TestPacked = merge_with_max_repeated_len("22 03 11 e8 07", 2).unwrap();
+    assert_eq!(vec![17, 1000], packed.varints);
+    let unpacked: TestUnpacked = merge_with_max_repeated_len("20 11 20 e8 07", 2).unwrap();
+    assert_eq!(vec![17, 1000], unpacked.varints);
+
+    for hex in &["22 04 11 e8 07 01", "20 11 20 e8 07 20 01", "22 03 11 e8 07 20 01"] {
+        match merge_with_max_repeated_len::<TestPacked>(hex, 2) {
+            Err(ProtobufError::WireError(WireError::RepeatedLenLimitExceeded)) => {}
+            r => panic!("unexpected: {:?}", r),
+        }
+    }
+    match merge_with_max_repeated_len::<TestPacked>("2a 0c 11 00 00 00 e8 03 00 00 01 00 00 00", 2) {
+        Err(ProtobufError::WireError(WireError::RepeatedLenLimitExceeded)) => {}
+        r => panic!("unexpected: {:?}", r),
+    }
+}
@@ -0,0 +1,37 @@
+use protobuf::*;
+use protobuf_test_common::*;
+
+use super::test_rust_name_pb::*;
+
+#[test]
+fn test_accessors() {
+    let mut m = WithRustName::new();
+    m.set_ty(10);
+    m.set_field_match("m".to_owned());
+    m.mut_moves().push("a".to_owned());
+    m.set_lp("l".to_owned());
+    assert_eq!(10, m.get_ty());
+    assert_eq!(vec!["a".to_owned()], m.get_moves());
+    assert_eq!(Some(with_rust_name::One::lp("l".to_owned())), m.one);
+}
+
+#[test]
+fn test_wire_uses_field_number() {
+    let mut m = WithRustName::new();
+    m.set_ty(10);
+    // field 3, varint
+    test_serialize_deserialize("18 0a", &m);
+}
+
+#[test]
+fn test_reflection_uses_proto_name() {
+    let descriptor = WithRustName::descriptor_static();
+    let field = descriptor.field_by_name("type").unwrap();
+    assert!(descriptor.field_by_name("ty").is_none());
+
+    let mut m = WithRustName::new();
+    m.set_ty(17);
+    m.set_lp("l".to_owned());
+    assert_eq!(17, field.get_i32(&m));
+    assert!(descriptor.field_by_name("loop").unwrap().has_field(&m));
+}
@@ -116,3 +116,34 @@ fn test_map() {
     let deserialized: TestSerdeMap = serde_json::from_str(&serialized).unwrap();
     assert_eq!(deserialized, map);
 }
+
+#[test]
+fn test_skip_default() {
+    let serialized = serde_json::to_string(&TestSkipDefault::new()).unwrap();
+    assert_eq!(serialized, r#"{}"#);
+    let deserialized: TestSkipDefault = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(TestSkipDefault::new(), deserialized);
+
+    let mut m = TestSkipDefault::new();
+    m.set_iii(10);
+    let serialized = serde_json::to_string(&m).unwrap();
+    assert_eq!(serialized, r#"{"iii":10}"#);
+    let deserialized: TestSkipDefault = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(m, deserialized);
+}
+
+#[test]
+fn test_flatten() {
+    let mut address = Address::new();
+    address.set_city("Paris".to_owned());
+    address.set_zip(75001);
+    let mut m = TestFlatten::new();
+    m.set_name("shop".to_owned());
+    m.set_address(address);
+
+    let serialized = serde_json::to_string(&m).unwrap();
+    assert_eq!(serialized, r#"{"name":"shop","city":"Paris","zip":75001}"#);
+
+    let deserialized: TestFlatten = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, m);
+}
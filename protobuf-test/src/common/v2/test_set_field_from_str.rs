@@ -0,0 +1,55 @@
+use protobuf::reflect::set_field_from_str;
+use protobuf::reflect::SetFieldFromStrError;
+
+use super::test_set_field_from_str_pb::*;
+
+#[test]
+fn test_nested_int() {
+    let mut flags = Flags::new();
+    set_field_from_str(&mut flags, "server.port", "8080").unwrap();
+    set_field_from_str(&mut flags, "server.host", "localhost").unwrap();
+    assert_eq!(8080, flags.get_server().get_port());
+    assert_eq!("localhost", flags.get_server().get_host());
+}
+
+#[test]
+fn test_enum() {
+    let mut flags = Flags::new();
+    set_field_from_str(&mut flags, "level", "LEVEL_INFO").unwrap();
+    assert_eq!(Level::LEVEL_INFO, flags.get_level());
+}
+
+#[test]
+fn test_bool_and_repeated() {
+    let mut flags = Flags::new();
+    set_field_from_str(&mut flags, "verbose", "true").unwrap();
+    set_field_from_str(&mut flags, "tags", "a").unwrap();
+    set_field_from_str(&mut flags, "tags", "b").unwrap();
+    assert_eq!(true, flags.get_verbose());
+    assert_eq!(&["a".to_owned(), "b".to_owned()][..], flags.get_tags());
+}
+
+#[test]
+fn test_errors() {
+    let mut flags = Flags::new();
+    match set_field_from_str(&mut flags, "server.unknown", "1") {
+        Err(SetFieldFromStrError::UnknownField(ref name)) if name == "unknown" => {}
+        r => panic!("{:?}", r),
+    }
+    match set_field_from_str(&mut flags, "verbose.port", "1") {
+        Err(SetFieldFromStrError::NotSingularMessageField(ref name)) if name == "verbose" => {}
+        r => panic!("{:?}", r),
+    }
+    match set_field_from_str(&mut flags, "level", "LEVEL_NONE") {
+        Err(SetFieldFromStrError::UnknownEnumValueName(..)) => {}
+        r => panic!("{:?}", r),
+    }
+    match set_field_from_str(&mut flags, "server.port", "-1") {
+        Err(SetFieldFromStrError::ParseInt(..)) => {}
+        r => panic!("{:?}", r),
+    }
+    match set_field_from_str(&mut flags, "labels", "a") {
+        Err(SetFieldFromStrError::UnsupportedFieldType(..)) => {}
+        r => panic!("{:?}", r),
+    }
+}
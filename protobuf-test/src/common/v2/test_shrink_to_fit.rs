@@ -0,0 +1,47 @@
+use protobuf::Clear;
+use protobuf::Message;
+
+use super::test_shrink_to_fit_pb::*;
+
+fn item(name: &str) -> Item {
+    let mut item = Item::new();
+    item.set_name(name.to_owned());
+    item
+}
+
+#[test]
+fn test_shrink_repeated() {
+    let mut m = Items::new();
+    for i in 0..100 {
+        m.items.push(item(&format!("item{}", i)));
+        m.numbers.push(i);
+    }
+    m.clear();
+    assert!(m.items.capacity() >= 100);
+    assert!(m.numbers.capacity() >= 100);
+
+    m.shrink_to_fit();
+    assert_eq!(0, m.items.capacity());
+    assert_eq!(0, m.numbers.capacity());
+}
+
+#[test]
+fn test_shrink_nested() {
+    let mut m = Items::new();
+    let mut first = item("first");
+    first.mut_unknown_fields().add_varint(100, 1);
+    m.first = Some(first).into();
+    m.by_name.insert("a".to_owned(), item("a"));
+    m.items.push(item("b"));
+
+    m.items[0].mut_name().reserve(100);
+    m.shrink_to_fit();
+    assert_eq!(1, m.items.len());
+    assert_eq!(1, m.by_name.len());
+    assert_eq!("first", m.get_first().get_name());
+    assert_eq!(1, m.items[0].mut_name().capacity());
+
+    m.by_name.clear();
+    m.shrink_to_fit();
+    assert_eq!(0, m.by_name.capacity());
+}
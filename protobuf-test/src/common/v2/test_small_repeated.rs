@@ -0,0 +1,53 @@
+use protobuf::parse_from_bytes;
+use protobuf::Message;
+
+use super::test_small_repeated_pb::*;
+
+use protobuf_test_common::*;
+
+fn make_small(count: usize) -> Small {
+    let mut m = Small::new();
+    for i in 0..count {
+        m.numbers.push(i as i32);
+        m.packed.push(-(i as i64));
+        m.strings.push(format!("s{}", i));
+        m.bytes.push(vec![i as u8]);
+        let mut item = Item::new();
+        item.set_id(i as i32);
+        m.items.push(item);
+    }
+    m
+}
+
+#[test]
+fn test_round_trip_below_capacity() {
+    let m = make_small(2);
+    test_serialize_deserialize_no_hex(&m);
+
+    let parsed = parse_from_bytes::<Small>(&m.write_to_bytes().unwrap()).unwrap();
+    assert_eq!(2, parsed.numbers.len());
+    assert!(!parsed.numbers.spilled());
+    assert!(!parsed.packed.spilled());
+    assert!(!parsed.strings.spilled());
+    assert!(!parsed.bytes.spilled());
+    assert!(!parsed.items.spilled());
+}
+
+#[test]
+fn test_round_trip_above_capacity() {
+    let m = make_small(5);
+    test_serialize_deserialize_no_hex(&m);
+
+    let parsed = parse_from_bytes::<Small>(&m.write_to_bytes().unwrap()).unwrap();
+    assert_eq!(&[0, -1, -2, -3, -4], &parsed.packed[..]);
+    assert_eq!(4, parsed.items[4].get_id());
+    assert!(parsed.numbers.spilled());
+    assert!(parsed.items.spilled());
+}
+
+#[test]
+fn test_reflect() {
+    let m = make_small(3);
+    let field = m.descriptor().field_by_name("numbers").unwrap();
+    assert_eq!(3, field.get_repeated(&m).len());
+}
@@ -0,0 +1,20 @@
+use super::test_string_wrapper_pb::*;
+
+fn id(value: &str) -> Id {
+    let mut id = Id::new();
+    id.set_value(value.to_owned());
+    id
+}
+
+#[test]
+fn test_eq_str() {
+    let id = id("abc");
+    assert!(id == "abc");
+    assert!(id != "abd");
+    assert!(id == *"abc");
+}
+
+#[test]
+fn test_eq_str_unset() {
+    assert!(Id::new() == "");
+}
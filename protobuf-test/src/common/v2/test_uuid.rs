@@ -0,0 +1,52 @@
+use uuid::Uuid;
+
+use protobuf::error::WireError;
+use protobuf::*;
+
+use protobuf_test_common::*;
+
+use super::test_uuid_pb::*;
+
+const ID: [u8; 16] = [
+    0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8,
+];
+
+#[test]
+fn test_round_trip() {
+    let id = Uuid::from_bytes(ID);
+    let other = Uuid::from_bytes([1; 16]);
+
+    let mut entity = Entity::new();
+    entity.set_id_uuid(id);
+    test_serialize_deserialize("0a 10 67 e5 50 44 10 b1 42 6f 92 47 bb 68 0e 5f e0 c8", &entity);
+
+    entity.set_refs_uuids(&[other, id]);
+    let entity: Entity = parse_from_bytes(&entity.write_to_bytes().unwrap()).unwrap();
+    assert_eq!(Some(id), entity.get_id_uuid());
+    assert_eq!(vec![other, id], entity.get_refs_uuids());
+    assert_eq!(None, Entity::new().get_id_uuid());
+}
+
+fn parse_entity_error(entity_bytes: &EntityBytes) -> WireError {
+    match parse_from_bytes::<Entity>(&entity_bytes.write_to_bytes().unwrap()) {
+        Err(ProtobufError::WireError(e)) => e,
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn test_incorrect_length() {
+    let mut entity_bytes = EntityBytes::new();
+    entity_bytes.set_id(ID[..15].to_vec());
+    match parse_entity_error(&entity_bytes) {
+        WireError::IncorrectUuidLength(15) => {}
+        e => panic!("{:?}", e),
+    }
+
+    let mut entity_bytes = EntityBytes::new();
+    entity_bytes.mut_refs().push(Vec::new());
+    match parse_entity_error(&entity_bytes) {
+        WireError::IncorrectUuidLength(0) => {}
+        e => panic!("{:?}", e),
+    }
+}
@@ -7,5 +7,5 @@ fn test_default_values() {
     assert_eq!("sss", TestCarllercheBytesDefaultValues::default_instance().get_s());
     assert_eq!(b"bbb", TestCarllercheBytesDefaultValues::default_instance().get_b());
     assert_eq!(&""[..], &**TestCarllercheBytesDefaultValues::new().mut_s());
-    assert_eq!(&b""[..], &**TestCarllercheBytesDefaultValues::new().mut_b());
+    assert_eq!(&b""[..], &*TestCarllercheBytesDefaultValues::new().take_b());
 }
@@ -0,0 +1,15 @@
+use protobuf_test_common::*;
+
+use super::test_crate_visibility_pb::*;
+
+#[test]
+fn test_use_within_crate() {
+    let mut nested = crate_message::Nested::new();
+    nested.set_a(1);
+    let mut m = CrateMessage::new();
+    m.nested = ::protobuf::SingularPtrField::some(nested);
+    m.set_e(CrateEnum::SECOND);
+    m.set_s("x".to_owned());
+    assert_eq!(Some(crate_message::One::s("x".to_owned())), m.one);
+    test_serialize_deserialize("0a 02 08 01 10 02 1a 01 78", &m);
+}
@@ -0,0 +1,37 @@
+use protobuf::*;
+
+use super::test_deprecated_pb::*;
+
+// Items marked `deprecated` in .proto file are generated with `#[deprecated]`
+// attribute, so using them in a `#[deny(deprecated)]` scope is a compile error.
+
+#[test]
+#[deny(deprecated)]
+fn test_not_deprecated() {
+    let mut m = MessageWithDeprecatedField::new();
+    m.set_current(10);
+    assert_eq!(10, m.get_current());
+    assert_eq!(EnumWithDeprecatedValue::CURRENT, EnumWithDeprecatedValue::from_i32(1).unwrap());
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_field() {
+    let mut m = MessageWithDeprecatedField::new();
+    m.set_old(20);
+    assert_eq!(20, m.get_old());
+    assert!(m.has_old());
+    m.clear_old();
+    assert!(!m.has_old());
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_message_and_enum() {
+    let mut m = DeprecatedMessage::new();
+    m.set_value(30);
+    assert_eq!(30, m.get_value());
+
+    assert_eq!(EnumWithDeprecatedValue::OLD, EnumWithDeprecatedValue::from_i32(2).unwrap());
+    assert_eq!(DeprecatedEnum::UNDEFINED, DeprecatedEnum::default());
+}
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use super::test_into_parts_pb::*;
+
+#[test]
+fn test_into_parts() {
+    let mut address = Address::new();
+    address.set_city("Paris".to_owned());
+
+    let mut record = Record::new();
+    record.set_name("shop".to_owned());
+    record.set_id(17);
+    record.set_address(address.clone());
+    record.mut_tags().push("a".to_owned());
+    record.mut_tags().push("b".to_owned());
+    record.mut_counts().insert("x".to_owned(), 3);
+    record.set_number(42);
+
+    let RecordParts {
+        name,
+        id,
+        address: record_address,
+        tags,
+        counts,
+        payload,
+    } = record.into_parts();
+
+    assert_eq!(Some("shop".to_owned()), name);
+    assert_eq!(Some(17), id);
+    assert_eq!(Some(address), record_address);
+    assert_eq!(vec!["a".to_owned(), "b".to_owned()], tags.into_vec());
+    let mut expected_counts = HashMap::new();
+    expected_counts.insert("x".to_owned(), 3);
+    assert_eq!(expected_counts, counts);
+    assert_eq!(Some(record::Payload::number(42)), payload);
+}
+
+#[test]
+fn test_into_parts_unset() {
+    let parts = Record::new().into_parts();
+    assert_eq!(RecordParts::default(), parts);
+    assert_eq!(None, parts.id);
+    assert_eq!(None, parts.address);
+    assert_eq!(None, parts.payload);
+}
@@ -0,0 +1,66 @@
+use protobuf::*;
+use protobuf_test_common::hex::decode_hex;
+use protobuf_test_common::*;
+
+use super::test_lazy_pb::*;
+
+// `inner` fields are serialized in reverse order, so re-serialization
+// of parsed message would produce different bytes
+const NON_CANONICAL: &str = "0a 05 12 01 78 08 05 10 07";
+
+// parses done by lazy fields on this thread since `start`
+fn parse_count(start: u64) -> u64 {
+    LazyMessage::<LazyInner>::thread_parse_count() - start
+}
+
+#[test]
+fn test_not_parsed_until_accessed() {
+    let start = LazyMessage::<LazyInner>::thread_parse_count();
+    let m: WithLazy = parse_from_bytes(&decode_hex(NON_CANONICAL)).unwrap();
+    assert!(m.inner.is_some());
+    assert_eq!(0, parse_count(start));
+
+    assert_eq!(decode_hex(NON_CANONICAL), m.write_to_bytes().unwrap());
+    assert_eq!(0, parse_count(start));
+
+    assert_eq!(5, m.inner.get().unwrap().get_a());
+    assert_eq!(1, parse_count(start));
+    assert_eq!(5, m.inner.get().unwrap().get_a());
+    assert_eq!(1, parse_count(start));
+    assert_eq!(decode_hex(NON_CANONICAL), m.write_to_bytes().unwrap());
+    assert_eq!(1, parse_count(start));
+}
+
+#[test]
+fn test_modified() {
+    let mut m: WithLazy = parse_from_bytes(&decode_hex(NON_CANONICAL)).unwrap();
+    m.inner.get_mut().unwrap().set_a(6);
+    assert_eq!(
+        decode_hex("0a 05 08 06 12 01 78 10 07"),
+        m.write_to_bytes().unwrap()
+    );
+}
+
+#[test]
+fn test_unset() {
+    let mut m = WithLazy::new();
+    m.set_x(7);
+    test_serialize_deserialize("10 07", &m);
+
+    let mut inner = LazyInner::new();
+    inner.set_a(5);
+    m.inner.set(inner);
+    test_serialize_deserialize("0a 02 08 05 10 07", &m);
+}
+
+#[test]
+fn test_reflect() {
+    let m: WithLazy = parse_from_bytes(&decode_hex(NON_CANONICAL)).unwrap();
+    let field = WithLazy::descriptor_static().field_by_name("inner").unwrap();
+    assert!(field.has_field(&m));
+    let inner = field.get_message(&m);
+    assert_eq!(
+        5,
+        inner.descriptor().field_by_name("a").unwrap().get_i32(inner)
+    );
+}
@@ -0,0 +1,71 @@
+use std::mem;
+
+use protobuf::reflect::ReflectValueBox;
+use protobuf::*;
+use protobuf_test_common::hex::decode_hex;
+use protobuf_test_common::*;
+
+use super::test_lazy_packed_pb::*;
+
+#[test]
+fn test_iter() {
+    let m: WithLazyPacked = parse_from_bytes(&decode_hex("0a 03 01 96 01 12 02 01 02 20 07")).unwrap();
+    assert_eq!(vec![1, 150], m.iter_values().collect::<Vec<_>>());
+    assert_eq!(vec![-1, 1], m.iter_signed().collect::<Vec<_>>());
+    assert_eq!(7, m.get_x());
+}
+
+#[test]
+fn test_not_materialized() {
+    let count = 100000;
+    let values: LazyPacked<reflect::types::ProtobufTypeInt32> =
+        (0..count).map(|i| i % 100).collect();
+    let mut m = WithLazyPacked::new();
+    m.values = values;
+    let bytes = m.write_to_bytes().unwrap();
+
+    let parsed: WithLazyPacked = parse_from_bytes(&bytes).unwrap();
+    // single byte per element is stored instead of decoded `i32`
+    assert_eq!(count as usize, parsed.values.raw_bytes().len());
+    assert!(parsed.values.raw_bytes().len() < count as usize * mem::size_of::<i32>());
+
+    let mut expected = 0;
+    for v in parsed.iter_values() {
+        assert_eq!(expected % 100, v);
+        expected += 1;
+    }
+    assert_eq!(count, expected);
+
+    // untouched field is written as is
+    assert_eq!(bytes, parsed.write_to_bytes().unwrap());
+}
+
+#[test]
+fn test_unpacked_input() {
+    let m: WithLazyPacked = parse_from_bytes(&decode_hex("08 01 08 02 0a 01 03")).unwrap();
+    assert_eq!(vec![1, 2, 3], m.iter_values().collect::<Vec<_>>());
+    assert_eq!(decode_hex("0a 03 01 02 03"), m.write_to_bytes().unwrap());
+}
+
+#[test]
+fn test_enum() {
+    let mut m = WithLazyPacked::new();
+    m.enums.push(ProtobufEnumOrUnknown::new(LazyPackedEnum::ONE));
+    m.enums.push(ProtobufEnumOrUnknown::from_i32(5));
+    test_serialize_deserialize("1a 02 01 05", &m);
+}
+
+#[test]
+fn test_invalid() {
+    assert!(parse_from_bytes::<WithLazyPacked>(&decode_hex("0a 02 01 96")).is_err());
+}
+
+#[test]
+fn test_reflect() {
+    let mut m = WithLazyPacked::new();
+    m.values.push(10);
+    let field = m.descriptor().field_by_name("values").unwrap();
+    assert_eq!("[10]", format!("{:?}", field.get_repeated(&m)));
+    field.mut_repeated(&mut m).push(ReflectValueBox::I32(20));
+    assert_eq!(vec![10, 20], m.iter_values().collect::<Vec<_>>());
+}
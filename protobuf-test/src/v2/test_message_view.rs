@@ -0,0 +1,64 @@
+use protobuf::*;
+
+use super::test_message_view_pb::*;
+
+fn points_into(input: &[u8], s: &[u8]) -> bool {
+    let start = input.as_ptr() as usize;
+    let p = s.as_ptr() as usize;
+    p >= start && p + s.len() <= start + input.len()
+}
+
+#[test]
+fn test_parse_view() {
+    let mut order = Order::new();
+    order.set_id(17);
+    order.set_customer("Alice".to_owned());
+    order.tags.push("urgent".to_owned());
+    order.tags.push("gift".to_owned());
+    {
+        let main_item = order.main_item.set_default();
+        main_item.set_name("book".to_owned());
+        main_item.set_data(vec![1, 2, 3]);
+    }
+    order.items.push_default().set_name("pen".to_owned());
+    order.set_kind(Kind::LARGE);
+    order.amounts.extend(&[10, -20]);
+    order.blobs.push(vec![4, 5]);
+    order.mut_unknown_fields().add_varint(100, 1);
+
+    let bytes = order.write_to_bytes().unwrap();
+    let view = OrderView::parse(&bytes).unwrap();
+
+    assert_eq!(Some(17), view.id);
+    assert_eq!(Some("Alice"), view.customer);
+    assert_eq!(vec!["urgent", "gift"], view.tags);
+    let main_item = view.main_item.as_ref().unwrap();
+    assert_eq!(Some("book"), main_item.name);
+    assert_eq!(Some(&[1, 2, 3][..]), main_item.data);
+    assert_eq!(1, view.items.len());
+    assert_eq!(Some("pen"), view.items[0].name);
+    assert_eq!(None, view.items[0].data);
+    assert_eq!(Some(ProtobufEnumOrUnknown::new(Kind::LARGE)), view.kind);
+    assert_eq!(vec![10, -20], view.amounts);
+    assert_eq!(vec![&[4, 5][..]], view.blobs);
+
+    // strings and bytes are not copied
+    assert!(points_into(&bytes, view.customer.unwrap().as_bytes()));
+    assert!(points_into(&bytes, view.tags[1].as_bytes()));
+    assert!(points_into(&bytes, main_item.name.unwrap().as_bytes()));
+    assert!(points_into(&bytes, main_item.data.unwrap()));
+    assert!(points_into(&bytes, view.blobs[0]));
+}
+
+#[test]
+fn test_parse_view_empty() {
+    assert_eq!(OrderView::default(), OrderView::parse(&[]).unwrap());
+}
+
+#[test]
+fn test_parse_view_error() {
+    let mut item = Item::new();
+    item.set_name("book".to_owned());
+    let bytes = item.write_to_bytes().unwrap();
+    assert!(ItemView::parse(&bytes[..bytes.len() - 1]).is_err());
+}
@@ -0,0 +1,120 @@
+use protobuf::*;
+
+use protobuf_test_common::hex::decode_hex;
+use protobuf_test_common::*;
+
+use super::test_optimize_for_code_size_pb::*;
+use super::test_optimize_for_code_size_speed_pb as speed;
+
+fn speed_nested(a: i32, s: &str) -> speed::CodeSizeNested {
+    let mut nested = speed::CodeSizeNested::new();
+    nested.set_a(a);
+    nested.mut_s().push(s.to_owned());
+    nested
+}
+
+// maps have single entry, so serialized bytes do not depend on iteration order
+fn speed_message() -> speed::CodeSizeMessage {
+    let mut m = speed::CodeSizeMessage::new();
+    m.set_double_field(1.5);
+    m.set_float_field(-2.5);
+    m.set_int32_field(-3);
+    m.set_int64_field(-4);
+    m.set_uint32_field(5);
+    m.set_uint64_field(6);
+    m.set_sint32_field(-7);
+    m.set_sint64_field(-8);
+    m.set_fixed32_field(9);
+    m.set_fixed64_field(10);
+    m.set_sfixed32_field(-11);
+    m.set_sfixed64_field(-12);
+    m.set_bool_field(true);
+    m.set_string_field("thirteen".to_owned());
+    m.set_bytes_field(b"fourteen".to_vec());
+    m.set_enum_field(speed::CodeSizeEnum::GREEN);
+    m.set_nested_field(speed_nested(15, "fifteen"));
+    m.set_repeated_int32_field(vec![16, -16]);
+    m.set_packed_sint64_field(vec![17, -17]);
+    m.set_packed_enum_field(vec![
+        speed::CodeSizeEnum::RED.into(),
+        speed::CodeSizeEnum::UNKNOWN.into(),
+    ]);
+    m.mut_repeated_nested_field().push(speed_nested(18, "eighteen"));
+    m.mut_repeated_nested_field().push(speed::CodeSizeNested::new());
+    m.mut_map_nested_field().insert("nineteen".to_owned(), speed_nested(19, ""));
+    m.mut_map_enum_field().insert(20, speed::CodeSizeEnum::GREEN.into());
+    m.set_one_nested(speed_nested(21, "twenty one"));
+    m
+}
+
+#[test]
+fn test_same_bytes_as_speed() {
+    let speed_message = speed_message();
+    let bytes = speed_message.write_to_bytes().unwrap();
+
+    let m = parse_from_bytes::<CodeSizeMessage>(&bytes).unwrap();
+    assert_eq!(-3, m.get_int32_field());
+    assert_eq!(-8, m.get_sint64_field());
+    assert_eq!("thirteen", m.get_string_field());
+    assert_eq!(CodeSizeEnum::GREEN, m.get_enum_field());
+    assert_eq!(15, m.get_nested_field().get_a());
+    assert_eq!(&[17, -17], m.get_packed_sint64_field());
+    assert_eq!(
+        &[CodeSizeEnum::RED, CodeSizeEnum::UNKNOWN],
+        m.get_packed_enum_field()
+    );
+    assert_eq!(19, m.get_map_nested_field()["nineteen"].get_a());
+    assert_eq!(CodeSizeEnum::GREEN, m.get_map_enum_field()[&20]);
+    assert_eq!("twenty one", m.get_one_nested().get_s()[0]);
+
+    assert_eq!(bytes.len() as u32, m.compute_size());
+    assert_eq!(bytes, m.write_to_bytes().unwrap());
+
+    let parsed = parse_from_bytes::<speed::CodeSizeMessage>(&m.write_to_bytes().unwrap()).unwrap();
+    assert_eq!(speed_message, parsed);
+}
+
+#[test]
+fn test_serialize() {
+    let mut m = CodeSizeMessage::new();
+    m.set_int32_field(150);
+    m.set_one_string("ab".to_owned());
+    test_serialize_deserialize("18 96 01 c2 01 02 61 62", &m);
+
+    let mut m = CodeSizeMessage::new();
+    m.set_packed_sint64_field(vec![1, -1]);
+    test_serialize_deserialize("9a 01 02 02 01", &m);
+}
+
+#[test]
+fn test_unknown_fields() {
+    let bytes = decode_hex("a0 06 01");
+    let m = parse_from_bytes::<CodeSizeMessage>(&bytes).unwrap();
+    assert_eq!(1, m.get_unknown_fields().iter().count());
+    assert_eq!(bytes, m.write_to_bytes().unwrap());
+}
+
+#[test]
+fn test_unknown_enum_value() {
+    // reflection cannot store unknown value in enum field,
+    // so it is preserved in unknown fields
+    let bytes = decode_hex("80 01 05");
+    let m = parse_from_bytes::<CodeSizeMessage>(&bytes).unwrap();
+    assert!(!m.has_enum_field());
+    assert_eq!(1, m.get_unknown_fields().iter().count());
+    assert_eq!(bytes, m.write_to_bytes().unwrap());
+
+    // map entry `1 -> 5`
+    let bytes = decode_hex("ba 01 04 08 01 10 05");
+    let m = parse_from_bytes::<CodeSizeMessage>(&bytes).unwrap();
+    assert!(m.get_map_enum_field().is_empty());
+    assert_eq!(1, m.get_unknown_fields().iter().count());
+    assert_eq!(bytes, m.write_to_bytes().unwrap());
+}
+
+#[test]
+fn test_unpacked_input() {
+    // field 19 declared packed, but parsers must accept unpacked values
+    let m: CodeSizeMessage = parse_from_bytes(&decode_hex("98 01 02 98 01 01")).unwrap();
+    assert_eq!(&[1, -1], m.get_packed_sint64_field());
+}
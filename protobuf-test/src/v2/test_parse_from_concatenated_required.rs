@@ -0,0 +1,32 @@
+use protobuf::parse_from_concatenated;
+use protobuf::CodedOutputStream;
+use protobuf::Message;
+
+use super::test_parse_from_concatenated_required_pb::*;
+
+#[test]
+fn test_required_in_any_chunk() {
+    let mut m = WithRequired::new();
+    m.set_name("first".to_owned());
+    // `write_to_bytes` checks required fields
+    m.compute_size();
+    let mut without_id = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut without_id);
+        m.write_to_with_cached_sizes(&mut os).unwrap();
+        os.flush().unwrap();
+    }
+    let mut m = WithRequired::new();
+    m.set_id(2);
+    let with_id = m.write_to_bytes().unwrap();
+
+    let parsed: WithRequired = parse_from_concatenated(&[&without_id[..], &with_id[..]]).unwrap();
+    assert_eq!("first", parsed.get_name());
+    assert_eq!(2, parsed.get_id());
+    assert!(parse_from_concatenated::<WithRequired>(&[&without_id[..]]).is_err());
+}
+
+#[test]
+fn test_empty() {
+    assert!(parse_from_concatenated::<WithRequired>(&[]).is_err());
+}
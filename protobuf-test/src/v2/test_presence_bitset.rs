@@ -0,0 +1,130 @@
+use std::mem;
+
+use protobuf::prelude::*;
+use protobuf::reflect::ReflectValueBox;
+use protobuf::*;
+
+use super::test_presence_bitset_pb::*;
+
+fn small_with_fields(mask: u32) -> Small {
+    let mut m = Small::new();
+    if mask & 1 != 0 {
+        m.set_a(0);
+    }
+    if mask & 2 != 0 {
+        m.set_b(true);
+    }
+    if mask & 4 != 0 {
+        m.set_c(2.5);
+    }
+    if mask & 8 != 0 {
+        m.set_d(-3);
+    }
+    if mask & 16 != 0 {
+        m.set_e(4);
+    }
+    if mask & 32 != 0 {
+        m.set_s("s".to_owned());
+    }
+    m
+}
+
+fn small_presence(m: &Small) -> u32 {
+    m.has_a() as u32
+        | (m.has_b() as u32) << 1
+        | (m.has_c() as u32) << 2
+        | (m.has_d() as u32) << 3
+        | (m.has_e() as u32) << 4
+        | (m.has_s() as u32) << 5
+}
+
+#[test]
+fn round_trip_all_combinations() {
+    for mask in 0..64 {
+        let m = small_with_fields(mask);
+        assert_eq!(mask, small_presence(&m));
+
+        let bytes = m.write_to_bytes().unwrap();
+        let parsed: Small = parse_from_bytes(&bytes).unwrap();
+        assert_eq!(mask, small_presence(&parsed));
+        assert_eq!(m, parsed);
+    }
+}
+
+#[test]
+fn getters_return_default_if_unset() {
+    let m = Small::new();
+    assert_eq!(0, m.get_a());
+    assert_eq!(false, m.get_b());
+    assert_eq!(1.5, m.get_c());
+    assert_eq!(0, m.get_d());
+
+    let m = small_with_fields(63);
+    assert_eq!(0, m.get_a());
+    assert_eq!(true, m.get_b());
+    assert_eq!(2.5, m.get_c());
+    assert_eq!(-3, m.get_d());
+    assert_eq!(4, m.get_e());
+}
+
+#[test]
+fn clear_fields() {
+    for mask in 0..32 {
+        let mut m = small_with_fields(31);
+        if mask & 1 != 0 {
+            m.clear_a();
+        }
+        if mask & 2 != 0 {
+            m.clear_b();
+        }
+        if mask & 4 != 0 {
+            m.clear_c();
+        }
+        if mask & 8 != 0 {
+            m.clear_d();
+        }
+        if mask & 16 != 0 {
+            m.clear_e();
+        }
+        assert_eq!(31 & !mask, small_presence(&m));
+        // cleared fields are equal to never set fields
+        assert_eq!(small_with_fields(31 & !mask), m);
+    }
+
+    let mut m = small_with_fields(63);
+    m.clear();
+    assert_eq!(Small::new(), m);
+}
+
+#[test]
+fn required() {
+    let mut m = WithRequired::new();
+    assert!(!m.is_initialized());
+    m.set_r(0);
+    assert!(m.is_initialized());
+    m.clear_r();
+    assert!(!m.is_initialized());
+}
+
+#[test]
+fn size_of() {
+    assert!(mem::size_of::<Fields32>() < mem::size_of::<Fields32Option>());
+}
+
+#[test]
+fn reflect_multiple_words() {
+    let descriptor = Fields33::descriptor_static();
+    for i in 0..33 {
+        let mut m = Fields33::new();
+        let field = descriptor.field_by_name(&format!("f{}", i)).unwrap();
+        field.set_singular_field(&mut m, ReflectValueBox::I32(i));
+
+        for f in descriptor.fields() {
+            assert_eq!(f.name() == field.name(), f.has_field(&m));
+        }
+
+        let parsed: Fields33 = parse_from_bytes(&m.write_to_bytes().unwrap()).unwrap();
+        assert_eq!(m, parsed);
+        assert_eq!(i, field.get_i32(&parsed));
+    }
+}
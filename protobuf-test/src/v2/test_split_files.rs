@@ -0,0 +1,52 @@
+use protobuf::prelude::*;
+use protobuf::*;
+
+use super::test_split_files_imported_pb::*;
+use super::test_split_files_pb::*;
+
+#[test]
+fn test_reexported() {
+    let mut imported = SplitImported::new();
+    imported.set_c(10);
+
+    let mut second = SplitSecond::new();
+    second.set_b("bb".to_owned());
+    second.imported = SingularPtrField::some(imported);
+    second.set_imported_enum(split_imported::ImportedEnum::RED);
+
+    let mut nested = split_first::Nested::new();
+    nested.second = SingularPtrField::some(second.clone());
+
+    let mut first = SplitFirst::new();
+    first.set_a(17);
+    first.second = SingularPtrField::some(second);
+    first.set_e(SplitEnum::FOO);
+    first.nested = SingularPtrField::some(nested);
+
+    let bytes = first.write_to_bytes().unwrap();
+    let parsed = parse_from_bytes::<SplitFirst>(&bytes).unwrap();
+    assert_eq!(first, parsed);
+}
+
+#[test]
+fn test_descriptor() {
+    assert_eq!("SplitFirst", SplitFirst::descriptor_static().name());
+    assert_eq!("Nested", split_first::Nested::descriptor_static().name());
+    assert_eq!("SplitEnum", SplitEnum::enum_descriptor_static().name());
+}
+
+#[test]
+fn test_file_per_type() {
+    let mod_rs = include_str!("test_split_files_pb/mod.rs");
+    for name in &["split_first_pb", "split_second_pb", "split_enum_pb"] {
+        assert!(mod_rs.contains(&format!("mod {};\npub use self::{}::*;\n", name, name)));
+    }
+    assert!(!mod_rs.contains("pub struct"));
+
+    let first = include_str!("test_split_files_pb/split_first_pb.rs");
+    assert!(first.contains("pub struct SplitFirst {"));
+    // nested messages are generated with their parent
+    assert!(first.contains("pub mod split_first {"));
+    assert!(!first.contains("pub struct SplitSecond {"));
+    assert!(include_str!("test_split_files_pb/split_enum_pb.rs").contains("pub enum SplitEnum {"));
+}
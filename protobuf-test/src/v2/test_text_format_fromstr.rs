@@ -0,0 +1,23 @@
+use super::test_text_format_fromstr_pb::*;
+
+#[test]
+fn test_parse() {
+    let config: Config = "field: 1".parse().unwrap();
+    assert_eq!(1, config.get_field());
+    assert!(!config.has_name());
+
+    let config: Config = "name: \"prod\" endpoints { host: \"a\" port: 80 }"
+        .parse()
+        .unwrap();
+    assert_eq!("prod", config.get_name());
+    assert_eq!(1, config.get_endpoints().len());
+    assert_eq!("a", config.get_endpoints()[0].get_host());
+    assert_eq!(80, config.get_endpoints()[0].get_port());
+}
+
+#[test]
+fn test_parse_error() {
+    assert!("field: \"a\"".parse::<Config>().is_err());
+    assert!("unknown: 1".parse::<Endpoint>().is_err());
+    assert!("".parse::<Required>().is_err());
+}
@@ -0,0 +1,22 @@
+use protobuf::*;
+
+use super::test_message_view_pb::*;
+
+#[test]
+fn test_parse_view() {
+    let mut item = Item::new();
+    item.name = "book".to_owned();
+    item.count = 3;
+    item.child.set_default().data = vec![1, 2];
+
+    let bytes = item.write_to_bytes().unwrap();
+    let view = ItemView::parse(&bytes).unwrap();
+
+    assert_eq!("book", view.name);
+    assert_eq!(&[] as &[u8], view.data);
+    assert_eq!(3, view.count);
+    let child = view.child.as_ref().unwrap();
+    assert_eq!("", child.name);
+    assert_eq!(&[1, 2][..], child.data);
+    assert_eq!(None, child.child);
+}
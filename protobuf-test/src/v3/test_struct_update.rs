@@ -0,0 +1,15 @@
+use protobuf_test_common::*;
+
+use super::test_struct_update_pb::*;
+
+#[test]
+fn test_struct_update_syntax() {
+    let m = StructUpdate {
+        name: "x".into(),
+        ..Default::default()
+    };
+    assert_eq!("x", m.name);
+    assert_eq!(0, m.count);
+    assert!(m.tags.is_empty());
+    test_serialize_deserialize("0a 01 78", &m);
+}
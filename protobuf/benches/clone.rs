@@ -0,0 +1,26 @@
+// `cargo test --benches` and `#[feature(test)]` work only in nightly
+#![cfg(rustc_nightly)]
+#![feature(test)]
+
+extern crate protobuf;
+extern crate test;
+
+use protobuf::descriptor;
+
+use self::test::Bencher;
+
+#[bench]
+fn bench_clone(b: &mut Bencher) {
+    let source = descriptor::file_descriptor_proto();
+    b.iter(|| test::black_box(source.clone()));
+}
+
+#[bench]
+fn bench_clone_from(b: &mut Bencher) {
+    let source = descriptor::file_descriptor_proto();
+    let mut target = source.clone();
+    b.iter(|| {
+        target.clone_from(test::black_box(source));
+        test::black_box(&target);
+    });
+}
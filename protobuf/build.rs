@@ -1,6 +1,9 @@
 use std::env;
 use std::env::VarError;
+use std::fs::File;
 use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
 use std::process;
 
 // % rustc +stable --version
@@ -48,7 +51,47 @@ fn cfg_serde() {
     }
 }
 
+fn out_dir() -> PathBuf {
+    PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR"))
+}
+
+// `VERSION_3_0_0` for `3.0.0`, `VERSION_3_0_0_ALPHA_1` for `3.0.0-alpha.1`
+fn version_ident(version: &str) -> String {
+    format!("VERSION_{}", version.replace(".", "_").replace("-", "_").to_uppercase())
+}
+
+fn write_version() {
+    let version = env::var("CARGO_PKG_VERSION").expect("CARGO_PKG_VERSION");
+    let version_ident = version_ident(&version);
+
+    let mut out = File::create(out_dir().join("version.rs")).expect("create version.rs");
+    write!(
+        out,
+        r#"/// Version of `protobuf` crate
+pub const VERSION: &str = "{version}";
+
+/// Name of the const referenced by generated code
+///
+/// ```
+/// let ident = format!("VERSION_{{}}", protobuf::VERSION.replace(".", "_"));
+/// assert_eq!(ident.replace("-", "_").to_uppercase(), protobuf::VERSION_IDENT);
+/// ```
+#[doc(hidden)]
+pub const VERSION_IDENT: &str = "{version_ident}";
+
+/// Generated code references this const,
+/// so it fails to compile with `protobuf` crate of different version
+#[doc(hidden)]
+pub const {version_ident}: () = ();
+"#,
+        version = version,
+        version_ident = version_ident,
+    )
+    .expect("write version.rs");
+}
+
 fn main() {
     cfg_rust_version();
     cfg_serde();
+    write_version();
 }
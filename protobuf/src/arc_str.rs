@@ -0,0 +1,165 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+#[cfg(feature = "with-arbitrary")]
+use arbitrary;
+#[cfg(feature = "with-serde")]
+use serde;
+
+use clear::Clear;
+
+/// Immutable string stored in `Arc<str>`, used for `string` fields
+/// when `intern_strings` codegen option is enabled.
+///
+/// Cloning is cheap, and equal strings parsed by the same `CodedInputStream`
+/// share storage (see `CodedInputStream::read_interned_string`).
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArcStr(Arc<str>);
+
+impl ArcStr {
+    /// New empty object.
+    pub fn new() -> ArcStr {
+        ArcStr::from("")
+    }
+
+    /// True if both strings share the same storage.
+    pub fn ptr_eq(a: &ArcStr, b: &ArcStr) -> bool {
+        Arc::ptr_eq(&a.0, &b.0)
+    }
+
+    /// Len in bytes.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<'a> From<&'a str> for ArcStr {
+    fn from(src: &'a str) -> ArcStr {
+        ArcStr(Arc::from(src))
+    }
+}
+
+impl From<String> for ArcStr {
+    fn from(src: String) -> ArcStr {
+        ArcStr(Arc::from(src))
+    }
+}
+
+impl From<Arc<str>> for ArcStr {
+    fn from(src: Arc<str>) -> ArcStr {
+        ArcStr(src)
+    }
+}
+
+impl From<ArcStr> for String {
+    fn from(src: ArcStr) -> String {
+        (*src.0).to_owned()
+    }
+}
+
+impl From<ArcStr> for Arc<str> {
+    fn from(src: ArcStr) -> Arc<str> {
+        src.0
+    }
+}
+
+impl Default for ArcStr {
+    fn default() -> Self {
+        ArcStr::new()
+    }
+}
+
+impl Deref for ArcStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+// allows lookup of interned strings by `&str`
+impl Borrow<str> for ArcStr {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Clear for ArcStr {
+    fn clear(&mut self) {
+        *self = ArcStr::new();
+    }
+}
+
+impl fmt::Display for ArcStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl fmt::Debug for ArcStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl serde::Serialize for ArcStr {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<<S as serde::Serializer>::Ok, <S as serde::Serializer>::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self)
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl<'de> serde::Deserialize<'de> for ArcStr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as serde::Deserializer<'de>>::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(ArcStr::from)
+    }
+}
+
+#[cfg(feature = "with-arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ArcStr {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        String::arbitrary(u).map(ArcStr::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ArcStr;
+
+    #[test]
+    fn test_display_and_debug() {
+        let s = "test";
+        let string: String = s.into();
+        let arc_str: ArcStr = s.into();
+
+        assert_eq!(format!("{}", string), format!("{}", arc_str));
+        assert_eq!(format!("{:?}", string), format!("{:?}", arc_str));
+    }
+
+    #[test]
+    fn test_ptr_eq() {
+        let a = ArcStr::from("test");
+        let b = ArcStr::from("test");
+        assert_eq!(a, b);
+        assert!(!ArcStr::ptr_eq(&a, &b));
+        assert!(ArcStr::ptr_eq(&a, &a.clone()));
+    }
+}
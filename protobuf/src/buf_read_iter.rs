@@ -351,6 +351,28 @@ impl<'ignore> BufReadIter<'ignore> {
     }
 }
 
+impl<'a> BufReadIter<'a> {
+    /// Read exact number of bytes pointing into the input.
+    ///
+    /// Only possible when reading from byte slice or `Bytes`,
+    /// because buffer of reader does not outlive next read.
+    pub fn read_exact_borrowed(&mut self, len: usize) -> ProtobufResult<&'a [u8]> {
+        match self.input_source {
+            InputSource::Slice(..) => {}
+            #[cfg(feature = "bytes")]
+            InputSource::Bytes(..) => {}
+            _ => return Err(ProtobufError::WireError(WireError::BorrowFromReader)),
+        }
+        if len > self.remaining_in_buf_len() {
+            return Err(ProtobufError::WireError(WireError::UnexpectedEof));
+        }
+        let buf: &'a [u8] = self.buf;
+        let r = &buf[self.pos_within_buf..self.pos_within_buf + len];
+        self.pos_within_buf += len;
+        Ok(r)
+    }
+}
+
 #[cfg(all(test, feature = "bytes"))]
 mod test_bytes {
     use super::*;
@@ -434,4 +456,22 @@ mod test {
             .expect("read_exact");
         assert!(buf_read_iter.eof().expect("eof"));
     }
+
+    #[test]
+    fn read_exact_borrowed() {
+        let bytes = [1, 2, 3, 4, 5];
+        let mut bri = BufReadIter::from_byte_slice(&bytes);
+        let _prev_limit = bri.push_limit(4);
+        let read = bri.read_exact_borrowed(3).expect("read_exact_borrowed");
+        assert_eq!(&bytes[..3], read);
+        assert_eq!(bytes[..3].as_ptr(), read.as_ptr());
+        assert!(bri.read_exact_borrowed(2).is_err());
+
+        let mut reader = &bytes[..];
+        let mut bri = BufReadIter::from_read(&mut reader);
+        match bri.read_exact_borrowed(3) {
+            Err(ProtobufError::WireError(WireError::BorrowFromReader)) => {}
+            r => panic!("{:?}", r),
+        }
+    }
 }
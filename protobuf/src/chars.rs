@@ -91,6 +91,13 @@ impl fmt::Debug for Chars {
     }
 }
 
+#[cfg(feature = "with-arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Chars {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        String::arbitrary(u).map(Chars::from)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Chars;
@@ -1,5 +1,6 @@
 use std::any::Any;
 use std::fmt;
+use std::hash::Hasher;
 use std::io::Read;
 use std::io::Write;
 use std::any::TypeId;
@@ -10,6 +11,7 @@ use bytes::Bytes;
 use clear::Clear;
 use error::ProtobufError;
 use error::ProtobufResult;
+use reflect::hash_stable::hash_message;
 use reflect::MessageDescriptor;
 use reflect::ProtobufValue;
 use stream::CodedInputStream;
@@ -17,9 +19,14 @@ use stream::CodedOutputStream;
 use stream::WithCodedInputStream;
 use stream::WithCodedOutputStream;
 use unknown::UnknownFields;
+use wire_format::WireType;
 
 /// Trait implemented for all generated structs for protobuf messages.
 /// Also, generated messages implement `Clone + Default + PartialEq`
+///
+/// Functions returning `ProtobufResult` are not marked with `#[must_use]`:
+/// `Result` is already `#[must_use]`, so ignored serialization or parsing errors
+/// are reported by `unused_must_use` lint.
 pub trait Message: fmt::Debug + Clear + Send + Sync + ProtobufValue {
     /// Message descriptor for this message, used for reflection.
     fn descriptor(&self) -> &'static MessageDescriptor;
@@ -86,6 +93,36 @@ pub trait Message: fmt::Debug + Clear + Send + Sync + ProtobufValue {
         self.merge_from(&mut is)
     }
 
+    /// Merge fields of other message into this message.
+    ///
+    /// `other` must be a message of the same type, or a message of other type
+    /// generated from the same protobuf message (e. g. in different crate).
+    /// Otherwise `MessageTypeMismatch` error is returned.
+    ///
+    /// Merge is performed through the wire format, so the semantics
+    /// are the same as of `merge_from` with serialized `other`.
+    fn merge_dyn(&mut self, other: &Message) -> ProtobufResult<()> {
+        // compare type ids first, so descriptors are not needed when types match
+        if Any::type_id(&*self) != Any::type_id(other)
+            && self.descriptor().full_name() != other.descriptor().full_name()
+        {
+            return Err(ProtobufError::message_type_mismatch(
+                self.descriptor().full_name(),
+                other.descriptor().full_name(),
+            ));
+        }
+
+        // `other` is not required to be initialized
+        other.compute_size();
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            other.write_to_with_cached_sizes(&mut os)?;
+            os.flush()?;
+        }
+        self.merge_from_bytes(&bytes)
+    }
+
     /// Check if all required fields of this object are initialized.
     fn check_initialized(&self) -> ProtobufResult<()> {
         if !self.is_initialized() {
@@ -107,6 +144,28 @@ pub trait Message: fmt::Debug + Clear + Send + Sync + ProtobufValue {
         v.with_coded_output_stream(|os| self.write_to(os))
     }
 
+    /// Write the message to bytes vec, replacing its content.
+    ///
+    /// Unlike `write_to_bytes`, this function keeps allocated capacity of `v`,
+    /// so the same vec can be reused to serialize many messages.
+    fn write_to_vec_reuse(&self, v: &mut Vec<u8>) -> ProtobufResult<()> {
+        self.check_initialized()?;
+
+        let size = self.compute_size() as usize;
+        v.clear();
+        // allocates only if capacity is not enough
+        v.resize(size, 0);
+        let r = {
+            let mut os = CodedOutputStream::bytes(v);
+            self.write_to_with_cached_sizes(&mut os)
+                .map(|()| os.check_eof())
+        };
+        if r.is_err() {
+            v.clear();
+        }
+        r
+    }
+
     /// Write the message to bytes vec.
     ///    
     /// > **Note**: You can use `parse_from_bytes` to do the reverse.
@@ -151,6 +210,11 @@ pub trait Message: fmt::Debug + Clear + Send + Sync + ProtobufValue {
     where
         Self: Sized;
 
+    /// Clone this message into a new box.
+    ///
+    /// Allows cloning messages behind `Box<Message>`.
+    fn clone_box(&self) -> Box<Message>;
+
     /// Get message descriptor for message type.
     fn descriptor_static() -> &'static MessageDescriptor
     where
@@ -166,8 +230,33 @@ pub trait Message: fmt::Debug + Clear + Send + Sync + ProtobufValue {
     fn default_instance() -> &'static Self
     where
         Self: Sized;
+
+    /// Shrink capacity of all containers of this message and nested messages,
+    /// and drop values retained for reuse after `clear`.
+    fn shrink_to_fit(&mut self) {}
+
+    /// Hash logical content of the message.
+    ///
+    /// Hash does not depend on platform or in-memory layout:
+    /// fields are hashed in field number order, map entries in key order,
+    /// and unknown fields are ignored.
+    ///
+    /// Implemented with reflection, so it is not available with `lite_runtime`.
+    fn hash_stable<H: Hasher>(&self, state: &mut H)
+    where
+        Self: Sized,
+    {
+        hash_message(self, state)
+    }
 }
 
+/// Marker trait implemented by generated messages (unless `lite_runtime` is used).
+///
+/// It is an extension point for conversions to other formats:
+/// a crate can implement its own trait for all `T: ProtobufConvertible`,
+/// using `T::descriptor_static()` and `write_to_bytes` of `Message`.
+pub trait ProtobufConvertible: Message + Sized {}
+
 impl dyn Message {
     pub fn downcast_box<T: Any>(self: Box<Self>) -> Result<Box<T>, Box<Message>> {
         if Any::type_id(&*self) == TypeId::of::<T>() {
@@ -203,8 +292,7 @@ impl dyn Message {
 
 impl Clone for Box<Message> {
     fn clone(&self) -> Self {
-        use std::ops::Deref;
-        self.descriptor().clone(self.deref())
+        self.clone_box()
     }
 }
 
@@ -235,6 +323,36 @@ pub fn parse_from_bytes<M: Message>(bytes: &[u8]) -> ProtobufResult<M> {
     bytes.with_coded_input_stream(|is| parse_from::<M>(is))
 }
 
+/// Parse message from several byte arrays as if they were concatenated.
+///
+/// Concatenation of serialized messages is parsed as these messages merged:
+/// singular fields are overwritten by the last chunk setting them,
+/// and repeated fields are appended. Each chunk must be a complete message,
+/// required fields are checked only after all chunks are merged.
+pub fn parse_from_concatenated<M: Message>(chunks: &[&[u8]]) -> ProtobufResult<M> {
+    let mut r: M = Message::new();
+    for &chunk in chunks {
+        chunk.with_coded_input_stream(|is| r.merge_from(is))?;
+    }
+    r.check_initialized()?;
+    Ok(r)
+}
+
+/// Parse message from byte array calling given function
+/// for each unknown field with field number, wire type and encoded value.
+///
+/// See `CodedInputStream::set_unknown_field_handler`.
+pub fn parse_from_bytes_with_unknown_handler<M: Message>(
+    bytes: &[u8],
+    handler: &mut FnMut(u32, WireType, &[u8]),
+) -> ProtobufResult<M> {
+    let mut is = CodedInputStream::from_bytes(bytes);
+    is.set_unknown_field_handler(handler);
+    let r = parse_from::<M>(&mut is)?;
+    is.check_eof()?;
+    Ok(r)
+}
+
 /// Parse message from `Bytes` object.
 /// Resulting message may share references to the passed bytes object.
 #[cfg(feature = "bytes")]
@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(deprecated)]
 #![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -17,7 +18,10 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 
-#[derive(PartialEq,Clone,Default)]
+// fails to compile if generated code and protobuf runtime versions differ
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_0_0;
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct FileDescriptorSet {
     // message fields
@@ -53,7 +57,13 @@ impl ::protobuf::Message for FileDescriptorSet {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_repeated_message_into_repeated_field(wire_type, is, &mut self.file)?;
@@ -62,6 +72,7 @@ impl ::protobuf::Message for FileDescriptorSet {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -107,6 +118,10 @@ impl ::protobuf::Message for FileDescriptorSet {
         FileDescriptorSet::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -128,6 +143,14 @@ impl ::protobuf::Message for FileDescriptorSet {
         static instance: ::protobuf::rt::Lazy<FileDescriptorSet> = ::protobuf::rt::Lazy::INIT;
         instance.get(FileDescriptorSet::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.file.shrink_to_fit();
+        for v in self.file.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for FileDescriptorSet {
@@ -137,6 +160,22 @@ impl ::protobuf::Clear for FileDescriptorSet {
     }
 }
 
+impl ::std::clone::Clone for FileDescriptorSet {
+    fn clone(&self) -> FileDescriptorSet {
+        FileDescriptorSet {
+            file: ::std::clone::Clone::clone(&self.file),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &FileDescriptorSet) {
+        ::std::clone::Clone::clone_from(&mut self.file, &source.file);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for FileDescriptorSet {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -146,7 +185,10 @@ impl ::std::fmt::Debug for FileDescriptorSet {
 impl ::protobuf::reflect::ProtobufValue for FileDescriptorSet {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for FileDescriptorSet {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct FileDescriptorProto {
     // message fields
@@ -162,6 +204,7 @@ pub struct FileDescriptorProto {
     pub options: ::protobuf::SingularPtrField<FileOptions>,
     pub source_code_info: ::protobuf::SingularPtrField<SourceCodeInfo>,
     syntax: ::protobuf::SingularField<::std::string::String>,
+    edition: ::std::option::Option<::protobuf::ProtobufEnumOrUnknown<Edition>>,
     // special fields
     #[cfg_attr(serde, serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
@@ -287,6 +330,32 @@ impl FileDescriptorProto {
     pub fn take_syntax(&mut self) -> ::std::string::String {
         self.syntax.take().unwrap_or_else(|| ::std::string::String::new())
     }
+
+    // optional .google.protobuf.Edition edition = 14;
+
+    pub fn get_edition(&self) -> Edition {
+        match self.edition {
+            Some(e) => e.enum_value_or(Edition::EDITION_UNKNOWN),
+            None => Edition::EDITION_UNKNOWN,
+        }
+    }
+
+    pub fn clear_edition(&mut self) {
+        self.edition = ::std::option::Option::None;
+    }
+
+    pub fn has_edition(&self) -> bool {
+        self.edition.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edition(&mut self, v: Edition) {
+        self.edition = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::new(v));
+    }
+
+    pub fn set_edition_i32(&mut self, v: i32) {
+        self.edition = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::from_i32(v));
+    }
 }
 
 impl ::protobuf::Message for FileDescriptorProto {
@@ -326,7 +395,13 @@ impl ::protobuf::Message for FileDescriptorProto {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
@@ -364,10 +439,17 @@ impl ::protobuf::Message for FileDescriptorProto {
                 12 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.syntax)?;
                 },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.edition = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("edition")?);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -418,6 +500,9 @@ impl ::protobuf::Message for FileDescriptorProto {
         if let Some(v) = self.syntax.as_ref() {
             my_size += ::protobuf::rt::string_size(12, &v);
         }
+        if let Some(v) = self.edition {
+            my_size += ::protobuf::rt::enum_or_unknown_size(14, v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -460,6 +545,9 @@ impl ::protobuf::Message for FileDescriptorProto {
         if let Some(v) = self.syntax.as_ref() {
             os.write_string(12, v)?;
         }
+        if let Some(v) = self.edition {
+            os.write_enum(14, ::protobuf::ProtobufEnumOrUnknown::value(&v))?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -484,6 +572,10 @@ impl ::protobuf::Message for FileDescriptorProto {
         FileDescriptorProto::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -551,6 +643,12 @@ impl ::protobuf::Message for FileDescriptorProto {
                 |m: &mut FileDescriptorProto| { &mut m.syntax },
                 FileDescriptorProto::get_syntax,
             ));
+            fields.push(::protobuf::reflect::rt::make_option_enum_accessor::<_, Edition>(
+                "edition",
+                |m: &FileDescriptorProto| { &m.edition },
+                |m: &mut FileDescriptorProto| { &mut m.edition },
+                Edition::EDITION_UNKNOWN,
+            ));
             ::protobuf::reflect::MessageDescriptor::new::<FileDescriptorProto>(
                 "FileDescriptorProto",
                 fields,
@@ -563,6 +661,37 @@ impl ::protobuf::Message for FileDescriptorProto {
         static instance: ::protobuf::rt::Lazy<FileDescriptorProto> = ::protobuf::rt::Lazy::INIT;
         instance.get(FileDescriptorProto::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.name);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.package);
+        self.dependency.shrink_to_fit();
+        for v in self.dependency.iter_mut() {
+            v.shrink_to_fit();
+        };
+        self.public_dependency.shrink_to_fit();
+        self.weak_dependency.shrink_to_fit();
+        self.message_type.shrink_to_fit();
+        for v in self.message_type.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.enum_type.shrink_to_fit();
+        for v in self.enum_type.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.service.shrink_to_fit();
+        for v in self.service.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.extension.shrink_to_fit();
+        for v in self.extension.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        ::protobuf::rt::shrink_singular_message_to_fit::<FileOptions, _>(&mut self.options);
+        ::protobuf::rt::shrink_singular_message_to_fit::<SourceCodeInfo, _>(&mut self.source_code_info);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.syntax);
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for FileDescriptorProto {
@@ -579,10 +708,51 @@ impl ::protobuf::Clear for FileDescriptorProto {
         self.options.clear();
         self.source_code_info.clear();
         self.syntax.clear();
+        self.edition = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
 
+impl ::std::clone::Clone for FileDescriptorProto {
+    fn clone(&self) -> FileDescriptorProto {
+        FileDescriptorProto {
+            name: ::std::clone::Clone::clone(&self.name),
+            package: ::std::clone::Clone::clone(&self.package),
+            dependency: ::std::clone::Clone::clone(&self.dependency),
+            public_dependency: ::std::clone::Clone::clone(&self.public_dependency),
+            weak_dependency: ::std::clone::Clone::clone(&self.weak_dependency),
+            message_type: ::std::clone::Clone::clone(&self.message_type),
+            enum_type: ::std::clone::Clone::clone(&self.enum_type),
+            service: ::std::clone::Clone::clone(&self.service),
+            extension: ::std::clone::Clone::clone(&self.extension),
+            options: ::std::clone::Clone::clone(&self.options),
+            source_code_info: ::std::clone::Clone::clone(&self.source_code_info),
+            syntax: ::std::clone::Clone::clone(&self.syntax),
+            edition: ::std::clone::Clone::clone(&self.edition),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &FileDescriptorProto) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.package, &source.package);
+        ::std::clone::Clone::clone_from(&mut self.dependency, &source.dependency);
+        ::std::clone::Clone::clone_from(&mut self.public_dependency, &source.public_dependency);
+        ::std::clone::Clone::clone_from(&mut self.weak_dependency, &source.weak_dependency);
+        ::std::clone::Clone::clone_from(&mut self.message_type, &source.message_type);
+        ::std::clone::Clone::clone_from(&mut self.enum_type, &source.enum_type);
+        ::std::clone::Clone::clone_from(&mut self.service, &source.service);
+        ::std::clone::Clone::clone_from(&mut self.extension, &source.extension);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.source_code_info, &source.source_code_info);
+        ::std::clone::Clone::clone_from(&mut self.syntax, &source.syntax);
+        ::std::clone::Clone::clone_from(&mut self.edition, &source.edition);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for FileDescriptorProto {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -592,7 +762,10 @@ impl ::std::fmt::Debug for FileDescriptorProto {
 impl ::protobuf::reflect::ProtobufValue for FileDescriptorProto {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for FileDescriptorProto {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct DescriptorProto {
     // message fields
@@ -708,7 +881,13 @@ impl ::protobuf::Message for DescriptorProto {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
@@ -744,6 +923,7 @@ impl ::protobuf::Message for DescriptorProto {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -850,6 +1030,10 @@ impl ::protobuf::Message for DescriptorProto {
         DescriptorProto::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -917,6 +1101,44 @@ impl ::protobuf::Message for DescriptorProto {
         static instance: ::protobuf::rt::Lazy<DescriptorProto> = ::protobuf::rt::Lazy::INIT;
         instance.get(DescriptorProto::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.name);
+        self.field.shrink_to_fit();
+        for v in self.field.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.extension.shrink_to_fit();
+        for v in self.extension.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.nested_type.shrink_to_fit();
+        for v in self.nested_type.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.enum_type.shrink_to_fit();
+        for v in self.enum_type.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.extension_range.shrink_to_fit();
+        for v in self.extension_range.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.oneof_decl.shrink_to_fit();
+        for v in self.oneof_decl.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        ::protobuf::rt::shrink_singular_message_to_fit::<MessageOptions, _>(&mut self.options);
+        self.reserved_range.shrink_to_fit();
+        for v in self.reserved_range.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.reserved_name.shrink_to_fit();
+        for v in self.reserved_name.iter_mut() {
+            v.shrink_to_fit();
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for DescriptorProto {
@@ -935,6 +1157,40 @@ impl ::protobuf::Clear for DescriptorProto {
     }
 }
 
+impl ::std::clone::Clone for DescriptorProto {
+    fn clone(&self) -> DescriptorProto {
+        DescriptorProto {
+            name: ::std::clone::Clone::clone(&self.name),
+            field: ::std::clone::Clone::clone(&self.field),
+            extension: ::std::clone::Clone::clone(&self.extension),
+            nested_type: ::std::clone::Clone::clone(&self.nested_type),
+            enum_type: ::std::clone::Clone::clone(&self.enum_type),
+            extension_range: ::std::clone::Clone::clone(&self.extension_range),
+            oneof_decl: ::std::clone::Clone::clone(&self.oneof_decl),
+            options: ::std::clone::Clone::clone(&self.options),
+            reserved_range: ::std::clone::Clone::clone(&self.reserved_range),
+            reserved_name: ::std::clone::Clone::clone(&self.reserved_name),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &DescriptorProto) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.field, &source.field);
+        ::std::clone::Clone::clone_from(&mut self.extension, &source.extension);
+        ::std::clone::Clone::clone_from(&mut self.nested_type, &source.nested_type);
+        ::std::clone::Clone::clone_from(&mut self.enum_type, &source.enum_type);
+        ::std::clone::Clone::clone_from(&mut self.extension_range, &source.extension_range);
+        ::std::clone::Clone::clone_from(&mut self.oneof_decl, &source.oneof_decl);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.reserved_range, &source.reserved_range);
+        ::std::clone::Clone::clone_from(&mut self.reserved_name, &source.reserved_name);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for DescriptorProto {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -944,8 +1200,11 @@ impl ::std::fmt::Debug for DescriptorProto {
 impl ::protobuf::reflect::ProtobufValue for DescriptorProto {
 }
 
+impl ::protobuf::ProtobufConvertible for DescriptorProto {
+}
+
 pub mod descriptor_proto {
-    #[derive(PartialEq,Clone,Default)]
+    #[derive(PartialEq,Default)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
     pub struct ExtensionRange {
         // message fields
@@ -1015,22 +1274,42 @@ pub mod descriptor_proto {
 
         fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
             while !is.eof()? {
-                let (field_number, wire_type) = is.read_tag_unpack()?;
-                match field_number {
-                    1 => {
-                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                            return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                        }
+                let field_start = is.pos();
+                let tag = is.read_raw_varint32()?;
+                match tag {
+                    8 if is.field_allowed(1) => {
                         self.start = ::std::option::Option::Some(is.read_int32()?);
+                        is.record_field_offset(1, field_start);
                     },
-                    2 => {
-                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                            return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                        }
+                    16 if is.field_allowed(2) => {
                         self.end = ::std::option::Option::Some(is.read_int32()?);
+                        is.record_field_offset(2, field_start);
                     },
                     _ => {
-                        ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        let (field_number, wire_type) = ::protobuf::rt::unpack_tag(tag)?;
+                        if !is.field_allowed(field_number) {
+                            ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                            is.record_field_offset(field_number, field_start);
+                            continue;
+                        }
+                        match field_number {
+                            1 => {
+                                if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                    return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                                }
+                                self.start = ::std::option::Option::Some(is.read_int32()?);
+                            },
+                            2 => {
+                                if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                    return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                                }
+                                self.end = ::std::option::Option::Some(is.read_int32()?);
+                            },
+                            _ => {
+                                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                            },
+                        };
+                        is.record_field_offset(field_number, field_start);
                     },
                 };
             }
@@ -1083,6 +1362,10 @@ pub mod descriptor_proto {
             ExtensionRange::new()
         }
 
+        fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+            ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+        }
+
         fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
             static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
             descriptor.get(|| {
@@ -1111,6 +1394,10 @@ pub mod descriptor_proto {
             static instance: ::protobuf::rt::Lazy<ExtensionRange> = ::protobuf::rt::Lazy::INIT;
             instance.get(ExtensionRange::new)
         }
+
+        fn shrink_to_fit(&mut self) {
+            self.unknown_fields.shrink_to_fit();
+        }
     }
 
     impl ::protobuf::Clear for ExtensionRange {
@@ -1121,6 +1408,24 @@ pub mod descriptor_proto {
         }
     }
 
+    impl ::std::clone::Clone for ExtensionRange {
+        fn clone(&self) -> ExtensionRange {
+            ExtensionRange {
+                start: ::std::clone::Clone::clone(&self.start),
+                end: ::std::clone::Clone::clone(&self.end),
+                unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+                cached_size: ::std::clone::Clone::clone(&self.cached_size),
+            }
+        }
+
+        fn clone_from(&mut self, source: &ExtensionRange) {
+            ::std::clone::Clone::clone_from(&mut self.start, &source.start);
+            ::std::clone::Clone::clone_from(&mut self.end, &source.end);
+            ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+            ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+        }
+    }
+
     impl ::std::fmt::Debug for ExtensionRange {
         fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             ::protobuf::text_format::fmt(self, f)
@@ -1130,7 +1435,10 @@ pub mod descriptor_proto {
     impl ::protobuf::reflect::ProtobufValue for ExtensionRange {
     }
 
-    #[derive(PartialEq,Clone,Default)]
+    impl ::protobuf::ProtobufConvertible for ExtensionRange {
+    }
+
+    #[derive(PartialEq,Default)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
     pub struct ReservedRange {
         // message fields
@@ -1200,22 +1508,42 @@ pub mod descriptor_proto {
 
         fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
             while !is.eof()? {
-                let (field_number, wire_type) = is.read_tag_unpack()?;
-                match field_number {
-                    1 => {
-                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                            return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                        }
+                let field_start = is.pos();
+                let tag = is.read_raw_varint32()?;
+                match tag {
+                    8 if is.field_allowed(1) => {
                         self.start = ::std::option::Option::Some(is.read_int32()?);
+                        is.record_field_offset(1, field_start);
                     },
-                    2 => {
-                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                            return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                        }
+                    16 if is.field_allowed(2) => {
                         self.end = ::std::option::Option::Some(is.read_int32()?);
+                        is.record_field_offset(2, field_start);
                     },
                     _ => {
-                        ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        let (field_number, wire_type) = ::protobuf::rt::unpack_tag(tag)?;
+                        if !is.field_allowed(field_number) {
+                            ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                            is.record_field_offset(field_number, field_start);
+                            continue;
+                        }
+                        match field_number {
+                            1 => {
+                                if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                    return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                                }
+                                self.start = ::std::option::Option::Some(is.read_int32()?);
+                            },
+                            2 => {
+                                if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                    return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                                }
+                                self.end = ::std::option::Option::Some(is.read_int32()?);
+                            },
+                            _ => {
+                                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                            },
+                        };
+                        is.record_field_offset(field_number, field_start);
                     },
                 };
             }
@@ -1268,6 +1596,10 @@ pub mod descriptor_proto {
             ReservedRange::new()
         }
 
+        fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+            ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+        }
+
         fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
             static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
             descriptor.get(|| {
@@ -1296,6 +1628,10 @@ pub mod descriptor_proto {
             static instance: ::protobuf::rt::Lazy<ReservedRange> = ::protobuf::rt::Lazy::INIT;
             instance.get(ReservedRange::new)
         }
+
+        fn shrink_to_fit(&mut self) {
+            self.unknown_fields.shrink_to_fit();
+        }
     }
 
     impl ::protobuf::Clear for ReservedRange {
@@ -1306,6 +1642,24 @@ pub mod descriptor_proto {
         }
     }
 
+    impl ::std::clone::Clone for ReservedRange {
+        fn clone(&self) -> ReservedRange {
+            ReservedRange {
+                start: ::std::clone::Clone::clone(&self.start),
+                end: ::std::clone::Clone::clone(&self.end),
+                unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+                cached_size: ::std::clone::Clone::clone(&self.cached_size),
+            }
+        }
+
+        fn clone_from(&mut self, source: &ReservedRange) {
+            ::std::clone::Clone::clone_from(&mut self.start, &source.start);
+            ::std::clone::Clone::clone_from(&mut self.end, &source.end);
+            ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+            ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+        }
+    }
+
     impl ::std::fmt::Debug for ReservedRange {
         fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             ::protobuf::text_format::fmt(self, f)
@@ -1314,9 +1668,12 @@ pub mod descriptor_proto {
 
     impl ::protobuf::reflect::ProtobufValue for ReservedRange {
     }
+
+    impl ::protobuf::ProtobufConvertible for ReservedRange {
+    }
 }
 
-#[derive(PartialEq,Clone,Default)]
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct FieldDescriptorProto {
     // message fields
@@ -1425,6 +1782,10 @@ impl FieldDescriptorProto {
         self.label = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::new(v));
     }
 
+    pub fn set_label_i32(&mut self, v: i32) {
+        self.label = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::from_i32(v));
+    }
+
     // optional .google.protobuf.FieldDescriptorProto.Type type = 5;
 
     pub fn get_field_type(&self) -> field_descriptor_proto::Type {
@@ -1447,6 +1808,10 @@ impl FieldDescriptorProto {
         self.field_type = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::new(v));
     }
 
+    pub fn set_field_type_i32(&mut self, v: i32) {
+        self.field_type = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::from_i32(v));
+    }
+
     // optional string type_name = 6;
 
     pub fn get_type_name(&self) -> &str {
@@ -1623,7 +1988,13 @@ impl ::protobuf::Message for FieldDescriptorProto {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
@@ -1638,13 +2009,13 @@ impl ::protobuf::Message for FieldDescriptorProto {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.label = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    self.label = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("label")?);
                 },
                 5 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.field_type = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    self.field_type = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("type")?);
                 },
                 6 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.type_name)?;
@@ -1671,6 +2042,7 @@ impl ::protobuf::Message for FieldDescriptorProto {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -1770,6 +2142,10 @@ impl ::protobuf::Message for FieldDescriptorProto {
         FieldDescriptorProto::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -1845,6 +2221,16 @@ impl ::protobuf::Message for FieldDescriptorProto {
         static instance: ::protobuf::rt::Lazy<FieldDescriptorProto> = ::protobuf::rt::Lazy::INIT;
         instance.get(FieldDescriptorProto::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.name);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.type_name);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.extendee);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.default_value);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.json_name);
+        ::protobuf::rt::shrink_singular_message_to_fit::<FieldOptions, _>(&mut self.options);
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for FieldDescriptorProto {
@@ -1863,6 +2249,40 @@ impl ::protobuf::Clear for FieldDescriptorProto {
     }
 }
 
+impl ::std::clone::Clone for FieldDescriptorProto {
+    fn clone(&self) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: ::std::clone::Clone::clone(&self.name),
+            number: ::std::clone::Clone::clone(&self.number),
+            label: ::std::clone::Clone::clone(&self.label),
+            field_type: ::std::clone::Clone::clone(&self.field_type),
+            type_name: ::std::clone::Clone::clone(&self.type_name),
+            extendee: ::std::clone::Clone::clone(&self.extendee),
+            default_value: ::std::clone::Clone::clone(&self.default_value),
+            oneof_index: ::std::clone::Clone::clone(&self.oneof_index),
+            json_name: ::std::clone::Clone::clone(&self.json_name),
+            options: ::std::clone::Clone::clone(&self.options),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &FieldDescriptorProto) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.number, &source.number);
+        ::std::clone::Clone::clone_from(&mut self.label, &source.label);
+        ::std::clone::Clone::clone_from(&mut self.field_type, &source.field_type);
+        ::std::clone::Clone::clone_from(&mut self.type_name, &source.type_name);
+        ::std::clone::Clone::clone_from(&mut self.extendee, &source.extendee);
+        ::std::clone::Clone::clone_from(&mut self.default_value, &source.default_value);
+        ::std::clone::Clone::clone_from(&mut self.oneof_index, &source.oneof_index);
+        ::std::clone::Clone::clone_from(&mut self.json_name, &source.json_name);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for FieldDescriptorProto {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -1872,6 +2292,9 @@ impl ::std::fmt::Debug for FieldDescriptorProto {
 impl ::protobuf::reflect::ProtobufValue for FieldDescriptorProto {
 }
 
+impl ::protobuf::ProtobufConvertible for FieldDescriptorProto {
+}
+
 pub mod field_descriptor_proto {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
@@ -1925,6 +2348,29 @@ pub mod field_descriptor_proto {
             }
         }
 
+        fn name(&self) -> &'static str {
+            match *self {
+                Type::TYPE_DOUBLE => "TYPE_DOUBLE",
+                Type::TYPE_FLOAT => "TYPE_FLOAT",
+                Type::TYPE_INT64 => "TYPE_INT64",
+                Type::TYPE_UINT64 => "TYPE_UINT64",
+                Type::TYPE_INT32 => "TYPE_INT32",
+                Type::TYPE_FIXED64 => "TYPE_FIXED64",
+                Type::TYPE_FIXED32 => "TYPE_FIXED32",
+                Type::TYPE_BOOL => "TYPE_BOOL",
+                Type::TYPE_STRING => "TYPE_STRING",
+                Type::TYPE_GROUP => "TYPE_GROUP",
+                Type::TYPE_MESSAGE => "TYPE_MESSAGE",
+                Type::TYPE_BYTES => "TYPE_BYTES",
+                Type::TYPE_UINT32 => "TYPE_UINT32",
+                Type::TYPE_ENUM => "TYPE_ENUM",
+                Type::TYPE_SFIXED32 => "TYPE_SFIXED32",
+                Type::TYPE_SFIXED64 => "TYPE_SFIXED64",
+                Type::TYPE_SINT32 => "TYPE_SINT32",
+                Type::TYPE_SINT64 => "TYPE_SINT64",
+            }
+        }
+
         fn values() -> &'static [Self] {
             static values: &'static [Type] = &[
                 Type::TYPE_DOUBLE,
@@ -1967,6 +2413,18 @@ pub mod field_descriptor_proto {
     impl ::protobuf::reflect::ProtobufValue for Type {
     }
 
+    impl ::std::convert::From<Type> for &'static str {
+        fn from(value: Type) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<Type>> for Type {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<Type>) -> bool {
+            other == self
+        }
+    }
+
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
     pub enum Label {
@@ -1989,6 +2447,14 @@ pub mod field_descriptor_proto {
             }
         }
 
+        fn name(&self) -> &'static str {
+            match *self {
+                Label::LABEL_OPTIONAL => "LABEL_OPTIONAL",
+                Label::LABEL_REQUIRED => "LABEL_REQUIRED",
+                Label::LABEL_REPEATED => "LABEL_REPEATED",
+            }
+        }
+
         fn values() -> &'static [Self] {
             static values: &'static [Label] = &[
                 Label::LABEL_OPTIONAL,
@@ -2015,9 +2481,21 @@ pub mod field_descriptor_proto {
 
     impl ::protobuf::reflect::ProtobufValue for Label {
     }
+
+    impl ::std::convert::From<Label> for &'static str {
+        fn from(value: Label) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<Label>> for Label {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<Label>) -> bool {
+            other == self
+        }
+    }
 }
 
-#[derive(PartialEq,Clone,Default)]
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct OneofDescriptorProto {
     // message fields
@@ -2090,7 +2568,13 @@ impl ::protobuf::Message for OneofDescriptorProto {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
@@ -2102,6 +2586,7 @@ impl ::protobuf::Message for OneofDescriptorProto {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -2153,6 +2638,10 @@ impl ::protobuf::Message for OneofDescriptorProto {
         OneofDescriptorProto::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -2180,6 +2669,12 @@ impl ::protobuf::Message for OneofDescriptorProto {
         static instance: ::protobuf::rt::Lazy<OneofDescriptorProto> = ::protobuf::rt::Lazy::INIT;
         instance.get(OneofDescriptorProto::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.name);
+        ::protobuf::rt::shrink_singular_message_to_fit::<OneofOptions, _>(&mut self.options);
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for OneofDescriptorProto {
@@ -2190,6 +2685,24 @@ impl ::protobuf::Clear for OneofDescriptorProto {
     }
 }
 
+impl ::std::clone::Clone for OneofDescriptorProto {
+    fn clone(&self) -> OneofDescriptorProto {
+        OneofDescriptorProto {
+            name: ::std::clone::Clone::clone(&self.name),
+            options: ::std::clone::Clone::clone(&self.options),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &OneofDescriptorProto) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for OneofDescriptorProto {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -2199,7 +2712,10 @@ impl ::std::fmt::Debug for OneofDescriptorProto {
 impl ::protobuf::reflect::ProtobufValue for OneofDescriptorProto {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for OneofDescriptorProto {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct EnumDescriptorProto {
     // message fields
@@ -2278,7 +2794,13 @@ impl ::protobuf::Message for EnumDescriptorProto {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
@@ -2293,6 +2815,7 @@ impl ::protobuf::Message for EnumDescriptorProto {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -2351,6 +2874,10 @@ impl ::protobuf::Message for EnumDescriptorProto {
         EnumDescriptorProto::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -2383,6 +2910,16 @@ impl ::protobuf::Message for EnumDescriptorProto {
         static instance: ::protobuf::rt::Lazy<EnumDescriptorProto> = ::protobuf::rt::Lazy::INIT;
         instance.get(EnumDescriptorProto::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.name);
+        self.value.shrink_to_fit();
+        for v in self.value.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        ::protobuf::rt::shrink_singular_message_to_fit::<EnumOptions, _>(&mut self.options);
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for EnumDescriptorProto {
@@ -2394,6 +2931,26 @@ impl ::protobuf::Clear for EnumDescriptorProto {
     }
 }
 
+impl ::std::clone::Clone for EnumDescriptorProto {
+    fn clone(&self) -> EnumDescriptorProto {
+        EnumDescriptorProto {
+            name: ::std::clone::Clone::clone(&self.name),
+            value: ::std::clone::Clone::clone(&self.value),
+            options: ::std::clone::Clone::clone(&self.options),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &EnumDescriptorProto) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.value, &source.value);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for EnumDescriptorProto {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -2403,7 +2960,10 @@ impl ::std::fmt::Debug for EnumDescriptorProto {
 impl ::protobuf::reflect::ProtobufValue for EnumDescriptorProto {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for EnumDescriptorProto {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct EnumValueDescriptorProto {
     // message fields
@@ -2496,7 +3056,13 @@ impl ::protobuf::Message for EnumValueDescriptorProto {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
@@ -2514,6 +3080,7 @@ impl ::protobuf::Message for EnumValueDescriptorProto {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -2571,6 +3138,10 @@ impl ::protobuf::Message for EnumValueDescriptorProto {
         EnumValueDescriptorProto::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -2604,6 +3175,12 @@ impl ::protobuf::Message for EnumValueDescriptorProto {
         static instance: ::protobuf::rt::Lazy<EnumValueDescriptorProto> = ::protobuf::rt::Lazy::INIT;
         instance.get(EnumValueDescriptorProto::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.name);
+        ::protobuf::rt::shrink_singular_message_to_fit::<EnumValueOptions, _>(&mut self.options);
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for EnumValueDescriptorProto {
@@ -2615,6 +3192,26 @@ impl ::protobuf::Clear for EnumValueDescriptorProto {
     }
 }
 
+impl ::std::clone::Clone for EnumValueDescriptorProto {
+    fn clone(&self) -> EnumValueDescriptorProto {
+        EnumValueDescriptorProto {
+            name: ::std::clone::Clone::clone(&self.name),
+            number: ::std::clone::Clone::clone(&self.number),
+            options: ::std::clone::Clone::clone(&self.options),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &EnumValueDescriptorProto) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.number, &source.number);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for EnumValueDescriptorProto {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -2624,7 +3221,10 @@ impl ::std::fmt::Debug for EnumValueDescriptorProto {
 impl ::protobuf::reflect::ProtobufValue for EnumValueDescriptorProto {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for EnumValueDescriptorProto {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct ServiceDescriptorProto {
     // message fields
@@ -2703,7 +3303,13 @@ impl ::protobuf::Message for ServiceDescriptorProto {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
@@ -2718,6 +3324,7 @@ impl ::protobuf::Message for ServiceDescriptorProto {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -2776,6 +3383,10 @@ impl ::protobuf::Message for ServiceDescriptorProto {
         ServiceDescriptorProto::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -2808,6 +3419,16 @@ impl ::protobuf::Message for ServiceDescriptorProto {
         static instance: ::protobuf::rt::Lazy<ServiceDescriptorProto> = ::protobuf::rt::Lazy::INIT;
         instance.get(ServiceDescriptorProto::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.name);
+        self.method.shrink_to_fit();
+        for v in self.method.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        ::protobuf::rt::shrink_singular_message_to_fit::<ServiceOptions, _>(&mut self.options);
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for ServiceDescriptorProto {
@@ -2819,6 +3440,26 @@ impl ::protobuf::Clear for ServiceDescriptorProto {
     }
 }
 
+impl ::std::clone::Clone for ServiceDescriptorProto {
+    fn clone(&self) -> ServiceDescriptorProto {
+        ServiceDescriptorProto {
+            name: ::std::clone::Clone::clone(&self.name),
+            method: ::std::clone::Clone::clone(&self.method),
+            options: ::std::clone::Clone::clone(&self.options),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &ServiceDescriptorProto) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.method, &source.method);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for ServiceDescriptorProto {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -2828,7 +3469,10 @@ impl ::std::fmt::Debug for ServiceDescriptorProto {
 impl ::protobuf::reflect::ProtobufValue for ServiceDescriptorProto {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for ServiceDescriptorProto {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct MethodDescriptorProto {
     // message fields
@@ -3015,7 +3659,13 @@ impl ::protobuf::Message for MethodDescriptorProto {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
@@ -3045,6 +3695,7 @@ impl ::protobuf::Message for MethodDescriptorProto {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -3120,6 +3771,10 @@ impl ::protobuf::Message for MethodDescriptorProto {
         MethodDescriptorProto::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -3171,6 +3826,14 @@ impl ::protobuf::Message for MethodDescriptorProto {
         static instance: ::protobuf::rt::Lazy<MethodDescriptorProto> = ::protobuf::rt::Lazy::INIT;
         instance.get(MethodDescriptorProto::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.name);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.input_type);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.output_type);
+        ::protobuf::rt::shrink_singular_message_to_fit::<MethodOptions, _>(&mut self.options);
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for MethodDescriptorProto {
@@ -3185,6 +3848,32 @@ impl ::protobuf::Clear for MethodDescriptorProto {
     }
 }
 
+impl ::std::clone::Clone for MethodDescriptorProto {
+    fn clone(&self) -> MethodDescriptorProto {
+        MethodDescriptorProto {
+            name: ::std::clone::Clone::clone(&self.name),
+            input_type: ::std::clone::Clone::clone(&self.input_type),
+            output_type: ::std::clone::Clone::clone(&self.output_type),
+            options: ::std::clone::Clone::clone(&self.options),
+            client_streaming: ::std::clone::Clone::clone(&self.client_streaming),
+            server_streaming: ::std::clone::Clone::clone(&self.server_streaming),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &MethodDescriptorProto) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.input_type, &source.input_type);
+        ::std::clone::Clone::clone_from(&mut self.output_type, &source.output_type);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.client_streaming, &source.client_streaming);
+        ::std::clone::Clone::clone_from(&mut self.server_streaming, &source.server_streaming);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for MethodDescriptorProto {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -3194,13 +3883,17 @@ impl ::std::fmt::Debug for MethodDescriptorProto {
 impl ::protobuf::reflect::ProtobufValue for MethodDescriptorProto {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for MethodDescriptorProto {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct FileOptions {
     // message fields
     java_package: ::protobuf::SingularField<::std::string::String>,
     java_outer_classname: ::protobuf::SingularField<::std::string::String>,
     java_multiple_files: ::std::option::Option<bool>,
+    #[deprecated(note = "field `.google.protobuf.FileOptions.java_generate_equals_and_hash` is deprecated")]
     java_generate_equals_and_hash: ::std::option::Option<bool>,
     java_string_check_utf8: ::std::option::Option<bool>,
     optimize_for: ::std::option::Option<::protobuf::ProtobufEnumOrUnknown<file_options::OptimizeMode>>,
@@ -3212,6 +3905,7 @@ pub struct FileOptions {
     cc_enable_arenas: ::std::option::Option<bool>,
     objc_class_prefix: ::protobuf::SingularField<::std::string::String>,
     csharp_namespace: ::protobuf::SingularField<::std::string::String>,
+    pub features: ::protobuf::SingularPtrField<FeatureSet>,
     pub uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
     #[cfg_attr(serde, serde(skip))]
@@ -3324,19 +4018,23 @@ impl FileOptions {
 
     // optional bool java_generate_equals_and_hash = 20;
 
+    #[deprecated(note = "field `.google.protobuf.FileOptions.java_generate_equals_and_hash` is deprecated")]
     pub fn get_java_generate_equals_and_hash(&self) -> bool {
         self.java_generate_equals_and_hash.unwrap_or(false)
     }
 
+    #[deprecated(note = "field `.google.protobuf.FileOptions.java_generate_equals_and_hash` is deprecated")]
     pub fn clear_java_generate_equals_and_hash(&mut self) {
         self.java_generate_equals_and_hash = ::std::option::Option::None;
     }
 
+    #[deprecated(note = "field `.google.protobuf.FileOptions.java_generate_equals_and_hash` is deprecated")]
     pub fn has_java_generate_equals_and_hash(&self) -> bool {
         self.java_generate_equals_and_hash.is_some()
     }
 
     // Param is passed by value, moved
+    #[deprecated(note = "field `.google.protobuf.FileOptions.java_generate_equals_and_hash` is deprecated")]
     pub fn set_java_generate_equals_and_hash(&mut self, v: bool) {
         self.java_generate_equals_and_hash = ::std::option::Option::Some(v);
     }
@@ -3382,6 +4080,10 @@ impl FileOptions {
         self.optimize_for = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::new(v));
     }
 
+    pub fn set_optimize_for_i32(&mut self, v: i32) {
+        self.optimize_for = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::from_i32(v));
+    }
+
     // optional string go_package = 11;
 
     pub fn get_go_package(&self) -> &str {
@@ -3588,6 +4290,11 @@ impl FileOptions {
 
 impl ::protobuf::Message for FileOptions {
     fn is_initialized(&self) -> bool {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
@@ -3598,7 +4305,13 @@ impl ::protobuf::Message for FileOptions {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.java_package)?;
@@ -3628,7 +4341,7 @@ impl ::protobuf::Message for FileOptions {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.optimize_for = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    self.optimize_for = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("optimize_for")?);
                 },
                 11 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.go_package)?;
@@ -3669,6 +4382,9 @@ impl ::protobuf::Message for FileOptions {
                 37 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.csharp_namespace)?;
                 },
+                50 => {
+                    ::protobuf::rt::read_singular_message_into::<FeatureSet, _>(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     ::protobuf::rt::read_repeated_message_into_repeated_field(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -3676,6 +4392,7 @@ impl ::protobuf::Message for FileOptions {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -3726,6 +4443,10 @@ impl ::protobuf::Message for FileOptions {
         if let Some(v) = self.csharp_namespace.as_ref() {
             my_size += ::protobuf::rt::string_size(37, &v);
         }
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
@@ -3778,6 +4499,9 @@ impl ::protobuf::Message for FileOptions {
         if let Some(v) = self.csharp_namespace.as_ref() {
             os.write_string(37, v)?;
         }
+        if let Some(v) = self.features.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(50, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             ::protobuf::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -3805,6 +4529,10 @@ impl ::protobuf::Message for FileOptions {
         FileOptions::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -3893,6 +4621,11 @@ impl ::protobuf::Message for FileOptions {
                 |m: &mut FileOptions| { &mut m.csharp_namespace },
                 FileOptions::get_csharp_namespace,
             ));
+            fields.push(::protobuf::reflect::rt::make_option_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FeatureSet>, _>(
+                "features",
+                |m: &FileOptions| { &m.features },
+                |m: &mut FileOptions| { &mut m.features },
+            ));
             fields.push(::protobuf::reflect::rt::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
                 "uninterpreted_option",
                 |m: &FileOptions| { &m.uninterpreted_option },
@@ -3910,6 +4643,20 @@ impl ::protobuf::Message for FileOptions {
         static instance: ::protobuf::rt::Lazy<FileOptions> = ::protobuf::rt::Lazy::INIT;
         instance.get(FileOptions::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.java_package);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.java_outer_classname);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.go_package);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.objc_class_prefix);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.csharp_namespace);
+        ::protobuf::rt::shrink_singular_message_to_fit::<FeatureSet, _>(&mut self.features);
+        self.uninterpreted_option.shrink_to_fit();
+        for v in self.uninterpreted_option.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for FileOptions {
@@ -3928,11 +4675,58 @@ impl ::protobuf::Clear for FileOptions {
         self.cc_enable_arenas = ::std::option::Option::None;
         self.objc_class_prefix.clear();
         self.csharp_namespace.clear();
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 }
 
+impl ::std::clone::Clone for FileOptions {
+    fn clone(&self) -> FileOptions {
+        FileOptions {
+            java_package: ::std::clone::Clone::clone(&self.java_package),
+            java_outer_classname: ::std::clone::Clone::clone(&self.java_outer_classname),
+            java_multiple_files: ::std::clone::Clone::clone(&self.java_multiple_files),
+            java_generate_equals_and_hash: ::std::clone::Clone::clone(&self.java_generate_equals_and_hash),
+            java_string_check_utf8: ::std::clone::Clone::clone(&self.java_string_check_utf8),
+            optimize_for: ::std::clone::Clone::clone(&self.optimize_for),
+            go_package: ::std::clone::Clone::clone(&self.go_package),
+            cc_generic_services: ::std::clone::Clone::clone(&self.cc_generic_services),
+            java_generic_services: ::std::clone::Clone::clone(&self.java_generic_services),
+            py_generic_services: ::std::clone::Clone::clone(&self.py_generic_services),
+            deprecated: ::std::clone::Clone::clone(&self.deprecated),
+            cc_enable_arenas: ::std::clone::Clone::clone(&self.cc_enable_arenas),
+            objc_class_prefix: ::std::clone::Clone::clone(&self.objc_class_prefix),
+            csharp_namespace: ::std::clone::Clone::clone(&self.csharp_namespace),
+            features: ::std::clone::Clone::clone(&self.features),
+            uninterpreted_option: ::std::clone::Clone::clone(&self.uninterpreted_option),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &FileOptions) {
+        ::std::clone::Clone::clone_from(&mut self.java_package, &source.java_package);
+        ::std::clone::Clone::clone_from(&mut self.java_outer_classname, &source.java_outer_classname);
+        ::std::clone::Clone::clone_from(&mut self.java_multiple_files, &source.java_multiple_files);
+        ::std::clone::Clone::clone_from(&mut self.java_generate_equals_and_hash, &source.java_generate_equals_and_hash);
+        ::std::clone::Clone::clone_from(&mut self.java_string_check_utf8, &source.java_string_check_utf8);
+        ::std::clone::Clone::clone_from(&mut self.optimize_for, &source.optimize_for);
+        ::std::clone::Clone::clone_from(&mut self.go_package, &source.go_package);
+        ::std::clone::Clone::clone_from(&mut self.cc_generic_services, &source.cc_generic_services);
+        ::std::clone::Clone::clone_from(&mut self.java_generic_services, &source.java_generic_services);
+        ::std::clone::Clone::clone_from(&mut self.py_generic_services, &source.py_generic_services);
+        ::std::clone::Clone::clone_from(&mut self.deprecated, &source.deprecated);
+        ::std::clone::Clone::clone_from(&mut self.cc_enable_arenas, &source.cc_enable_arenas);
+        ::std::clone::Clone::clone_from(&mut self.objc_class_prefix, &source.objc_class_prefix);
+        ::std::clone::Clone::clone_from(&mut self.csharp_namespace, &source.csharp_namespace);
+        ::std::clone::Clone::clone_from(&mut self.features, &source.features);
+        ::std::clone::Clone::clone_from(&mut self.uninterpreted_option, &source.uninterpreted_option);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for FileOptions {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -3942,6 +4736,9 @@ impl ::std::fmt::Debug for FileOptions {
 impl ::protobuf::reflect::ProtobufValue for FileOptions {
 }
 
+impl ::protobuf::ProtobufConvertible for FileOptions {
+}
+
 pub mod file_options {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
@@ -3965,6 +4762,14 @@ pub mod file_options {
             }
         }
 
+        fn name(&self) -> &'static str {
+            match *self {
+                OptimizeMode::SPEED => "SPEED",
+                OptimizeMode::CODE_SIZE => "CODE_SIZE",
+                OptimizeMode::LITE_RUNTIME => "LITE_RUNTIME",
+            }
+        }
+
         fn values() -> &'static [Self] {
             static values: &'static [OptimizeMode] = &[
                 OptimizeMode::SPEED,
@@ -3991,9 +4796,21 @@ pub mod file_options {
 
     impl ::protobuf::reflect::ProtobufValue for OptimizeMode {
     }
+
+    impl ::std::convert::From<OptimizeMode> for &'static str {
+        fn from(value: OptimizeMode) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<OptimizeMode>> for OptimizeMode {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<OptimizeMode>) -> bool {
+            other == self
+        }
+    }
 }
 
-#[derive(PartialEq,Clone,Default)]
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct MessageOptions {
     // message fields
@@ -4001,6 +4818,7 @@ pub struct MessageOptions {
     no_standard_descriptor_accessor: ::std::option::Option<bool>,
     deprecated: ::std::option::Option<bool>,
     map_entry: ::std::option::Option<bool>,
+    pub features: ::protobuf::SingularPtrField<FeatureSet>,
     pub uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
     #[cfg_attr(serde, serde(skip))]
@@ -4099,17 +4917,28 @@ impl MessageOptions {
 
 impl ::protobuf::Message for MessageOptions {
     fn is_initialized(&self) -> bool {
-        for v in &self.uninterpreted_option {
+        for v in &self.features {
             if !v.is_initialized() {
                 return false;
             }
         };
-        true
-    }
+        for v in &self.uninterpreted_option {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
@@ -4135,6 +4964,9 @@ impl ::protobuf::Message for MessageOptions {
                     }
                     self.map_entry = ::std::option::Option::Some(is.read_bool()?);
                 },
+                12 => {
+                    ::protobuf::rt::read_singular_message_into::<FeatureSet, _>(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     ::protobuf::rt::read_repeated_message_into_repeated_field(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -4142,6 +4974,7 @@ impl ::protobuf::Message for MessageOptions {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -4162,6 +4995,10 @@ impl ::protobuf::Message for MessageOptions {
         if let Some(v) = self.map_entry {
             my_size += 2;
         }
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
@@ -4184,6 +5021,9 @@ impl ::protobuf::Message for MessageOptions {
         if let Some(v) = self.map_entry {
             os.write_bool(7, v)?;
         }
+        if let Some(v) = self.features.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(12, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             ::protobuf::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -4211,6 +5051,10 @@ impl ::protobuf::Message for MessageOptions {
         MessageOptions::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -4239,6 +5083,11 @@ impl ::protobuf::Message for MessageOptions {
                 |m: &mut MessageOptions| { &mut m.map_entry },
                 MessageOptions::get_map_entry,
             ));
+            fields.push(::protobuf::reflect::rt::make_option_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FeatureSet>, _>(
+                "features",
+                |m: &MessageOptions| { &m.features },
+                |m: &mut MessageOptions| { &mut m.features },
+            ));
             fields.push(::protobuf::reflect::rt::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
                 "uninterpreted_option",
                 |m: &MessageOptions| { &m.uninterpreted_option },
@@ -4256,6 +5105,15 @@ impl ::protobuf::Message for MessageOptions {
         static instance: ::protobuf::rt::Lazy<MessageOptions> = ::protobuf::rt::Lazy::INIT;
         instance.get(MessageOptions::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_message_to_fit::<FeatureSet, _>(&mut self.features);
+        self.uninterpreted_option.shrink_to_fit();
+        for v in self.uninterpreted_option.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for MessageOptions {
@@ -4264,11 +5122,38 @@ impl ::protobuf::Clear for MessageOptions {
         self.no_standard_descriptor_accessor = ::std::option::Option::None;
         self.deprecated = ::std::option::Option::None;
         self.map_entry = ::std::option::Option::None;
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 }
 
+impl ::std::clone::Clone for MessageOptions {
+    fn clone(&self) -> MessageOptions {
+        MessageOptions {
+            message_set_wire_format: ::std::clone::Clone::clone(&self.message_set_wire_format),
+            no_standard_descriptor_accessor: ::std::clone::Clone::clone(&self.no_standard_descriptor_accessor),
+            deprecated: ::std::clone::Clone::clone(&self.deprecated),
+            map_entry: ::std::clone::Clone::clone(&self.map_entry),
+            features: ::std::clone::Clone::clone(&self.features),
+            uninterpreted_option: ::std::clone::Clone::clone(&self.uninterpreted_option),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &MessageOptions) {
+        ::std::clone::Clone::clone_from(&mut self.message_set_wire_format, &source.message_set_wire_format);
+        ::std::clone::Clone::clone_from(&mut self.no_standard_descriptor_accessor, &source.no_standard_descriptor_accessor);
+        ::std::clone::Clone::clone_from(&mut self.deprecated, &source.deprecated);
+        ::std::clone::Clone::clone_from(&mut self.map_entry, &source.map_entry);
+        ::std::clone::Clone::clone_from(&mut self.features, &source.features);
+        ::std::clone::Clone::clone_from(&mut self.uninterpreted_option, &source.uninterpreted_option);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for MessageOptions {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -4278,7 +5163,10 @@ impl ::std::fmt::Debug for MessageOptions {
 impl ::protobuf::reflect::ProtobufValue for MessageOptions {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for MessageOptions {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct FieldOptions {
     // message fields
@@ -4288,6 +5176,7 @@ pub struct FieldOptions {
     lazy: ::std::option::Option<bool>,
     deprecated: ::std::option::Option<bool>,
     weak: ::std::option::Option<bool>,
+    pub features: ::protobuf::SingularPtrField<FeatureSet>,
     pub uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
     #[cfg_attr(serde, serde(skip))]
@@ -4329,6 +5218,10 @@ impl FieldOptions {
         self.ctype = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::new(v));
     }
 
+    pub fn set_ctype_i32(&mut self, v: i32) {
+        self.ctype = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::from_i32(v));
+    }
+
     // optional bool packed = 2;
 
     pub fn get_packed(&self) -> bool {
@@ -4370,6 +5263,10 @@ impl FieldOptions {
         self.jstype = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::new(v));
     }
 
+    pub fn set_jstype_i32(&mut self, v: i32) {
+        self.jstype = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::from_i32(v));
+    }
+
     // optional bool lazy = 5;
 
     pub fn get_lazy(&self) -> bool {
@@ -4430,6 +5327,11 @@ impl FieldOptions {
 
 impl ::protobuf::Message for FieldOptions {
     fn is_initialized(&self) -> bool {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
@@ -4440,13 +5342,19 @@ impl ::protobuf::Message for FieldOptions {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.ctype = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    self.ctype = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("ctype")?);
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
@@ -4458,7 +5366,7 @@ impl ::protobuf::Message for FieldOptions {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.jstype = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    self.jstype = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("jstype")?);
                 },
                 5 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
@@ -4478,6 +5386,9 @@ impl ::protobuf::Message for FieldOptions {
                     }
                     self.weak = ::std::option::Option::Some(is.read_bool()?);
                 },
+                21 => {
+                    ::protobuf::rt::read_singular_message_into::<FeatureSet, _>(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     ::protobuf::rt::read_repeated_message_into_repeated_field(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -4485,6 +5396,7 @@ impl ::protobuf::Message for FieldOptions {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -4511,6 +5423,10 @@ impl ::protobuf::Message for FieldOptions {
         if let Some(v) = self.weak {
             my_size += 2;
         }
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
@@ -4539,6 +5455,9 @@ impl ::protobuf::Message for FieldOptions {
         if let Some(v) = self.weak {
             os.write_bool(10, v)?;
         }
+        if let Some(v) = self.features.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(21, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             ::protobuf::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -4566,6 +5485,10 @@ impl ::protobuf::Message for FieldOptions {
         FieldOptions::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -4606,6 +5529,11 @@ impl ::protobuf::Message for FieldOptions {
                 |m: &mut FieldOptions| { &mut m.weak },
                 FieldOptions::get_weak,
             ));
+            fields.push(::protobuf::reflect::rt::make_option_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FeatureSet>, _>(
+                "features",
+                |m: &FieldOptions| { &m.features },
+                |m: &mut FieldOptions| { &mut m.features },
+            ));
             fields.push(::protobuf::reflect::rt::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
                 "uninterpreted_option",
                 |m: &FieldOptions| { &m.uninterpreted_option },
@@ -4623,6 +5551,15 @@ impl ::protobuf::Message for FieldOptions {
         static instance: ::protobuf::rt::Lazy<FieldOptions> = ::protobuf::rt::Lazy::INIT;
         instance.get(FieldOptions::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_message_to_fit::<FeatureSet, _>(&mut self.features);
+        self.uninterpreted_option.shrink_to_fit();
+        for v in self.uninterpreted_option.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for FieldOptions {
@@ -4633,11 +5570,42 @@ impl ::protobuf::Clear for FieldOptions {
         self.lazy = ::std::option::Option::None;
         self.deprecated = ::std::option::Option::None;
         self.weak = ::std::option::Option::None;
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 }
 
+impl ::std::clone::Clone for FieldOptions {
+    fn clone(&self) -> FieldOptions {
+        FieldOptions {
+            ctype: ::std::clone::Clone::clone(&self.ctype),
+            packed: ::std::clone::Clone::clone(&self.packed),
+            jstype: ::std::clone::Clone::clone(&self.jstype),
+            lazy: ::std::clone::Clone::clone(&self.lazy),
+            deprecated: ::std::clone::Clone::clone(&self.deprecated),
+            weak: ::std::clone::Clone::clone(&self.weak),
+            features: ::std::clone::Clone::clone(&self.features),
+            uninterpreted_option: ::std::clone::Clone::clone(&self.uninterpreted_option),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &FieldOptions) {
+        ::std::clone::Clone::clone_from(&mut self.ctype, &source.ctype);
+        ::std::clone::Clone::clone_from(&mut self.packed, &source.packed);
+        ::std::clone::Clone::clone_from(&mut self.jstype, &source.jstype);
+        ::std::clone::Clone::clone_from(&mut self.lazy, &source.lazy);
+        ::std::clone::Clone::clone_from(&mut self.deprecated, &source.deprecated);
+        ::std::clone::Clone::clone_from(&mut self.weak, &source.weak);
+        ::std::clone::Clone::clone_from(&mut self.features, &source.features);
+        ::std::clone::Clone::clone_from(&mut self.uninterpreted_option, &source.uninterpreted_option);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for FieldOptions {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -4647,6 +5615,9 @@ impl ::std::fmt::Debug for FieldOptions {
 impl ::protobuf::reflect::ProtobufValue for FieldOptions {
 }
 
+impl ::protobuf::ProtobufConvertible for FieldOptions {
+}
+
 pub mod field_options {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
@@ -4670,6 +5641,14 @@ pub mod field_options {
             }
         }
 
+        fn name(&self) -> &'static str {
+            match *self {
+                CType::STRING => "STRING",
+                CType::CORD => "CORD",
+                CType::STRING_PIECE => "STRING_PIECE",
+            }
+        }
+
         fn values() -> &'static [Self] {
             static values: &'static [CType] = &[
                 CType::STRING,
@@ -4696,6 +5675,18 @@ pub mod field_options {
     impl ::protobuf::reflect::ProtobufValue for CType {
     }
 
+    impl ::std::convert::From<CType> for &'static str {
+        fn from(value: CType) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<CType>> for CType {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<CType>) -> bool {
+            other == self
+        }
+    }
+
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
     pub enum JSType {
@@ -4718,6 +5709,14 @@ pub mod field_options {
             }
         }
 
+        fn name(&self) -> &'static str {
+            match *self {
+                JSType::JS_NORMAL => "JS_NORMAL",
+                JSType::JS_STRING => "JS_STRING",
+                JSType::JS_NUMBER => "JS_NUMBER",
+            }
+        }
+
         fn values() -> &'static [Self] {
             static values: &'static [JSType] = &[
                 JSType::JS_NORMAL,
@@ -4743,12 +5742,25 @@ pub mod field_options {
 
     impl ::protobuf::reflect::ProtobufValue for JSType {
     }
+
+    impl ::std::convert::From<JSType> for &'static str {
+        fn from(value: JSType) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<JSType>> for JSType {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<JSType>) -> bool {
+            other == self
+        }
+    }
 }
 
-#[derive(PartialEq,Clone,Default)]
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct OneofOptions {
     // message fields
+    pub features: ::protobuf::SingularPtrField<FeatureSet>,
     pub uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
     #[cfg_attr(serde, serde(skip))]
@@ -4771,6 +5783,11 @@ impl OneofOptions {
 
 impl ::protobuf::Message for OneofOptions {
     fn is_initialized(&self) -> bool {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
@@ -4781,8 +5798,17 @@ impl ::protobuf::Message for OneofOptions {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into::<FeatureSet, _>(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     ::protobuf::rt::read_repeated_message_into_repeated_field(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -4790,6 +5816,7 @@ impl ::protobuf::Message for OneofOptions {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -4798,6 +5825,10 @@ impl ::protobuf::Message for OneofOptions {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
@@ -4808,6 +5839,9 @@ impl ::protobuf::Message for OneofOptions {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.features.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             ::protobuf::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -4835,10 +5869,19 @@ impl ::protobuf::Message for OneofOptions {
         OneofOptions::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::rt::make_option_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FeatureSet>, _>(
+                "features",
+                |m: &OneofOptions| { &m.features },
+                |m: &mut OneofOptions| { &mut m.features },
+            ));
             fields.push(::protobuf::reflect::rt::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
                 "uninterpreted_option",
                 |m: &OneofOptions| { &m.uninterpreted_option },
@@ -4856,15 +5899,43 @@ impl ::protobuf::Message for OneofOptions {
         static instance: ::protobuf::rt::Lazy<OneofOptions> = ::protobuf::rt::Lazy::INIT;
         instance.get(OneofOptions::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_message_to_fit::<FeatureSet, _>(&mut self.features);
+        self.uninterpreted_option.shrink_to_fit();
+        for v in self.uninterpreted_option.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for OneofOptions {
     fn clear(&mut self) {
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 }
 
+impl ::std::clone::Clone for OneofOptions {
+    fn clone(&self) -> OneofOptions {
+        OneofOptions {
+            features: ::std::clone::Clone::clone(&self.features),
+            uninterpreted_option: ::std::clone::Clone::clone(&self.uninterpreted_option),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &OneofOptions) {
+        ::std::clone::Clone::clone_from(&mut self.features, &source.features);
+        ::std::clone::Clone::clone_from(&mut self.uninterpreted_option, &source.uninterpreted_option);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for OneofOptions {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -4874,12 +5945,16 @@ impl ::std::fmt::Debug for OneofOptions {
 impl ::protobuf::reflect::ProtobufValue for OneofOptions {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for OneofOptions {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct EnumOptions {
     // message fields
     allow_alias: ::std::option::Option<bool>,
     deprecated: ::std::option::Option<bool>,
+    pub features: ::protobuf::SingularPtrField<FeatureSet>,
     pub uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
     #[cfg_attr(serde, serde(skip))]
@@ -4940,6 +6015,11 @@ impl EnumOptions {
 
 impl ::protobuf::Message for EnumOptions {
     fn is_initialized(&self) -> bool {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
@@ -4950,7 +6030,13 @@ impl ::protobuf::Message for EnumOptions {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
@@ -4964,6 +6050,9 @@ impl ::protobuf::Message for EnumOptions {
                     }
                     self.deprecated = ::std::option::Option::Some(is.read_bool()?);
                 },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into::<FeatureSet, _>(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     ::protobuf::rt::read_repeated_message_into_repeated_field(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -4971,6 +6060,7 @@ impl ::protobuf::Message for EnumOptions {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -4985,6 +6075,10 @@ impl ::protobuf::Message for EnumOptions {
         if let Some(v) = self.deprecated {
             my_size += 2;
         }
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
@@ -5001,6 +6095,9 @@ impl ::protobuf::Message for EnumOptions {
         if let Some(v) = self.deprecated {
             os.write_bool(3, v)?;
         }
+        if let Some(v) = self.features.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             ::protobuf::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -5028,6 +6125,10 @@ impl ::protobuf::Message for EnumOptions {
         EnumOptions::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -5044,6 +6145,11 @@ impl ::protobuf::Message for EnumOptions {
                 |m: &mut EnumOptions| { &mut m.deprecated },
                 EnumOptions::get_deprecated,
             ));
+            fields.push(::protobuf::reflect::rt::make_option_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FeatureSet>, _>(
+                "features",
+                |m: &EnumOptions| { &m.features },
+                |m: &mut EnumOptions| { &mut m.features },
+            ));
             fields.push(::protobuf::reflect::rt::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
                 "uninterpreted_option",
                 |m: &EnumOptions| { &m.uninterpreted_option },
@@ -5061,17 +6167,49 @@ impl ::protobuf::Message for EnumOptions {
         static instance: ::protobuf::rt::Lazy<EnumOptions> = ::protobuf::rt::Lazy::INIT;
         instance.get(EnumOptions::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_message_to_fit::<FeatureSet, _>(&mut self.features);
+        self.uninterpreted_option.shrink_to_fit();
+        for v in self.uninterpreted_option.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for EnumOptions {
     fn clear(&mut self) {
         self.allow_alias = ::std::option::Option::None;
         self.deprecated = ::std::option::Option::None;
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 }
 
+impl ::std::clone::Clone for EnumOptions {
+    fn clone(&self) -> EnumOptions {
+        EnumOptions {
+            allow_alias: ::std::clone::Clone::clone(&self.allow_alias),
+            deprecated: ::std::clone::Clone::clone(&self.deprecated),
+            features: ::std::clone::Clone::clone(&self.features),
+            uninterpreted_option: ::std::clone::Clone::clone(&self.uninterpreted_option),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &EnumOptions) {
+        ::std::clone::Clone::clone_from(&mut self.allow_alias, &source.allow_alias);
+        ::std::clone::Clone::clone_from(&mut self.deprecated, &source.deprecated);
+        ::std::clone::Clone::clone_from(&mut self.features, &source.features);
+        ::std::clone::Clone::clone_from(&mut self.uninterpreted_option, &source.uninterpreted_option);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for EnumOptions {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -5081,11 +6219,15 @@ impl ::std::fmt::Debug for EnumOptions {
 impl ::protobuf::reflect::ProtobufValue for EnumOptions {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for EnumOptions {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct EnumValueOptions {
     // message fields
     deprecated: ::std::option::Option<bool>,
+    pub features: ::protobuf::SingularPtrField<FeatureSet>,
     pub uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
     #[cfg_attr(serde, serde(skip))]
@@ -5127,6 +6269,11 @@ impl EnumValueOptions {
 
 impl ::protobuf::Message for EnumValueOptions {
     fn is_initialized(&self) -> bool {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
@@ -5137,7 +6284,13 @@ impl ::protobuf::Message for EnumValueOptions {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
@@ -5145,6 +6298,9 @@ impl ::protobuf::Message for EnumValueOptions {
                     }
                     self.deprecated = ::std::option::Option::Some(is.read_bool()?);
                 },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into::<FeatureSet, _>(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     ::protobuf::rt::read_repeated_message_into_repeated_field(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -5152,6 +6308,7 @@ impl ::protobuf::Message for EnumValueOptions {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -5163,6 +6320,10 @@ impl ::protobuf::Message for EnumValueOptions {
         if let Some(v) = self.deprecated {
             my_size += 2;
         }
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
@@ -5176,6 +6337,9 @@ impl ::protobuf::Message for EnumValueOptions {
         if let Some(v) = self.deprecated {
             os.write_bool(1, v)?;
         }
+        if let Some(v) = self.features.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             ::protobuf::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -5203,6 +6367,10 @@ impl ::protobuf::Message for EnumValueOptions {
         EnumValueOptions::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -5213,6 +6381,11 @@ impl ::protobuf::Message for EnumValueOptions {
                 |m: &mut EnumValueOptions| { &mut m.deprecated },
                 EnumValueOptions::get_deprecated,
             ));
+            fields.push(::protobuf::reflect::rt::make_option_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FeatureSet>, _>(
+                "features",
+                |m: &EnumValueOptions| { &m.features },
+                |m: &mut EnumValueOptions| { &mut m.features },
+            ));
             fields.push(::protobuf::reflect::rt::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
                 "uninterpreted_option",
                 |m: &EnumValueOptions| { &m.uninterpreted_option },
@@ -5230,16 +6403,46 @@ impl ::protobuf::Message for EnumValueOptions {
         static instance: ::protobuf::rt::Lazy<EnumValueOptions> = ::protobuf::rt::Lazy::INIT;
         instance.get(EnumValueOptions::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_message_to_fit::<FeatureSet, _>(&mut self.features);
+        self.uninterpreted_option.shrink_to_fit();
+        for v in self.uninterpreted_option.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for EnumValueOptions {
     fn clear(&mut self) {
         self.deprecated = ::std::option::Option::None;
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
 }
 
+impl ::std::clone::Clone for EnumValueOptions {
+    fn clone(&self) -> EnumValueOptions {
+        EnumValueOptions {
+            deprecated: ::std::clone::Clone::clone(&self.deprecated),
+            features: ::std::clone::Clone::clone(&self.features),
+            uninterpreted_option: ::std::clone::Clone::clone(&self.uninterpreted_option),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &EnumValueOptions) {
+        ::std::clone::Clone::clone_from(&mut self.deprecated, &source.deprecated);
+        ::std::clone::Clone::clone_from(&mut self.features, &source.features);
+        ::std::clone::Clone::clone_from(&mut self.uninterpreted_option, &source.uninterpreted_option);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for EnumValueOptions {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -5249,7 +6452,10 @@ impl ::std::fmt::Debug for EnumValueOptions {
 impl ::protobuf::reflect::ProtobufValue for EnumValueOptions {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for EnumValueOptions {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct ServiceOptions {
     // message fields
@@ -5305,7 +6511,13 @@ impl ::protobuf::Message for ServiceOptions {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 33 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
@@ -5320,6 +6532,7 @@ impl ::protobuf::Message for ServiceOptions {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -5371,6 +6584,10 @@ impl ::protobuf::Message for ServiceOptions {
         ServiceOptions::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -5398,6 +6615,14 @@ impl ::protobuf::Message for ServiceOptions {
         static instance: ::protobuf::rt::Lazy<ServiceOptions> = ::protobuf::rt::Lazy::INIT;
         instance.get(ServiceOptions::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.uninterpreted_option.shrink_to_fit();
+        for v in self.uninterpreted_option.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for ServiceOptions {
@@ -5408,6 +6633,24 @@ impl ::protobuf::Clear for ServiceOptions {
     }
 }
 
+impl ::std::clone::Clone for ServiceOptions {
+    fn clone(&self) -> ServiceOptions {
+        ServiceOptions {
+            deprecated: ::std::clone::Clone::clone(&self.deprecated),
+            uninterpreted_option: ::std::clone::Clone::clone(&self.uninterpreted_option),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &ServiceOptions) {
+        ::std::clone::Clone::clone_from(&mut self.deprecated, &source.deprecated);
+        ::std::clone::Clone::clone_from(&mut self.uninterpreted_option, &source.uninterpreted_option);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for ServiceOptions {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -5417,7 +6660,10 @@ impl ::std::fmt::Debug for ServiceOptions {
 impl ::protobuf::reflect::ProtobufValue for ServiceOptions {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for ServiceOptions {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct MethodOptions {
     // message fields
@@ -5473,7 +6719,13 @@ impl ::protobuf::Message for MethodOptions {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 33 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
@@ -5488,6 +6740,7 @@ impl ::protobuf::Message for MethodOptions {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -5539,6 +6792,10 @@ impl ::protobuf::Message for MethodOptions {
         MethodOptions::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -5566,6 +6823,14 @@ impl ::protobuf::Message for MethodOptions {
         static instance: ::protobuf::rt::Lazy<MethodOptions> = ::protobuf::rt::Lazy::INIT;
         instance.get(MethodOptions::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.uninterpreted_option.shrink_to_fit();
+        for v in self.uninterpreted_option.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for MethodOptions {
@@ -5576,6 +6841,24 @@ impl ::protobuf::Clear for MethodOptions {
     }
 }
 
+impl ::std::clone::Clone for MethodOptions {
+    fn clone(&self) -> MethodOptions {
+        MethodOptions {
+            deprecated: ::std::clone::Clone::clone(&self.deprecated),
+            uninterpreted_option: ::std::clone::Clone::clone(&self.uninterpreted_option),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &MethodOptions) {
+        ::std::clone::Clone::clone_from(&mut self.deprecated, &source.deprecated);
+        ::std::clone::Clone::clone_from(&mut self.uninterpreted_option, &source.uninterpreted_option);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for MethodOptions {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -5585,17 +6868,19 @@ impl ::std::fmt::Debug for MethodOptions {
 impl ::protobuf::reflect::ProtobufValue for MethodOptions {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for MethodOptions {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
-pub struct UninterpretedOption {
+pub struct FeatureSet {
     // message fields
-    pub name: ::protobuf::RepeatedField<uninterpreted_option::NamePart>,
-    identifier_value: ::protobuf::SingularField<::std::string::String>,
-    positive_int_value: ::std::option::Option<u64>,
-    negative_int_value: ::std::option::Option<i64>,
-    double_value: ::std::option::Option<f64>,
-    string_value: ::protobuf::SingularField<::std::vec::Vec<u8>>,
-    aggregate_value: ::protobuf::SingularField<::std::string::String>,
+    field_presence: ::std::option::Option<::protobuf::ProtobufEnumOrUnknown<feature_set::FieldPresence>>,
+    enum_type: ::std::option::Option<::protobuf::ProtobufEnumOrUnknown<feature_set::EnumType>>,
+    repeated_field_encoding: ::std::option::Option<::protobuf::ProtobufEnumOrUnknown<feature_set::RepeatedFieldEncoding>>,
+    utf8_validation: ::std::option::Option<::protobuf::ProtobufEnumOrUnknown<feature_set::Utf8Validation>>,
+    message_encoding: ::std::option::Option<::protobuf::ProtobufEnumOrUnknown<feature_set::MessageEncoding>>,
+    json_format: ::std::option::Option<::protobuf::ProtobufEnumOrUnknown<feature_set::JsonFormat>>,
     // special fields
     #[cfg_attr(serde, serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
@@ -5603,116 +6888,987 @@ pub struct UninterpretedOption {
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a UninterpretedOption {
-    fn default() -> &'a UninterpretedOption {
-        <UninterpretedOption as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a FeatureSet {
+    fn default() -> &'a FeatureSet {
+        <FeatureSet as ::protobuf::Message>::default_instance()
     }
 }
 
-impl UninterpretedOption {
-    pub fn new() -> UninterpretedOption {
+impl FeatureSet {
+    pub fn new() -> FeatureSet {
         ::std::default::Default::default()
     }
 
-    // optional string identifier_value = 3;
+    // optional .google.protobuf.FeatureSet.FieldPresence field_presence = 1;
 
-    pub fn get_identifier_value(&self) -> &str {
-        match self.identifier_value.as_ref() {
-            Some(v) => v,
-            None => "",
+    pub fn get_field_presence(&self) -> feature_set::FieldPresence {
+        match self.field_presence {
+            Some(e) => e.enum_value_or(feature_set::FieldPresence::FIELD_PRESENCE_UNKNOWN),
+            None => feature_set::FieldPresence::FIELD_PRESENCE_UNKNOWN,
         }
     }
 
-    pub fn clear_identifier_value(&mut self) {
-        self.identifier_value.clear();
+    pub fn clear_field_presence(&mut self) {
+        self.field_presence = ::std::option::Option::None;
     }
 
-    pub fn has_identifier_value(&self) -> bool {
-        self.identifier_value.is_some()
+    pub fn has_field_presence(&self) -> bool {
+        self.field_presence.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_identifier_value(&mut self, v: ::std::string::String) {
-        self.identifier_value = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_identifier_value(&mut self) -> &mut ::std::string::String {
-        if self.identifier_value.is_none() {
-            self.identifier_value.set_default();
-        }
-        self.identifier_value.as_mut().unwrap()
+    pub fn set_field_presence(&mut self, v: feature_set::FieldPresence) {
+        self.field_presence = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::new(v));
     }
 
-    // Take field
-    pub fn take_identifier_value(&mut self) -> ::std::string::String {
-        self.identifier_value.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn set_field_presence_i32(&mut self, v: i32) {
+        self.field_presence = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::from_i32(v));
     }
 
-    // optional uint64 positive_int_value = 4;
+    // optional .google.protobuf.FeatureSet.EnumType enum_type = 2;
 
-    pub fn get_positive_int_value(&self) -> u64 {
-        self.positive_int_value.unwrap_or(0)
+    pub fn get_enum_type(&self) -> feature_set::EnumType {
+        match self.enum_type {
+            Some(e) => e.enum_value_or(feature_set::EnumType::ENUM_TYPE_UNKNOWN),
+            None => feature_set::EnumType::ENUM_TYPE_UNKNOWN,
+        }
     }
 
-    pub fn clear_positive_int_value(&mut self) {
-        self.positive_int_value = ::std::option::Option::None;
+    pub fn clear_enum_type(&mut self) {
+        self.enum_type = ::std::option::Option::None;
     }
 
-    pub fn has_positive_int_value(&self) -> bool {
-        self.positive_int_value.is_some()
+    pub fn has_enum_type(&self) -> bool {
+        self.enum_type.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_positive_int_value(&mut self, v: u64) {
-        self.positive_int_value = ::std::option::Option::Some(v);
+    pub fn set_enum_type(&mut self, v: feature_set::EnumType) {
+        self.enum_type = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::new(v));
     }
 
-    // optional int64 negative_int_value = 5;
+    pub fn set_enum_type_i32(&mut self, v: i32) {
+        self.enum_type = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::from_i32(v));
+    }
 
-    pub fn get_negative_int_value(&self) -> i64 {
-        self.negative_int_value.unwrap_or(0)
+    // optional .google.protobuf.FeatureSet.RepeatedFieldEncoding repeated_field_encoding = 3;
+
+    pub fn get_repeated_field_encoding(&self) -> feature_set::RepeatedFieldEncoding {
+        match self.repeated_field_encoding {
+            Some(e) => e.enum_value_or(feature_set::RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN),
+            None => feature_set::RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN,
+        }
     }
 
-    pub fn clear_negative_int_value(&mut self) {
-        self.negative_int_value = ::std::option::Option::None;
+    pub fn clear_repeated_field_encoding(&mut self) {
+        self.repeated_field_encoding = ::std::option::Option::None;
     }
 
-    pub fn has_negative_int_value(&self) -> bool {
-        self.negative_int_value.is_some()
+    pub fn has_repeated_field_encoding(&self) -> bool {
+        self.repeated_field_encoding.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_negative_int_value(&mut self, v: i64) {
-        self.negative_int_value = ::std::option::Option::Some(v);
+    pub fn set_repeated_field_encoding(&mut self, v: feature_set::RepeatedFieldEncoding) {
+        self.repeated_field_encoding = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::new(v));
     }
 
-    // optional double double_value = 6;
+    pub fn set_repeated_field_encoding_i32(&mut self, v: i32) {
+        self.repeated_field_encoding = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::from_i32(v));
+    }
 
-    pub fn get_double_value(&self) -> f64 {
-        self.double_value.unwrap_or(0.)
+    // optional .google.protobuf.FeatureSet.Utf8Validation utf8_validation = 4;
+
+    pub fn get_utf8_validation(&self) -> feature_set::Utf8Validation {
+        match self.utf8_validation {
+            Some(e) => e.enum_value_or(feature_set::Utf8Validation::UTF8_VALIDATION_UNKNOWN),
+            None => feature_set::Utf8Validation::UTF8_VALIDATION_UNKNOWN,
+        }
     }
 
-    pub fn clear_double_value(&mut self) {
-        self.double_value = ::std::option::Option::None;
+    pub fn clear_utf8_validation(&mut self) {
+        self.utf8_validation = ::std::option::Option::None;
     }
 
-    pub fn has_double_value(&self) -> bool {
-        self.double_value.is_some()
+    pub fn has_utf8_validation(&self) -> bool {
+        self.utf8_validation.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_double_value(&mut self, v: f64) {
-        self.double_value = ::std::option::Option::Some(v);
+    pub fn set_utf8_validation(&mut self, v: feature_set::Utf8Validation) {
+        self.utf8_validation = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::new(v));
     }
 
-    // optional bytes string_value = 7;
+    pub fn set_utf8_validation_i32(&mut self, v: i32) {
+        self.utf8_validation = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::from_i32(v));
+    }
 
-    pub fn get_string_value(&self) -> &[u8] {
-        match self.string_value.as_ref() {
-            Some(v) => v,
-            None => &[],
+    // optional .google.protobuf.FeatureSet.MessageEncoding message_encoding = 5;
+
+    pub fn get_message_encoding(&self) -> feature_set::MessageEncoding {
+        match self.message_encoding {
+            Some(e) => e.enum_value_or(feature_set::MessageEncoding::MESSAGE_ENCODING_UNKNOWN),
+            None => feature_set::MessageEncoding::MESSAGE_ENCODING_UNKNOWN,
+        }
+    }
+
+    pub fn clear_message_encoding(&mut self) {
+        self.message_encoding = ::std::option::Option::None;
+    }
+
+    pub fn has_message_encoding(&self) -> bool {
+        self.message_encoding.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_encoding(&mut self, v: feature_set::MessageEncoding) {
+        self.message_encoding = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::new(v));
+    }
+
+    pub fn set_message_encoding_i32(&mut self, v: i32) {
+        self.message_encoding = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::from_i32(v));
+    }
+
+    // optional .google.protobuf.FeatureSet.JsonFormat json_format = 6;
+
+    pub fn get_json_format(&self) -> feature_set::JsonFormat {
+        match self.json_format {
+            Some(e) => e.enum_value_or(feature_set::JsonFormat::JSON_FORMAT_UNKNOWN),
+            None => feature_set::JsonFormat::JSON_FORMAT_UNKNOWN,
+        }
+    }
+
+    pub fn clear_json_format(&mut self) {
+        self.json_format = ::std::option::Option::None;
+    }
+
+    pub fn has_json_format(&self) -> bool {
+        self.json_format.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_json_format(&mut self, v: feature_set::JsonFormat) {
+        self.json_format = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::new(v));
+    }
+
+    pub fn set_json_format_i32(&mut self, v: i32) {
+        self.json_format = ::std::option::Option::Some(::protobuf::ProtobufEnumOrUnknown::from_i32(v));
+    }
+}
+
+impl ::protobuf::Message for FeatureSet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let field_start = is.pos();
+            let tag = is.read_raw_varint32()?;
+            match tag {
+                8 if is.field_allowed(1) => {
+                    self.field_presence = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("field_presence")?);
+                    is.record_field_offset(1, field_start);
+                },
+                16 if is.field_allowed(2) => {
+                    self.enum_type = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("enum_type")?);
+                    is.record_field_offset(2, field_start);
+                },
+                24 if is.field_allowed(3) => {
+                    self.repeated_field_encoding = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("repeated_field_encoding")?);
+                    is.record_field_offset(3, field_start);
+                },
+                32 if is.field_allowed(4) => {
+                    self.utf8_validation = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("utf8_validation")?);
+                    is.record_field_offset(4, field_start);
+                },
+                40 if is.field_allowed(5) => {
+                    self.message_encoding = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("message_encoding")?);
+                    is.record_field_offset(5, field_start);
+                },
+                48 if is.field_allowed(6) => {
+                    self.json_format = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("json_format")?);
+                    is.record_field_offset(6, field_start);
+                },
+                _ => {
+                    let (field_number, wire_type) = ::protobuf::rt::unpack_tag(tag)?;
+                    if !is.field_allowed(field_number) {
+                        ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        is.record_field_offset(field_number, field_start);
+                        continue;
+                    }
+                    match field_number {
+                        1 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.field_presence = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("field_presence")?);
+                        },
+                        2 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.enum_type = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("enum_type")?);
+                        },
+                        3 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.repeated_field_encoding = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("repeated_field_encoding")?);
+                        },
+                        4 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.utf8_validation = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("utf8_validation")?);
+                        },
+                        5 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.message_encoding = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("message_encoding")?);
+                        },
+                        6 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.json_format = ::std::option::Option::Some(is.read_enum_or_unknown_for_field("json_format")?);
+                        },
+                        _ => {
+                            ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        },
+                    };
+                    is.record_field_offset(field_number, field_start);
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.field_presence {
+            my_size += ::protobuf::rt::enum_or_unknown_size(1, v);
+        }
+        if let Some(v) = self.enum_type {
+            my_size += ::protobuf::rt::enum_or_unknown_size(2, v);
+        }
+        if let Some(v) = self.repeated_field_encoding {
+            my_size += ::protobuf::rt::enum_or_unknown_size(3, v);
+        }
+        if let Some(v) = self.utf8_validation {
+            my_size += ::protobuf::rt::enum_or_unknown_size(4, v);
+        }
+        if let Some(v) = self.message_encoding {
+            my_size += ::protobuf::rt::enum_or_unknown_size(5, v);
+        }
+        if let Some(v) = self.json_format {
+            my_size += ::protobuf::rt::enum_or_unknown_size(6, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.field_presence {
+            os.write_enum(1, ::protobuf::ProtobufEnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.enum_type {
+            os.write_enum(2, ::protobuf::ProtobufEnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.repeated_field_encoding {
+            os.write_enum(3, ::protobuf::ProtobufEnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.utf8_validation {
+            os.write_enum(4, ::protobuf::ProtobufEnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.message_encoding {
+            os.write_enum(5, ::protobuf::ProtobufEnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.json_format {
+            os.write_enum(6, ::protobuf::ProtobufEnumOrUnknown::value(&v))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FeatureSet {
+        FeatureSet::new()
+    }
+
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::rt::make_option_enum_accessor::<_, feature_set::FieldPresence>(
+                "field_presence",
+                |m: &FeatureSet| { &m.field_presence },
+                |m: &mut FeatureSet| { &mut m.field_presence },
+                feature_set::FieldPresence::FIELD_PRESENCE_UNKNOWN,
+            ));
+            fields.push(::protobuf::reflect::rt::make_option_enum_accessor::<_, feature_set::EnumType>(
+                "enum_type",
+                |m: &FeatureSet| { &m.enum_type },
+                |m: &mut FeatureSet| { &mut m.enum_type },
+                feature_set::EnumType::ENUM_TYPE_UNKNOWN,
+            ));
+            fields.push(::protobuf::reflect::rt::make_option_enum_accessor::<_, feature_set::RepeatedFieldEncoding>(
+                "repeated_field_encoding",
+                |m: &FeatureSet| { &m.repeated_field_encoding },
+                |m: &mut FeatureSet| { &mut m.repeated_field_encoding },
+                feature_set::RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN,
+            ));
+            fields.push(::protobuf::reflect::rt::make_option_enum_accessor::<_, feature_set::Utf8Validation>(
+                "utf8_validation",
+                |m: &FeatureSet| { &m.utf8_validation },
+                |m: &mut FeatureSet| { &mut m.utf8_validation },
+                feature_set::Utf8Validation::UTF8_VALIDATION_UNKNOWN,
+            ));
+            fields.push(::protobuf::reflect::rt::make_option_enum_accessor::<_, feature_set::MessageEncoding>(
+                "message_encoding",
+                |m: &FeatureSet| { &m.message_encoding },
+                |m: &mut FeatureSet| { &mut m.message_encoding },
+                feature_set::MessageEncoding::MESSAGE_ENCODING_UNKNOWN,
+            ));
+            fields.push(::protobuf::reflect::rt::make_option_enum_accessor::<_, feature_set::JsonFormat>(
+                "json_format",
+                |m: &FeatureSet| { &m.json_format },
+                |m: &mut FeatureSet| { &mut m.json_format },
+                feature_set::JsonFormat::JSON_FORMAT_UNKNOWN,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<FeatureSet>(
+                "FeatureSet",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FeatureSet {
+        static instance: ::protobuf::rt::Lazy<FeatureSet> = ::protobuf::rt::Lazy::INIT;
+        instance.get(FeatureSet::new)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.unknown_fields.shrink_to_fit();
+    }
+}
+
+impl ::protobuf::Clear for FeatureSet {
+    fn clear(&mut self) {
+        self.field_presence = ::std::option::Option::None;
+        self.enum_type = ::std::option::Option::None;
+        self.repeated_field_encoding = ::std::option::Option::None;
+        self.utf8_validation = ::std::option::Option::None;
+        self.message_encoding = ::std::option::Option::None;
+        self.json_format = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::clone::Clone for FeatureSet {
+    fn clone(&self) -> FeatureSet {
+        FeatureSet {
+            field_presence: ::std::clone::Clone::clone(&self.field_presence),
+            enum_type: ::std::clone::Clone::clone(&self.enum_type),
+            repeated_field_encoding: ::std::clone::Clone::clone(&self.repeated_field_encoding),
+            utf8_validation: ::std::clone::Clone::clone(&self.utf8_validation),
+            message_encoding: ::std::clone::Clone::clone(&self.message_encoding),
+            json_format: ::std::clone::Clone::clone(&self.json_format),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &FeatureSet) {
+        ::std::clone::Clone::clone_from(&mut self.field_presence, &source.field_presence);
+        ::std::clone::Clone::clone_from(&mut self.enum_type, &source.enum_type);
+        ::std::clone::Clone::clone_from(&mut self.repeated_field_encoding, &source.repeated_field_encoding);
+        ::std::clone::Clone::clone_from(&mut self.utf8_validation, &source.utf8_validation);
+        ::std::clone::Clone::clone_from(&mut self.message_encoding, &source.message_encoding);
+        ::std::clone::Clone::clone_from(&mut self.json_format, &source.json_format);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
+impl ::std::fmt::Debug for FeatureSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FeatureSet {
+}
+
+impl ::protobuf::ProtobufConvertible for FeatureSet {
+}
+
+pub mod feature_set {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    pub enum FieldPresence {
+        FIELD_PRESENCE_UNKNOWN = 0,
+        EXPLICIT = 1,
+        IMPLICIT = 2,
+        LEGACY_REQUIRED = 3,
+    }
+
+    impl ::protobuf::ProtobufEnum for FieldPresence {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<FieldPresence> {
+            match value {
+                0 => ::std::option::Option::Some(FieldPresence::FIELD_PRESENCE_UNKNOWN),
+                1 => ::std::option::Option::Some(FieldPresence::EXPLICIT),
+                2 => ::std::option::Option::Some(FieldPresence::IMPLICIT),
+                3 => ::std::option::Option::Some(FieldPresence::LEGACY_REQUIRED),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            match *self {
+                FieldPresence::FIELD_PRESENCE_UNKNOWN => "FIELD_PRESENCE_UNKNOWN",
+                FieldPresence::EXPLICIT => "EXPLICIT",
+                FieldPresence::IMPLICIT => "IMPLICIT",
+                FieldPresence::LEGACY_REQUIRED => "LEGACY_REQUIRED",
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [FieldPresence] = &[
+                FieldPresence::FIELD_PRESENCE_UNKNOWN,
+                FieldPresence::EXPLICIT,
+                FieldPresence::IMPLICIT,
+                FieldPresence::LEGACY_REQUIRED,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::INIT;
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new::<FieldPresence>("FeatureSet.FieldPresence", super::file_descriptor_proto())
+            })
+        }
+    }
+
+    impl ::std::default::Default for FieldPresence {
+        fn default() -> Self {
+            FieldPresence::FIELD_PRESENCE_UNKNOWN
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for FieldPresence {
+    }
+
+    impl ::std::convert::From<FieldPresence> for &'static str {
+        fn from(value: FieldPresence) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<FieldPresence>> for FieldPresence {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<FieldPresence>) -> bool {
+            other == self
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    pub enum EnumType {
+        ENUM_TYPE_UNKNOWN = 0,
+        OPEN = 1,
+        CLOSED = 2,
+    }
+
+    impl ::protobuf::ProtobufEnum for EnumType {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<EnumType> {
+            match value {
+                0 => ::std::option::Option::Some(EnumType::ENUM_TYPE_UNKNOWN),
+                1 => ::std::option::Option::Some(EnumType::OPEN),
+                2 => ::std::option::Option::Some(EnumType::CLOSED),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            match *self {
+                EnumType::ENUM_TYPE_UNKNOWN => "ENUM_TYPE_UNKNOWN",
+                EnumType::OPEN => "OPEN",
+                EnumType::CLOSED => "CLOSED",
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [EnumType] = &[
+                EnumType::ENUM_TYPE_UNKNOWN,
+                EnumType::OPEN,
+                EnumType::CLOSED,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::INIT;
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new::<EnumType>("FeatureSet.EnumType", super::file_descriptor_proto())
+            })
+        }
+    }
+
+    impl ::std::default::Default for EnumType {
+        fn default() -> Self {
+            EnumType::ENUM_TYPE_UNKNOWN
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for EnumType {
+    }
+
+    impl ::std::convert::From<EnumType> for &'static str {
+        fn from(value: EnumType) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<EnumType>> for EnumType {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<EnumType>) -> bool {
+            other == self
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    pub enum RepeatedFieldEncoding {
+        REPEATED_FIELD_ENCODING_UNKNOWN = 0,
+        PACKED = 1,
+        EXPANDED = 2,
+    }
+
+    impl ::protobuf::ProtobufEnum for RepeatedFieldEncoding {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<RepeatedFieldEncoding> {
+            match value {
+                0 => ::std::option::Option::Some(RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN),
+                1 => ::std::option::Option::Some(RepeatedFieldEncoding::PACKED),
+                2 => ::std::option::Option::Some(RepeatedFieldEncoding::EXPANDED),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            match *self {
+                RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN => "REPEATED_FIELD_ENCODING_UNKNOWN",
+                RepeatedFieldEncoding::PACKED => "PACKED",
+                RepeatedFieldEncoding::EXPANDED => "EXPANDED",
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [RepeatedFieldEncoding] = &[
+                RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN,
+                RepeatedFieldEncoding::PACKED,
+                RepeatedFieldEncoding::EXPANDED,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::INIT;
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new::<RepeatedFieldEncoding>("FeatureSet.RepeatedFieldEncoding", super::file_descriptor_proto())
+            })
+        }
+    }
+
+    impl ::std::default::Default for RepeatedFieldEncoding {
+        fn default() -> Self {
+            RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for RepeatedFieldEncoding {
+    }
+
+    impl ::std::convert::From<RepeatedFieldEncoding> for &'static str {
+        fn from(value: RepeatedFieldEncoding) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<RepeatedFieldEncoding>> for RepeatedFieldEncoding {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<RepeatedFieldEncoding>) -> bool {
+            other == self
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    pub enum Utf8Validation {
+        UTF8_VALIDATION_UNKNOWN = 0,
+        VERIFY = 2,
+        NONE = 3,
+    }
+
+    impl ::protobuf::ProtobufEnum for Utf8Validation {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Utf8Validation> {
+            match value {
+                0 => ::std::option::Option::Some(Utf8Validation::UTF8_VALIDATION_UNKNOWN),
+                2 => ::std::option::Option::Some(Utf8Validation::VERIFY),
+                3 => ::std::option::Option::Some(Utf8Validation::NONE),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            match *self {
+                Utf8Validation::UTF8_VALIDATION_UNKNOWN => "UTF8_VALIDATION_UNKNOWN",
+                Utf8Validation::VERIFY => "VERIFY",
+                Utf8Validation::NONE => "NONE",
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [Utf8Validation] = &[
+                Utf8Validation::UTF8_VALIDATION_UNKNOWN,
+                Utf8Validation::VERIFY,
+                Utf8Validation::NONE,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::INIT;
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new::<Utf8Validation>("FeatureSet.Utf8Validation", super::file_descriptor_proto())
+            })
+        }
+    }
+
+    impl ::std::default::Default for Utf8Validation {
+        fn default() -> Self {
+            Utf8Validation::UTF8_VALIDATION_UNKNOWN
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Utf8Validation {
+    }
+
+    impl ::std::convert::From<Utf8Validation> for &'static str {
+        fn from(value: Utf8Validation) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<Utf8Validation>> for Utf8Validation {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<Utf8Validation>) -> bool {
+            other == self
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    pub enum MessageEncoding {
+        MESSAGE_ENCODING_UNKNOWN = 0,
+        LENGTH_PREFIXED = 1,
+        DELIMITED = 2,
+    }
+
+    impl ::protobuf::ProtobufEnum for MessageEncoding {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<MessageEncoding> {
+            match value {
+                0 => ::std::option::Option::Some(MessageEncoding::MESSAGE_ENCODING_UNKNOWN),
+                1 => ::std::option::Option::Some(MessageEncoding::LENGTH_PREFIXED),
+                2 => ::std::option::Option::Some(MessageEncoding::DELIMITED),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            match *self {
+                MessageEncoding::MESSAGE_ENCODING_UNKNOWN => "MESSAGE_ENCODING_UNKNOWN",
+                MessageEncoding::LENGTH_PREFIXED => "LENGTH_PREFIXED",
+                MessageEncoding::DELIMITED => "DELIMITED",
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [MessageEncoding] = &[
+                MessageEncoding::MESSAGE_ENCODING_UNKNOWN,
+                MessageEncoding::LENGTH_PREFIXED,
+                MessageEncoding::DELIMITED,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::INIT;
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new::<MessageEncoding>("FeatureSet.MessageEncoding", super::file_descriptor_proto())
+            })
+        }
+    }
+
+    impl ::std::default::Default for MessageEncoding {
+        fn default() -> Self {
+            MessageEncoding::MESSAGE_ENCODING_UNKNOWN
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for MessageEncoding {
+    }
+
+    impl ::std::convert::From<MessageEncoding> for &'static str {
+        fn from(value: MessageEncoding) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<MessageEncoding>> for MessageEncoding {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<MessageEncoding>) -> bool {
+            other == self
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    pub enum JsonFormat {
+        JSON_FORMAT_UNKNOWN = 0,
+        ALLOW = 1,
+        LEGACY_BEST_EFFORT = 2,
+    }
+
+    impl ::protobuf::ProtobufEnum for JsonFormat {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<JsonFormat> {
+            match value {
+                0 => ::std::option::Option::Some(JsonFormat::JSON_FORMAT_UNKNOWN),
+                1 => ::std::option::Option::Some(JsonFormat::ALLOW),
+                2 => ::std::option::Option::Some(JsonFormat::LEGACY_BEST_EFFORT),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            match *self {
+                JsonFormat::JSON_FORMAT_UNKNOWN => "JSON_FORMAT_UNKNOWN",
+                JsonFormat::ALLOW => "ALLOW",
+                JsonFormat::LEGACY_BEST_EFFORT => "LEGACY_BEST_EFFORT",
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [JsonFormat] = &[
+                JsonFormat::JSON_FORMAT_UNKNOWN,
+                JsonFormat::ALLOW,
+                JsonFormat::LEGACY_BEST_EFFORT,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::INIT;
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new::<JsonFormat>("FeatureSet.JsonFormat", super::file_descriptor_proto())
+            })
+        }
+    }
+
+    impl ::std::default::Default for JsonFormat {
+        fn default() -> Self {
+            JsonFormat::JSON_FORMAT_UNKNOWN
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for JsonFormat {
+    }
+
+    impl ::std::convert::From<JsonFormat> for &'static str {
+        fn from(value: JsonFormat) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<JsonFormat>> for JsonFormat {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<JsonFormat>) -> bool {
+            other == self
+        }
+    }
+}
+
+#[derive(PartialEq,Default)]
+#[cfg_attr(serde, derive(Serialize, Deserialize))]
+pub struct UninterpretedOption {
+    // message fields
+    pub name: ::protobuf::RepeatedField<uninterpreted_option::NamePart>,
+    identifier_value: ::protobuf::SingularField<::std::string::String>,
+    positive_int_value: ::std::option::Option<u64>,
+    negative_int_value: ::std::option::Option<i64>,
+    double_value: ::std::option::Option<f64>,
+    string_value: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    aggregate_value: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    #[cfg_attr(serde, serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(serde, serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UninterpretedOption {
+    fn default() -> &'a UninterpretedOption {
+        <UninterpretedOption as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UninterpretedOption {
+    pub fn new() -> UninterpretedOption {
+        ::std::default::Default::default()
+    }
+
+    // optional string identifier_value = 3;
+
+    pub fn get_identifier_value(&self) -> &str {
+        match self.identifier_value.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_identifier_value(&mut self) {
+        self.identifier_value.clear();
+    }
+
+    pub fn has_identifier_value(&self) -> bool {
+        self.identifier_value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_identifier_value(&mut self, v: ::std::string::String) {
+        self.identifier_value = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_identifier_value(&mut self) -> &mut ::std::string::String {
+        if self.identifier_value.is_none() {
+            self.identifier_value.set_default();
+        }
+        self.identifier_value.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_identifier_value(&mut self) -> ::std::string::String {
+        self.identifier_value.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional uint64 positive_int_value = 4;
+
+    pub fn get_positive_int_value(&self) -> u64 {
+        self.positive_int_value.unwrap_or(0)
+    }
+
+    pub fn clear_positive_int_value(&mut self) {
+        self.positive_int_value = ::std::option::Option::None;
+    }
+
+    pub fn has_positive_int_value(&self) -> bool {
+        self.positive_int_value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_positive_int_value(&mut self, v: u64) {
+        self.positive_int_value = ::std::option::Option::Some(v);
+    }
+
+    // optional int64 negative_int_value = 5;
+
+    pub fn get_negative_int_value(&self) -> i64 {
+        self.negative_int_value.unwrap_or(0)
+    }
+
+    pub fn clear_negative_int_value(&mut self) {
+        self.negative_int_value = ::std::option::Option::None;
+    }
+
+    pub fn has_negative_int_value(&self) -> bool {
+        self.negative_int_value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_negative_int_value(&mut self, v: i64) {
+        self.negative_int_value = ::std::option::Option::Some(v);
+    }
+
+    // optional double double_value = 6;
+
+    pub fn get_double_value(&self) -> f64 {
+        self.double_value.unwrap_or(0.)
+    }
+
+    pub fn clear_double_value(&mut self) {
+        self.double_value = ::std::option::Option::None;
+    }
+
+    pub fn has_double_value(&self) -> bool {
+        self.double_value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_double_value(&mut self, v: f64) {
+        self.double_value = ::std::option::Option::Some(v);
+    }
+
+    // optional bytes string_value = 7;
+
+    pub fn get_string_value(&self) -> &[u8] {
+        match self.string_value.as_ref() {
+            Some(v) => v,
+            None => &[],
         }
     }
 
@@ -5792,7 +7948,13 @@ impl ::protobuf::Message for UninterpretedOption {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 2 => {
                     ::protobuf::rt::read_repeated_message_into_repeated_field(wire_type, is, &mut self.name)?;
@@ -5828,6 +7990,7 @@ impl ::protobuf::Message for UninterpretedOption {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -5909,6 +8072,10 @@ impl ::protobuf::Message for UninterpretedOption {
         UninterpretedOption::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -5966,6 +8133,17 @@ impl ::protobuf::Message for UninterpretedOption {
         static instance: ::protobuf::rt::Lazy<UninterpretedOption> = ::protobuf::rt::Lazy::INIT;
         instance.get(UninterpretedOption::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.name.shrink_to_fit();
+        for v in self.name.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.identifier_value);
+        ::protobuf::rt::shrink_singular_bytes_to_fit(&mut self.string_value);
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.aggregate_value);
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for UninterpretedOption {
@@ -5981,6 +8159,34 @@ impl ::protobuf::Clear for UninterpretedOption {
     }
 }
 
+impl ::std::clone::Clone for UninterpretedOption {
+    fn clone(&self) -> UninterpretedOption {
+        UninterpretedOption {
+            name: ::std::clone::Clone::clone(&self.name),
+            identifier_value: ::std::clone::Clone::clone(&self.identifier_value),
+            positive_int_value: ::std::clone::Clone::clone(&self.positive_int_value),
+            negative_int_value: ::std::clone::Clone::clone(&self.negative_int_value),
+            double_value: ::std::clone::Clone::clone(&self.double_value),
+            string_value: ::std::clone::Clone::clone(&self.string_value),
+            aggregate_value: ::std::clone::Clone::clone(&self.aggregate_value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &UninterpretedOption) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.identifier_value, &source.identifier_value);
+        ::std::clone::Clone::clone_from(&mut self.positive_int_value, &source.positive_int_value);
+        ::std::clone::Clone::clone_from(&mut self.negative_int_value, &source.negative_int_value);
+        ::std::clone::Clone::clone_from(&mut self.double_value, &source.double_value);
+        ::std::clone::Clone::clone_from(&mut self.string_value, &source.string_value);
+        ::std::clone::Clone::clone_from(&mut self.aggregate_value, &source.aggregate_value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for UninterpretedOption {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -5990,8 +8196,11 @@ impl ::std::fmt::Debug for UninterpretedOption {
 impl ::protobuf::reflect::ProtobufValue for UninterpretedOption {
 }
 
+impl ::protobuf::ProtobufConvertible for UninterpretedOption {
+}
+
 pub mod uninterpreted_option {
-    #[derive(PartialEq,Clone,Default)]
+    #[derive(PartialEq,Default)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
     pub struct NamePart {
         // message fields
@@ -6084,7 +8293,13 @@ pub mod uninterpreted_option {
 
         fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
             while !is.eof()? {
+                let field_start = is.pos();
                 let (field_number, wire_type) = is.read_tag_unpack()?;
+                if !is.field_allowed(field_number) {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                    is.record_field_offset(field_number, field_start);
+                    continue;
+                }
                 match field_number {
                     1 => {
                         ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name_part)?;
@@ -6099,6 +8314,7 @@ pub mod uninterpreted_option {
                         ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                     },
                 };
+                is.record_field_offset(field_number, field_start);
             }
             ::std::result::Result::Ok(())
         }
@@ -6149,6 +8365,10 @@ pub mod uninterpreted_option {
             NamePart::new()
         }
 
+        fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+            ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+        }
+
         fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
             static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
             descriptor.get(|| {
@@ -6177,6 +8397,11 @@ pub mod uninterpreted_option {
             static instance: ::protobuf::rt::Lazy<NamePart> = ::protobuf::rt::Lazy::INIT;
             instance.get(NamePart::new)
         }
+
+        fn shrink_to_fit(&mut self) {
+            ::protobuf::rt::shrink_singular_string_to_fit(&mut self.name_part);
+            self.unknown_fields.shrink_to_fit();
+        }
     }
 
     impl ::protobuf::Clear for NamePart {
@@ -6187,6 +8412,24 @@ pub mod uninterpreted_option {
         }
     }
 
+    impl ::std::clone::Clone for NamePart {
+        fn clone(&self) -> NamePart {
+            NamePart {
+                name_part: ::std::clone::Clone::clone(&self.name_part),
+                is_extension: ::std::clone::Clone::clone(&self.is_extension),
+                unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+                cached_size: ::std::clone::Clone::clone(&self.cached_size),
+            }
+        }
+
+        fn clone_from(&mut self, source: &NamePart) {
+            ::std::clone::Clone::clone_from(&mut self.name_part, &source.name_part);
+            ::std::clone::Clone::clone_from(&mut self.is_extension, &source.is_extension);
+            ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+            ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+        }
+    }
+
     impl ::std::fmt::Debug for NamePart {
         fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             ::protobuf::text_format::fmt(self, f)
@@ -6195,9 +8438,12 @@ pub mod uninterpreted_option {
 
     impl ::protobuf::reflect::ProtobufValue for NamePart {
     }
+
+    impl ::protobuf::ProtobufConvertible for NamePart {
+    }
 }
 
-#[derive(PartialEq,Clone,Default)]
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct SourceCodeInfo {
     // message fields
@@ -6233,7 +8479,13 @@ impl ::protobuf::Message for SourceCodeInfo {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_repeated_message_into_repeated_field(wire_type, is, &mut self.location)?;
@@ -6242,6 +8494,7 @@ impl ::protobuf::Message for SourceCodeInfo {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -6287,6 +8540,10 @@ impl ::protobuf::Message for SourceCodeInfo {
         SourceCodeInfo::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -6308,6 +8565,14 @@ impl ::protobuf::Message for SourceCodeInfo {
         static instance: ::protobuf::rt::Lazy<SourceCodeInfo> = ::protobuf::rt::Lazy::INIT;
         instance.get(SourceCodeInfo::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.location.shrink_to_fit();
+        for v in self.location.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for SourceCodeInfo {
@@ -6317,6 +8582,22 @@ impl ::protobuf::Clear for SourceCodeInfo {
     }
 }
 
+impl ::std::clone::Clone for SourceCodeInfo {
+    fn clone(&self) -> SourceCodeInfo {
+        SourceCodeInfo {
+            location: ::std::clone::Clone::clone(&self.location),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &SourceCodeInfo) {
+        ::std::clone::Clone::clone_from(&mut self.location, &source.location);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for SourceCodeInfo {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -6326,8 +8607,11 @@ impl ::std::fmt::Debug for SourceCodeInfo {
 impl ::protobuf::reflect::ProtobufValue for SourceCodeInfo {
 }
 
+impl ::protobuf::ProtobufConvertible for SourceCodeInfo {
+}
+
 pub mod source_code_info {
-    #[derive(PartialEq,Clone,Default)]
+    #[derive(PartialEq,Default)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
     pub struct Location {
         // message fields
@@ -6434,7 +8718,13 @@ pub mod source_code_info {
 
         fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
             while !is.eof()? {
+                let field_start = is.pos();
                 let (field_number, wire_type) = is.read_tag_unpack()?;
+                if !is.field_allowed(field_number) {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                    is.record_field_offset(field_number, field_start);
+                    continue;
+                }
                 match field_number {
                     1 => {
                         ::protobuf::rt::read_repeated_int32_into(wire_type, is, &mut self.path)?;
@@ -6455,6 +8745,7 @@ pub mod source_code_info {
                         ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                     },
                 };
+                is.record_field_offset(field_number, field_start);
             }
             ::std::result::Result::Ok(())
         }
@@ -6533,6 +8824,10 @@ pub mod source_code_info {
             Location::new()
         }
 
+        fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+            ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+        }
+
         fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
             static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
             descriptor.get(|| {
@@ -6576,6 +8871,18 @@ pub mod source_code_info {
             static instance: ::protobuf::rt::Lazy<Location> = ::protobuf::rt::Lazy::INIT;
             instance.get(Location::new)
         }
+
+        fn shrink_to_fit(&mut self) {
+            self.path.shrink_to_fit();
+            self.span.shrink_to_fit();
+            ::protobuf::rt::shrink_singular_string_to_fit(&mut self.leading_comments);
+            ::protobuf::rt::shrink_singular_string_to_fit(&mut self.trailing_comments);
+            self.leading_detached_comments.shrink_to_fit();
+            for v in self.leading_detached_comments.iter_mut() {
+                v.shrink_to_fit();
+            };
+            self.unknown_fields.shrink_to_fit();
+        }
     }
 
     impl ::protobuf::Clear for Location {
@@ -6589,6 +8896,30 @@ pub mod source_code_info {
         }
     }
 
+    impl ::std::clone::Clone for Location {
+        fn clone(&self) -> Location {
+            Location {
+                path: ::std::clone::Clone::clone(&self.path),
+                span: ::std::clone::Clone::clone(&self.span),
+                leading_comments: ::std::clone::Clone::clone(&self.leading_comments),
+                trailing_comments: ::std::clone::Clone::clone(&self.trailing_comments),
+                leading_detached_comments: ::std::clone::Clone::clone(&self.leading_detached_comments),
+                unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+                cached_size: ::std::clone::Clone::clone(&self.cached_size),
+            }
+        }
+
+        fn clone_from(&mut self, source: &Location) {
+            ::std::clone::Clone::clone_from(&mut self.path, &source.path);
+            ::std::clone::Clone::clone_from(&mut self.span, &source.span);
+            ::std::clone::Clone::clone_from(&mut self.leading_comments, &source.leading_comments);
+            ::std::clone::Clone::clone_from(&mut self.trailing_comments, &source.trailing_comments);
+            ::std::clone::Clone::clone_from(&mut self.leading_detached_comments, &source.leading_detached_comments);
+            ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+            ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+        }
+    }
+
     impl ::std::fmt::Debug for Location {
         fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             ::protobuf::text_format::fmt(self, f)
@@ -6597,9 +8928,12 @@ pub mod source_code_info {
 
     impl ::protobuf::reflect::ProtobufValue for Location {
     }
+
+    impl ::protobuf::ProtobufConvertible for Location {
+    }
 }
 
-#[derive(PartialEq,Clone,Default)]
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct GeneratedCodeInfo {
     // message fields
@@ -6635,7 +8969,13 @@ impl ::protobuf::Message for GeneratedCodeInfo {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_repeated_message_into_repeated_field(wire_type, is, &mut self.annotation)?;
@@ -6644,6 +8984,7 @@ impl ::protobuf::Message for GeneratedCodeInfo {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -6689,6 +9030,10 @@ impl ::protobuf::Message for GeneratedCodeInfo {
         GeneratedCodeInfo::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -6710,6 +9055,14 @@ impl ::protobuf::Message for GeneratedCodeInfo {
         static instance: ::protobuf::rt::Lazy<GeneratedCodeInfo> = ::protobuf::rt::Lazy::INIT;
         instance.get(GeneratedCodeInfo::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.annotation.shrink_to_fit();
+        for v in self.annotation.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for GeneratedCodeInfo {
@@ -6719,6 +9072,22 @@ impl ::protobuf::Clear for GeneratedCodeInfo {
     }
 }
 
+impl ::std::clone::Clone for GeneratedCodeInfo {
+    fn clone(&self) -> GeneratedCodeInfo {
+        GeneratedCodeInfo {
+            annotation: ::std::clone::Clone::clone(&self.annotation),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &GeneratedCodeInfo) {
+        ::std::clone::Clone::clone_from(&mut self.annotation, &source.annotation);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for GeneratedCodeInfo {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -6728,8 +9097,11 @@ impl ::std::fmt::Debug for GeneratedCodeInfo {
 impl ::protobuf::reflect::ProtobufValue for GeneratedCodeInfo {
 }
 
+impl ::protobuf::ProtobufConvertible for GeneratedCodeInfo {
+}
+
 pub mod generated_code_info {
-    #[derive(PartialEq,Clone,Default)]
+    #[derive(PartialEq,Default)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
     pub struct Annotation {
         // message fields
@@ -6837,7 +9209,13 @@ pub mod generated_code_info {
 
         fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
             while !is.eof()? {
+                let field_start = is.pos();
                 let (field_number, wire_type) = is.read_tag_unpack()?;
+                if !is.field_allowed(field_number) {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                    is.record_field_offset(field_number, field_start);
+                    continue;
+                }
                 match field_number {
                     1 => {
                         ::protobuf::rt::read_repeated_int32_into(wire_type, is, &mut self.path)?;
@@ -6861,6 +9239,7 @@ pub mod generated_code_info {
                         ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                     },
                 };
+                is.record_field_offset(field_number, field_start);
             }
             ::std::result::Result::Ok(())
         }
@@ -6928,6 +9307,10 @@ pub mod generated_code_info {
             Annotation::new()
         }
 
+        fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+            ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+        }
+
         fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
             static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
             descriptor.get(|| {
@@ -6967,6 +9350,12 @@ pub mod generated_code_info {
             static instance: ::protobuf::rt::Lazy<Annotation> = ::protobuf::rt::Lazy::INIT;
             instance.get(Annotation::new)
         }
+
+        fn shrink_to_fit(&mut self) {
+            self.path.shrink_to_fit();
+            ::protobuf::rt::shrink_singular_string_to_fit(&mut self.source_file);
+            self.unknown_fields.shrink_to_fit();
+        }
     }
 
     impl ::protobuf::Clear for Annotation {
@@ -6979,6 +9368,28 @@ pub mod generated_code_info {
         }
     }
 
+    impl ::std::clone::Clone for Annotation {
+        fn clone(&self) -> Annotation {
+            Annotation {
+                path: ::std::clone::Clone::clone(&self.path),
+                source_file: ::std::clone::Clone::clone(&self.source_file),
+                begin: ::std::clone::Clone::clone(&self.begin),
+                end: ::std::clone::Clone::clone(&self.end),
+                unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+                cached_size: ::std::clone::Clone::clone(&self.cached_size),
+            }
+        }
+
+        fn clone_from(&mut self, source: &Annotation) {
+            ::std::clone::Clone::clone_from(&mut self.path, &source.path);
+            ::std::clone::Clone::clone_from(&mut self.source_file, &source.source_file);
+            ::std::clone::Clone::clone_from(&mut self.begin, &source.begin);
+            ::std::clone::Clone::clone_from(&mut self.end, &source.end);
+            ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+            ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+        }
+    }
+
     impl ::std::fmt::Debug for Annotation {
         fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             ::protobuf::text_format::fmt(self, f)
@@ -6987,12 +9398,91 @@ pub mod generated_code_info {
 
     impl ::protobuf::reflect::ProtobufValue for Annotation {
     }
+
+    impl ::protobuf::ProtobufConvertible for Annotation {
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(serde, derive(Serialize, Deserialize))]
+pub enum Edition {
+    EDITION_UNKNOWN = 0,
+    EDITION_PROTO2 = 998,
+    EDITION_PROTO3 = 999,
+    EDITION_2023 = 1000,
+    EDITION_2024 = 1001,
+}
+
+impl ::protobuf::ProtobufEnum for Edition {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Edition> {
+        match value {
+            0 => ::std::option::Option::Some(Edition::EDITION_UNKNOWN),
+            998 => ::std::option::Option::Some(Edition::EDITION_PROTO2),
+            999 => ::std::option::Option::Some(Edition::EDITION_PROTO3),
+            1000 => ::std::option::Option::Some(Edition::EDITION_2023),
+            1001 => ::std::option::Option::Some(Edition::EDITION_2024),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match *self {
+            Edition::EDITION_UNKNOWN => "EDITION_UNKNOWN",
+            Edition::EDITION_PROTO2 => "EDITION_PROTO2",
+            Edition::EDITION_PROTO3 => "EDITION_PROTO3",
+            Edition::EDITION_2023 => "EDITION_2023",
+            Edition::EDITION_2024 => "EDITION_2024",
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Edition] = &[
+            Edition::EDITION_UNKNOWN,
+            Edition::EDITION_PROTO2,
+            Edition::EDITION_PROTO3,
+            Edition::EDITION_2023,
+            Edition::EDITION_2024,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new::<Edition>("Edition", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::default::Default for Edition {
+    fn default() -> Self {
+        Edition::EDITION_UNKNOWN
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Edition {
+}
+
+impl ::std::convert::From<Edition> for &'static str {
+    fn from(value: Edition) -> &'static str {
+        ::protobuf::ProtobufEnum::name(&value)
+    }
+}
+
+impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<Edition>> for Edition {
+    fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<Edition>) -> bool {
+        other == self
+    }
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x20google/protobuf/descriptor.proto\x12\x0fgoogle.protobuf\"M\n\x11Fi\
     leDescriptorSet\x128\n\x04file\x18\x01\x20\x03(\x0b2$.google.protobuf.Fi\
-    leDescriptorProtoR\x04file\"\xe4\x04\n\x13FileDescriptorProto\x12\x12\n\
+    leDescriptorProtoR\x04file\"\x9a\x05\n\x13FileDescriptorProto\x12\x12\n\
     \x04name\x18\x01\x20\x01(\tR\x04name\x12\x18\n\x07package\x18\x02\x20\
     \x01(\tR\x07package\x12\x1e\n\ndependency\x18\x03\x20\x03(\tR\ndependenc\
     y\x12+\n\x11public_dependency\x18\n\x20\x03(\x05R\x10publicDependency\
@@ -7005,32 +9495,33 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     sion\x126\n\x07options\x18\x08\x20\x01(\x0b2\x1c.google.protobuf.FileOpt\
     ionsR\x07options\x12I\n\x10source_code_info\x18\t\x20\x01(\x0b2\x1f.goog\
     le.protobuf.SourceCodeInfoR\x0esourceCodeInfo\x12\x16\n\x06syntax\x18\
-    \x0c\x20\x01(\tR\x06syntax\"\xf7\x05\n\x0fDescriptorProto\x12\x12\n\x04n\
-    ame\x18\x01\x20\x01(\tR\x04name\x12;\n\x05field\x18\x02\x20\x03(\x0b2%.g\
-    oogle.protobuf.FieldDescriptorProtoR\x05field\x12C\n\textension\x18\x06\
-    \x20\x03(\x0b2%.google.protobuf.FieldDescriptorProtoR\textension\x12A\n\
-    \x0bnested_type\x18\x03\x20\x03(\x0b2\x20.google.protobuf.DescriptorProt\
-    oR\nnestedType\x12A\n\tenum_type\x18\x04\x20\x03(\x0b2$.google.protobuf.\
-    EnumDescriptorProtoR\x08enumType\x12X\n\x0fextension_range\x18\x05\x20\
-    \x03(\x0b2/.google.protobuf.DescriptorProto.ExtensionRangeR\x0eextension\
-    Range\x12D\n\noneof_decl\x18\x08\x20\x03(\x0b2%.google.protobuf.OneofDes\
-    criptorProtoR\toneofDecl\x129\n\x07options\x18\x07\x20\x01(\x0b2\x1f.goo\
-    gle.protobuf.MessageOptionsR\x07options\x12U\n\x0ereserved_range\x18\t\
-    \x20\x03(\x0b2..google.protobuf.DescriptorProto.ReservedRangeR\rreserved\
-    Range\x12#\n\rreserved_name\x18\n\x20\x03(\tR\x0creservedName\x1a8\n\x0e\
-    ExtensionRange\x12\x14\n\x05start\x18\x01\x20\x01(\x05R\x05start\x12\x10\
-    \n\x03end\x18\x02\x20\x01(\x05R\x03end\x1a7\n\rReservedRange\x12\x14\n\
-    \x05start\x18\x01\x20\x01(\x05R\x05start\x12\x10\n\x03end\x18\x02\x20\
-    \x01(\x05R\x03end\"\x98\x06\n\x14FieldDescriptorProto\x12\x12\n\x04name\
-    \x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06number\x18\x03\x20\x01(\x05R\
-    \x06number\x12A\n\x05label\x18\x04\x20\x01(\x0e2+.google.protobuf.FieldD\
-    escriptorProto.LabelR\x05label\x12>\n\x04type\x18\x05\x20\x01(\x0e2*.goo\
-    gle.protobuf.FieldDescriptorProto.TypeR\x04type\x12\x1b\n\ttype_name\x18\
-    \x06\x20\x01(\tR\x08typeName\x12\x1a\n\x08extendee\x18\x02\x20\x01(\tR\
-    \x08extendee\x12#\n\rdefault_value\x18\x07\x20\x01(\tR\x0cdefaultValue\
-    \x12\x1f\n\x0boneof_index\x18\t\x20\x01(\x05R\noneofIndex\x12\x1b\n\tjso\
-    n_name\x18\n\x20\x01(\tR\x08jsonName\x127\n\x07options\x18\x08\x20\x01(\
-    \x0b2\x1d.google.protobuf.FieldOptionsR\x07options\"\xb6\x02\n\x04Type\
+    \x0c\x20\x01(\tR\x06syntax\x124\n\x07edition\x18\x0e\x20\x01(\x0e2\x18.g\
+    oogle.protobuf.EditionR\x07editionB\0\"\xf7\x05\n\x0fDescriptorProto\x12\
+    \x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12;\n\x05field\x18\x02\x20\
+    \x03(\x0b2%.google.protobuf.FieldDescriptorProtoR\x05field\x12C\n\texten\
+    sion\x18\x06\x20\x03(\x0b2%.google.protobuf.FieldDescriptorProtoR\texten\
+    sion\x12A\n\x0bnested_type\x18\x03\x20\x03(\x0b2\x20.google.protobuf.Des\
+    criptorProtoR\nnestedType\x12A\n\tenum_type\x18\x04\x20\x03(\x0b2$.googl\
+    e.protobuf.EnumDescriptorProtoR\x08enumType\x12X\n\x0fextension_range\
+    \x18\x05\x20\x03(\x0b2/.google.protobuf.DescriptorProto.ExtensionRangeR\
+    \x0eextensionRange\x12D\n\noneof_decl\x18\x08\x20\x03(\x0b2%.google.prot\
+    obuf.OneofDescriptorProtoR\toneofDecl\x129\n\x07options\x18\x07\x20\x01(\
+    \x0b2\x1f.google.protobuf.MessageOptionsR\x07options\x12U\n\x0ereserved_\
+    range\x18\t\x20\x03(\x0b2..google.protobuf.DescriptorProto.ReservedRange\
+    R\rreservedRange\x12#\n\rreserved_name\x18\n\x20\x03(\tR\x0creservedName\
+    \x1a8\n\x0eExtensionRange\x12\x14\n\x05start\x18\x01\x20\x01(\x05R\x05st\
+    art\x12\x10\n\x03end\x18\x02\x20\x01(\x05R\x03end\x1a7\n\rReservedRange\
+    \x12\x14\n\x05start\x18\x01\x20\x01(\x05R\x05start\x12\x10\n\x03end\x18\
+    \x02\x20\x01(\x05R\x03end\"\x98\x06\n\x14FieldDescriptorProto\x12\x12\n\
+    \x04name\x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06number\x18\x03\x20\x01\
+    (\x05R\x06number\x12A\n\x05label\x18\x04\x20\x01(\x0e2+.google.protobuf.\
+    FieldDescriptorProto.LabelR\x05label\x12>\n\x04type\x18\x05\x20\x01(\x0e\
+    2*.google.protobuf.FieldDescriptorProto.TypeR\x04type\x12\x1b\n\ttype_na\
+    me\x18\x06\x20\x01(\tR\x08typeName\x12\x1a\n\x08extendee\x18\x02\x20\x01\
+    (\tR\x08extendee\x12#\n\rdefault_value\x18\x07\x20\x01(\tR\x0cdefaultVal\
+    ue\x12\x1f\n\x0boneof_index\x18\t\x20\x01(\x05R\noneofIndex\x12\x1b\n\tj\
+    son_name\x18\n\x20\x01(\tR\x08jsonName\x127\n\x07options\x18\x08\x20\x01\
+    (\x0b2\x1d.google.protobuf.FieldOptionsR\x07options\"\xb6\x02\n\x04Type\
     \x12\x0f\n\x0bTYPE_DOUBLE\x10\x01\x12\x0e\n\nTYPE_FLOAT\x10\x02\x12\x0e\
     \n\nTYPE_INT64\x10\x03\x12\x0f\n\x0bTYPE_UINT64\x10\x04\x12\x0e\n\nTYPE_\
     INT32\x10\x05\x12\x10\n\x0cTYPE_FIXED64\x10\x06\x12\x10\n\x0cTYPE_FIXED3\
@@ -7059,7 +9550,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x07options\x18\x04\x20\x01(\x0b2\x1e.google.protobuf.MethodOptionsR\
     \x07options\x120\n\x10client_streaming\x18\x05\x20\x01(\x08:\x05falseR\
     \x0fclientStreaming\x120\n\x10server_streaming\x18\x06\x20\x01(\x08:\x05\
-    falseR\x0fserverStreaming\"\x88\x07\n\x0bFileOptions\x12!\n\x0cjava_pack\
+    falseR\x0fserverStreaming\"\xc3\x07\n\x0bFileOptions\x12!\n\x0cjava_pack\
     age\x18\x01\x20\x01(\tR\x0bjavaPackage\x120\n\x14java_outer_classname\
     \x18\x08\x20\x01(\tR\x12javaOuterClassname\x125\n\x13java_multiple_files\
     \x18\n\x20\x01(\x08:\x05falseR\x11javaMultipleFiles\x12D\n\x1djava_gener\
@@ -7076,142 +9567,174 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     ableArenas\x12*\n\x11objc_class_prefix\x18$\x20\x01(\tR\x0fobjcClassPref\
     ix\x12)\n\x10csharp_namespace\x18%\x20\x01(\tR\x0fcsharpNamespace\x12X\n\
     \x14uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.Unin\
-    terpretedOptionR\x13uninterpretedOption\":\n\x0cOptimizeMode\x12\t\n\x05\
-    SPEED\x10\x01\x12\r\n\tCODE_SIZE\x10\x02\x12\x10\n\x0cLITE_RUNTIME\x10\
-    \x03*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\xc5\x02\n\x0eMessageOption\
-    s\x12<\n\x17message_set_wire_format\x18\x01\x20\x01(\x08:\x05falseR\x14m\
-    essageSetWireFormat\x12L\n\x1fno_standard_descriptor_accessor\x18\x02\
-    \x20\x01(\x08:\x05falseR\x1cnoStandardDescriptorAccessor\x12%\n\ndepreca\
-    ted\x18\x03\x20\x01(\x08:\x05falseR\ndeprecated\x12\x1b\n\tmap_entry\x18\
-    \x07\x20\x01(\x08R\x08mapEntry\x12X\n\x14uninterpreted_option\x18\xe7\
-    \x07\x20\x03(\x0b2$.google.protobuf.UninterpretedOptionR\x13uninterprete\
-    dOption*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\xdc\x03\n\x0cFieldOptio\
-    ns\x12A\n\x05ctype\x18\x01\x20\x01(\x0e2#.google.protobuf.FieldOptions.C\
-    Type:\x06STRINGR\x05ctype\x12\x16\n\x06packed\x18\x02\x20\x01(\x08R\x06p\
-    acked\x12G\n\x06jstype\x18\x06\x20\x01(\x0e2$.google.protobuf.FieldOptio\
-    ns.JSType:\tJS_NORMALR\x06jstype\x12\x19\n\x04lazy\x18\x05\x20\x01(\x08:\
-    \x05falseR\x04lazy\x12%\n\ndeprecated\x18\x03\x20\x01(\x08:\x05falseR\nd\
-    eprecated\x12\x19\n\x04weak\x18\n\x20\x01(\x08:\x05falseR\x04weak\x12X\n\
-    \x14uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.Unin\
-    terpretedOptionR\x13uninterpretedOption\"/\n\x05CType\x12\n\n\x06STRING\
-    \x10\0\x12\x08\n\x04CORD\x10\x01\x12\x10\n\x0cSTRING_PIECE\x10\x02\"5\n\
-    \x06JSType\x12\r\n\tJS_NORMAL\x10\0\x12\r\n\tJS_STRING\x10\x01\x12\r\n\t\
-    JS_NUMBER\x10\x02*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"s\n\x0cOneofOp\
-    tions\x12X\n\x14uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.p\
-    rotobuf.UninterpretedOptionR\x13uninterpretedOption*\t\x08\xe8\x07\x10\
-    \x80\x80\x80\x80\x02\"\xba\x01\n\x0bEnumOptions\x12\x1f\n\x0ballow_alias\
+    terpretedOptionR\x13uninterpretedOption\x129\n\x08features\x182\x20\x01(\
+    \x0b2\x1b.google.protobuf.FeatureSetR\x08featuresB\0\":\n\x0cOptimizeMod\
+    e\x12\t\n\x05SPEED\x10\x01\x12\r\n\tCODE_SIZE\x10\x02\x12\x10\n\x0cLITE_\
+    RUNTIME\x10\x03*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\x80\x03\n\x0eMe\
+    ssageOptions\x12<\n\x17message_set_wire_format\x18\x01\x20\x01(\x08:\x05\
+    falseR\x14messageSetWireFormat\x12L\n\x1fno_standard_descriptor_accessor\
+    \x18\x02\x20\x01(\x08:\x05falseR\x1cnoStandardDescriptorAccessor\x12%\n\
+    \ndeprecated\x18\x03\x20\x01(\x08:\x05falseR\ndeprecated\x12\x1b\n\tmap_\
+    entry\x18\x07\x20\x01(\x08R\x08mapEntry\x12X\n\x14uninterpreted_option\
+    \x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.UninterpretedOptionR\x13unin\
+    terpretedOption\x129\n\x08features\x18\x0c\x20\x01(\x0b2\x1b.google.prot\
+    obuf.FeatureSetR\x08featuresB\0*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\
+    \x97\x04\n\x0cFieldOptions\x12A\n\x05ctype\x18\x01\x20\x01(\x0e2#.google\
+    .protobuf.FieldOptions.CType:\x06STRINGR\x05ctype\x12\x16\n\x06packed\
+    \x18\x02\x20\x01(\x08R\x06packed\x12G\n\x06jstype\x18\x06\x20\x01(\x0e2$\
+    .google.protobuf.FieldOptions.JSType:\tJS_NORMALR\x06jstype\x12\x19\n\
+    \x04lazy\x18\x05\x20\x01(\x08:\x05falseR\x04lazy\x12%\n\ndeprecated\x18\
+    \x03\x20\x01(\x08:\x05falseR\ndeprecated\x12\x19\n\x04weak\x18\n\x20\x01\
+    (\x08:\x05falseR\x04weak\x12X\n\x14uninterpreted_option\x18\xe7\x07\x20\
+    \x03(\x0b2$.google.protobuf.UninterpretedOptionR\x13uninterpretedOption\
+    \x129\n\x08features\x18\x15\x20\x01(\x0b2\x1b.google.protobuf.FeatureSet\
+    R\x08featuresB\0\"/\n\x05CType\x12\n\n\x06STRING\x10\0\x12\x08\n\x04CORD\
+    \x10\x01\x12\x10\n\x0cSTRING_PIECE\x10\x02\"5\n\x06JSType\x12\r\n\tJS_NO\
+    RMAL\x10\0\x12\r\n\tJS_STRING\x10\x01\x12\r\n\tJS_NUMBER\x10\x02*\t\x08\
+    \xe8\x07\x10\x80\x80\x80\x80\x02\"\xae\x01\n\x0cOneofOptions\x12X\n\x14u\
+    ninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.Uninterpr\
+    etedOptionR\x13uninterpretedOption\x129\n\x08features\x18\x01\x20\x01(\
+    \x0b2\x1b.google.protobuf.FeatureSetR\x08featuresB\0*\t\x08\xe8\x07\x10\
+    \x80\x80\x80\x80\x02\"\xf5\x01\n\x0bEnumOptions\x12\x1f\n\x0ballow_alias\
     \x18\x02\x20\x01(\x08R\nallowAlias\x12%\n\ndeprecated\x18\x03\x20\x01(\
     \x08:\x05falseR\ndeprecated\x12X\n\x14uninterpreted_option\x18\xe7\x07\
     \x20\x03(\x0b2$.google.protobuf.UninterpretedOptionR\x13uninterpretedOpt\
-    ion*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\x9e\x01\n\x10EnumValueOptio\
-    ns\x12%\n\ndeprecated\x18\x01\x20\x01(\x08:\x05falseR\ndeprecated\x12X\n\
-    \x14uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.Unin\
-    terpretedOptionR\x13uninterpretedOption*\t\x08\xe8\x07\x10\x80\x80\x80\
-    \x80\x02\"\x9c\x01\n\x0eServiceOptions\x12%\n\ndeprecated\x18!\x20\x01(\
-    \x08:\x05falseR\ndeprecated\x12X\n\x14uninterpreted_option\x18\xe7\x07\
-    \x20\x03(\x0b2$.google.protobuf.UninterpretedOptionR\x13uninterpretedOpt\
-    ion*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\x9b\x01\n\rMethodOptions\
+    ion\x129\n\x08features\x18\x07\x20\x01(\x0b2\x1b.google.protobuf.Feature\
+    SetR\x08featuresB\0*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\xd9\x01\n\
+    \x10EnumValueOptions\x12%\n\ndeprecated\x18\x01\x20\x01(\x08:\x05falseR\
+    \ndeprecated\x12X\n\x14uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.g\
+    oogle.protobuf.UninterpretedOptionR\x13uninterpretedOption\x129\n\x08fea\
+    tures\x18\x02\x20\x01(\x0b2\x1b.google.protobuf.FeatureSetR\x08featuresB\
+    \0*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\x9c\x01\n\x0eServiceOptions\
     \x12%\n\ndeprecated\x18!\x20\x01(\x08:\x05falseR\ndeprecated\x12X\n\x14u\
     ninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.Uninterpr\
     etedOptionR\x13uninterpretedOption*\t\x08\xe8\x07\x10\x80\x80\x80\x80\
-    \x02\"\x9a\x03\n\x13UninterpretedOption\x12A\n\x04name\x18\x02\x20\x03(\
-    \x0b2-.google.protobuf.UninterpretedOption.NamePartR\x04name\x12)\n\x10i\
-    dentifier_value\x18\x03\x20\x01(\tR\x0fidentifierValue\x12,\n\x12positiv\
-    e_int_value\x18\x04\x20\x01(\x04R\x10positiveIntValue\x12,\n\x12negative\
-    _int_value\x18\x05\x20\x01(\x03R\x10negativeIntValue\x12!\n\x0cdouble_va\
-    lue\x18\x06\x20\x01(\x01R\x0bdoubleValue\x12!\n\x0cstring_value\x18\x07\
-    \x20\x01(\x0cR\x0bstringValue\x12'\n\x0faggregate_value\x18\x08\x20\x01(\
-    \tR\x0eaggregateValue\x1aJ\n\x08NamePart\x12\x1b\n\tname_part\x18\x01\
-    \x20\x02(\tR\x08namePart\x12!\n\x0cis_extension\x18\x02\x20\x02(\x08R\
-    \x0bisExtension\"\xa7\x02\n\x0eSourceCodeInfo\x12D\n\x08location\x18\x01\
-    \x20\x03(\x0b2(.google.protobuf.SourceCodeInfo.LocationR\x08location\x1a\
-    \xce\x01\n\x08Location\x12\x16\n\x04path\x18\x01\x20\x03(\x05R\x04pathB\
-    \x02\x10\x01\x12\x16\n\x04span\x18\x02\x20\x03(\x05R\x04spanB\x02\x10\
-    \x01\x12)\n\x10leading_comments\x18\x03\x20\x01(\tR\x0fleadingComments\
-    \x12+\n\x11trailing_comments\x18\x04\x20\x01(\tR\x10trailingComments\x12\
-    :\n\x19leading_detached_comments\x18\x06\x20\x03(\tR\x17leadingDetachedC\
-    omments\"\xd1\x01\n\x11GeneratedCodeInfo\x12M\n\nannotation\x18\x01\x20\
-    \x03(\x0b2-.google.protobuf.GeneratedCodeInfo.AnnotationR\nannotation\
-    \x1am\n\nAnnotation\x12\x16\n\x04path\x18\x01\x20\x03(\x05R\x04pathB\x02\
-    \x10\x01\x12\x1f\n\x0bsource_file\x18\x02\x20\x01(\tR\nsourceFile\x12\
-    \x14\n\x05begin\x18\x03\x20\x01(\x05R\x05begin\x12\x10\n\x03end\x18\x04\
-    \x20\x01(\x05R\x03endBX\n\x13com.google.protobufB\x10DescriptorProtosH\
-    \x01Z\ndescriptor\xa2\x02\x03GPB\xaa\x02\x1aGoogle.Protobuf.ReflectionJ\
-    \xfe\xa4\x02\n\x07\x12\x05'\0\xa3\x06\x01\n\xaa\x0f\n\x01\x0c\x12\x03'\0\
-    \x122\xc1\x0c\x20Protocol\x20Buffers\x20-\x20Google's\x20data\x20interch\
-    ange\x20format\n\x20Copyright\x202008\x20Google\x20Inc.\x20\x20All\x20ri\
-    ghts\x20reserved.\n\x20https://developers.google.com/protocol-buffers/\n\
-    \n\x20Redistribution\x20and\x20use\x20in\x20source\x20and\x20binary\x20f\
-    orms,\x20with\x20or\x20without\n\x20modification,\x20are\x20permitted\
-    \x20provided\x20that\x20the\x20following\x20conditions\x20are\n\x20met:\
-    \n\n\x20\x20\x20\x20\x20*\x20Redistributions\x20of\x20source\x20code\x20\
-    must\x20retain\x20the\x20above\x20copyright\n\x20notice,\x20this\x20list\
-    \x20of\x20conditions\x20and\x20the\x20following\x20disclaimer.\n\x20\x20\
-    \x20\x20\x20*\x20Redistributions\x20in\x20binary\x20form\x20must\x20repr\
-    oduce\x20the\x20above\n\x20copyright\x20notice,\x20this\x20list\x20of\
-    \x20conditions\x20and\x20the\x20following\x20disclaimer\n\x20in\x20the\
-    \x20documentation\x20and/or\x20other\x20materials\x20provided\x20with\
-    \x20the\n\x20distribution.\n\x20\x20\x20\x20\x20*\x20Neither\x20the\x20n\
-    ame\x20of\x20Google\x20Inc.\x20nor\x20the\x20names\x20of\x20its\n\x20con\
-    tributors\x20may\x20be\x20used\x20to\x20endorse\x20or\x20promote\x20prod\
-    ucts\x20derived\x20from\n\x20this\x20software\x20without\x20specific\x20\
-    prior\x20written\x20permission.\n\n\x20THIS\x20SOFTWARE\x20IS\x20PROVIDE\
-    D\x20BY\x20THE\x20COPYRIGHT\x20HOLDERS\x20AND\x20CONTRIBUTORS\n\x20\"AS\
-    \x20IS\"\x20AND\x20ANY\x20EXPRESS\x20OR\x20IMPLIED\x20WARRANTIES,\x20INC\
-    LUDING,\x20BUT\x20NOT\n\x20LIMITED\x20TO,\x20THE\x20IMPLIED\x20WARRANTIE\
-    S\x20OF\x20MERCHANTABILITY\x20AND\x20FITNESS\x20FOR\n\x20A\x20PARTICULAR\
-    \x20PURPOSE\x20ARE\x20DISCLAIMED.\x20IN\x20NO\x20EVENT\x20SHALL\x20THE\
-    \x20COPYRIGHT\n\x20OWNER\x20OR\x20CONTRIBUTORS\x20BE\x20LIABLE\x20FOR\
-    \x20ANY\x20DIRECT,\x20INDIRECT,\x20INCIDENTAL,\n\x20SPECIAL,\x20EXEMPLAR\
-    Y,\x20OR\x20CONSEQUENTIAL\x20DAMAGES\x20(INCLUDING,\x20BUT\x20NOT\n\x20L\
-    IMITED\x20TO,\x20PROCUREMENT\x20OF\x20SUBSTITUTE\x20GOODS\x20OR\x20SERVI\
-    CES;\x20LOSS\x20OF\x20USE,\n\x20DATA,\x20OR\x20PROFITS;\x20OR\x20BUSINES\
-    S\x20INTERRUPTION)\x20HOWEVER\x20CAUSED\x20AND\x20ON\x20ANY\n\x20THEORY\
-    \x20OF\x20LIABILITY,\x20WHETHER\x20IN\x20CONTRACT,\x20STRICT\x20LIABILIT\
-    Y,\x20OR\x20TORT\n\x20(INCLUDING\x20NEGLIGENCE\x20OR\x20OTHERWISE)\x20AR\
-    ISING\x20IN\x20ANY\x20WAY\x20OUT\x20OF\x20THE\x20USE\n\x20OF\x20THIS\x20\
-    SOFTWARE,\x20EVEN\x20IF\x20ADVISED\x20OF\x20THE\x20POSSIBILITY\x20OF\x20\
-    SUCH\x20DAMAGE.\n2\xdb\x02\x20Author:\x20kenton@google.com\x20(Kenton\
-    \x20Varda)\n\x20\x20Based\x20on\x20original\x20Protocol\x20Buffers\x20de\
-    sign\x20by\n\x20\x20Sanjay\x20Ghemawat,\x20Jeff\x20Dean,\x20and\x20other\
-    s.\n\n\x20The\x20messages\x20in\x20this\x20file\x20describe\x20the\x20de\
-    finitions\x20found\x20in\x20.proto\x20files.\n\x20A\x20valid\x20.proto\
-    \x20file\x20can\x20be\x20translated\x20directly\x20to\x20a\x20FileDescri\
-    ptorProto\n\x20without\x20any\x20other\x20information\x20(e.g.\x20withou\
-    t\x20reading\x20its\x20imports).\n\n\x08\n\x01\x02\x12\x03)\x08\x17\n\
-    \x08\n\x01\x08\x12\x03*\0!\n\x0b\n\x04\x08\xe7\x07\0\x12\x03*\0!\n\x0c\n\
-    \x05\x08\xe7\x07\0\x02\x12\x03*\x07\x11\n\r\n\x06\x08\xe7\x07\0\x02\0\
-    \x12\x03*\x07\x11\n\x0e\n\x07\x08\xe7\x07\0\x02\0\x01\x12\x03*\x07\x11\n\
-    \x0c\n\x05\x08\xe7\x07\0\x07\x12\x03*\x14\x20\n\x08\n\x01\x08\x12\x03+\0\
-    ,\n\x0b\n\x04\x08\xe7\x07\x01\x12\x03+\0,\n\x0c\n\x05\x08\xe7\x07\x01\
-    \x02\x12\x03+\x07\x13\n\r\n\x06\x08\xe7\x07\x01\x02\0\x12\x03+\x07\x13\n\
-    \x0e\n\x07\x08\xe7\x07\x01\x02\0\x01\x12\x03+\x07\x13\n\x0c\n\x05\x08\
-    \xe7\x07\x01\x07\x12\x03+\x16+\n\x08\n\x01\x08\x12\x03,\01\n\x0b\n\x04\
-    \x08\xe7\x07\x02\x12\x03,\01\n\x0c\n\x05\x08\xe7\x07\x02\x02\x12\x03,\
-    \x07\x1b\n\r\n\x06\x08\xe7\x07\x02\x02\0\x12\x03,\x07\x1b\n\x0e\n\x07\
-    \x08\xe7\x07\x02\x02\0\x01\x12\x03,\x07\x1b\n\x0c\n\x05\x08\xe7\x07\x02\
-    \x07\x12\x03,\x1e0\n\x08\n\x01\x08\x12\x03-\07\n\x0b\n\x04\x08\xe7\x07\
-    \x03\x12\x03-\07\n\x0c\n\x05\x08\xe7\x07\x03\x02\x12\x03-\x07\x17\n\r\n\
-    \x06\x08\xe7\x07\x03\x02\0\x12\x03-\x07\x17\n\x0e\n\x07\x08\xe7\x07\x03\
-    \x02\0\x01\x12\x03-\x07\x17\n\x0c\n\x05\x08\xe7\x07\x03\x07\x12\x03-\x1a\
-    6\n\x08\n\x01\x08\x12\x03.\0!\n\x0b\n\x04\x08\xe7\x07\x04\x12\x03.\0!\n\
-    \x0c\n\x05\x08\xe7\x07\x04\x02\x12\x03.\x07\x18\n\r\n\x06\x08\xe7\x07\
-    \x04\x02\0\x12\x03.\x07\x18\n\x0e\n\x07\x08\xe7\x07\x04\x02\0\x01\x12\
-    \x03.\x07\x18\n\x0c\n\x05\x08\xe7\x07\x04\x07\x12\x03.\x1b\x20\n\x08\n\
-    \x01\x08\x12\x032\0\x1c\n\x81\x01\n\x04\x08\xe7\x07\x05\x12\x032\0\x1c\
-    \x1at\x20descriptor.proto\x20must\x20be\x20optimized\x20for\x20speed\x20\
-    because\x20reflection-based\n\x20algorithms\x20don't\x20work\x20during\
-    \x20bootstrapping.\n\n\x0c\n\x05\x08\xe7\x07\x05\x02\x12\x032\x07\x13\n\
-    \r\n\x06\x08\xe7\x07\x05\x02\0\x12\x032\x07\x13\n\x0e\n\x07\x08\xe7\x07\
-    \x05\x02\0\x01\x12\x032\x07\x13\n\x0c\n\x05\x08\xe7\x07\x05\x03\x12\x032\
-    \x16\x1b\nj\n\x02\x04\0\x12\x046\08\x01\x1a^\x20The\x20protocol\x20compi\
-    ler\x20can\x20output\x20a\x20FileDescriptorSet\x20containing\x20the\x20.\
-    proto\n\x20files\x20it\x20parses.\n\n\n\n\x03\x04\0\x01\x12\x036\x08\x19\
-    \n\x0b\n\x04\x04\0\x02\0\x12\x037\x02(\n\x0c\n\x05\x04\0\x02\0\x04\x12\
-    \x037\x02\n\n\x0c\n\x05\x04\0\x02\0\x06\x12\x037\x0b\x1e\n\x0c\n\x05\x04\
-    \0\x02\0\x01\x12\x037\x1f#\n\x0c\n\x05\x04\0\x02\0\x03\x12\x037&'\n/\n\
-    \x02\x04\x01\x12\x04;\0X\x01\x1a#\x20Describes\x20a\x20complete\x20.prot\
-    o\x20file.\n\n\n\n\x03\x04\x01\x01\x12\x03;\x08\x1b\n9\n\x04\x04\x01\x02\
+    \x02\"\x9b\x01\n\rMethodOptions\x12%\n\ndeprecated\x18!\x20\x01(\x08:\
+    \x05falseR\ndeprecated\x12X\n\x14uninterpreted_option\x18\xe7\x07\x20\
+    \x03(\x0b2$.google.protobuf.UninterpretedOptionR\x13uninterpretedOption*\
+    \t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\x9a\x03\n\x13UninterpretedOptio\
+    n\x12A\n\x04name\x18\x02\x20\x03(\x0b2-.google.protobuf.UninterpretedOpt\
+    ion.NamePartR\x04name\x12)\n\x10identifier_value\x18\x03\x20\x01(\tR\x0f\
+    identifierValue\x12,\n\x12positive_int_value\x18\x04\x20\x01(\x04R\x10po\
+    sitiveIntValue\x12,\n\x12negative_int_value\x18\x05\x20\x01(\x03R\x10neg\
+    ativeIntValue\x12!\n\x0cdouble_value\x18\x06\x20\x01(\x01R\x0bdoubleValu\
+    e\x12!\n\x0cstring_value\x18\x07\x20\x01(\x0cR\x0bstringValue\x12'\n\x0f\
+    aggregate_value\x18\x08\x20\x01(\tR\x0eaggregateValue\x1aJ\n\x08NamePart\
+    \x12\x1b\n\tname_part\x18\x01\x20\x02(\tR\x08namePart\x12!\n\x0cis_exten\
+    sion\x18\x02\x20\x02(\x08R\x0bisExtension\"\xa7\x02\n\x0eSourceCodeInfo\
+    \x12D\n\x08location\x18\x01\x20\x03(\x0b2(.google.protobuf.SourceCodeInf\
+    o.LocationR\x08location\x1a\xce\x01\n\x08Location\x12\x16\n\x04path\x18\
+    \x01\x20\x03(\x05R\x04pathB\x02\x10\x01\x12\x16\n\x04span\x18\x02\x20\
+    \x03(\x05R\x04spanB\x02\x10\x01\x12)\n\x10leading_comments\x18\x03\x20\
+    \x01(\tR\x0fleadingComments\x12+\n\x11trailing_comments\x18\x04\x20\x01(\
+    \tR\x10trailingComments\x12:\n\x19leading_detached_comments\x18\x06\x20\
+    \x03(\tR\x17leadingDetachedComments\"\xd1\x01\n\x11GeneratedCodeInfo\x12\
+    M\n\nannotation\x18\x01\x20\x03(\x0b2-.google.protobuf.GeneratedCodeInfo\
+    .AnnotationR\nannotation\x1am\n\nAnnotation\x12\x16\n\x04path\x18\x01\
+    \x20\x03(\x05R\x04pathB\x02\x10\x01\x12\x1f\n\x0bsource_file\x18\x02\x20\
+    \x01(\tR\nsourceFile\x12\x14\n\x05begin\x18\x03\x20\x01(\x05R\x05begin\
+    \x12\x10\n\x03end\x18\x04\x20\x01(\x05R\x03end\"\xb8\x07\n\nFeatureSet\
+    \x12C\n\x0efield_presence\x18\x01\x20\x01(\x0e2).google.protobuf.Feature\
+    Set.FieldPresenceB\0\x129\n\tenum_type\x18\x02\x20\x01(\x0e2$.google.pro\
+    tobuf.FeatureSet.EnumTypeB\0\x12T\n\x17repeated_field_encoding\x18\x03\
+    \x20\x01(\x0e21.google.protobuf.FeatureSet.RepeatedFieldEncodingB\0\x12E\
+    \n\x0futf8_validation\x18\x04\x20\x01(\x0e2*.google.protobuf.FeatureSet.\
+    Utf8ValidationB\0\x12G\n\x10message_encoding\x18\x05\x20\x01(\x0e2+.goog\
+    le.protobuf.FeatureSet.MessageEncodingB\0\x12=\n\x0bjson_format\x18\x06\
+    \x20\x01(\x0e2&.google.protobuf.FeatureSet.JsonFormatB\0\"f\n\rFieldPres\
+    ence\x12\x1c\n\x16FIELD_PRESENCE_UNKNOWN\x10\0\x1a\0\x12\x0e\n\x08EXPLIC\
+    IT\x10\x01\x1a\0\x12\x0e\n\x08IMPLICIT\x10\x02\x1a\0\x12\x15\n\x0fLEGACY\
+    _REQUIRED\x10\x03\x1a\0\x1a\0\"?\n\x08EnumType\x12\x17\n\x11ENUM_TYPE_UN\
+    KNOWN\x10\0\x1a\0\x12\n\n\x04OPEN\x10\x01\x1a\0\x12\x0c\n\x06CLOSED\x10\
+    \x02\x1a\0\x1a\0\"^\n\x15RepeatedFieldEncoding\x12%\n\x1fREPEATED_FIELD_\
+    ENCODING_UNKNOWN\x10\0\x1a\0\x12\x0c\n\x06PACKED\x10\x01\x1a\0\x12\x0e\n\
+    \x08EXPANDED\x10\x02\x1a\0\x1a\0\"K\n\x0eUtf8Validation\x12\x1d\n\x17UTF\
+    8_VALIDATION_UNKNOWN\x10\0\x1a\0\x12\x0c\n\x06VERIFY\x10\x02\x1a\0\x12\n\
+    \n\x04NONE\x10\x03\x1a\0\x1a\0\"[\n\x0fMessageEncoding\x12\x1e\n\x18MESS\
+    AGE_ENCODING_UNKNOWN\x10\0\x1a\0\x12\x15\n\x0fLENGTH_PREFIXED\x10\x01\
+    \x1a\0\x12\x0f\n\tDELIMITED\x10\x02\x1a\0\x1a\0\"P\n\nJsonFormat\x12\x19\
+    \n\x13JSON_FORMAT_UNKNOWN\x10\0\x1a\0\x12\x0b\n\x05ALLOW\x10\x01\x1a\0\
+    \x12\x18\n\x12LEGACY_BEST_EFFORT\x10\x02\x1a\0\x1a\0:\0*z\n\x07Edition\
+    \x12\x15\n\x0fEDITION_UNKNOWN\x10\0\x1a\0\x12\x15\n\x0eEDITION_PROTO2\
+    \x10\xe6\x07\x1a\0\x12\x15\n\x0eEDITION_PROTO3\x10\xe7\x07\x1a\0\x12\x13\
+    \n\x0cEDITION_2023\x10\xe8\x07\x1a\0\x12\x13\n\x0cEDITION_2024\x10\xe9\
+    \x07\x1a\0\x1a\0BX\n\x13com.google.protobufB\x10DescriptorProtosH\x01Z\n\
+    descriptor\xa2\x02\x03GPB\xaa\x02\x1aGoogle.Protobuf.ReflectionJ\xfe\xa4\
+    \x02\n\x07\x12\x05'\0\xa3\x06\x01\n\xaa\x0f\n\x01\x0c\x12\x03'\0\x122\
+    \xc1\x0c\x20Protocol\x20Buffers\x20-\x20Google's\x20data\x20interchange\
+    \x20format\n\x20Copyright\x202008\x20Google\x20Inc.\x20\x20All\x20rights\
+    \x20reserved.\n\x20https://developers.google.com/protocol-buffers/\n\n\
+    \x20Redistribution\x20and\x20use\x20in\x20source\x20and\x20binary\x20for\
+    ms,\x20with\x20or\x20without\n\x20modification,\x20are\x20permitted\x20p\
+    rovided\x20that\x20the\x20following\x20conditions\x20are\n\x20met:\n\n\
+    \x20\x20\x20\x20\x20*\x20Redistributions\x20of\x20source\x20code\x20must\
+    \x20retain\x20the\x20above\x20copyright\n\x20notice,\x20this\x20list\x20\
+    of\x20conditions\x20and\x20the\x20following\x20disclaimer.\n\x20\x20\x20\
+    \x20\x20*\x20Redistributions\x20in\x20binary\x20form\x20must\x20reproduc\
+    e\x20the\x20above\n\x20copyright\x20notice,\x20this\x20list\x20of\x20con\
+    ditions\x20and\x20the\x20following\x20disclaimer\n\x20in\x20the\x20docum\
+    entation\x20and/or\x20other\x20materials\x20provided\x20with\x20the\n\
+    \x20distribution.\n\x20\x20\x20\x20\x20*\x20Neither\x20the\x20name\x20of\
+    \x20Google\x20Inc.\x20nor\x20the\x20names\x20of\x20its\n\x20contributors\
+    \x20may\x20be\x20used\x20to\x20endorse\x20or\x20promote\x20products\x20d\
+    erived\x20from\n\x20this\x20software\x20without\x20specific\x20prior\x20\
+    written\x20permission.\n\n\x20THIS\x20SOFTWARE\x20IS\x20PROVIDED\x20BY\
+    \x20THE\x20COPYRIGHT\x20HOLDERS\x20AND\x20CONTRIBUTORS\n\x20\"AS\x20IS\"\
+    \x20AND\x20ANY\x20EXPRESS\x20OR\x20IMPLIED\x20WARRANTIES,\x20INCLUDING,\
+    \x20BUT\x20NOT\n\x20LIMITED\x20TO,\x20THE\x20IMPLIED\x20WARRANTIES\x20OF\
+    \x20MERCHANTABILITY\x20AND\x20FITNESS\x20FOR\n\x20A\x20PARTICULAR\x20PUR\
+    POSE\x20ARE\x20DISCLAIMED.\x20IN\x20NO\x20EVENT\x20SHALL\x20THE\x20COPYR\
+    IGHT\n\x20OWNER\x20OR\x20CONTRIBUTORS\x20BE\x20LIABLE\x20FOR\x20ANY\x20D\
+    IRECT,\x20INDIRECT,\x20INCIDENTAL,\n\x20SPECIAL,\x20EXEMPLARY,\x20OR\x20\
+    CONSEQUENTIAL\x20DAMAGES\x20(INCLUDING,\x20BUT\x20NOT\n\x20LIMITED\x20TO\
+    ,\x20PROCUREMENT\x20OF\x20SUBSTITUTE\x20GOODS\x20OR\x20SERVICES;\x20LOSS\
+    \x20OF\x20USE,\n\x20DATA,\x20OR\x20PROFITS;\x20OR\x20BUSINESS\x20INTERRU\
+    PTION)\x20HOWEVER\x20CAUSED\x20AND\x20ON\x20ANY\n\x20THEORY\x20OF\x20LIA\
+    BILITY,\x20WHETHER\x20IN\x20CONTRACT,\x20STRICT\x20LIABILITY,\x20OR\x20T\
+    ORT\n\x20(INCLUDING\x20NEGLIGENCE\x20OR\x20OTHERWISE)\x20ARISING\x20IN\
+    \x20ANY\x20WAY\x20OUT\x20OF\x20THE\x20USE\n\x20OF\x20THIS\x20SOFTWARE,\
+    \x20EVEN\x20IF\x20ADVISED\x20OF\x20THE\x20POSSIBILITY\x20OF\x20SUCH\x20D\
+    AMAGE.\n2\xdb\x02\x20Author:\x20kenton@google.com\x20(Kenton\x20Varda)\n\
+    \x20\x20Based\x20on\x20original\x20Protocol\x20Buffers\x20design\x20by\n\
+    \x20\x20Sanjay\x20Ghemawat,\x20Jeff\x20Dean,\x20and\x20others.\n\n\x20Th\
+    e\x20messages\x20in\x20this\x20file\x20describe\x20the\x20definitions\
+    \x20found\x20in\x20.proto\x20files.\n\x20A\x20valid\x20.proto\x20file\
+    \x20can\x20be\x20translated\x20directly\x20to\x20a\x20FileDescriptorProt\
+    o\n\x20without\x20any\x20other\x20information\x20(e.g.\x20without\x20rea\
+    ding\x20its\x20imports).\n\n\x08\n\x01\x02\x12\x03)\x08\x17\n\x08\n\x01\
+    \x08\x12\x03*\0!\n\x0b\n\x04\x08\xe7\x07\0\x12\x03*\0!\n\x0c\n\x05\x08\
+    \xe7\x07\0\x02\x12\x03*\x07\x11\n\r\n\x06\x08\xe7\x07\0\x02\0\x12\x03*\
+    \x07\x11\n\x0e\n\x07\x08\xe7\x07\0\x02\0\x01\x12\x03*\x07\x11\n\x0c\n\
+    \x05\x08\xe7\x07\0\x07\x12\x03*\x14\x20\n\x08\n\x01\x08\x12\x03+\0,\n\
+    \x0b\n\x04\x08\xe7\x07\x01\x12\x03+\0,\n\x0c\n\x05\x08\xe7\x07\x01\x02\
+    \x12\x03+\x07\x13\n\r\n\x06\x08\xe7\x07\x01\x02\0\x12\x03+\x07\x13\n\x0e\
+    \n\x07\x08\xe7\x07\x01\x02\0\x01\x12\x03+\x07\x13\n\x0c\n\x05\x08\xe7\
+    \x07\x01\x07\x12\x03+\x16+\n\x08\n\x01\x08\x12\x03,\01\n\x0b\n\x04\x08\
+    \xe7\x07\x02\x12\x03,\01\n\x0c\n\x05\x08\xe7\x07\x02\x02\x12\x03,\x07\
+    \x1b\n\r\n\x06\x08\xe7\x07\x02\x02\0\x12\x03,\x07\x1b\n\x0e\n\x07\x08\
+    \xe7\x07\x02\x02\0\x01\x12\x03,\x07\x1b\n\x0c\n\x05\x08\xe7\x07\x02\x07\
+    \x12\x03,\x1e0\n\x08\n\x01\x08\x12\x03-\07\n\x0b\n\x04\x08\xe7\x07\x03\
+    \x12\x03-\07\n\x0c\n\x05\x08\xe7\x07\x03\x02\x12\x03-\x07\x17\n\r\n\x06\
+    \x08\xe7\x07\x03\x02\0\x12\x03-\x07\x17\n\x0e\n\x07\x08\xe7\x07\x03\x02\
+    \0\x01\x12\x03-\x07\x17\n\x0c\n\x05\x08\xe7\x07\x03\x07\x12\x03-\x1a6\n\
+    \x08\n\x01\x08\x12\x03.\0!\n\x0b\n\x04\x08\xe7\x07\x04\x12\x03.\0!\n\x0c\
+    \n\x05\x08\xe7\x07\x04\x02\x12\x03.\x07\x18\n\r\n\x06\x08\xe7\x07\x04\
+    \x02\0\x12\x03.\x07\x18\n\x0e\n\x07\x08\xe7\x07\x04\x02\0\x01\x12\x03.\
+    \x07\x18\n\x0c\n\x05\x08\xe7\x07\x04\x07\x12\x03.\x1b\x20\n\x08\n\x01\
+    \x08\x12\x032\0\x1c\n\x81\x01\n\x04\x08\xe7\x07\x05\x12\x032\0\x1c\x1at\
+    \x20descriptor.proto\x20must\x20be\x20optimized\x20for\x20speed\x20becau\
+    se\x20reflection-based\n\x20algorithms\x20don't\x20work\x20during\x20boo\
+    tstrapping.\n\n\x0c\n\x05\x08\xe7\x07\x05\x02\x12\x032\x07\x13\n\r\n\x06\
+    \x08\xe7\x07\x05\x02\0\x12\x032\x07\x13\n\x0e\n\x07\x08\xe7\x07\x05\x02\
+    \0\x01\x12\x032\x07\x13\n\x0c\n\x05\x08\xe7\x07\x05\x03\x12\x032\x16\x1b\
+    \nj\n\x02\x04\0\x12\x046\08\x01\x1a^\x20The\x20protocol\x20compiler\x20c\
+    an\x20output\x20a\x20FileDescriptorSet\x20containing\x20the\x20.proto\n\
+    \x20files\x20it\x20parses.\n\n\n\n\x03\x04\0\x01\x12\x036\x08\x19\n\x0b\
+    \n\x04\x04\0\x02\0\x12\x037\x02(\n\x0c\n\x05\x04\0\x02\0\x04\x12\x037\
+    \x02\n\n\x0c\n\x05\x04\0\x02\0\x06\x12\x037\x0b\x1e\n\x0c\n\x05\x04\0\
+    \x02\0\x01\x12\x037\x1f#\n\x0c\n\x05\x04\0\x02\0\x03\x12\x037&'\n/\n\x02\
+    \x04\x01\x12\x04;\0X\x01\x1a#\x20Describes\x20a\x20complete\x20.proto\
+    \x20file.\n\n\n\n\x03\x04\x01\x01\x12\x03;\x08\x1b\n9\n\x04\x04\x01\x02\
     \0\x12\x03<\x02\x1b\",\x20file\x20name,\x20relative\x20to\x20root\x20of\
     \x20source\x20tree\n\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03<\x02\n\n\x0c\
     \n\x05\x04\x01\x02\0\x05\x12\x03<\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\
@@ -1,4 +1,6 @@
 use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
 
 use reflect::EnumDescriptor;
 use reflect::EnumValueDescriptor;
@@ -15,6 +17,11 @@ pub trait ProtobufEnum: Eq + Sized + Copy + 'static + ProtobufValue + fmt::Debug
     /// Return `None` if value is unknown.
     fn from_i32(v: i32) -> Option<Self>;
 
+    /// Get enum value name as specified in `.proto` file.
+    fn name(&self) -> &'static str {
+        self.descriptor().name()
+    }
+
     /// Get all enum values for enum type.
     fn values() -> &'static [Self] {
         panic!();
@@ -89,6 +96,12 @@ impl<E: ProtobufEnum> ProtobufEnumOrUnknown<E> {
         self.enum_value().unwrap_or(map_unknown)
     }
 
+    /// Get enum value name as specified in `.proto` file.
+    /// Return `None` if value is unknown.
+    pub fn name(&self) -> Option<&'static str> {
+        self.enum_value().ok().map(|e| e.name())
+    }
+
     /// Get enum descriptor by type.
     pub fn enum_descriptor_static() -> &'static EnumDescriptor {
         E::enum_descriptor_static()
@@ -101,12 +114,25 @@ impl<E: ProtobufEnum> From<E> for ProtobufEnumOrUnknown<E> {
     }
 }
 
+/// Unknown values are not equal to any enum variant.
+impl<E: ProtobufEnum> PartialEq<E> for ProtobufEnumOrUnknown<E> {
+    fn eq(&self, other: &E) -> bool {
+        self.value == other.value()
+    }
+}
+
 impl<E: ProtobufEnum> Default for ProtobufEnumOrUnknown<E> {
     fn default() -> ProtobufEnumOrUnknown<E> {
         ProtobufEnumOrUnknown::new(E::default())
     }
 }
 
+impl<E: ProtobufEnum> Hash for ProtobufEnumOrUnknown<E> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
 impl<E: ProtobufEnum> fmt::Debug for ProtobufEnumOrUnknown<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.enum_value() {
@@ -143,3 +169,13 @@ impl<'de, E: serde::Deserialize<'de> + ProtobufEnum> serde::Deserialize<'de> for
     }
 }
 
+#[cfg(feature = "with-arbitrary")]
+impl<'a, E: arbitrary::Arbitrary<'a> + ProtobufEnum> arbitrary::Arbitrary<'a>
+    for ProtobufEnumOrUnknown<E>
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // only known values are generated
+        E::arbitrary(u).map(ProtobufEnumOrUnknown::new)
+    }
+}
+
@@ -9,7 +9,7 @@ pub type ProtobufResult<T> = Result<T, ProtobufError>;
 
 /// Enum values added here for diagnostic purposes.
 /// Users should not depend on specific values.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum WireError {
     UnexpectedEof,
     UnexpectedWireType(WireType),
@@ -21,6 +21,19 @@ pub enum WireError {
     InvalidEnumValue(i32),
     OverRecursionLimit,
     TruncatedMessage,
+    TotalBytesLimitExceeded,
+    /// Borrowed read is only possible when reading from byte slice
+    BorrowFromReader,
+    /// `bytes` field with `ip_addr` option has wrong length
+    IncorrectIpAddrLength(usize),
+    /// `bytes` field with `uuid` option has length other than 16 bytes
+    IncorrectUuidLength(usize),
+    /// Enum field contains a value unknown to the enum type,
+    /// and `CodedInputStream` rejects unknown enum values
+    UnknownEnumValue { field: &'static str, value: i32 },
+    /// Repeated field has more elements than allowed by
+    /// `CodedInputStream::set_max_repeated_len`
+    RepeatedLenLimitExceeded,
     Other,
 }
 
@@ -30,12 +43,23 @@ pub enum ProtobufError {
     WireError(WireError),
     Utf8(str::Utf8Error),
     MessageNotInitialized { message: &'static str },
+    /// Message passed to `merge_dyn` is of different type
+    MessageTypeMismatch {
+        expected: &'static str,
+        actual: &'static str,
+    },
+    /// Value passed to `FieldDescriptor::try_set_singular_field` does not match field type
+    FieldValueTypeMismatch { field: &'static str },
 }
 
 impl ProtobufError {
     pub fn message_not_initialized(message: &'static str) -> ProtobufError {
         ProtobufError::MessageNotInitialized { message: message }
     }
+
+    pub fn message_type_mismatch(expected: &'static str, actual: &'static str) -> ProtobufError {
+        ProtobufError::MessageTypeMismatch { expected, actual }
+    }
 }
 
 impl fmt::Display for ProtobufError {
@@ -59,10 +83,18 @@ impl Error for ProtobufError {
                 WireError::UnexpectedEof => "unexpected EOF",
                 WireError::OverRecursionLimit => "over recursion limit",
                 WireError::TruncatedMessage => "truncated message",
+                WireError::TotalBytesLimitExceeded => "total bytes limit exceeded",
+                WireError::BorrowFromReader => "cannot borrow bytes from reader",
+                WireError::IncorrectIpAddrLength(..) => "incorrect IP address length",
+                WireError::IncorrectUuidLength(..) => "incorrect UUID length",
+                WireError::UnknownEnumValue { .. } => "unknown enum value",
+                WireError::RepeatedLenLimitExceeded => "repeated field length limit exceeded",
                 WireError::Other => "other error",
             },
             &ProtobufError::Utf8(ref e) => &e.description(),
             &ProtobufError::MessageNotInitialized { .. } => "not all message fields set",
+            &ProtobufError::MessageTypeMismatch { .. } => "message type mismatch",
+            &ProtobufError::FieldValueTypeMismatch { .. } => "field value type mismatch",
         }
     }
 
@@ -72,6 +104,8 @@ impl Error for ProtobufError {
             &ProtobufError::Utf8(ref e) => Some(e),
             &ProtobufError::WireError(..) => None,
             &ProtobufError::MessageNotInitialized { .. } => None,
+            &ProtobufError::MessageTypeMismatch { .. } => None,
+            &ProtobufError::FieldValueTypeMismatch { .. } => None,
         }
     }
 }
@@ -99,6 +133,14 @@ impl From<ProtobufError> for io::Error {
                 io::ErrorKind::InvalidInput,
                 ProtobufError::MessageNotInitialized { message: msg },
             ),
+            ProtobufError::MessageTypeMismatch { expected, actual } => io::Error::new(
+                io::ErrorKind::InvalidInput,
+                ProtobufError::MessageTypeMismatch { expected, actual },
+            ),
+            ProtobufError::FieldValueTypeMismatch { field } => io::Error::new(
+                io::ErrorKind::InvalidInput,
+                ProtobufError::FieldValueTypeMismatch { field },
+            ),
             e => io::Error::new(io::ErrorKind::Other, Box::new(e)),
         }
     }
@@ -5,8 +5,13 @@ mod base64;
 mod float;
 mod json_name;
 mod parse;
+#[cfg(feature = "with-serde-json")]
+mod parse_value;
 mod print;
 mod rfc_3339;
+#[cfg(feature = "with-serde")]
+mod serialize;
+mod stream;
 mod well_known_wrapper;
 
 pub(crate) use self::json_name::json_name;
@@ -16,7 +21,24 @@ pub use self::parse::parse_dynamic_from_str;
 pub use self::parse::parse_dynamic_from_str_with_options;
 pub use self::parse::parse_from_str;
 pub use self::parse::parse_from_str_with_options;
+pub use self::parse::ParseErrorWithLoc;
 pub use self::parse::ParseOptions;
+#[cfg(feature = "with-serde-json")]
+pub use self::parse_value::merge_from_value;
+#[cfg(feature = "with-serde-json")]
+pub use self::parse_value::merge_from_value_with_options;
+#[cfg(feature = "with-serde-json")]
+pub use self::parse_value::parse_dynamic_from_value_with_options;
+#[cfg(feature = "with-serde-json")]
+pub use self::parse_value::parse_from_value;
+#[cfg(feature = "with-serde-json")]
+pub use self::parse_value::parse_from_value_with_options;
 pub use self::print::print_to_string;
 pub use self::print::print_to_string_with_options;
 pub use self::print::PrintOptions;
+#[cfg(feature = "with-serde")]
+pub use self::serialize::serialize;
+pub use self::stream::StreamError;
+pub use self::stream::StreamReader;
+pub use self::stream::StreamResult;
+pub use self::stream::StreamWriter;
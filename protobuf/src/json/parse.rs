@@ -65,6 +65,9 @@ pub enum ParseError {
     ExpectingBool,
     ExpectingStrOrInt,
     ExpectingNumber,
+    ExpectingStr,
+    ExpectingList,
+    ExpectingObject,
     UnexpectedToken,
     AnyParsingIsNotImplemented,
     MessageNotInitialized,
@@ -102,8 +105,8 @@ impl From<rfc_3339::Rfc3339ParseError> for ParseError {
 
 #[derive(Debug)]
 pub struct ParseErrorWithLoc {
-    error: ParseError,
-    loc: Loc,
+    pub(super) error: ParseError,
+    pub(super) loc: Loc,
 }
 
 pub type ParseResult<A> = Result<A, ParseError>;
@@ -115,7 +118,7 @@ struct Parser<'a> {
     parse_options: ParseOptions,
 }
 
-trait FromJsonNumber: PartialEq + Sized {
+pub(super) trait FromJsonNumber: PartialEq + Sized {
     fn from_f64(v: f64) -> Self;
     fn to_f64(&self) -> f64;
     fn from_string(v: &str) -> ParseResult<Self>;
@@ -221,21 +224,134 @@ impl FromJsonNumber for f64 {
     }
 }
 
-impl<'a> Parser<'a> {
-    fn read_bool(&mut self) -> ParseResult<bool> {
-        if self.tokenizer.next_ident_if_eq("true")? {
-            Ok(true)
-        } else if self.tokenizer.next_ident_if_eq("false")? {
-            Ok(false)
+pub(super) fn parse_bool(s: &str) -> ParseResult<bool> {
+    if s == "true" {
+        Ok(true)
+    } else if s == "false" {
+        Ok(false)
+    } else {
+        Err(ParseError::ExpectingBool)
+    }
+}
+
+pub(super) fn parse_number<V: FromJsonNumber>(s: &str) -> ParseResult<V> {
+    V::from_string(s)
+}
+
+pub(super) fn parse_bytes(s: &str) -> ParseResult<Vec<u8>> {
+    Ok(base64::decode(s)?)
+}
+
+pub(super) fn parse_enum<'e>(
+    name: String,
+    descriptor: &'e EnumDescriptor,
+) -> ParseResult<&'e EnumValueDescriptor> {
+    // TODO: can map key be int
+    match descriptor.value_by_name(&name) {
+        Some(v) => Ok(v),
+        None => Err(ParseError::UnknownEnumVariantName(name)),
+    }
+}
+
+pub(super) fn parse_key(key: String, t: &RuntimeTypeDynamic) -> ParseResult<ReflectValueBox> {
+    let t = t.to_box();
+    match t {
+        RuntimeTypeBox::I32 => parse_number::<i32>(&key).map(ReflectValueBox::I32),
+        RuntimeTypeBox::U32 => parse_number::<u32>(&key).map(ReflectValueBox::U32),
+        RuntimeTypeBox::I64 | RuntimeTypeBox::U64 => {
+            Ok(ReflectValueBox::int64_from_str(&t, &key)?)
+        }
+        // technically f32 and f64 cannot be map keys
+        RuntimeTypeBox::F32 => parse_number::<f32>(&key).map(ReflectValueBox::F32),
+        RuntimeTypeBox::F64 => parse_number::<f64>(&key).map(ReflectValueBox::F64),
+        RuntimeTypeBox::Bool => parse_bool(&key).map(ReflectValueBox::from),
+        RuntimeTypeBox::String | RuntimeTypeBox::Chars => Ok(ReflectValueBox::String(key)),
+        RuntimeTypeBox::VecU8 | RuntimeTypeBox::CarllercheBytes => {
+            parse_bytes(&key).map(ReflectValueBox::Bytes)
+        }
+        RuntimeTypeBox::Enum(e) => parse_enum(key, e).map(ReflectValueBox::Enum),
+        RuntimeTypeBox::Message(_) => panic!("message cannot be a map key"),
+    }
+}
+
+pub(super) fn merge_wk_duration_from_str(duration: &mut Duration, s: &str) -> ParseResult<()> {
+    let mut lexer = Lexer::new(s, ParserLanguage::Json);
+
+    fn next_dec(lexer: &mut Lexer) -> ParseResult<(u64, u32)> {
+        let s = lexer.take_while(|c| c >= '0' && c <= '9');
+
+        if s.len() == 0 {
+            Ok((0, 0))
         } else {
-            Err(ParseError::ExpectingBool)
+            match s.parse() {
+                Ok(n) => Ok((n, s.len() as u32)),
+                Err(_) => Err(ParseError::IncorrectDuration),
+            }
         }
     }
 
-    fn parse_bool(&self, s: &str) -> ParseResult<bool> {
-        if s == "true" {
+    let minus = lexer.next_char_if_eq('-');
+    let seconds = match next_dec(&mut lexer)? {
+        (_, 0) => return Err(ParseError::IncorrectDuration),
+        (s, _) => s,
+    };
+    let nanos = if lexer.next_char_if_eq('.') {
+        let (mut a, mut b) = next_dec(&mut lexer)?;
+        if b > 9 {
+            return Err(ParseError::IncorrectDuration);
+        }
+        while b != 9 {
+            b += 1;
+            a *= 10;
+        }
+
+        if a > 999_999_999 {
+            return Err(ParseError::IncorrectDuration);
+        }
+
+        a
+    } else {
+        0
+    };
+
+    // The suffix "s" is required
+    if !lexer.next_char_if_eq('s') {
+        return Err(ParseError::IncorrectDuration);
+    }
+
+    if !lexer.eof() {
+        return Err(ParseError::IncorrectDuration);
+    }
+
+    if minus {
+        duration.seconds = -(seconds as i64);
+        duration.nanos = -(nanos as i32);
+    } else {
+        duration.seconds = seconds as i64;
+        duration.nanos = nanos as i32;
+    }
+    Ok(())
+}
+
+pub(super) fn merge_wk_timestamp_from_str(timestamp: &mut Timestamp, s: &str) -> ParseResult<()> {
+    let (seconds, nanos) = rfc_3339::TmUtc::parse_rfc_3339(s)?;
+    timestamp.seconds = seconds;
+    timestamp.nanos = nanos as i32;
+    Ok(())
+}
+
+pub(super) fn merge_wk_field_mask_from_str(field_mask: &mut FieldMask, s: &str) -> ParseResult<()> {
+    if !s.is_empty() {
+        field_mask.paths = s.split(',').map(|s| s.to_owned()).collect();
+    }
+    Ok(())
+}
+
+impl<'a> Parser<'a> {
+    fn read_bool(&mut self) -> ParseResult<bool> {
+        if self.tokenizer.next_ident_if_eq("true")? {
             Ok(true)
-        } else if s == "false" {
+        } else if self.tokenizer.next_ident_if_eq("false")? {
             Ok(false)
         } else {
             Err(ParseError::ExpectingBool)
@@ -254,16 +370,12 @@ impl<'a> Parser<'a> {
             V::from_string(&v.0)
         } else if self.tokenizer.lookahead_is_str_lit()? {
             let v = self.read_string()?;
-            self.parse_number(&v)
+            parse_number(&v)
         } else {
             Err(ParseError::ExpectingNumber)
         }
     }
 
-    fn parse_number<V: FromJsonNumber>(&self, s: &str) -> ParseResult<V> {
-        V::from_string(s)
-    }
-
     fn merge_wrapper<W>(&mut self, w: &mut W) -> ParseResult<()>
     where
         W: WellKnownWrapper,
@@ -329,11 +441,7 @@ impl<'a> Parser<'a> {
 
     fn read_bytes(&mut self) -> ParseResult<Vec<u8>> {
         let s = self.read_string()?;
-        self.parse_bytes(&s)
-    }
-
-    fn parse_bytes(&self, s: &str) -> ParseResult<Vec<u8>> {
-        Ok(base64::decode(s)?)
+        parse_bytes(&s)
     }
 
     fn read_enum<'e>(
@@ -346,7 +454,7 @@ impl<'a> Parser<'a> {
 
         if self.tokenizer.lookahead_is_str_lit()? {
             let name = self.read_string()?;
-            self.parse_enum(name, descriptor)
+            parse_enum(name, descriptor)
         } else if self.tokenizer.lookahead_is_json_number()? {
             let number = self.read_i32()?;
             match descriptor.value_by_number(number) {
@@ -359,18 +467,6 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_enum<'e>(
-        &self,
-        name: String,
-        descriptor: &'e EnumDescriptor,
-    ) -> ParseResult<&'e EnumValueDescriptor> {
-        // TODO: can map key be int
-        match descriptor.value_by_name(&name) {
-            Some(v) => Ok(v),
-            None => Err(ParseError::UnknownEnumVariantName(name)),
-        }
-    }
-
     fn read_wk_null_value(&mut self) -> ParseResult<NullValue> {
         self.tokenizer.next_ident_expect_eq("null")?;
         Ok(NullValue::NULL_VALUE)
@@ -490,25 +586,6 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
-    fn parse_key(&self, key: String, t: &RuntimeTypeDynamic) -> ParseResult<ReflectValueBox> {
-        match t.to_box() {
-            RuntimeTypeBox::I32 => self.parse_number::<i32>(&key).map(ReflectValueBox::I32),
-            RuntimeTypeBox::I64 => self.parse_number::<i64>(&key).map(ReflectValueBox::I64),
-            RuntimeTypeBox::U32 => self.parse_number::<u32>(&key).map(ReflectValueBox::U32),
-            RuntimeTypeBox::U64 => self.parse_number::<u64>(&key).map(ReflectValueBox::U64),
-            // technically f32 and f64 cannot be map keys
-            RuntimeTypeBox::F32 => self.parse_number::<f32>(&key).map(ReflectValueBox::F32),
-            RuntimeTypeBox::F64 => self.parse_number::<f64>(&key).map(ReflectValueBox::F64),
-            RuntimeTypeBox::Bool => self.parse_bool(&key).map(ReflectValueBox::from),
-            RuntimeTypeBox::String | RuntimeTypeBox::Chars => Ok(ReflectValueBox::String(key)),
-            RuntimeTypeBox::VecU8 | RuntimeTypeBox::CarllercheBytes => {
-                self.parse_bytes(&key).map(ReflectValueBox::Bytes)
-            }
-            RuntimeTypeBox::Enum(e) => self.parse_enum(key, e).map(ReflectValueBox::Enum),
-            RuntimeTypeBox::Message(_) => panic!("message cannot be a map key"),
-        }
-    }
-
     fn merge_map_field(
         &mut self,
         message: &mut Message,
@@ -520,7 +597,7 @@ impl<'a> Parser<'a> {
         map.clear();
 
         self.read_map(
-            |ss, s| ss.parse_key(s, kt),
+            |_, s| parse_key(s, kt),
             |s, k| {
                 let v = s.read_value(vt)?;
                 map.insert(k, v);
@@ -665,78 +742,17 @@ impl<'a> Parser<'a> {
 
     fn merge_wk_duration(&mut self, duration: &mut Duration) -> ParseResult<()> {
         let s = self.read_string()?;
-        let mut lexer = Lexer::new(&s, ParserLanguage::Json);
-
-        fn next_dec(lexer: &mut Lexer) -> ParseResult<(u64, u32)> {
-            let s = lexer.take_while(|c| c >= '0' && c <= '9');
-
-            if s.len() == 0 {
-                Ok((0, 0))
-            } else {
-                match s.parse() {
-                    Ok(n) => Ok((n, s.len() as u32)),
-                    Err(_) => Err(ParseError::IncorrectDuration),
-                }
-            }
-        }
-
-        let minus = lexer.next_char_if_eq('-');
-        let seconds = match next_dec(&mut lexer)? {
-            (_, 0) => return Err(ParseError::IncorrectDuration),
-            (s, _) => s,
-        };
-        let nanos = if lexer.next_char_if_eq('.') {
-            let (mut a, mut b) = next_dec(&mut lexer)?;
-            if b > 9 {
-                return Err(ParseError::IncorrectDuration);
-            }
-            while b != 9 {
-                b += 1;
-                a *= 10;
-            }
-
-            if a > 999_999_999 {
-                return Err(ParseError::IncorrectDuration);
-            }
-
-            a
-        } else {
-            0
-        };
-
-        // The suffix "s" is required
-        if !lexer.next_char_if_eq('s') {
-            return Err(ParseError::IncorrectDuration);
-        }
-
-        if !lexer.eof() {
-            return Err(ParseError::IncorrectDuration);
-        }
-
-        if minus {
-            duration.seconds = -(seconds as i64);
-            duration.nanos = -(nanos as i32);
-        } else {
-            duration.seconds = seconds as i64;
-            duration.nanos = nanos as i32;
-        }
-        Ok(())
+        merge_wk_duration_from_str(duration, &s)
     }
 
     fn merge_wk_timestamp(&mut self, timestamp: &mut Timestamp) -> ParseResult<()> {
         let s = self.read_string()?;
-        let (seconds, nanos) = rfc_3339::TmUtc::parse_rfc_3339(&s)?;
-        timestamp.seconds = seconds;
-        timestamp.nanos = nanos as i32;
-        Ok(())
+        merge_wk_timestamp_from_str(timestamp, &s)
     }
 
     fn merge_wk_field_mask(&mut self, field_mask: &mut FieldMask) -> ParseResult<()> {
         let s = self.read_string()?;
-        if !s.is_empty() {
-            field_mask.paths = s.split(',').map(|s| s.to_owned()).collect();
-        }
-        Ok(())
+        merge_wk_field_mask_from_str(field_mask, &s)
     }
 
     fn read_wk_list_value(&mut self) -> ParseResult<ListValue> {
@@ -0,0 +1,372 @@
+//! Convert `serde_json::Value` tree to protobuf message
+//! using the same JSON mapping as string parser.
+
+use serde_json;
+
+use enums::ProtobufEnum;
+use reflect::EnumDescriptor;
+use reflect::EnumValueDescriptor;
+use reflect::FieldDescriptor;
+use reflect::MessageDescriptor;
+use reflect::ReflectValueBox;
+use reflect::RuntimeFieldType;
+use reflect::RuntimeTypeBox;
+use reflect::RuntimeTypeDynamic;
+use text_format::lexer::Loc;
+use Message;
+
+use super::parse::merge_wk_duration_from_str;
+use super::parse::merge_wk_field_mask_from_str;
+use super::parse::merge_wk_timestamp_from_str;
+use super::parse::parse_bytes;
+use super::parse::parse_enum;
+use super::parse::parse_key;
+use super::parse::FromJsonNumber;
+use super::parse::ParseError;
+use super::parse::ParseErrorWithLoc;
+use super::parse::ParseOptions;
+use super::parse::ParseResult;
+use super::parse::ParseWithLocResult;
+
+use json::well_known_wrapper::WellKnownWrapper;
+use well_known_types::Any;
+use well_known_types::BoolValue;
+use well_known_types::BytesValue;
+use well_known_types::DoubleValue;
+use well_known_types::FloatValue;
+use well_known_types::Int32Value;
+use well_known_types::Int64Value;
+use well_known_types::ListValue;
+use well_known_types::NullValue;
+use well_known_types::StringValue;
+use well_known_types::Struct;
+use well_known_types::UInt32Value;
+use well_known_types::UInt64Value;
+use well_known_types::Value;
+use well_known_types::value;
+
+struct ValueParser<'a> {
+    parse_options: &'a ParseOptions,
+}
+
+impl<'a> ValueParser<'a> {
+    fn read_bool(&self, json: &serde_json::Value) -> ParseResult<bool> {
+        match *json {
+            serde_json::Value::Bool(b) => Ok(b),
+            _ => Err(ParseError::ExpectingBool),
+        }
+    }
+
+    fn read_number<V: FromJsonNumber>(&self, json: &serde_json::Value) -> ParseResult<V> {
+        match *json {
+            serde_json::Value::Number(ref n) => V::from_string(&n.to_string()),
+            serde_json::Value::String(ref s) => V::from_string(s),
+            _ => Err(ParseError::ExpectingNumber),
+        }
+    }
+
+    fn read_str<'j>(&self, json: &'j serde_json::Value) -> ParseResult<&'j str> {
+        match *json {
+            serde_json::Value::String(ref s) => Ok(s),
+            _ => Err(ParseError::ExpectingStr),
+        }
+    }
+
+    fn read_string(&self, json: &serde_json::Value) -> ParseResult<String> {
+        self.read_str(json).map(|s| s.to_owned())
+    }
+
+    fn read_bytes(&self, json: &serde_json::Value) -> ParseResult<Vec<u8>> {
+        parse_bytes(self.read_str(json)?)
+    }
+
+    fn read_enum<'e>(
+        &self,
+        json: &serde_json::Value,
+        descriptor: &'e EnumDescriptor,
+    ) -> ParseResult<&'e EnumValueDescriptor> {
+        if descriptor.is::<NullValue>() {
+            return Ok(self.read_wk_null_value(json)?.descriptor());
+        }
+
+        match *json {
+            serde_json::Value::String(ref name) => parse_enum(name.clone(), descriptor),
+            serde_json::Value::Number(..) => {
+                let number = self.read_number::<i32>(json)?;
+                match descriptor.value_by_number(number) {
+                    Some(v) => Ok(v),
+                    None => Err(ParseError::UnknownEnumVariantNumber(number)),
+                }
+            }
+            _ => Err(ParseError::ExpectingStrOrInt),
+        }
+    }
+
+    fn read_wk_null_value(&self, json: &serde_json::Value) -> ParseResult<NullValue> {
+        match *json {
+            serde_json::Value::Null => Ok(NullValue::NULL_VALUE),
+            _ => Err(ParseError::UnexpectedToken),
+        }
+    }
+
+    fn read_message(
+        &self,
+        json: &serde_json::Value,
+        descriptor: &MessageDescriptor,
+    ) -> ParseResult<Box<Message>> {
+        let mut m = descriptor.new_instance();
+        self.merge_inner(json, &mut *m)?;
+        Ok(m)
+    }
+
+    fn read_value(
+        &self,
+        json: &serde_json::Value,
+        t: &RuntimeTypeDynamic,
+    ) -> ParseResult<ReflectValueBox> {
+        match t.to_box() {
+            RuntimeTypeBox::I32 => self.read_number::<i32>(json).map(ReflectValueBox::from),
+            RuntimeTypeBox::I64 => self.read_number::<i64>(json).map(ReflectValueBox::from),
+            RuntimeTypeBox::U32 => self.read_number::<u32>(json).map(ReflectValueBox::from),
+            RuntimeTypeBox::U64 => self.read_number::<u64>(json).map(ReflectValueBox::from),
+            RuntimeTypeBox::F32 => self.read_number::<f32>(json).map(ReflectValueBox::from),
+            RuntimeTypeBox::F64 => self.read_number::<f64>(json).map(ReflectValueBox::from),
+            RuntimeTypeBox::Bool => self.read_bool(json).map(ReflectValueBox::from),
+            RuntimeTypeBox::String | RuntimeTypeBox::Chars => {
+                self.read_string(json).map(ReflectValueBox::from)
+            }
+            RuntimeTypeBox::VecU8 | RuntimeTypeBox::CarllercheBytes => {
+                self.read_bytes(json).map(ReflectValueBox::from)
+            }
+            RuntimeTypeBox::Enum(e) => self.read_enum(json, e).map(ReflectValueBox::from),
+            RuntimeTypeBox::Message(m) => self.read_message(json, m).map(ReflectValueBox::from),
+        }
+    }
+
+    // `null` is accepted as empty list
+    fn read_list<'j>(&self, json: &'j serde_json::Value) -> ParseResult<&'j [serde_json::Value]> {
+        match *json {
+            serde_json::Value::Null => Ok(&[]),
+            serde_json::Value::Array(ref items) => Ok(items),
+            _ => Err(ParseError::ExpectingList),
+        }
+    }
+
+    // `null` is accepted as empty map
+    fn read_map<'j>(
+        &self,
+        json: &'j serde_json::Value,
+    ) -> ParseResult<Vec<(&'j String, &'j serde_json::Value)>> {
+        match *json {
+            serde_json::Value::Null => Ok(Vec::new()),
+            serde_json::Value::Object(ref map) => Ok(map.iter().collect()),
+            _ => Err(ParseError::ExpectingObject),
+        }
+    }
+
+    fn merge_field(
+        &self,
+        json: &serde_json::Value,
+        message: &mut Message,
+        field: &FieldDescriptor,
+    ) -> ParseResult<()> {
+        match field.runtime_field_type() {
+            RuntimeFieldType::Singular(t) => {
+                field.set_singular_field(message, self.read_value(json, t)?);
+            }
+            RuntimeFieldType::Repeated(t) => {
+                let mut repeated = field.mut_repeated(message);
+                repeated.clear();
+                for item in self.read_list(json)? {
+                    repeated.push(self.read_value(item, t)?);
+                }
+            }
+            RuntimeFieldType::Map(kt, vt) => {
+                let mut map = field.mut_map(message);
+                map.clear();
+                for (k, v) in self.read_map(json)? {
+                    map.insert(parse_key(k.clone(), kt)?, self.read_value(v, vt)?);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn merge_wrapper<W>(&self, json: &serde_json::Value, w: &mut W) -> ParseResult<()>
+    where
+        W: WellKnownWrapper,
+        W::Underlying: FromJsonNumber,
+    {
+        *w.get_mut() = self.read_number(json)?;
+        Ok(())
+    }
+
+    fn read_wk_value(&self, json: &serde_json::Value) -> ParseResult<Value> {
+        let mut value = Value::new();
+        value.kind = Some(match *json {
+            serde_json::Value::Null => value::Kind::null_value(NullValue::NULL_VALUE.into()),
+            serde_json::Value::Bool(b) => value::Kind::bool_value(b),
+            serde_json::Value::Number(..) => value::Kind::number_value(self.read_number(json)?),
+            serde_json::Value::String(ref s) => value::Kind::string_value(s.clone()),
+            serde_json::Value::Array(..) => value::Kind::list_value(self.read_wk_list_value(json)?),
+            serde_json::Value::Object(..) => value::Kind::struct_value(self.read_wk_struct(json)?),
+        });
+        Ok(value)
+    }
+
+    fn read_wk_list_value(&self, json: &serde_json::Value) -> ParseResult<ListValue> {
+        let mut list = ListValue::new();
+        for item in self.read_list(json)? {
+            list.values.push(self.read_wk_value(item)?);
+        }
+        Ok(list)
+    }
+
+    fn read_wk_struct(&self, json: &serde_json::Value) -> ParseResult<Struct> {
+        let mut struct_value = Struct::new();
+        for (k, v) in self.read_map(json)? {
+            struct_value.fields.insert(k.clone(), self.read_wk_value(v)?);
+        }
+        Ok(struct_value)
+    }
+
+    fn merge_inner(&self, json: &serde_json::Value, message: &mut Message) -> ParseResult<()> {
+        if let Some(duration) = message.downcast_mut() {
+            return merge_wk_duration_from_str(duration, self.read_str(json)?);
+        }
+
+        if let Some(timestamp) = message.downcast_mut() {
+            return merge_wk_timestamp_from_str(timestamp, self.read_str(json)?);
+        }
+
+        if let Some(field_mask) = message.downcast_mut() {
+            return merge_wk_field_mask_from_str(field_mask, self.read_str(json)?);
+        }
+
+        if let Some(value) = message.downcast_mut::<Value>() {
+            *value = self.read_wk_value(json)?;
+            return Ok(());
+        }
+
+        if let Some(_) = message.downcast_mut::<Any>() {
+            return Err(ParseError::AnyParsingIsNotImplemented);
+        }
+
+        if let Some(value) = message.downcast_mut::<DoubleValue>() {
+            return self.merge_wrapper(json, value);
+        }
+
+        if let Some(value) = message.downcast_mut::<FloatValue>() {
+            return self.merge_wrapper(json, value);
+        }
+
+        if let Some(value) = message.downcast_mut::<Int64Value>() {
+            return self.merge_wrapper(json, value);
+        }
+
+        if let Some(value) = message.downcast_mut::<UInt64Value>() {
+            return self.merge_wrapper(json, value);
+        }
+
+        if let Some(value) = message.downcast_mut::<Int32Value>() {
+            return self.merge_wrapper(json, value);
+        }
+
+        if let Some(value) = message.downcast_mut::<UInt32Value>() {
+            return self.merge_wrapper(json, value);
+        }
+
+        if let Some(value) = message.downcast_mut::<BoolValue>() {
+            value.value = self.read_bool(json)?;
+            return Ok(());
+        }
+
+        if let Some(value) = message.downcast_mut::<StringValue>() {
+            value.value = self.read_string(json)?;
+            return Ok(());
+        }
+
+        if let Some(value) = message.downcast_mut::<BytesValue>() {
+            value.value = self.read_bytes(json)?;
+            return Ok(());
+        }
+
+        if let Some(value) = message.downcast_mut::<ListValue>() {
+            *value = self.read_wk_list_value(json)?;
+            return Ok(());
+        }
+
+        if let Some(value) = message.downcast_mut::<Struct>() {
+            *value = self.read_wk_struct(json)?;
+            return Ok(());
+        }
+
+        let descriptor = message.descriptor();
+
+        let fields = match *json {
+            serde_json::Value::Object(ref map) => map,
+            _ => return Err(ParseError::ExpectingObject),
+        };
+        for (field_name, field_value) in fields {
+            // Proto3 JSON parsers are required to accept both
+            // the converted `lowerCamelCase` name and the proto field name.
+            match descriptor.field_by_name_or_json_name(field_name) {
+                Some(field) => self.merge_field(field_value, message, field)?,
+                None if self.parse_options.ignore_unknown_fields => {}
+                None => return Err(ParseError::UnknownFieldName(field_name.clone())),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn without_loc(error: ParseError) -> ParseErrorWithLoc {
+    // value tree has no source locations
+    ParseErrorWithLoc {
+        error,
+        loc: Loc::start(),
+    }
+}
+
+/// Merge `serde_json::Value` into provided message
+pub fn merge_from_value_with_options(
+    message: &mut Message,
+    json: &serde_json::Value,
+    parse_options: &ParseOptions,
+) -> ParseWithLocResult<()> {
+    let parser = ValueParser { parse_options };
+    parser.merge_inner(json, message).map_err(without_loc)
+}
+
+/// Merge `serde_json::Value` into provided message
+pub fn merge_from_value(message: &mut Message, json: &serde_json::Value) -> ParseWithLocResult<()> {
+    merge_from_value_with_options(message, json, &ParseOptions::default())
+}
+
+/// Convert `serde_json::Value` to protobuf message.
+pub fn parse_dynamic_from_value_with_options(
+    d: &MessageDescriptor,
+    json: &serde_json::Value,
+    parse_options: &ParseOptions,
+) -> ParseWithLocResult<Box<Message>> {
+    let mut m = d.new_instance();
+    merge_from_value_with_options(&mut *m, json, parse_options)?;
+    if let Err(_) = m.check_initialized() {
+        return Err(without_loc(ParseError::MessageNotInitialized));
+    }
+    Ok(m)
+}
+
+/// Convert `serde_json::Value` to protobuf message.
+pub fn parse_from_value_with_options<M: Message>(
+    json: &serde_json::Value,
+    parse_options: &ParseOptions,
+) -> ParseWithLocResult<M> {
+    let m = parse_dynamic_from_value_with_options(&M::descriptor_static(), json, parse_options)?;
+    Ok(*m.downcast_box().unwrap())
+}
+
+/// Convert `serde_json::Value` to protobuf message.
+pub fn parse_from_value<M: Message>(json: &serde_json::Value) -> ParseWithLocResult<M> {
+    parse_from_value_with_options(json, &ParseOptions::default())
+}
@@ -194,9 +194,11 @@ impl<'a> PrintableToJson for ReflectValueRef<'a> {
     fn print_to_json(&self, w: &mut Printer) -> PrintResult<()> {
         match self {
             ReflectValueRef::U32(v) => w.print_printable(v),
-            ReflectValueRef::U64(v) => w.print_printable(v),
             ReflectValueRef::I32(v) => w.print_printable(v),
-            ReflectValueRef::I64(v) => w.print_printable(v),
+            ReflectValueRef::U64(_) | ReflectValueRef::I64(_) => {
+                // 64-bit integers are quoted
+                w.print_printable::<str>(&self.to_int64_string().unwrap())
+            }
             ReflectValueRef::F32(v) => w.print_printable(v),
             ReflectValueRef::F64(v) => w.print_printable(v),
             ReflectValueRef::Bool(v) => w.print_printable(v),
@@ -208,26 +210,33 @@ impl<'a> PrintableToJson for ReflectValueRef<'a> {
     }
 }
 
+pub(super) fn duration_to_json_string(duration: &Duration) -> String {
+    let sign = if duration.seconds >= 0 { "" } else { "-" };
+    format!(
+        "{}{}.{:09}s",
+        sign,
+        duration.seconds.abs(),
+        duration.nanos.abs()
+    )
+}
+
+pub(super) fn timestamp_to_json_string(timestamp: &Timestamp) -> PrintResult<String> {
+    if timestamp.nanos < 0 {
+        return Err(PrintError::TimestampNegativeNanos);
+    }
+    let tm_utc = TmUtc::from_protobuf_timestamp(timestamp.seconds, timestamp.nanos as u32);
+    Ok(tm_utc.to_string())
+}
+
 impl PrintableToJson for Duration {
     fn print_to_json(&self, w: &mut Printer) -> PrintResult<()> {
-        let sign = if self.seconds >= 0 { "" } else { "-" };
-        Ok(write!(
-            w.buf,
-            "\"{}{}.{:09}s\"",
-            sign,
-            self.seconds.abs(),
-            self.nanos.abs()
-        )?)
+        w.print_printable(&duration_to_json_string(self))
     }
 }
 
 impl PrintableToJson for Timestamp {
     fn print_to_json(&self, w: &mut Printer) -> PrintResult<()> {
-        if self.nanos < 0 {
-            return Err(PrintError::TimestampNegativeNanos);
-        }
-        let tm_utc = TmUtc::from_protobuf_timestamp(self.seconds, self.nanos as u32);
-        w.print_printable(&tm_utc.to_string())
+        w.print_printable(&timestamp_to_json_string(self)?)
     }
 }
 
@@ -288,9 +297,9 @@ impl<'a> ObjectKey for ReflectValueRef<'a> {
         match self {
             ReflectValueRef::String(v) => return w.print_printable::<str>(v),
             ReflectValueRef::Bytes(v) => return w.print_printable::<[u8]>(v),
-            // do not quote, because printable is quoted
-            ReflectValueRef::U64(v) => return w.print_printable(v),
-            ReflectValueRef::I64(v) => return w.print_printable(v),
+            ReflectValueRef::U64(_) | ReflectValueRef::I64(_) => {
+                return w.print_printable::<str>(&self.to_int64_string().unwrap())
+            }
             ReflectValueRef::Enum(v) if !w.print_options.enum_values_int => return w.print_enum(v),
             _ => {}
         }
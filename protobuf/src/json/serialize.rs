@@ -0,0 +1,252 @@
+//! `serde::Serialize` implementation which follows JSON mapping,
+//! so serialized message is the same as printed with `print_to_string`.
+
+use serde::ser::Error;
+use serde::ser::SerializeMap;
+use serde::ser::SerializeSeq;
+use serde::Serialize;
+use serde::Serializer;
+
+use json::base64;
+use json::float;
+use json::print::duration_to_json_string;
+use json::print::timestamp_to_json_string;
+use reflect::EnumValueDescriptor;
+use reflect::ReflectFieldRef;
+use reflect::ReflectMapRef;
+use reflect::ReflectRepeatedRef;
+use reflect::ReflectValueRef;
+use Message;
+
+use well_known_types::value;
+use well_known_types::Any;
+use well_known_types::BoolValue;
+use well_known_types::BytesValue;
+use well_known_types::DoubleValue;
+use well_known_types::Duration;
+use well_known_types::FieldMask;
+use well_known_types::FloatValue;
+use well_known_types::Int32Value;
+use well_known_types::Int64Value;
+use well_known_types::ListValue;
+use well_known_types::NullValue;
+use well_known_types::StringValue;
+use well_known_types::Struct;
+use well_known_types::Timestamp;
+use well_known_types::UInt32Value;
+use well_known_types::UInt64Value;
+use well_known_types::Value;
+
+/// Serialize message with serde using protobuf JSON mapping
+/// (`lowerCamelCase` field names, 64-bit integers as strings, enums as names,
+/// special representation of well-known types).
+///
+/// Can be used to implement `serde::Serialize` for generated messages,
+/// see `json_serialize` codegen option.
+pub fn serialize<S: Serializer>(message: &Message, serializer: S) -> Result<S::Ok, S::Error> {
+    SerializeMessage(message).serialize(serializer)
+}
+
+struct SerializeMessage<'a>(&'a Message);
+
+struct SerializeValue<'a>(ReflectValueRef<'a>);
+
+struct SerializeRepeated<'a>(ReflectRepeatedRef<'a>);
+
+struct SerializeMapField<'a>(ReflectMapRef<'a>);
+
+struct SerializeWkValue<'a>(&'a Value);
+
+struct SerializeWkListValue<'a>(&'a ListValue);
+
+struct SerializeWkStruct<'a>(&'a Struct);
+
+fn serialize_f64<S: Serializer>(v: f64, serializer: S) -> Result<S::Ok, S::Error> {
+    if v.is_nan() {
+        serializer.serialize_str(float::PROTOBUF_JSON_NAN)
+    } else if v.is_infinite() && v > 0.0 {
+        serializer.serialize_str(float::PROTOBUF_JSON_INF)
+    } else if v.is_infinite() {
+        serializer.serialize_str(float::PROTOBUF_JSON_MINUS_INF)
+    } else {
+        serializer.serialize_f64(v)
+    }
+}
+
+fn serialize_f32<S: Serializer>(v: f32, serializer: S) -> Result<S::Ok, S::Error> {
+    if v.is_finite() {
+        serializer.serialize_f32(v)
+    } else {
+        serialize_f64(v as f64, serializer)
+    }
+}
+
+fn serialize_enum<S: Serializer>(
+    value: &EnumValueDescriptor,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if let Some(NullValue::NULL_VALUE) = value.cast() {
+        serializer.serialize_unit()
+    } else {
+        serializer.serialize_str(value.name())
+    }
+}
+
+// JSON object keys are always strings
+fn map_key_to_string(key: &ReflectValueRef) -> String {
+    match *key {
+        ReflectValueRef::U32(v) => v.to_string(),
+        ReflectValueRef::U64(v) => v.to_string(),
+        ReflectValueRef::I32(v) => v.to_string(),
+        ReflectValueRef::I64(v) => v.to_string(),
+        ReflectValueRef::Bool(v) => v.to_string(),
+        ReflectValueRef::String(v) => v.to_owned(),
+        ReflectValueRef::Bytes(v) => base64::encode(v),
+        ReflectValueRef::Enum(v) => v.name().to_owned(),
+        ReflectValueRef::F32(_) | ReflectValueRef::F64(_) | ReflectValueRef::Message(_) => {
+            panic!("cannot be object key")
+        }
+    }
+}
+
+impl<'a> Serialize for SerializeValue<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            ReflectValueRef::U32(v) => serializer.serialize_u32(v),
+            ReflectValueRef::I32(v) => serializer.serialize_i32(v),
+            // 64-bit integers are quoted
+            ReflectValueRef::U64(v) => serializer.serialize_str(&v.to_string()),
+            ReflectValueRef::I64(v) => serializer.serialize_str(&v.to_string()),
+            ReflectValueRef::F32(v) => serialize_f32(v, serializer),
+            ReflectValueRef::F64(v) => serialize_f64(v, serializer),
+            ReflectValueRef::Bool(v) => serializer.serialize_bool(v),
+            ReflectValueRef::String(v) => serializer.serialize_str(v),
+            ReflectValueRef::Bytes(v) => serializer.serialize_str(&base64::encode(v)),
+            ReflectValueRef::Enum(v) => serialize_enum(v, serializer),
+            ReflectValueRef::Message(v) => SerializeMessage(v).serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for SerializeRepeated<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for v in &self.0 {
+            seq.serialize_element(&SerializeValue(v))?;
+        }
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for SerializeMapField<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (k, v) in &self.0 {
+            map.serialize_entry(&map_key_to_string(&k), &SerializeValue(v))?;
+        }
+        map.end()
+    }
+}
+
+impl<'a> Serialize for SerializeWkValue<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0.kind {
+            // None should not be possible here, but it's better to serialize null than crash
+            None => serializer.serialize_unit(),
+            Some(value::Kind::null_value(_)) => serializer.serialize_unit(),
+            Some(value::Kind::bool_value(b)) => serializer.serialize_bool(b),
+            Some(value::Kind::number_value(n)) => serialize_f64(n, serializer),
+            Some(value::Kind::string_value(ref s)) => serializer.serialize_str(s),
+            Some(value::Kind::struct_value(ref s)) => SerializeWkStruct(s).serialize(serializer),
+            Some(value::Kind::list_value(ref l)) => SerializeWkListValue(l).serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for SerializeWkListValue<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.values.len()))?;
+        for v in &self.0.values {
+            seq.serialize_element(&SerializeWkValue(v))?;
+        }
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for SerializeWkStruct<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.fields.len()))?;
+        for (k, v) in &self.0.fields {
+            map.serialize_entry(k, &SerializeWkValue(v))?;
+        }
+        map.end()
+    }
+}
+
+impl<'a> SerializeMessage<'a> {
+    fn serialize_regular<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let message = self.0;
+        let mut map = serializer.serialize_map(None)?;
+        for field in message.descriptor().fields() {
+            match field.get_reflect(message) {
+                ReflectFieldRef::Optional(None) => {}
+                ReflectFieldRef::Optional(Some(v)) => {
+                    map.serialize_entry(field.json_name(), &SerializeValue(v))?;
+                }
+                ReflectFieldRef::Repeated(v) => {
+                    if !v.is_empty() {
+                        map.serialize_entry(field.json_name(), &SerializeRepeated(v))?;
+                    }
+                }
+                ReflectFieldRef::Map(v) => {
+                    if !v.is_empty() {
+                        map.serialize_entry(field.json_name(), &SerializeMapField(v))?;
+                    }
+                }
+            }
+        }
+        map.end()
+    }
+
+    // Well-known wrapper types are serialized as their single `value` field
+    fn serialize_wrapper<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let field = self.0.descriptor().field_by_name("value").unwrap();
+        SerializeValue(field.get_singular_field_or_default(self.0)).serialize(serializer)
+    }
+}
+
+impl<'a> Serialize for SerializeMessage<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let message = self.0;
+        if let Some(duration) = message.downcast_ref::<Duration>() {
+            serializer.serialize_str(&duration_to_json_string(duration))
+        } else if let Some(timestamp) = message.downcast_ref::<Timestamp>() {
+            let s = timestamp_to_json_string(timestamp)
+                .map_err(|e| S::Error::custom(format!("{:?}", e)))?;
+            serializer.serialize_str(&s)
+        } else if let Some(field_mask) = message.downcast_ref::<FieldMask>() {
+            serializer.serialize_str(&field_mask.paths.join(","))
+        } else if message.downcast_ref::<Any>().is_some() {
+            Err(S::Error::custom("Any serialization is not implemented"))
+        } else if let Some(value) = message.downcast_ref::<Value>() {
+            SerializeWkValue(value).serialize(serializer)
+        } else if let Some(value) = message.downcast_ref::<ListValue>() {
+            SerializeWkListValue(value).serialize(serializer)
+        } else if let Some(value) = message.downcast_ref::<Struct>() {
+            SerializeWkStruct(value).serialize(serializer)
+        } else if message.downcast_ref::<DoubleValue>().is_some()
+            || message.downcast_ref::<FloatValue>().is_some()
+            || message.downcast_ref::<Int64Value>().is_some()
+            || message.downcast_ref::<UInt64Value>().is_some()
+            || message.downcast_ref::<Int32Value>().is_some()
+            || message.downcast_ref::<UInt32Value>().is_some()
+            || message.downcast_ref::<BoolValue>().is_some()
+            || message.downcast_ref::<StringValue>().is_some()
+            || message.downcast_ref::<BytesValue>().is_some()
+        {
+            self.serialize_wrapper(serializer)
+        } else {
+            self.serialize_regular(serializer)
+        }
+    }
+}
@@ -0,0 +1,380 @@
+//! Streaming of JSON arrays of messages (`[obj, obj, ...]`)
+//! without holding the whole array in memory.
+
+use std::error;
+use std::fmt;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::marker;
+use std::str;
+
+use json::parse::parse_from_str_with_options;
+use json::parse::ParseErrorWithLoc;
+use json::parse::ParseOptions;
+use json::print::print_to_string_with_options;
+use json::print::PrintError;
+use json::print::PrintOptions;
+use Message;
+
+/// Error of streaming JSON reader or writer.
+#[derive(Debug)]
+pub enum StreamError {
+    /// Error reading or writing underlying stream
+    Io(io::Error),
+    /// Message cannot be printed as JSON
+    Print(PrintError),
+    /// Array element is not a valid JSON of message
+    Parse(ParseErrorWithLoc),
+    /// Array element is not valid UTF-8
+    Utf8(str::Utf8Error),
+    /// Input does not start with `[`
+    ExpectingArrayStart,
+    /// Array elements must be separated with `,`
+    ExpectingCommaOrArrayEnd,
+    /// Non-whitespace input after final `]`
+    TrailingData,
+    /// Input ends before final `]`
+    UnexpectedEof,
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &StreamError::Io(ref e) => write!(f, "{}", e),
+            &StreamError::Print(ref e) => write!(f, "failed to print message: {:?}", e),
+            &StreamError::Parse(ref e) => write!(f, "failed to parse message: {:?}", e),
+            &StreamError::Utf8(ref e) => write!(f, "{}", e),
+            &StreamError::ExpectingArrayStart => write!(f, "expecting `[`"),
+            &StreamError::ExpectingCommaOrArrayEnd => write!(f, "expecting `,` or `]`"),
+            &StreamError::TrailingData => write!(f, "trailing data after `]`"),
+            &StreamError::UnexpectedEof => write!(f, "unexpected EOF"),
+        }
+    }
+}
+
+impl error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            &StreamError::Io(ref e) => Some(e),
+            &StreamError::Utf8(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<PrintError> for StreamError {
+    fn from(e: PrintError) -> Self {
+        StreamError::Print(e)
+    }
+}
+
+impl From<ParseErrorWithLoc> for StreamError {
+    fn from(e: ParseErrorWithLoc) -> Self {
+        StreamError::Parse(e)
+    }
+}
+
+impl From<str::Utf8Error> for StreamError {
+    fn from(e: str::Utf8Error) -> Self {
+        StreamError::Utf8(e)
+    }
+}
+
+pub type StreamResult<T> = Result<T, StreamError>;
+
+/// Write messages as JSON array incrementally.
+///
+/// `finish` must be called to write the closing `]`.
+pub struct StreamWriter<W: Write> {
+    write: W,
+    print_options: PrintOptions,
+    count: u64,
+}
+
+impl<W: Write> StreamWriter<W> {
+    pub fn new(write: W) -> StreamWriter<W> {
+        StreamWriter::with_options(write, &PrintOptions::default())
+    }
+
+    pub fn with_options(write: W, print_options: &PrintOptions) -> StreamWriter<W> {
+        StreamWriter {
+            write,
+            print_options: print_options.clone(),
+            count: 0,
+        }
+    }
+
+    /// Write next array element.
+    pub fn write_message(&mut self, message: &Message) -> StreamResult<()> {
+        let json = print_to_string_with_options(message, &self.print_options)?;
+        let sep: &[u8] = if self.count == 0 { b"[" } else { b", " };
+        self.write.write_all(sep)?;
+        self.write.write_all(json.as_bytes())?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Write the closing `]` and return underlying writer.
+    pub fn finish(mut self) -> StreamResult<W> {
+        let end: &[u8] = if self.count == 0 { b"[]" } else { b"]" };
+        self.write.write_all(end)?;
+        self.write.flush()?;
+        Ok(self.write)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum ReaderState {
+    BeforeArray,
+    FirstElement,
+    NextElement,
+    Done,
+}
+
+/// Read JSON array of messages incrementally.
+///
+/// Each array element is parsed when iterator is advanced.
+pub struct StreamReader<R: Read, M: Message> {
+    read: BufReader<R>,
+    parse_options: ParseOptions,
+    state: ReaderState,
+    marker: marker::PhantomData<M>,
+}
+
+impl<R: Read, M: Message> StreamReader<R, M> {
+    pub fn new(read: R) -> StreamReader<R, M> {
+        StreamReader::with_options(read, &ParseOptions::default())
+    }
+
+    pub fn with_options(read: R, parse_options: &ParseOptions) -> StreamReader<R, M> {
+        StreamReader {
+            read: BufReader::new(read),
+            parse_options: parse_options.clone(),
+            state: ReaderState::BeforeArray,
+            marker: marker::PhantomData,
+        }
+    }
+
+    fn peek_byte(&mut self) -> StreamResult<Option<u8>> {
+        Ok(self.read.fill_buf()?.first().cloned())
+    }
+
+    fn next_byte(&mut self) -> StreamResult<u8> {
+        match self.peek_byte()? {
+            Some(b) => {
+                self.read.consume(1);
+                Ok(b)
+            }
+            None => Err(StreamError::UnexpectedEof),
+        }
+    }
+
+    fn skip_ws(&mut self) -> StreamResult<Option<u8>> {
+        loop {
+            match self.peek_byte()? {
+                Some(b' ') | Some(b'\t') | Some(b'\r') | Some(b'\n') => self.read.consume(1),
+                b => return Ok(b),
+            }
+        }
+    }
+
+    /// Read bytes of single JSON value without parsing it.
+    fn read_value_bytes(&mut self) -> StreamResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        let mut depth = 0u32;
+        let mut in_str = false;
+        let mut escape = false;
+        loop {
+            if !in_str && depth == 0 && !bytes.is_empty() {
+                match self.peek_byte()? {
+                    None
+                    | Some(b',')
+                    | Some(b']')
+                    | Some(b' ')
+                    | Some(b'\t')
+                    | Some(b'\r')
+                    | Some(b'\n') => return Ok(bytes),
+                    Some(_) => {}
+                }
+                // complete string, object or array
+                match bytes[0] {
+                    b'"' | b'{' | b'[' => return Ok(bytes),
+                    _ => {}
+                }
+            }
+
+            let b = self.next_byte()?;
+            bytes.push(b);
+            if in_str {
+                if escape {
+                    escape = false;
+                } else if b == b'\\' {
+                    escape = true;
+                } else if b == b'"' {
+                    in_str = false;
+                }
+                continue;
+            }
+            match b {
+                b'"' => in_str = true,
+                b'{' | b'[' => depth += 1,
+                b'}' | b']' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+    }
+
+    fn next_message(&mut self) -> StreamResult<Option<M>> {
+        if self.state == ReaderState::BeforeArray {
+            match self.skip_ws()? {
+                Some(b'[') => self.read.consume(1),
+                Some(_) => return Err(StreamError::ExpectingArrayStart),
+                None => return Err(StreamError::UnexpectedEof),
+            }
+            self.state = ReaderState::FirstElement;
+        }
+
+        match self.skip_ws()? {
+            Some(b']') => {
+                self.read.consume(1);
+                self.state = ReaderState::Done;
+                return match self.skip_ws()? {
+                    None => Ok(None),
+                    Some(_) => Err(StreamError::TrailingData),
+                };
+            }
+            Some(b',') if self.state == ReaderState::NextElement => {
+                self.read.consume(1);
+                self.skip_ws()?;
+            }
+            Some(_) if self.state == ReaderState::FirstElement => {}
+            Some(_) => return Err(StreamError::ExpectingCommaOrArrayEnd),
+            None => return Err(StreamError::UnexpectedEof),
+        }
+
+        let bytes = self.read_value_bytes()?;
+        let json = str::from_utf8(&bytes)?;
+        let message = parse_from_str_with_options(json, &self.parse_options)?;
+        self.state = ReaderState::NextElement;
+        Ok(Some(message))
+    }
+}
+
+impl<R: Read, M: Message> Iterator for StreamReader<R, M> {
+    type Item = StreamResult<M>;
+
+    fn next(&mut self) -> Option<StreamResult<M>> {
+        if self.state == ReaderState::Done {
+            return None;
+        }
+        match self.next_message() {
+            Ok(Some(message)) => Some(Ok(message)),
+            Ok(None) => None,
+            Err(e) => {
+                // do not try to continue after malformed input
+                self.state = ReaderState::Done;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use well_known_types::Duration;
+    use well_known_types::Int32Value;
+
+    fn int32_value(value: i32) -> Int32Value {
+        let mut m = Int32Value::new();
+        m.value = value;
+        m
+    }
+
+    fn read_all<M: Message>(json: &str) -> StreamResult<Vec<M>> {
+        StreamReader::new(json.as_bytes()).collect()
+    }
+
+    #[test]
+    fn write_empty() {
+        let writer = StreamWriter::new(Vec::new());
+        assert_eq!(b"[]", &writer.finish().unwrap()[..]);
+    }
+
+    #[test]
+    fn read_empty() {
+        assert_eq!(Vec::<Int32Value>::new(), read_all::<Int32Value>(" [ ] \n").unwrap());
+    }
+
+    #[test]
+    fn write_read_scalars() {
+        let mut writer = StreamWriter::new(Vec::new());
+        writer.write_message(&int32_value(10)).unwrap();
+        writer.write_message(&int32_value(-20)).unwrap();
+        let bytes = writer.finish().unwrap();
+        assert_eq!("[10, -20]", str::from_utf8(&bytes).unwrap());
+
+        assert_eq!(
+            vec![int32_value(10), int32_value(-20)],
+            read_all::<Int32Value>(str::from_utf8(&bytes).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn read_strings_with_special_chars() {
+        let mut d = Duration::new();
+        d.seconds = 3;
+        let ds = read_all::<Duration>("[\"3s\",\n\t\"3s\"]").unwrap();
+        assert_eq!(vec![d.clone(), d], ds);
+    }
+
+    #[test]
+    fn read_errors() {
+        match read_all::<Int32Value>("{}") {
+            Err(StreamError::ExpectingArrayStart) => {}
+            r => panic!("{:?}", r),
+        }
+        match read_all::<Int32Value>("[1 2]") {
+            Err(StreamError::ExpectingCommaOrArrayEnd) => {}
+            r => panic!("{:?}", r),
+        }
+        match read_all::<Int32Value>("[1, 2") {
+            Err(StreamError::UnexpectedEof) => {}
+            r => panic!("{:?}", r),
+        }
+        match read_all::<Int32Value>("[1] 2") {
+            Err(StreamError::TrailingData) => {}
+            r => panic!("{:?}", r),
+        }
+    }
+
+    #[test]
+    fn display_error() {
+        assert_eq!(
+            "expecting `,` or `]`",
+            format!("{}", read_all::<Int32Value>("[1 2]").unwrap_err())
+        );
+        let e = read_all::<Int32Value>("[\"a\"]").unwrap_err();
+        assert!(format!("{}", e).starts_with("failed to parse message: "));
+    }
+
+    #[test]
+    fn error_source() {
+        use std::error::Error;
+
+        let e = StreamError::from(io::Error::new(io::ErrorKind::Other, "oops"));
+        assert_eq!("oops", format!("{}", e));
+        assert_eq!("oops", format!("{}", e.source().unwrap()));
+        assert!(StreamError::TrailingData.source().is_none());
+    }
+}
@@ -0,0 +1,401 @@
+//! Message field parsed on first access.
+
+#[cfg(feature = "with-serde")]
+use serde;
+
+use std::cell::Cell;
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::io;
+use std::sync::Once;
+
+use clear::Clear;
+use core::parse_from_bytes;
+use core::Message;
+use error::ProtobufError;
+use error::ProtobufResult;
+use stream::CodedOutputStream;
+
+/// Message field which keeps serialized message when read from input,
+/// and parses it on first access.
+///
+/// Used for fields with `(rustproto.lazy) = true` option.
+/// Field which was not modified is written back as is, without parsing.
+pub struct LazyMessage<M: Message> {
+    // serialized message, `None` if message is not set or was modified
+    bytes: Option<Vec<u8>>,
+    // guards parsing `bytes` into `message`
+    parse_once: Once,
+    // parsed `bytes`, or message itself when `bytes` is `None`
+    message: UnsafeCell<Option<M>>,
+    // error of parsing `bytes`, set inside `parse_once`
+    parse_error: UnsafeCell<Option<ProtobufError>>,
+}
+
+thread_local! {
+    // number of `bytes` parses on this thread, to check parsing is lazy in tests
+    static PARSE_COUNT: Cell<u64> = const { Cell::new(0) };
+}
+
+// `ProtobufError` is not `Clone`, and `io::Error` cannot be cloned
+fn copy_error(e: &ProtobufError) -> ProtobufError {
+    match *e {
+        ProtobufError::IoError(ref e) => ProtobufError::IoError(io::Error::new(e.kind(), e.to_string())),
+        ProtobufError::WireError(ref e) => ProtobufError::WireError(e.clone()),
+        ProtobufError::Utf8(e) => ProtobufError::Utf8(e),
+        ProtobufError::MessageNotInitialized { message } => {
+            ProtobufError::MessageNotInitialized { message }
+        }
+        ProtobufError::MessageTypeMismatch { expected, actual } => {
+            ProtobufError::MessageTypeMismatch { expected, actual }
+        }
+        ProtobufError::FieldValueTypeMismatch { field } => {
+            ProtobufError::FieldValueTypeMismatch { field }
+        }
+    }
+}
+
+// `message` and `parse_error` are modified through shared reference only inside `parse_once`
+unsafe impl<M: Message> Sync for LazyMessage<M> {}
+
+impl<M: Message> LazyMessage<M> {
+    /// Create an empty field.
+    pub fn new() -> LazyMessage<M> {
+        LazyMessage {
+            bytes: None,
+            parse_once: Once::new(),
+            message: UnsafeCell::new(None),
+            parse_error: UnsafeCell::new(None),
+        }
+    }
+
+    /// Number of serialized messages parsed by `LazyMessage` of any type
+    /// on the current thread.
+    #[doc(hidden)]
+    pub fn thread_parse_count() -> u64 {
+        PARSE_COUNT.with(|c| c.get())
+    }
+
+    /// Create a field from serialized message, which is parsed on access.
+    pub fn from_bytes(bytes: Vec<u8>) -> LazyMessage<M> {
+        LazyMessage {
+            bytes: Some(bytes),
+            ..LazyMessage::new()
+        }
+    }
+
+    // may only be called when `bytes` is `None` or `parse_once` is completed
+    fn message(&self) -> &Option<M> {
+        unsafe { &*self.message.get() }
+    }
+
+    /// Is field set?
+    pub fn is_some(&self) -> bool {
+        self.bytes.is_some() || self.message().is_some()
+    }
+
+    /// Is field not set?
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    /// False if field contains serialized message not parsed yet.
+    pub fn is_parsed(&self) -> bool {
+        match self.bytes {
+            Some(..) => self.parse_once.is_completed() && self.message().is_some(),
+            None => true,
+        }
+    }
+
+    /// Get message parsing it if necessary.
+    ///
+    /// Default instance is returned if field is not set.
+    pub fn get(&self) -> ProtobufResult<&M> {
+        match self.bytes {
+            Some(ref bytes) => {
+                self.parse_once.call_once(|| {
+                    PARSE_COUNT.with(|c| c.set(c.get() + 1));
+                    match parse_from_bytes(bytes) {
+                        Ok(message) => unsafe { *self.message.get() = Some(message) },
+                        Err(e) => unsafe { *self.parse_error.get() = Some(e) },
+                    }
+                });
+                match *self.message() {
+                    Some(ref message) => Ok(message),
+                    None => {
+                        let e = unsafe { &*self.parse_error.get() };
+                        Err(copy_error(e.as_ref().unwrap()))
+                    }
+                }
+            }
+            None => Ok(match *self.message() {
+                Some(ref message) => message,
+                None => M::default_instance(),
+            }),
+        }
+    }
+
+    /// Get mutable message parsing it if necessary.
+    ///
+    /// Field is set to default message if not set.
+    /// Serialized message is discarded, so the field is serialized
+    /// from the parsed message afterwards.
+    pub fn get_mut(&mut self) -> ProtobufResult<&mut M> {
+        if self.bytes.is_some() {
+            self.get()?;
+            self.bytes = None;
+            self.parse_once = Once::new();
+        }
+        let message = unsafe { &mut *self.message.get() };
+        if message.is_none() {
+            *message = Some(M::new());
+        }
+        Ok(message.as_mut().unwrap())
+    }
+
+    /// Set field to given message.
+    pub fn set(&mut self, message: M) {
+        self.bytes = None;
+        self.parse_once = Once::new();
+        self.message = UnsafeCell::new(Some(message));
+        self.parse_error = UnsafeCell::new(None);
+    }
+
+    /// Take message leaving the field empty.
+    pub fn take(&mut self) -> ProtobufResult<Option<M>> {
+        if self.is_none() {
+            return Ok(None);
+        }
+        self.get_mut()?;
+        Ok(unsafe { &mut *self.message.get() }.take())
+    }
+
+    /// Clear the field.
+    pub fn clear(&mut self) {
+        self.bytes = None;
+        self.parse_once = Once::new();
+        self.message = UnsafeCell::new(None);
+        self.parse_error = UnsafeCell::new(None);
+    }
+
+    /// Merge serialized message into this field.
+    ///
+    /// Unmodified field is not parsed: serialized messages are concatenated,
+    /// which is the same as merging.
+    pub fn merge_from_bytes(&mut self, bytes: &[u8]) -> ProtobufResult<()> {
+        if let Some(ref mut self_bytes) = self.bytes {
+            self_bytes.extend_from_slice(bytes);
+            self.parse_once = Once::new();
+            self.message = UnsafeCell::new(None);
+            self.parse_error = UnsafeCell::new(None);
+            return Ok(());
+        }
+        match unsafe { &mut *self.message.get() } {
+            &mut Some(ref mut message) => message.merge_from_bytes(bytes),
+            &mut None => {
+                self.bytes = Some(bytes.to_vec());
+                Ok(())
+            }
+        }
+    }
+
+    /// Check if the message is initialized.
+    ///
+    /// Serialized message is not parsed,
+    /// and it is checked when parsed on access.
+    pub fn is_initialized(&self) -> bool {
+        match self.bytes {
+            Some(..) => true,
+            None => self.message().as_ref().map_or(true, |m| m.is_initialized()),
+        }
+    }
+
+    /// Compute and cache size of the message (without length prefix).
+    pub fn compute_size(&self) -> u32 {
+        match self.bytes {
+            Some(ref bytes) => bytes.len() as u32,
+            None => self.message().as_ref().map_or(0, |m| m.compute_size()),
+        }
+    }
+
+    /// Size of the message computed by `compute_size`.
+    pub fn get_cached_size(&self) -> u32 {
+        match self.bytes {
+            Some(ref bytes) => bytes.len() as u32,
+            None => self.message().as_ref().map_or(0, |m| m.get_cached_size()),
+        }
+    }
+
+    /// Write the message (without length prefix) using sizes cached by `compute_size`.
+    pub fn write_to_with_cached_sizes(&self, os: &mut CodedOutputStream) -> ProtobufResult<()> {
+        match self.bytes {
+            Some(ref bytes) => os.write_raw_bytes(bytes),
+            None => match *self.message() {
+                Some(ref message) => message.write_to_with_cached_sizes(os),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+impl<M: Message> Default for LazyMessage<M> {
+    fn default() -> LazyMessage<M> {
+        LazyMessage::new()
+    }
+}
+
+impl<M: Message> From<M> for LazyMessage<M> {
+    fn from(message: M) -> LazyMessage<M> {
+        let mut r = LazyMessage::new();
+        r.set(message);
+        r
+    }
+}
+
+impl<M: Message> Clear for LazyMessage<M> {
+    fn clear(&mut self) {
+        LazyMessage::clear(self)
+    }
+}
+
+impl<M: Message + Clone> Clone for LazyMessage<M> {
+    fn clone(&self) -> LazyMessage<M> {
+        let r = LazyMessage {
+            bytes: self.bytes.clone(),
+            ..LazyMessage::new()
+        };
+        if self.is_parsed() {
+            let message = self.message().clone();
+            match r.bytes {
+                Some(..) => r.parse_once.call_once(|| unsafe {
+                    *r.message.get() = message;
+                }),
+                None => unsafe {
+                    *r.message.get() = message;
+                },
+            }
+        }
+        r
+    }
+}
+
+impl<M: Message + PartialEq> PartialEq for LazyMessage<M> {
+    fn eq(&self, other: &LazyMessage<M>) -> bool {
+        if self.is_some() != other.is_some() {
+            return false;
+        }
+        match (self.get(), other.get()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => self.bytes == other.bytes,
+        }
+    }
+}
+
+impl<M: Message> fmt::Debug for LazyMessage<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_none() {
+            return write!(f, "None");
+        }
+        match self.get() {
+            Ok(message) => write!(f, "Some({:?})", message),
+            Err(e) => write!(f, "Some(<{}>)", e),
+        }
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl<M: Message + serde::Serialize> serde::Serialize for LazyMessage<M> {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<<S as serde::Serializer>::Ok, <S as serde::Serializer>::Error>
+    where
+        S: serde::Serializer,
+    {
+        if self.is_none() {
+            return serializer.serialize_none();
+        }
+        let message = self.get().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_some(message)
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl<'de, M: Message + serde::Deserialize<'de>> serde::Deserialize<'de> for LazyMessage<M> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as serde::Deserializer<'de>>::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Option::<M>::deserialize(deserializer).map(|m| match m {
+            Some(m) => LazyMessage::from(m),
+            None => LazyMessage::new(),
+        })
+    }
+}
+
+#[cfg(feature = "with-arbitrary")]
+impl<'a, M: Message + arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for LazyMessage<M> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Option::<M>::arbitrary(u).map(|m| match m {
+            Some(m) => LazyMessage::from(m),
+            None => LazyMessage::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LazyMessage;
+    use descriptor::FileDescriptorProto;
+    use Message;
+
+    fn serialized_file(name: &str) -> Vec<u8> {
+        let mut file = FileDescriptorProto::new();
+        file.set_name(name.to_owned());
+        file.write_to_bytes().unwrap()
+    }
+
+    #[test]
+    fn parse_on_get() {
+        let lazy = LazyMessage::<FileDescriptorProto>::from_bytes(serialized_file("a.proto"));
+        assert!(lazy.is_some());
+        assert!(!lazy.is_parsed());
+        assert_eq!("a.proto", lazy.get().unwrap().get_name());
+        assert!(lazy.is_parsed());
+    }
+
+    #[test]
+    fn merge_unparsed() {
+        let mut lazy = LazyMessage::<FileDescriptorProto>::new();
+        assert!(lazy.is_none());
+        lazy.merge_from_bytes(&serialized_file("a.proto")).unwrap();
+        lazy.merge_from_bytes(&serialized_file("b.proto")).unwrap();
+        assert!(!lazy.is_parsed());
+        assert_eq!("b.proto", lazy.get().unwrap().get_name());
+    }
+
+    #[test]
+    fn get_mut_discards_bytes() {
+        let mut lazy = LazyMessage::<FileDescriptorProto>::from_bytes(serialized_file("a.proto"));
+        lazy.get_mut().unwrap().set_package("p".to_owned());
+        let mut expected = FileDescriptorProto::new();
+        expected.set_name("a.proto".to_owned());
+        expected.set_package("p".to_owned());
+        assert_eq!(expected.compute_size(), lazy.compute_size());
+        assert_eq!(LazyMessage::from(expected), lazy);
+    }
+
+    #[test]
+    fn parse_error() {
+        let lazy = LazyMessage::<FileDescriptorProto>::from_bytes(vec![0xff]);
+        let count = LazyMessage::<FileDescriptorProto>::thread_parse_count();
+        assert!(lazy.get().is_err());
+        // error is cached, input is not parsed again
+        assert!(lazy.get().is_err());
+        assert_eq!(
+            count + 1,
+            LazyMessage::<FileDescriptorProto>::thread_parse_count()
+        );
+        assert!(!lazy.is_parsed());
+    }
+}
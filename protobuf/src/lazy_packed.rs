@@ -0,0 +1,327 @@
+//! Packed repeated field decoded on iteration.
+
+#[cfg(feature = "with-serde")]
+use serde;
+
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::marker;
+use std::sync::Once;
+
+use clear::Clear;
+use error::ProtobufResult;
+use reflect::runtime_types::RuntimeType;
+use reflect::types::ProtobufType;
+use rt;
+use stream::CodedInputStream;
+use stream::CodedOutputStream;
+use wire_format;
+use wire_format::WireType;
+
+/// Element type of `LazyPacked<T>`.
+pub type LazyPackedValue<T> = <<T as ProtobufType>::RuntimeType as RuntimeType>::Value;
+
+/// Packed repeated numeric, bool or enum field which keeps encoded elements
+/// and decodes them on iteration.
+///
+/// Used for fields with `(rustproto.lazy) = true` option.
+/// Elements are validated when read from input,
+/// and the field is written back as is.
+pub struct LazyPacked<T: ProtobufType> {
+    // encoded elements without tags and length
+    bytes: Vec<u8>,
+    // guards decoding `bytes` into `decoded`
+    decode_once: Once,
+    // elements decoded for reflection
+    decoded: UnsafeCell<Vec<LazyPackedValue<T>>>,
+    _marker: marker::PhantomData<T>,
+}
+
+// `decoded` is modified through shared reference only inside `decode_once`
+unsafe impl<T: ProtobufType> Sync for LazyPacked<T> {}
+
+impl<T: ProtobufType> LazyPacked<T> {
+    /// Create an empty field.
+    pub fn new() -> LazyPacked<T> {
+        LazyPacked {
+            bytes: Vec::new(),
+            decode_once: Once::new(),
+            decoded: UnsafeCell::new(Vec::new()),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Create a field from encoded elements (content of packed field without length).
+    pub fn from_bytes(bytes: Vec<u8>) -> ProtobufResult<LazyPacked<T>> {
+        LazyPacked::<T>::validate(&bytes)?;
+        Ok(LazyPacked {
+            bytes,
+            ..LazyPacked::new()
+        })
+    }
+
+    fn validate(bytes: &[u8]) -> ProtobufResult<()> {
+        let mut is = CodedInputStream::from_bytes(bytes);
+        while !is.eof()? {
+            T::read(&mut is)?;
+        }
+        Ok(())
+    }
+
+    // discard elements decoded for reflection after modification
+    fn modified(&mut self) {
+        self.decode_once = Once::new();
+        self.decoded = UnsafeCell::new(Vec::new());
+    }
+
+    /// Encoded elements (content of packed field without length).
+    pub fn raw_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Is field empty?
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Number of elements.
+    ///
+    /// Elements are decoded to be counted.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Iterate over elements decoding them.
+    pub fn iter<'a>(&'a self) -> LazyPackedIter<'a, T> {
+        LazyPackedIter {
+            is: CodedInputStream::from_bytes(&self.bytes),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Append an element.
+    pub fn push(&mut self, value: LazyPackedValue<T>) {
+        let start = self.bytes.len();
+        {
+            let mut os = CodedOutputStream::vec(&mut self.bytes);
+            // writing to vec cannot fail
+            T::write_with_cached_size(1, &value, &mut os).unwrap();
+            os.finish().unwrap();
+        }
+        // tag of field 1 is single byte
+        self.bytes.remove(start);
+        self.modified();
+    }
+
+    /// Clear the field.
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+        self.modified();
+    }
+
+    /// Elements decoded and cached on first call.
+    pub(crate) fn decoded(&self) -> &[LazyPackedValue<T>] {
+        self.decode_once.call_once(|| unsafe {
+            *self.decoded.get() = self.iter().collect();
+        });
+        unsafe { &*self.decoded.get() }
+    }
+
+    /// Replace all elements.
+    pub(crate) fn set_all(&mut self, values: Vec<LazyPackedValue<T>>) {
+        self.clear();
+        for value in values {
+            self.push(value);
+        }
+    }
+
+    /// Read packed or single element.
+    pub(crate) fn read_from(
+        &mut self,
+        wire_type: WireType,
+        is: &mut CodedInputStream,
+    ) -> ProtobufResult<()> {
+        if wire_type == wire_format::WireTypeLengthDelimited {
+            let len = is.read_raw_varint32()?;
+            let bytes = is.read_raw_bytes(len)?;
+            LazyPacked::<T>::validate(&bytes)?;
+            if self.bytes.is_empty() {
+                self.bytes = bytes;
+            } else {
+                self.bytes.extend_from_slice(&bytes);
+            }
+            self.modified();
+            Ok(())
+        } else if wire_type == T::wire_type() {
+            let value = T::read(is)?;
+            self.push(value);
+            Ok(())
+        } else {
+            Err(rt::unexpected_wire_type(wire_type))
+        }
+    }
+
+    /// Size of the field with tag and length, zero if field is empty.
+    pub fn compute_size(&self, field_number: u32) -> u32 {
+        if self.bytes.is_empty() {
+            return 0;
+        }
+        let len = self.bytes.len() as u32;
+        rt::tag_size(field_number) + rt::compute_raw_varint32_size(len) + len
+    }
+
+    /// Write the field with tag and length, nothing is written if field is empty.
+    pub fn write_to(&self, field_number: u32, os: &mut CodedOutputStream) -> ProtobufResult<()> {
+        if self.bytes.is_empty() {
+            return Ok(());
+        }
+        os.write_tag(field_number, wire_format::WireTypeLengthDelimited)?;
+        os.write_raw_varint32(self.bytes.len() as u32)?;
+        os.write_raw_bytes(&self.bytes)
+    }
+}
+
+/// Iterator over elements of `LazyPacked`.
+pub struct LazyPackedIter<'a, T: ProtobufType> {
+    is: CodedInputStream<'a>,
+    _marker: marker::PhantomData<T>,
+}
+
+impl<'a, T: ProtobufType> Iterator for LazyPackedIter<'a, T> {
+    type Item = LazyPackedValue<T>;
+
+    fn next(&mut self) -> Option<LazyPackedValue<T>> {
+        // elements were validated when stored
+        if self.is.eof().expect("eof") {
+            None
+        } else {
+            Some(T::read(&mut self.is).expect("read validated element"))
+        }
+    }
+}
+
+impl<T: ProtobufType> Default for LazyPacked<T> {
+    fn default() -> LazyPacked<T> {
+        LazyPacked::new()
+    }
+}
+
+impl<T: ProtobufType> Clear for LazyPacked<T> {
+    fn clear(&mut self) {
+        LazyPacked::clear(self)
+    }
+}
+
+impl<T: ProtobufType> Clone for LazyPacked<T> {
+    fn clone(&self) -> LazyPacked<T> {
+        LazyPacked {
+            bytes: self.bytes.clone(),
+            ..LazyPacked::new()
+        }
+    }
+}
+
+/// Fields are equal if their encoded elements are equal.
+impl<T: ProtobufType> PartialEq for LazyPacked<T> {
+    fn eq(&self, other: &LazyPacked<T>) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl<T: ProtobufType> fmt::Debug for LazyPacked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: ProtobufType> ::std::iter::FromIterator<LazyPackedValue<T>> for LazyPacked<T> {
+    fn from_iter<I: IntoIterator<Item = LazyPackedValue<T>>>(iter: I) -> LazyPacked<T> {
+        let mut r = LazyPacked::new();
+        for value in iter {
+            r.push(value);
+        }
+        r
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl<T: ProtobufType> serde::Serialize for LazyPacked<T>
+where
+    LazyPackedValue<T>: serde::Serialize,
+{
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<<S as serde::Serializer>::Ok, <S as serde::Serializer>::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl<'de, T: ProtobufType> serde::Deserialize<'de> for LazyPacked<T>
+where
+    LazyPackedValue<T>: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as serde::Deserializer<'de>>::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(|v| v.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "with-arbitrary")]
+impl<'a, T: ProtobufType> arbitrary::Arbitrary<'a> for LazyPacked<T>
+where
+    LazyPackedValue<T>: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Vec::arbitrary(u).map(|v| v.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LazyPacked;
+    use hex::decode_hex;
+    use reflect::types::ProtobufTypeFixed32;
+    use reflect::types::ProtobufTypeSint32;
+    use stream::CodedInputStream;
+    use wire_format;
+
+    #[test]
+    fn push_iter() {
+        let mut field = LazyPacked::<ProtobufTypeSint32>::new();
+        field.push(1);
+        field.push(-1);
+        field.push(300);
+        assert_eq!(&decode_hex("02 01 d8 04")[..], field.raw_bytes());
+        assert_eq!(vec![1, -1, 300], field.iter().collect::<Vec<_>>());
+        assert_eq!(&[1, -1, 300], field.decoded());
+    }
+
+    #[test]
+    fn read_packed_and_unpacked() {
+        let mut field = LazyPacked::<ProtobufTypeFixed32>::new();
+        let bytes = decode_hex("04 01 00 00 00 02 00 00 00");
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        field
+            .read_from(wire_format::WireTypeLengthDelimited, &mut is)
+            .unwrap();
+        field.read_from(wire_format::WireTypeFixed32, &mut is).unwrap();
+        assert_eq!(vec![1, 2], field.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn read_invalid() {
+        let mut field = LazyPacked::<ProtobufTypeFixed32>::new();
+        let bytes = decode_hex("03 01 00 00");
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        assert!(field
+            .read_from(wire_format::WireTypeLengthDelimited, &mut is)
+            .is_err());
+    }
+}
@@ -9,19 +9,36 @@
 extern crate bytes;
 #[cfg(feature = "with-serde")]
 extern crate serde;
+#[cfg(feature = "with-arbitrary")]
+extern crate arbitrary;
+#[cfg(feature = "with-serde-json")]
+extern crate serde_json;
+#[cfg(feature = "with-rayon")]
+extern crate rayon;
+#[cfg(feature = "with-smallvec")]
+extern crate smallvec;
+#[cfg(feature = "with-uuid")]
+extern crate uuid;
 #[macro_use]
 #[cfg(feature = "with-serde")]
 
 extern crate serde_derive;
 pub use clear::Clear;
 pub use core::parse_from_bytes;
+pub use core::parse_from_bytes_with_unknown_handler;
 #[cfg(feature = "bytes")]
 pub use core::parse_from_carllerche_bytes;
+pub use core::parse_from_concatenated;
 pub use core::parse_from_reader;
 pub use core::Message;
+pub use core::ProtobufConvertible;
 pub use enums::ProtobufEnum;
 pub use enums::ProtobufEnumOrUnknown;
 pub use oneof::Oneof;
+pub use lazy_message::LazyMessage;
+pub use lazy_packed::LazyPacked;
+pub use lazy_packed::LazyPackedIter;
+pub use lazy_packed::LazyPackedValue;
 pub use repeated::RepeatedField;
 pub use singular::SingularField;
 pub use singular::SingularPtrField;
@@ -33,13 +50,17 @@ pub use unknown::UnknownValue;
 pub use unknown::UnknownValueRef;
 pub use unknown::UnknownValues;
 pub use unknown::UnknownValuesIter;
+pub use view::parse_view_from_bytes;
+pub use view::MessageView;
 pub mod wire_format;
+pub use arc_str::ArcStr;
 pub use cached_size::CachedSize;
 #[cfg(feature = "bytes")]
 pub use chars::Chars;
 pub use error::ProtobufError;
 pub use error::ProtobufResult;
 
+pub use reflect::strip_unknown_fields;
 pub use reflect::types;
 
 // generated
@@ -55,32 +76,41 @@ pub mod error;
 pub mod ext;
 pub mod json;
 mod lazy;
+mod lazy_message;
+mod lazy_packed;
 mod oneof;
 pub mod prelude;
 pub mod reflect;
 mod repeated;
 pub mod rt;
+pub mod service;
 mod singular;
 pub mod stream;
 pub mod text_format;
 pub mod well_known_types;
+mod well_known_types_util;
 
 // used by test
 #[cfg(test)]
 #[path = "../../protobuf-test-common/src/hex.rs"]
 mod hex;
 
+mod arc_str;
 mod cached_size;
 mod chars;
 mod paginate;
 mod unknown;
 mod varint;
+mod view;
 mod zigzag;
 
 mod misc;
 
 mod buf_read_iter;
 
+// `VERSION`, `VERSION_IDENT` and per-version const checked by generated code
+include!(concat!(env!("OUT_DIR"), "/version.rs"));
+
 // so `use protobuf::*` could work in mod descriptor and well_known_types
 mod protobuf {
     pub use cached_size::CachedSize;
@@ -108,4 +138,5 @@ mod protobuf {
     pub use unknown::UnknownValuesIter;
     pub use well_known_types;
     pub use wire_format;
+    pub use VERSION_3_0_0;
 }
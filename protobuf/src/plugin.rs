@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(deprecated)]
 #![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -17,7 +18,10 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 
-#[derive(PartialEq,Clone,Default)]
+// fails to compile if generated code and protobuf runtime versions differ
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_0_0;
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct CodeGeneratorRequest {
     // message fields
@@ -91,7 +95,13 @@ impl ::protobuf::Message for CodeGeneratorRequest {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.file_to_generate)?;
@@ -106,6 +116,7 @@ impl ::protobuf::Message for CodeGeneratorRequest {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -163,6 +174,10 @@ impl ::protobuf::Message for CodeGeneratorRequest {
         CodeGeneratorRequest::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -195,6 +210,19 @@ impl ::protobuf::Message for CodeGeneratorRequest {
         static instance: ::protobuf::rt::Lazy<CodeGeneratorRequest> = ::protobuf::rt::Lazy::INIT;
         instance.get(CodeGeneratorRequest::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.file_to_generate.shrink_to_fit();
+        for v in self.file_to_generate.iter_mut() {
+            v.shrink_to_fit();
+        };
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.parameter);
+        self.proto_file.shrink_to_fit();
+        for v in self.proto_file.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for CodeGeneratorRequest {
@@ -206,6 +234,26 @@ impl ::protobuf::Clear for CodeGeneratorRequest {
     }
 }
 
+impl ::std::clone::Clone for CodeGeneratorRequest {
+    fn clone(&self) -> CodeGeneratorRequest {
+        CodeGeneratorRequest {
+            file_to_generate: ::std::clone::Clone::clone(&self.file_to_generate),
+            parameter: ::std::clone::Clone::clone(&self.parameter),
+            proto_file: ::std::clone::Clone::clone(&self.proto_file),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &CodeGeneratorRequest) {
+        ::std::clone::Clone::clone_from(&mut self.file_to_generate, &source.file_to_generate);
+        ::std::clone::Clone::clone_from(&mut self.parameter, &source.parameter);
+        ::std::clone::Clone::clone_from(&mut self.proto_file, &source.proto_file);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for CodeGeneratorRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -215,7 +263,10 @@ impl ::std::fmt::Debug for CodeGeneratorRequest {
 impl ::protobuf::reflect::ProtobufValue for CodeGeneratorRequest {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for CodeGeneratorRequest {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct CodeGeneratorResponse {
     // message fields
@@ -288,7 +339,13 @@ impl ::protobuf::Message for CodeGeneratorResponse {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.error)?;
@@ -300,6 +357,7 @@ impl ::protobuf::Message for CodeGeneratorResponse {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -351,6 +409,10 @@ impl ::protobuf::Message for CodeGeneratorResponse {
         CodeGeneratorResponse::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -378,6 +440,15 @@ impl ::protobuf::Message for CodeGeneratorResponse {
         static instance: ::protobuf::rt::Lazy<CodeGeneratorResponse> = ::protobuf::rt::Lazy::INIT;
         instance.get(CodeGeneratorResponse::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        ::protobuf::rt::shrink_singular_string_to_fit(&mut self.error);
+        self.file.shrink_to_fit();
+        for v in self.file.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for CodeGeneratorResponse {
@@ -388,6 +459,24 @@ impl ::protobuf::Clear for CodeGeneratorResponse {
     }
 }
 
+impl ::std::clone::Clone for CodeGeneratorResponse {
+    fn clone(&self) -> CodeGeneratorResponse {
+        CodeGeneratorResponse {
+            error: ::std::clone::Clone::clone(&self.error),
+            file: ::std::clone::Clone::clone(&self.file),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &CodeGeneratorResponse) {
+        ::std::clone::Clone::clone_from(&mut self.error, &source.error);
+        ::std::clone::Clone::clone_from(&mut self.file, &source.file);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for CodeGeneratorResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -397,8 +486,11 @@ impl ::std::fmt::Debug for CodeGeneratorResponse {
 impl ::protobuf::reflect::ProtobufValue for CodeGeneratorResponse {
 }
 
+impl ::protobuf::ProtobufConvertible for CodeGeneratorResponse {
+}
+
 pub mod code_generator_response {
-    #[derive(PartialEq,Clone,Default)]
+    #[derive(PartialEq,Default)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
     pub struct File {
         // message fields
@@ -539,7 +631,13 @@ pub mod code_generator_response {
 
         fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
             while !is.eof()? {
+                let field_start = is.pos();
                 let (field_number, wire_type) = is.read_tag_unpack()?;
+                if !is.field_allowed(field_number) {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                    is.record_field_offset(field_number, field_start);
+                    continue;
+                }
                 match field_number {
                     1 => {
                         ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
@@ -554,6 +652,7 @@ pub mod code_generator_response {
                         ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                     },
                 };
+                is.record_field_offset(field_number, field_start);
             }
             ::std::result::Result::Ok(())
         }
@@ -610,6 +709,10 @@ pub mod code_generator_response {
             File::new()
         }
 
+        fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+            ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+        }
+
         fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
             static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
             descriptor.get(|| {
@@ -644,6 +747,13 @@ pub mod code_generator_response {
             static instance: ::protobuf::rt::Lazy<File> = ::protobuf::rt::Lazy::INIT;
             instance.get(File::new)
         }
+
+        fn shrink_to_fit(&mut self) {
+            ::protobuf::rt::shrink_singular_string_to_fit(&mut self.name);
+            ::protobuf::rt::shrink_singular_string_to_fit(&mut self.insertion_point);
+            ::protobuf::rt::shrink_singular_string_to_fit(&mut self.content);
+            self.unknown_fields.shrink_to_fit();
+        }
     }
 
     impl ::protobuf::Clear for File {
@@ -655,6 +765,26 @@ pub mod code_generator_response {
         }
     }
 
+    impl ::std::clone::Clone for File {
+        fn clone(&self) -> File {
+            File {
+                name: ::std::clone::Clone::clone(&self.name),
+                insertion_point: ::std::clone::Clone::clone(&self.insertion_point),
+                content: ::std::clone::Clone::clone(&self.content),
+                unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+                cached_size: ::std::clone::Clone::clone(&self.cached_size),
+            }
+        }
+
+        fn clone_from(&mut self, source: &File) {
+            ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+            ::std::clone::Clone::clone_from(&mut self.insertion_point, &source.insertion_point);
+            ::std::clone::Clone::clone_from(&mut self.content, &source.content);
+            ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+            ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+        }
+    }
+
     impl ::std::fmt::Debug for File {
         fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             ::protobuf::text_format::fmt(self, f)
@@ -663,6 +793,9 @@ pub mod code_generator_response {
 
     impl ::protobuf::reflect::ProtobufValue for File {
     }
+
+    impl ::protobuf::ProtobufConvertible for File {
+    }
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
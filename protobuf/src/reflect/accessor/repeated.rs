@@ -1,5 +1,6 @@
 use std::marker;
 
+use LazyPacked;
 use Message;
 use RepeatedField;
 
@@ -68,6 +69,37 @@ where
     }
 }
 
+impl<M, V> RepeatedFieldGetMut<M, ReflectRepeated> for RepeatedFieldGetMutImpl<M, LazyPacked<V>>
+where
+    M: Message + 'static,
+    V: ProtobufType,
+{
+    fn get_field<'a>(&self, m: &'a M) -> &'a ReflectRepeated {
+        (self.get_field)(m) as &ReflectRepeated
+    }
+
+    fn mut_field<'a>(&self, m: &'a mut M) -> &'a mut ReflectRepeated {
+        (self.mut_field)(m) as &mut ReflectRepeated
+    }
+}
+
+#[cfg(feature = "with-smallvec")]
+impl<M, A> RepeatedFieldGetMut<M, ReflectRepeated>
+    for RepeatedFieldGetMutImpl<M, ::smallvec::SmallVec<A>>
+where
+    M: Message + 'static,
+    A: ::smallvec::Array + Sync + 'static,
+    A::Item: ProtobufValue + fmt::Debug + 'static,
+{
+    fn get_field<'a>(&self, m: &'a M) -> &'a ReflectRepeated {
+        (self.get_field)(m) as &ReflectRepeated
+    }
+
+    fn mut_field<'a>(&self, m: &'a mut M) -> &'a mut ReflectRepeated {
+        (self.mut_field)(m) as &mut ReflectRepeated
+    }
+}
+
 struct RepeatedFieldAccessorImpl<M, V>
 where
     M: Message,
@@ -154,3 +186,53 @@ where
         }),
     }
 }
+
+#[cfg(feature = "with-smallvec")]
+pub fn make_small_vec_accessor<M, V, A>(
+    name: &'static str,
+    get_vec: for<'a> fn(&'a M) -> &'a ::smallvec::SmallVec<A>,
+    mut_vec: for<'a> fn(&'a mut M) -> &'a mut ::smallvec::SmallVec<A>,
+) -> FieldAccessor
+where
+    M: Message + 'static,
+    V: ProtobufType + 'static,
+    A: ::smallvec::Array<Item = <V::RuntimeType as RuntimeType>::Value> + Sync + 'static,
+{
+    FieldAccessor {
+        name,
+        accessor: AccessorKind::Repeated(RepeatedFieldAccessorHolder {
+            accessor: Box::new(RepeatedFieldAccessorImpl::<M, V> {
+                fns: Box::new(RepeatedFieldGetMutImpl::<M, ::smallvec::SmallVec<A>> {
+                    get_field: get_vec,
+                    mut_field: mut_vec,
+                }),
+                _marker: marker::PhantomData::<V>,
+            }),
+            element_type: V::dynamic(),
+        }),
+    }
+}
+
+pub fn make_lazy_packed_accessor<M, V>(
+    name: &'static str,
+    get_field: for<'a> fn(&'a M) -> &'a LazyPacked<V>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut LazyPacked<V>,
+) -> FieldAccessor
+where
+    M: Message + 'static,
+    V: ProtobufType + 'static,
+{
+    FieldAccessor {
+        name,
+        accessor: AccessorKind::Repeated(RepeatedFieldAccessorHolder {
+            accessor: Box::new(RepeatedFieldAccessorImpl::<M, V> {
+                fns: Box::new(RepeatedFieldGetMutImpl::<M, LazyPacked<V>> {
+                    get_field,
+                    mut_field,
+                }),
+                _marker: marker::PhantomData::<V>,
+            }),
+            element_type: V::dynamic(),
+        }),
+    }
+}
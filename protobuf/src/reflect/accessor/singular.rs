@@ -9,6 +9,7 @@ use reflect::types::ProtobufTypeMessage;
 use reflect::ReflectValueBox;
 use reflect::ReflectValueRef;
 use singular::OptionLike;
+use LazyMessage;
 use std::marker;
 use Message;
 use ProtobufEnum;
@@ -411,6 +412,9 @@ where
 // TODO: make_singular_xxx_accessor are used only for oneof fields
 // oneof codegen should be changed
 
+// `make_oneof_copy_has_get_set_accessors` is also used for fields
+// which presence is stored in message bitset
+
 pub fn make_oneof_copy_has_get_set_accessors<M, V>(
     name: &'static str,
     has: fn(&M) -> bool,
@@ -499,6 +503,92 @@ where
     }
 }
 
+struct LazyMessageFieldPointers<M, F>
+where
+    M: Message,
+    F: Message,
+{
+    get_field: for<'a> fn(&'a M) -> &'a LazyMessage<F>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut LazyMessage<F>,
+}
+
+impl<M, F> GetOptionImpl<M> for LazyMessageFieldPointers<M, F>
+where
+    M: Message,
+    F: Message,
+{
+    fn get_reflect_impl<'a>(&self, m: &'a M) -> Option<ReflectValueRef<'a>> {
+        let field = (self.get_field)(m);
+        if field.is_some() {
+            Some(ReflectValueRef::Message(field.get().expect("cannot parse lazy message")))
+        } else {
+            None
+        }
+    }
+}
+
+impl<M, F> GetOrDefaultImpl<M> for LazyMessageFieldPointers<M, F>
+where
+    M: Message,
+    F: Message,
+{
+    fn get_singular_field_or_default_impl<'a>(&self, m: &'a M) -> ReflectValueRef<'a> {
+        ReflectValueRef::Message((self.get_field)(m).get().expect("cannot parse lazy message"))
+    }
+}
+
+impl<M, F> MutOrDefaultImpl<M> for LazyMessageFieldPointers<M, F>
+where
+    M: Message,
+    F: Message,
+{
+    fn mut_singular_field_or_default_impl<'a>(&self, m: &'a mut M) -> ReflectValueMut<'a> {
+        ReflectValueMut::Message((self.mut_field)(m).get_mut().expect("cannot parse lazy message"))
+    }
+}
+
+impl<M, F> SetImpl<M> for LazyMessageFieldPointers<M, F>
+where
+    M: Message,
+    F: Message + Default + Clone,
+{
+    fn set_singular_field(&self, m: &mut M, value: ReflectValueBox) {
+        (self.mut_field)(m).set(RuntimeTypeMessage::<F>::from_value_box(value));
+    }
+}
+
+/// Message field with `lazy` option
+pub fn make_lazy_message_accessor<M, F>(
+    name: &'static str,
+    get_field: for<'a> fn(&'a M) -> &'a LazyMessage<F>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut LazyMessage<F>,
+) -> FieldAccessor
+where
+    M: Message + 'static,
+    F: Message + Default + Clone + 'static,
+{
+    FieldAccessor {
+        name,
+        accessor: AccessorKind::Singular(SingularFieldAccessorHolder {
+            accessor: Box::new(SingularFieldAccessorImpl::<
+                M,
+                ProtobufTypeMessage<F>,
+                _,
+                _,
+                _,
+                _,
+            > {
+                get_option_impl: LazyMessageFieldPointers { get_field, mut_field },
+                get_or_default_impl: LazyMessageFieldPointers { get_field, mut_field },
+                mut_or_default_impl: LazyMessageFieldPointers { get_field, mut_field },
+                set_impl: LazyMessageFieldPointers { get_field, mut_field },
+                _marker: marker::PhantomData,
+            }),
+            element_type: ProtobufTypeMessage::<F>::dynamic(),
+        }),
+    }
+}
+
 pub fn make_option_accessor<M, V, O>(
     name: &'static str,
     get_field: for<'a> fn(&'a M) -> &'a O,
@@ -0,0 +1,522 @@
+//! Reflection-driven parser and serializer, used by code generated
+//! for files with `option optimize_for = CODE_SIZE`.
+//!
+//! Generated `merge_from`, `compute_size` and `write_to_with_cached_sizes`
+//! delegate to these functions instead of per-field code,
+//! so the wire format is handled once here for all messages.
+//!
+//! Reflection cannot represent unknown enum values in enum fields,
+//! so these values (and map entries with such values) are preserved
+//! in unknown fields.
+
+use descriptor::field_descriptor_proto::Type;
+use error::WireError;
+use prelude::MessageField;
+use reflect::FieldDescriptor;
+use reflect::MessageDescriptor;
+use reflect::ReflectFieldRef;
+use reflect::ReflectValueBox;
+use reflect::ReflectValueRef;
+use reflect::RuntimeFieldType;
+use reflect::RuntimeTypeDynamic;
+use rt;
+use wire_format::WireType;
+use zigzag::encode_zig_zag_32;
+use zigzag::encode_zig_zag_64;
+use CodedInputStream;
+use CodedOutputStream;
+use Message;
+use ProtobufError;
+use ProtobufResult;
+
+fn wire_type_for(t: Type) -> WireType {
+    match t {
+        Type::TYPE_INT32
+        | Type::TYPE_INT64
+        | Type::TYPE_UINT32
+        | Type::TYPE_UINT64
+        | Type::TYPE_SINT32
+        | Type::TYPE_SINT64
+        | Type::TYPE_BOOL
+        | Type::TYPE_ENUM => WireType::WireTypeVarint,
+        Type::TYPE_FIXED32 | Type::TYPE_SFIXED32 | Type::TYPE_FLOAT => WireType::WireTypeFixed32,
+        Type::TYPE_FIXED64 | Type::TYPE_SFIXED64 | Type::TYPE_DOUBLE => WireType::WireTypeFixed64,
+        Type::TYPE_STRING | Type::TYPE_BYTES | Type::TYPE_MESSAGE => {
+            WireType::WireTypeLengthDelimited
+        }
+        Type::TYPE_GROUP => panic!("group fields are not supported by reflection codec"),
+    }
+}
+
+fn is_packable(t: Type) -> bool {
+    wire_type_for(t) != WireType::WireTypeLengthDelimited
+}
+
+// same as `packed` of generated code for proto2 and proto3 files
+fn is_packed(field: &FieldDescriptor) -> bool {
+    field.proto().options.get_message().get_packed()
+}
+
+// key and value types of map field, taken from map entry message
+// nested in the message declaring the field
+fn map_entry_types(descriptor: &MessageDescriptor, field: &FieldDescriptor) -> (Type, Type) {
+    let type_name = field.proto().get_type_name();
+    let entry_name = &type_name[type_name.rfind('.').map_or(0, |i| i + 1)..];
+    let entry = descriptor
+        .get_proto()
+        .nested_type
+        .iter()
+        .find(|n| n.get_name() == entry_name)
+        .expect("map entry message not found");
+    (
+        entry.field[0].get_field_type(),
+        entry.field[1].get_field_type(),
+    )
+}
+
+// fields in the order generated `write_to_with_cached_sizes` writes them:
+// regular fields in declaration order, then oneof fields grouped by oneof
+fn fields_in_write_order(descriptor: &MessageDescriptor) -> Vec<&FieldDescriptor> {
+    let mut fields: Vec<_> = descriptor.fields().iter().collect();
+    fields.sort_by_key(|f| {
+        if f.proto().has_oneof_index() {
+            f.proto().get_oneof_index() + 1
+        } else {
+            0
+        }
+    });
+    fields
+}
+
+enum ReadValue {
+    Known(ReflectValueBox),
+    UnknownEnum(i32),
+}
+
+fn read_value(
+    t: Type,
+    runtime_type: &RuntimeTypeDynamic,
+    is: &mut CodedInputStream,
+) -> ProtobufResult<ReadValue> {
+    let value = match t {
+        Type::TYPE_DOUBLE => ReflectValueBox::F64(is.read_double()?),
+        Type::TYPE_FLOAT => ReflectValueBox::F32(is.read_float()?),
+        Type::TYPE_INT64 => ReflectValueBox::I64(is.read_int64()?),
+        Type::TYPE_UINT64 => ReflectValueBox::U64(is.read_uint64()?),
+        Type::TYPE_INT32 => ReflectValueBox::I32(is.read_int32()?),
+        Type::TYPE_FIXED64 => ReflectValueBox::U64(is.read_fixed64()?),
+        Type::TYPE_FIXED32 => ReflectValueBox::U32(is.read_fixed32()?),
+        Type::TYPE_BOOL => ReflectValueBox::Bool(is.read_bool()?),
+        Type::TYPE_STRING => ReflectValueBox::String(is.read_string()?),
+        Type::TYPE_BYTES => ReflectValueBox::Bytes(is.read_bytes()?),
+        Type::TYPE_UINT32 => ReflectValueBox::U32(is.read_uint32()?),
+        Type::TYPE_SFIXED32 => ReflectValueBox::I32(is.read_sfixed32()?),
+        Type::TYPE_SFIXED64 => ReflectValueBox::I64(is.read_sfixed64()?),
+        Type::TYPE_SINT32 => ReflectValueBox::I32(is.read_sint32()?),
+        Type::TYPE_SINT64 => ReflectValueBox::I64(is.read_sint64()?),
+        Type::TYPE_ENUM => {
+            let number = is.read_int32()?;
+            match runtime_type.enum_descriptor().value_by_number(number) {
+                Some(v) => ReflectValueBox::Enum(v),
+                None => return Ok(ReadValue::UnknownEnum(number)),
+            }
+        }
+        Type::TYPE_MESSAGE => {
+            let mut m = runtime_type.message_descriptor().new_instance();
+            is.incr_recursion()?;
+            let r = is.merge_message(&mut *m);
+            is.decr_recursion();
+            r?;
+            ReflectValueBox::Message(m)
+        }
+        Type::TYPE_GROUP => panic!("group fields are not supported by reflection codec"),
+    };
+    Ok(ReadValue::Known(value))
+}
+
+fn unknown_enum_value(
+    field: &FieldDescriptor,
+    value: i32,
+    m: &mut Message,
+    is: &CodedInputStream,
+) -> ProtobufResult<()> {
+    if is.reject_unknown_enum_values() {
+        return Err(ProtobufError::WireError(WireError::UnknownEnumValue {
+            field: field.proto().get_name(),
+            value,
+        }));
+    }
+    let field_number = field.proto().get_number() as u32;
+    m.mut_unknown_fields()
+        .add_varint(field_number, value as i64 as u64);
+    Ok(())
+}
+
+fn read_repeated_element(
+    field: &FieldDescriptor,
+    t: Type,
+    runtime_type: &RuntimeTypeDynamic,
+    m: &mut Message,
+    is: &mut CodedInputStream,
+) -> ProtobufResult<()> {
+    is.check_repeated_len(field.mut_repeated(m).len() + 1)?;
+    match read_value(t, runtime_type, is)? {
+        ReadValue::Known(v) => field.mut_repeated(m).push(v),
+        ReadValue::UnknownEnum(v) => unknown_enum_value(field, v, m, is)?,
+    }
+    Ok(())
+}
+
+fn read_map_entry_value(
+    t: Type,
+    runtime_type: &RuntimeTypeDynamic,
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+) -> ProtobufResult<ReadValue> {
+    if wire_type != wire_type_for(t) {
+        return Err(rt::unexpected_wire_type(wire_type));
+    }
+    read_value(t, runtime_type, is)
+}
+
+// map entry with unknown enum value is stored in unknown fields as is
+fn map_entry_with_unknown_enum_value(
+    key_type: Type,
+    key: &ReflectValueBox,
+    value: i32,
+) -> ProtobufResult<Vec<u8>> {
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        os.write_tag(1, wire_type_for(key_type))?;
+        write_value(key_type, &key.as_value_ref(), &mut os)?;
+        os.write_tag(2, WireType::WireTypeVarint)?;
+        os.write_enum_no_tag(value)?;
+        os.flush()?;
+    }
+    Ok(bytes)
+}
+
+fn merge_field(
+    descriptor: &MessageDescriptor,
+    field: &FieldDescriptor,
+    wire_type: WireType,
+    m: &mut Message,
+    is: &mut CodedInputStream,
+) -> ProtobufResult<()> {
+    let t = field.proto().get_field_type();
+    match field.runtime_field_type() {
+        RuntimeFieldType::Singular(runtime_type) => {
+            if wire_type != wire_type_for(t) {
+                return Err(rt::unexpected_wire_type(wire_type));
+            }
+            match read_value(t, runtime_type, is)? {
+                ReadValue::Known(v) => field.set_singular_field(m, v),
+                ReadValue::UnknownEnum(v) => unknown_enum_value(field, v, m, is)?,
+            }
+        }
+        RuntimeFieldType::Repeated(runtime_type) => {
+            if wire_type == WireType::WireTypeLengthDelimited && is_packable(t) {
+                let len = is.read_raw_varint64()?;
+                let old_limit = is.push_limit(len)?;
+                while !is.eof()? {
+                    read_repeated_element(field, t, runtime_type, m, is)?;
+                }
+                is.pop_limit(old_limit);
+            } else if wire_type == wire_type_for(t) {
+                read_repeated_element(field, t, runtime_type, m, is)?;
+            } else {
+                return Err(rt::unexpected_wire_type(wire_type));
+            }
+        }
+        RuntimeFieldType::Map(key_runtime_type, value_runtime_type) => {
+            if wire_type != WireType::WireTypeLengthDelimited {
+                return Err(rt::unexpected_wire_type(wire_type));
+            }
+            let (key_type, value_type) = map_entry_types(descriptor, field);
+            let mut key = key_runtime_type.default_value_ref().to_box();
+            let mut value = ReadValue::Known(value_runtime_type.default_value_ref().to_box());
+
+            let len = is.read_raw_varint32()?;
+            let old_limit = is.push_limit(len as u64)?;
+            while !is.eof()? {
+                let (field_number, wire_type) = is.read_tag_unpack()?;
+                match field_number {
+                    1 => match read_map_entry_value(key_type, key_runtime_type, wire_type, is)? {
+                        ReadValue::Known(k) => key = k,
+                        ReadValue::UnknownEnum(..) => unreachable!("map key cannot be enum"),
+                    },
+                    2 => {
+                        value = read_map_entry_value(value_type, value_runtime_type, wire_type, is)?
+                    }
+                    _ => is.skip_field(wire_type)?,
+                }
+            }
+            is.pop_limit(old_limit);
+
+            match value {
+                ReadValue::Known(value) => {
+                    let mut map = field.mut_map(m);
+                    map.insert(key, value);
+                    is.check_repeated_len(map.len())?;
+                }
+                ReadValue::UnknownEnum(value) => {
+                    let entry = map_entry_with_unknown_enum_value(key_type, &key, value)?;
+                    let field_number = field.proto().get_number() as u32;
+                    m.mut_unknown_fields()
+                        .add_length_delimited(field_number, entry);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Implementation of `Message::merge_from` using message reflection.
+pub fn merge_from_reflect(m: &mut Message, is: &mut CodedInputStream) -> ProtobufResult<()> {
+    let descriptor = m.descriptor();
+    while !is.eof()? {
+        let field_start = is.pos();
+        let (field_number, wire_type) = is.read_tag_unpack()?;
+        match descriptor.field_by_number(field_number) {
+            Some(field) if is.field_allowed(field_number) => {
+                merge_field(descriptor, field, wire_type, m, is)?;
+            }
+            _ => {
+                rt::read_unknown_or_skip_group(
+                    field_number,
+                    wire_type,
+                    is,
+                    m.mut_unknown_fields(),
+                )?;
+            }
+        }
+        is.record_field_offset(field_number, field_start);
+    }
+    Ok(())
+}
+
+// size of value without tag,
+// nested message sizes are computed or taken from cache
+fn value_size(t: Type, value: &ReflectValueRef, cached_sizes: bool) -> u32 {
+    match (t, value) {
+        (Type::TYPE_INT32, &ReflectValueRef::I32(v)) => {
+            rt::compute_raw_varint64_size(v as i64 as u64)
+        }
+        (Type::TYPE_INT64, &ReflectValueRef::I64(v)) => rt::compute_raw_varint64_size(v as u64),
+        (Type::TYPE_UINT32, &ReflectValueRef::U32(v)) => rt::compute_raw_varint32_size(v),
+        (Type::TYPE_UINT64, &ReflectValueRef::U64(v)) => rt::compute_raw_varint64_size(v),
+        (Type::TYPE_SINT32, &ReflectValueRef::I32(v)) => {
+            rt::compute_raw_varint32_size(encode_zig_zag_32(v))
+        }
+        (Type::TYPE_SINT64, &ReflectValueRef::I64(v)) => {
+            rt::compute_raw_varint64_size(encode_zig_zag_64(v))
+        }
+        (Type::TYPE_BOOL, &ReflectValueRef::Bool(..)) => 1,
+        (Type::TYPE_ENUM, &ReflectValueRef::Enum(v)) => {
+            rt::compute_raw_varint64_size(v.value() as i64 as u64)
+        }
+        (Type::TYPE_FIXED32, _) | (Type::TYPE_SFIXED32, _) | (Type::TYPE_FLOAT, _) => 4,
+        (Type::TYPE_FIXED64, _) | (Type::TYPE_SFIXED64, _) | (Type::TYPE_DOUBLE, _) => 8,
+        (Type::TYPE_STRING, &ReflectValueRef::String(v)) => {
+            rt::compute_raw_varint32_size(v.len() as u32) + v.len() as u32
+        }
+        (Type::TYPE_BYTES, &ReflectValueRef::Bytes(v)) => {
+            rt::compute_raw_varint32_size(v.len() as u32) + v.len() as u32
+        }
+        (Type::TYPE_MESSAGE, &ReflectValueRef::Message(v)) => {
+            let len = if cached_sizes {
+                v.get_cached_size()
+            } else {
+                v.compute_size()
+            };
+            rt::compute_raw_varint32_size(len) + len
+        }
+        (t, v) => panic!("value {:?} does not match field type {:?}", v, t),
+    }
+}
+
+fn write_value(t: Type, value: &ReflectValueRef, os: &mut CodedOutputStream) -> ProtobufResult<()> {
+    match (t, value) {
+        (Type::TYPE_INT32, &ReflectValueRef::I32(v)) => os.write_int32_no_tag(v),
+        (Type::TYPE_INT64, &ReflectValueRef::I64(v)) => os.write_int64_no_tag(v),
+        (Type::TYPE_UINT32, &ReflectValueRef::U32(v)) => os.write_uint32_no_tag(v),
+        (Type::TYPE_UINT64, &ReflectValueRef::U64(v)) => os.write_uint64_no_tag(v),
+        (Type::TYPE_SINT32, &ReflectValueRef::I32(v)) => os.write_sint32_no_tag(v),
+        (Type::TYPE_SINT64, &ReflectValueRef::I64(v)) => os.write_sint64_no_tag(v),
+        (Type::TYPE_BOOL, &ReflectValueRef::Bool(v)) => os.write_bool_no_tag(v),
+        (Type::TYPE_ENUM, &ReflectValueRef::Enum(v)) => os.write_enum_no_tag(v.value()),
+        (Type::TYPE_FIXED32, &ReflectValueRef::U32(v)) => os.write_fixed32_no_tag(v),
+        (Type::TYPE_SFIXED32, &ReflectValueRef::I32(v)) => os.write_sfixed32_no_tag(v),
+        (Type::TYPE_FLOAT, &ReflectValueRef::F32(v)) => os.write_float_no_tag(v),
+        (Type::TYPE_FIXED64, &ReflectValueRef::U64(v)) => os.write_fixed64_no_tag(v),
+        (Type::TYPE_SFIXED64, &ReflectValueRef::I64(v)) => os.write_sfixed64_no_tag(v),
+        (Type::TYPE_DOUBLE, &ReflectValueRef::F64(v)) => os.write_double_no_tag(v),
+        (Type::TYPE_STRING, &ReflectValueRef::String(v)) => os.write_string_no_tag(v),
+        (Type::TYPE_BYTES, &ReflectValueRef::Bytes(v)) => os.write_bytes_no_tag(v),
+        (Type::TYPE_MESSAGE, &ReflectValueRef::Message(v)) => {
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)
+        }
+        (t, v) => panic!("value {:?} does not match field type {:?}", v, t),
+    }
+}
+
+fn map_entry_size(
+    key_type: Type,
+    key: &ReflectValueRef,
+    value_type: Type,
+    value: &ReflectValueRef,
+    cached_sizes: bool,
+) -> u32 {
+    // key and value tags are one byte each
+    1 + value_size(key_type, key, cached_sizes) + 1 + value_size(value_type, value, cached_sizes)
+}
+
+fn field_size(descriptor: &MessageDescriptor, field: &FieldDescriptor, m: &Message) -> u32 {
+    let field_number = field.proto().get_number() as u32;
+    let t = field.proto().get_field_type();
+    match field.get_reflect(m) {
+        ReflectFieldRef::Optional(Some(v)) => rt::tag_size(field_number) + value_size(t, &v, false),
+        ReflectFieldRef::Optional(None) => 0,
+        ReflectFieldRef::Repeated(ref repeated) if repeated.is_empty() => 0,
+        ReflectFieldRef::Repeated(ref repeated) => {
+            if is_packed(field) {
+                let data_size: u32 = repeated.into_iter().map(|v| value_size(t, &v, false)).sum();
+                rt::tag_size(field_number) + rt::compute_raw_varint32_size(data_size) + data_size
+            } else {
+                repeated
+                    .into_iter()
+                    .map(|v| rt::tag_size(field_number) + value_size(t, &v, false))
+                    .sum()
+            }
+        }
+        ReflectFieldRef::Map(ref map) => {
+            let (key_type, value_type) = map_entry_types(descriptor, field);
+            map.into_iter()
+                .map(|(k, v)| {
+                    let entry_size = map_entry_size(key_type, &k, value_type, &v, false);
+                    rt::tag_size(field_number)
+                        + rt::compute_raw_varint32_size(entry_size)
+                        + entry_size
+                })
+                .sum()
+        }
+    }
+}
+
+/// Implementation of `Message::compute_size` using message reflection.
+///
+/// Sizes of nested messages are cached, but size of `m`
+/// must be cached by the caller.
+pub fn compute_size_reflect(m: &Message) -> u32 {
+    let descriptor = m.descriptor();
+    let mut size = 0;
+    for field in fields_in_write_order(descriptor) {
+        size += field_size(descriptor, field, m);
+    }
+    size + rt::unknown_fields_size(m.get_unknown_fields())
+}
+
+fn write_field(
+    descriptor: &MessageDescriptor,
+    field: &FieldDescriptor,
+    m: &Message,
+    os: &mut CodedOutputStream,
+) -> ProtobufResult<()> {
+    let field_number = field.proto().get_number() as u32;
+    let t = field.proto().get_field_type();
+    match field.get_reflect(m) {
+        ReflectFieldRef::Optional(Some(v)) => {
+            os.write_tag(field_number, wire_type_for(t))?;
+            write_value(t, &v, os)?;
+        }
+        ReflectFieldRef::Optional(None) => {}
+        ReflectFieldRef::Repeated(ref repeated) if repeated.is_empty() => {}
+        ReflectFieldRef::Repeated(ref repeated) => {
+            if is_packed(field) {
+                let data_size: u32 = repeated.into_iter().map(|v| value_size(t, &v, true)).sum();
+                os.write_tag(field_number, WireType::WireTypeLengthDelimited)?;
+                os.write_raw_varint32(data_size)?;
+                for v in repeated {
+                    write_value(t, &v, os)?;
+                }
+            } else {
+                for v in repeated {
+                    os.write_tag(field_number, wire_type_for(t))?;
+                    write_value(t, &v, os)?;
+                }
+            }
+        }
+        ReflectFieldRef::Map(ref map) => {
+            let (key_type, value_type) = map_entry_types(descriptor, field);
+            for (k, v) in map {
+                os.write_tag(field_number, WireType::WireTypeLengthDelimited)?;
+                os.write_raw_varint32(map_entry_size(key_type, &k, value_type, &v, true))?;
+                os.write_tag(1, wire_type_for(key_type))?;
+                write_value(key_type, &k, os)?;
+                os.write_tag(2, wire_type_for(value_type))?;
+                write_value(value_type, &v, os)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Implementation of `Message::write_to_with_cached_sizes` using message reflection.
+pub fn write_to_with_cached_sizes_reflect(
+    m: &Message,
+    os: &mut CodedOutputStream,
+) -> ProtobufResult<()> {
+    let descriptor = m.descriptor();
+    for field in fields_in_write_order(descriptor) {
+        write_field(descriptor, field, m, os)?;
+    }
+    os.write_unknown_fields(m.get_unknown_fields())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::field_descriptor_proto::Label;
+    use descriptor::DescriptorProto;
+    use descriptor::EnumDescriptorProto;
+    use descriptor::FieldDescriptorProto;
+    use descriptor::FileDescriptorProto;
+    use parse_from_bytes;
+
+    // descriptor.proto messages are generated with `optimize_for = SPEED`,
+    // so reflection codec must produce the same bytes
+    #[test]
+    fn same_as_generated() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        file.dependency.push("bar.proto".to_owned());
+        file.public_dependency.push(0);
+        file.message_type.push(DescriptorProto::new());
+        file.message_type[0].set_name("Foo".to_owned());
+        file.message_type[0].field.push(FieldDescriptorProto::new());
+        file.message_type[0].field[0].set_number(-1);
+        file.message_type[0].field[0].set_label(Label::LABEL_REPEATED);
+        file.enum_type.push(EnumDescriptorProto::new());
+        file.options.set_default().set_java_package("foo".to_owned());
+        file.mut_unknown_fields().add_varint(100, 1);
+
+        let bytes = file.write_to_bytes().unwrap();
+
+        let size = compute_size_reflect(&file);
+        assert_eq!(bytes.len() as u32, size);
+        let mut reflect_bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut reflect_bytes);
+            write_to_with_cached_sizes_reflect(&file, &mut os).unwrap();
+            os.flush().unwrap();
+        }
+        assert_eq!(bytes, reflect_bytes);
+
+        let mut parsed = FileDescriptorProto::new();
+        merge_from_reflect(&mut parsed, &mut CodedInputStream::from_bytes(&bytes)).unwrap();
+        assert_eq!(file, parsed);
+        assert_eq!(file, parse_from_bytes::<FileDescriptorProto>(&bytes).unwrap());
+    }
+}
@@ -0,0 +1,99 @@
+//! Overwrite message with the content of another message of the same type,
+//! e. g. for generic assignment when only `&mut Message` is available.
+
+use std::fmt;
+
+use reflect::ReflectFieldRef;
+use Message;
+
+/// Error returned by `copy`.
+#[derive(Debug)]
+pub enum CopyError {
+    /// Messages are of different types; contains full names of destination and source types
+    DifferentDescriptors(String, String),
+}
+
+impl fmt::Display for CopyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CopyError::DifferentDescriptors(ref dst, ref src) => {
+                write!(f, "cannot copy message of type {} into {}", src, dst)
+            }
+        }
+    }
+}
+
+/// Replace the content of `dst` with the content of `src`.
+///
+/// Unlike `merge_from`, fields set in `dst` but not in `src` are cleared,
+/// and repeated and map fields are replaced rather than appended to.
+/// Unknown fields are copied too.
+pub fn copy(dst: &mut Message, src: &Message) -> Result<(), CopyError> {
+    if dst.descriptor() != src.descriptor() {
+        return Err(CopyError::DifferentDescriptors(
+            dst.descriptor().full_name().to_owned(),
+            src.descriptor().full_name().to_owned(),
+        ));
+    }
+    dst.clear();
+    for field in src.descriptor().fields() {
+        match field.get_reflect(src) {
+            ReflectFieldRef::Optional(None) => {}
+            ReflectFieldRef::Optional(Some(v)) => field.set_singular_field(dst, v.to_box()),
+            ReflectFieldRef::Repeated(v) => {
+                let mut repeated = field.mut_repeated(dst);
+                for e in &v {
+                    repeated.push(e.to_box());
+                }
+            }
+            ReflectFieldRef::Map(v) => {
+                let mut map = field.mut_map(dst);
+                for (k, e) in &v {
+                    map.insert(k.to_box(), e.to_box());
+                }
+            }
+        }
+    }
+    dst.mut_unknown_fields().clone_from(src.get_unknown_fields());
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::DescriptorProto;
+    use descriptor::FileDescriptorProto;
+
+    #[test]
+    fn overwrite() {
+        let mut src = FileDescriptorProto::new();
+        src.set_name("foo.proto".to_owned());
+        src.dependency.push("bar.proto".to_owned());
+        src.message_type.push(DescriptorProto::new());
+        src.message_type[0].set_name("Foo".to_owned());
+        src.mut_unknown_fields().add_varint(100, 1);
+
+        let mut dst = FileDescriptorProto::new();
+        dst.set_package("baz".to_owned());
+        dst.dependency.push("qux.proto".to_owned());
+        dst.dependency.push("quux.proto".to_owned());
+        dst.options.set_default().set_java_package("baz".to_owned());
+        dst.mut_unknown_fields().add_varint(101, 2);
+
+        copy(&mut dst, &src).unwrap();
+        assert_eq!(src, dst);
+        assert!(!dst.has_package());
+        assert!(dst.options.is_none());
+    }
+
+    #[test]
+    fn different_descriptors() {
+        match copy(&mut FileDescriptorProto::new(), &DescriptorProto::new()) {
+            Err(CopyError::DifferentDescriptors(dst, src)) => {
+                assert_eq!("google.protobuf.FileDescriptorProto", dst);
+                assert_eq!("google.protobuf.DescriptorProto", src);
+            }
+            r => panic!("unexpected: {:?}", r),
+        }
+    }
+}
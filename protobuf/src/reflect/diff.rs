@@ -0,0 +1,181 @@
+//! Field-level difference between two messages, e. g. to explain
+//! assertion failures in tests.
+
+use std::fmt;
+
+use reflect::ReflectFieldRef;
+use reflect::ReflectMapRef;
+use reflect::ReflectRepeatedRef;
+use reflect::ReflectValueBox;
+use reflect::ReflectValueRef;
+use Message;
+
+/// Single difference found by `diff`.
+#[derive(Debug, Clone)]
+pub struct FieldDiff {
+    /// Dotted path to the field, e. g. `foo.bar[3].baz`.
+    /// Repeated elements are addressed by index, map entries by key.
+    pub path: String,
+    /// Value in the first message, `None` if not present.
+    pub left: Option<ReflectValueBox>,
+    /// Value in the second message, `None` if not present.
+    pub right: Option<ReflectValueBox>,
+}
+
+/// Error returned by `diff`.
+#[derive(Debug)]
+pub enum DiffError {
+    /// Messages are of different types; contains full names of both types
+    DifferentDescriptors(String, String),
+}
+
+impl fmt::Display for DiffError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DiffError::DifferentDescriptors(ref a, ref b) => {
+                write!(f, "cannot diff messages of different types: {} and {}", a, b)
+            }
+        }
+    }
+}
+
+/// Find fields which differ in two messages of the same type.
+///
+/// Fields of submessages present in both messages are compared recursively,
+/// so a single changed nested field is reported as a single `FieldDiff`
+/// with a dotted path. Unknown fields are not compared.
+pub fn diff(a: &Message, b: &Message) -> Result<Vec<FieldDiff>, DiffError> {
+    if a.descriptor() != b.descriptor() {
+        return Err(DiffError::DifferentDescriptors(
+            a.descriptor().full_name().to_owned(),
+            b.descriptor().full_name().to_owned(),
+        ));
+    }
+    let mut r = Vec::new();
+    diff_messages("", a, b, &mut r);
+    Ok(r)
+}
+
+fn diff_messages(prefix: &str, a: &Message, b: &Message, r: &mut Vec<FieldDiff>) {
+    for field in a.descriptor().fields() {
+        let path = format!("{}{}", prefix, field.name());
+        match (field.get_reflect(a), field.get_reflect(b)) {
+            (ReflectFieldRef::Optional(a), ReflectFieldRef::Optional(b)) => {
+                diff_values(path, a, b, r);
+            }
+            (ReflectFieldRef::Repeated(a), ReflectFieldRef::Repeated(b)) => {
+                diff_repeated(&path, &a, &b, r);
+            }
+            (ReflectFieldRef::Map(a), ReflectFieldRef::Map(b)) => {
+                diff_maps(&path, &a, &b, r);
+            }
+            _ => unreachable!("same field of the same message type"),
+        }
+    }
+}
+
+fn diff_values(
+    path: String,
+    a: Option<ReflectValueRef>,
+    b: Option<ReflectValueRef>,
+    r: &mut Vec<FieldDiff>,
+) {
+    match (a, b) {
+        (None, None) => {}
+        (Some(ReflectValueRef::Message(a)), Some(ReflectValueRef::Message(b))) => {
+            diff_messages(&format!("{}.", path), a, b, r);
+        }
+        (Some(ref a), Some(ref b)) if a == b => {}
+        (a, b) => r.push(FieldDiff {
+            path,
+            left: a.map(|v| v.to_box()),
+            right: b.map(|v| v.to_box()),
+        }),
+    }
+}
+
+fn diff_repeated(
+    path: &str,
+    a: &ReflectRepeatedRef,
+    b: &ReflectRepeatedRef,
+    r: &mut Vec<FieldDiff>,
+) {
+    for i in 0..a.len().max(b.len()) {
+        let a = if i < a.len() { Some(a.get(i)) } else { None };
+        let b = if i < b.len() { Some(b.get(i)) } else { None };
+        diff_values(format!("{}[{}]", path, i), a, b, r);
+    }
+}
+
+fn diff_maps(path: &str, a: &ReflectMapRef, b: &ReflectMapRef, r: &mut Vec<FieldDiff>) {
+    for (k, v) in a {
+        diff_values(format!("{}[{}]", path, map_key_to_string(&k)), Some(v), b.get(k), r);
+    }
+    for (k, v) in b {
+        let key = map_key_to_string(&k);
+        if a.get(k).is_none() {
+            diff_values(format!("{}[{}]", path, key), None, Some(v), r);
+        }
+    }
+}
+
+fn map_key_to_string(key: &ReflectValueRef) -> String {
+    match *key {
+        ReflectValueRef::U32(v) => v.to_string(),
+        ReflectValueRef::U64(v) => v.to_string(),
+        ReflectValueRef::I32(v) => v.to_string(),
+        ReflectValueRef::I64(v) => v.to_string(),
+        ReflectValueRef::Bool(v) => v.to_string(),
+        ReflectValueRef::String(v) => format!("{:?}", v),
+        ref v => format!("{:?}", v),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::DescriptorProto;
+    use descriptor::FileDescriptorProto;
+
+    #[test]
+    fn nested_field() {
+        let mut a = FileDescriptorProto::new();
+        a.set_name("foo.proto".to_owned());
+        a.options.set_default().set_java_package("foo".to_owned());
+        a.message_type.push(DescriptorProto::new());
+        let mut b = a.clone();
+        assert!(diff(&a, &b).unwrap().is_empty());
+
+        b.options.set_default().set_java_package("bar".to_owned());
+        let d = diff(&a, &b).unwrap();
+        assert_eq!(1, d.len());
+        assert_eq!("options.java_package", d[0].path);
+        assert_eq!(Some(ReflectValueBox::String("foo".to_owned())), d[0].left);
+        assert_eq!(Some(ReflectValueBox::String("bar".to_owned())), d[0].right);
+    }
+
+    #[test]
+    fn repeated_field() {
+        let mut a = FileDescriptorProto::new();
+        a.message_type.push(DescriptorProto::new());
+        let mut b = a.clone();
+        b.message_type[0].set_name("Foo".to_owned());
+        b.dependency.push("bar.proto".to_owned());
+
+        let d = diff(&a, &b).unwrap();
+        let paths: Vec<_> = d.iter().map(|d| &d.path[..]).collect();
+        assert_eq!(vec!["dependency[0]", "message_type[0].name"], paths);
+        assert_eq!(None, d[0].left);
+    }
+
+    #[test]
+    fn different_descriptors() {
+        match diff(&FileDescriptorProto::new(), &DescriptorProto::new()) {
+            Err(DiffError::DifferentDescriptors(a, b)) => {
+                assert_eq!("google.protobuf.FileDescriptorProto", a);
+                assert_eq!("google.protobuf.DescriptorProto", b);
+            }
+            r => panic!("unexpected: {:?}", r),
+        }
+    }
+}
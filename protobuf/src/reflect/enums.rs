@@ -226,3 +226,33 @@ impl EnumDescriptor {
         }
     }
 }
+
+/// Numbers and names of all enum values in declaration order,
+/// e. g. to build a list of options in a UI.
+pub fn all_values_display(descriptor: &EnumDescriptor) -> Vec<(i32, &'static str)> {
+    descriptor
+        .values()
+        .iter()
+        .map(|v| (v.value(), v.name()))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::field_descriptor_proto;
+
+    #[test]
+    fn all_values_display_in_declaration_order() {
+        let values =
+            all_values_display(EnumDescriptor::for_type::<field_descriptor_proto::Label>());
+        assert_eq!(
+            vec![
+                (1, "LABEL_OPTIONAL"),
+                (2, "LABEL_REQUIRED"),
+                (3, "LABEL_REPEATED"),
+            ],
+            values
+        );
+    }
+}
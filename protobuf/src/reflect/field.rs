@@ -11,10 +11,14 @@ use reflect::repeated::ReflectRepeatedRef;
 use reflect::EnumDescriptor;
 use reflect::EnumValueDescriptor;
 use reflect::MessageDescriptor;
+use reflect::OneofDescriptor;
+use reflect::message::MessageFactory;
 use reflect::ReflectValueBox;
 use reflect::ReflectValueRef;
 use reflect::RuntimeTypeDynamic;
 use Message;
+use ProtobufError;
+use ProtobufResult;
 use reflect::accessor::singular::SingularFieldAccessorHolder;
 use reflect::accessor::repeated::RepeatedFieldAccessorHolder;
 use reflect::accessor::map::MapFieldAccessorHolder;
@@ -60,12 +64,15 @@ pub struct FieldDescriptor {
     proto: &'static FieldDescriptorProto,
     accessor: FieldAccessor,
     json_name: String,
+    // factory of containing message
+    factory: &'static MessageFactory,
 }
 
 impl FieldDescriptor {
     pub(crate) fn new(
         accessor: FieldAccessor,
         proto: &'static FieldDescriptorProto,
+        factory: &'static MessageFactory,
     ) -> FieldDescriptor {
         assert_eq!(proto.get_name(), accessor.name);
         FieldDescriptor {
@@ -73,6 +80,7 @@ impl FieldDescriptor {
             accessor,
             // probably could be lazy-init
             json_name: json_name(proto.get_name()),
+            factory,
         }
     }
 
@@ -92,6 +100,16 @@ impl FieldDescriptor {
         self.proto.get_label() == field_descriptor_proto::Label::LABEL_REPEATED
     }
 
+    /// Oneof this field belongs to, `None` if field is not a member of oneof
+    pub fn containing_oneof(&self) -> Option<&'static OneofDescriptor> {
+        if self.proto.has_oneof_index() {
+            let oneofs = self.factory.descriptor().oneofs();
+            Some(&oneofs[self.proto.get_oneof_index() as usize])
+        } else {
+            None
+        }
+    }
+
     /// Return enum descriptor for enum field, panics if field type is not enum.
     pub fn enum_descriptor(&self) -> &'static EnumDescriptor {
         match self.accessor.accessor {
@@ -263,6 +281,25 @@ impl FieldDescriptor {
         self.singular().accessor.set_singular_field(m, value)
     }
 
+    /// Set singular field value, checking that value type matches field type.
+    ///
+    /// Return `FieldValueTypeMismatch` error if value type does not match.
+    /// Panic if field is not singular.
+    pub fn try_set_singular_field(
+        &self,
+        m: &mut Message,
+        value: ReflectValueBox,
+    ) -> ProtobufResult<()> {
+        let singular = self.singular();
+        if !singular.element_type.runtime_type().to_box().is_compatible(&value) {
+            return Err(ProtobufError::FieldValueTypeMismatch {
+                field: self.proto.get_name(),
+            });
+        }
+        singular.accessor.set_singular_field(m, value);
+        Ok(())
+    }
+
     pub fn runtime_field_type(&self) -> RuntimeFieldType {
         use self::AccessorKind::*;
         match self.accessor.accessor {
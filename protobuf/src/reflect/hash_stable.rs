@@ -0,0 +1,97 @@
+//! Hashing of message content which does not depend on
+//! in-memory representation, platform or map iteration order.
+
+use std::cmp::Ordering;
+use std::hash::Hasher;
+
+use reflect::ReflectFieldRef;
+use reflect::ReflectValueRef;
+use Message;
+
+// Integers are always written as little-endian bytes,
+// because default `Hasher` functions use native endianness.
+fn write_u32(state: &mut Hasher, v: u32) {
+    state.write(&[v as u8, (v >> 8) as u8, (v >> 16) as u8, (v >> 24) as u8]);
+}
+
+fn write_u64(state: &mut Hasher, v: u64) {
+    write_u32(state, v as u32);
+    write_u32(state, (v >> 32) as u32);
+}
+
+fn write_bytes(state: &mut Hasher, v: &[u8]) {
+    write_u64(state, v.len() as u64);
+    state.write(v);
+}
+
+fn hash_value(value: &ReflectValueRef, state: &mut Hasher) {
+    match *value {
+        ReflectValueRef::U32(v) => write_u32(state, v),
+        ReflectValueRef::U64(v) => write_u64(state, v),
+        ReflectValueRef::I32(v) => write_u32(state, v as u32),
+        ReflectValueRef::I64(v) => write_u64(state, v as u64),
+        // `-0.0 == 0.0`, so they must have the same hash
+        ReflectValueRef::F32(v) => write_u32(state, if v == 0.0 { 0 } else { v.to_bits() }),
+        ReflectValueRef::F64(v) => write_u64(state, if v == 0.0 { 0 } else { v.to_bits() }),
+        ReflectValueRef::Bool(v) => write_u32(state, v as u32),
+        ReflectValueRef::String(v) => write_bytes(state, v.as_bytes()),
+        ReflectValueRef::Bytes(v) => write_bytes(state, v),
+        ReflectValueRef::Enum(v) => write_u32(state, v.value() as u32),
+        ReflectValueRef::Message(m) => hash_message(m, state),
+    }
+}
+
+fn cmp_map_keys(a: &ReflectValueRef, b: &ReflectValueRef) -> Ordering {
+    match (a, b) {
+        (&ReflectValueRef::U32(a), &ReflectValueRef::U32(b)) => a.cmp(&b),
+        (&ReflectValueRef::U64(a), &ReflectValueRef::U64(b)) => a.cmp(&b),
+        (&ReflectValueRef::I32(a), &ReflectValueRef::I32(b)) => a.cmp(&b),
+        (&ReflectValueRef::I64(a), &ReflectValueRef::I64(b)) => a.cmp(&b),
+        (&ReflectValueRef::Bool(a), &ReflectValueRef::Bool(b)) => a.cmp(&b),
+        (&ReflectValueRef::String(a), &ReflectValueRef::String(b)) => a.cmp(b),
+        _ => panic!("not a map key: {:?}", a),
+    }
+}
+
+/// Hash message fields in field number order, ignoring unknown fields.
+pub(crate) fn hash_message(m: &Message, state: &mut Hasher) {
+    let mut fields: Vec<_> = m.descriptor().fields().iter().collect();
+    fields.sort_by_key(|f| f.proto().get_number());
+
+    for field in fields {
+        let number = field.proto().get_number() as u32;
+        match field.get_reflect(m) {
+            ReflectFieldRef::Optional(None) => {}
+            ReflectFieldRef::Optional(Some(v)) => {
+                write_u32(state, number);
+                hash_value(&v, state);
+            }
+            ReflectFieldRef::Repeated(repeated) => {
+                if repeated.is_empty() {
+                    continue;
+                }
+                write_u32(state, number);
+                write_u64(state, repeated.len() as u64);
+                for i in 0..repeated.len() {
+                    hash_value(&repeated.get(i), state);
+                }
+            }
+            ReflectFieldRef::Map(map) => {
+                if map.is_empty() {
+                    continue;
+                }
+                write_u32(state, number);
+                write_u64(state, map.len() as u64);
+                let mut entries: Vec<_> = (&map).into_iter().collect();
+                entries.sort_by(|a, b| cmp_map_keys(&a.0, &b.0));
+                for (k, v) in entries {
+                    hash_value(&k, state);
+                    hash_value(&v, state);
+                }
+            }
+        }
+    }
+
+    // field numbers are positive, so zero marks the end of message
+    write_u32(state, 0);
+}
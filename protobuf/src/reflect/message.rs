@@ -9,13 +9,15 @@ use descriptor::FileDescriptorProto;
 use reflect::accessor::FieldAccessor;
 use reflect::reflect_deep_eq::ReflectDeepEq;
 use reflect::FieldDescriptor;
+use reflect::OneofDescriptor;
 use reflect::find_message_or_enum::find_message_or_enum;
 use reflect::find_message_or_enum::MessageOrEnum;
 
 use json;
 
 
-trait MessageFactory: Send + Sync + 'static {
+pub(crate) trait MessageFactory: Send + Sync + 'static {
+    fn descriptor(&self) -> &'static MessageDescriptor;
     fn new_instance(&self) -> Box<Message>;
     fn default_instance(&self) -> &Message;
     fn clone(&self, message: &Message) -> Box<Message>;
@@ -28,6 +30,10 @@ impl<M> MessageFactory for MessageFactoryImpl<M>
 where
     M: 'static + Message + Default + Clone + PartialEq,
 {
+    fn descriptor(&self) -> &'static MessageDescriptor {
+        M::descriptor_static()
+    }
+
     fn new_instance(&self) -> Box<Message> {
         let m: M = Default::default();
         Box::new(m)
@@ -55,6 +61,7 @@ pub struct MessageDescriptor {
     proto: &'static DescriptorProto,
     factory: &'static MessageFactory,
     fields: Vec<FieldDescriptor>,
+    oneofs: Vec<OneofDescriptor>,
 
     index_by_name: HashMap<String, usize>,
     index_by_name_or_json_name: HashMap<String, usize>,
@@ -127,6 +134,22 @@ impl MessageDescriptor {
             }
         }
 
+        let oneofs = proto
+            .oneof_decl
+            .iter()
+            .enumerate()
+            .map(|(oneof_index, oneof_proto)| {
+                let field_indices = proto
+                    .field
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, f)| {
+                        f.has_oneof_index() && f.get_oneof_index() as usize == oneof_index
+                    }).map(|(i, _)| i)
+                    .collect();
+                OneofDescriptor::new(oneof_proto, factory, field_indices)
+            }).collect();
+
         MessageDescriptor {
             full_name: MessageDescriptor::compute_full_name(
                 file_descriptor_proto.get_package(), &path_to_package, &proto),
@@ -136,8 +159,9 @@ impl MessageDescriptor {
                 .into_iter()
                 .map(|f| {
                     let proto = *field_proto_by_name.get(f.name).unwrap();
-                    FieldDescriptor::new(f, proto)
+                    FieldDescriptor::new(f, proto, factory)
                 }).collect(),
+            oneofs,
             index_by_name,
             index_by_name_or_json_name,
             index_by_number,
@@ -216,6 +240,11 @@ impl MessageDescriptor {
         &self.fields
     }
 
+    /// Oneofs declared in this message
+    pub fn oneofs<'a>(&'a self) -> &'a [OneofDescriptor] {
+        &self.oneofs
+    }
+
     /// Find message field by field name
     pub fn field_by_name<'a>(&'a self, name: &str) -> Option<&'a FieldDescriptor> {
         let &index = self.index_by_name.get(name)?;
@@ -1,10 +1,12 @@
 //! Reflection implementation for protobuf types.
 
 mod accessor;
+mod codec;
 mod enums;
 mod field;
 mod map;
 mod message;
+mod oneof;
 mod repeated;
 pub(crate) mod runtime_type_box;
 pub(crate) mod runtime_type_dynamic;
@@ -15,7 +17,14 @@ pub mod types;
 mod value;
 pub(crate) mod find_message_or_enum;
 
+mod copy;
+mod diff;
 mod reflect_deep_eq;
+mod registry;
+mod set_field_from_str;
+mod size_breakdown;
+mod strip_unknown_fields;
+pub(crate) mod hash_stable;
 
 pub mod rt;
 
@@ -29,14 +38,36 @@ pub use self::repeated::ReflectRepeatedRef;
 pub use self::map::ReflectMapMut;
 pub use self::map::ReflectMapRef;
 
+pub use self::enums::all_values_display;
 pub use self::enums::EnumDescriptor;
 pub use self::enums::EnumValueDescriptor;
 
 pub use self::message::MessageDescriptor;
 
+pub use self::oneof::OneofDescriptor;
+
 pub use self::field::FieldDescriptor;
 pub use self::field::ReflectFieldRef;
 pub use self::field::RuntimeFieldType;
 
 pub use self::runtime_type_box::RuntimeTypeBox;
 pub use self::runtime_type_dynamic::RuntimeTypeDynamic;
+
+pub use self::registry::lookup_message;
+pub use self::registry::register_message;
+pub use self::registry::register_message_descriptor;
+
+pub use self::set_field_from_str::set_field_from_str;
+pub use self::set_field_from_str::SetFieldFromStrError;
+pub use self::set_field_from_str::SetFieldFromStrResult;
+
+pub use self::size_breakdown::size_breakdown;
+
+pub use self::copy::copy;
+pub use self::copy::CopyError;
+
+pub use self::diff::diff;
+pub use self::diff::DiffError;
+pub use self::diff::FieldDiff;
+
+pub use self::strip_unknown_fields::strip_unknown_fields;
@@ -0,0 +1,66 @@
+use std::fmt;
+
+use descriptor::OneofDescriptorProto;
+use reflect::message::MessageFactory;
+use reflect::FieldDescriptor;
+use reflect::MessageDescriptor;
+
+/// Reflection information about oneof: a set of message fields
+/// at most one of which is set.
+pub struct OneofDescriptor {
+    proto: &'static OneofDescriptorProto,
+    factory: &'static MessageFactory,
+    // indices of fields in containing message descriptor
+    field_indices: Vec<usize>,
+}
+
+impl OneofDescriptor {
+    pub(crate) fn new(
+        proto: &'static OneofDescriptorProto,
+        factory: &'static MessageFactory,
+        field_indices: Vec<usize>,
+    ) -> OneofDescriptor {
+        OneofDescriptor {
+            proto,
+            factory,
+            field_indices,
+        }
+    }
+
+    /// Get underlying `OneofDescriptorProto` object.
+    pub fn proto(&self) -> &'static OneofDescriptorProto {
+        self.proto
+    }
+
+    /// Oneof name as specified in `.proto` file
+    pub fn name(&self) -> &'static str {
+        self.proto.get_name()
+    }
+
+    /// Message containing this oneof
+    pub fn containing_message(&self) -> &'static MessageDescriptor {
+        self.factory.descriptor()
+    }
+
+    /// Fields of this oneof in order of declaration
+    pub fn fields(&self) -> Vec<&'static FieldDescriptor> {
+        let fields = self.containing_message().fields();
+        self.field_indices.iter().map(|&i| &fields[i]).collect()
+    }
+}
+
+/// Identity comparison: oneof descriptor are equal if their addresses are equal
+impl PartialEq for OneofDescriptor {
+    fn eq(&self, other: &OneofDescriptor) -> bool {
+        self as *const OneofDescriptor == other as *const OneofDescriptor
+    }
+}
+
+impl fmt::Debug for OneofDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OneofDescriptor")
+            .field("name", &self.name())
+            .field("field_indices", &self.field_indices)
+            .finish()
+    }
+}
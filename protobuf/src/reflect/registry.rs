@@ -0,0 +1,105 @@
+//! Global registry of message descriptors by full name.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use lazy::Lazy;
+use reflect::MessageDescriptor;
+use well_known_types;
+use Message;
+
+static REGISTRY: Lazy<RwLock<HashMap<String, &'static MessageDescriptor>>> = Lazy::INIT;
+
+fn registry() -> &'static RwLock<HashMap<String, &'static MessageDescriptor>> {
+    REGISTRY.get(|| {
+        // well-known types are always available
+        let well_known = [
+            well_known_types::Any::descriptor_static(),
+            well_known_types::Api::descriptor_static(),
+            well_known_types::Method::descriptor_static(),
+            well_known_types::Mixin::descriptor_static(),
+            well_known_types::Duration::descriptor_static(),
+            well_known_types::Empty::descriptor_static(),
+            well_known_types::FieldMask::descriptor_static(),
+            well_known_types::SourceContext::descriptor_static(),
+            well_known_types::Struct::descriptor_static(),
+            well_known_types::Value::descriptor_static(),
+            well_known_types::ListValue::descriptor_static(),
+            well_known_types::Timestamp::descriptor_static(),
+            well_known_types::Type::descriptor_static(),
+            well_known_types::Field::descriptor_static(),
+            well_known_types::Enum::descriptor_static(),
+            well_known_types::EnumValue::descriptor_static(),
+            well_known_types::Option::descriptor_static(),
+            well_known_types::DoubleValue::descriptor_static(),
+            well_known_types::FloatValue::descriptor_static(),
+            well_known_types::Int64Value::descriptor_static(),
+            well_known_types::UInt64Value::descriptor_static(),
+            well_known_types::Int32Value::descriptor_static(),
+            well_known_types::UInt32Value::descriptor_static(),
+            well_known_types::BoolValue::descriptor_static(),
+            well_known_types::StringValue::descriptor_static(),
+            well_known_types::BytesValue::descriptor_static(),
+        ];
+        let map = well_known
+            .iter()
+            .map(|d| (d.full_name().to_owned(), *d))
+            .collect();
+        RwLock::new(map)
+    })
+}
+
+/// Register message descriptor in the global registry,
+/// so it can be found by `lookup_message`.
+///
+/// Registering the same message again is a no-op.
+/// If a different message with the same full name is already registered,
+/// the registered message is kept.
+///
+/// Return `true` if the message was not registered before.
+pub fn register_message_descriptor(descriptor: &'static MessageDescriptor) -> bool {
+    let mut registry = registry().write().unwrap();
+    if registry.contains_key(descriptor.full_name()) {
+        return false;
+    }
+    registry.insert(descriptor.full_name().to_owned(), descriptor);
+    true
+}
+
+/// Register message type in the global registry.
+///
+/// See `register_message_descriptor`.
+pub fn register_message<M: Message>() -> bool {
+    register_message_descriptor(M::descriptor_static())
+}
+
+/// Find registered message descriptor by full name like `pkg.Foo`.
+///
+/// Well-known types are registered by default,
+/// other messages need to be registered with `register_message`.
+/// Message instance can be then created with `MessageDescriptor::new_instance`.
+pub fn lookup_message(full_name: &str) -> Option<&'static MessageDescriptor> {
+    registry().read().unwrap().get(full_name).map(|d| *d)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::FileDescriptorProto;
+    use well_known_types::Duration;
+
+    #[test]
+    fn well_known_registered() {
+        let d = lookup_message("google.protobuf.Duration").unwrap();
+        assert!(d.new_instance().downcast_box::<Duration>().is_ok());
+    }
+
+    #[test]
+    fn register_and_lookup() {
+        assert!(lookup_message("google.protobuf.FileDescriptorProto").is_none());
+        assert!(register_message::<FileDescriptorProto>());
+        assert!(!register_message::<FileDescriptorProto>());
+        let d = lookup_message("google.protobuf.FileDescriptorProto").unwrap();
+        assert_eq!("FileDescriptorProto", d.name());
+    }
+}
@@ -6,6 +6,8 @@ use super::value::ReflectValueRef;
 use reflect::reflect_deep_eq::ReflectDeepEq;
 use reflect::runtime_type_dynamic::RuntimeTypeDynamic;
 use reflect::ReflectValueBox;
+use lazy_packed::LazyPacked;
+use reflect::types::ProtobufType;
 use repeated::RepeatedField;
 use std::fmt;
 
@@ -108,6 +110,75 @@ impl<V: ProtobufValue + fmt::Debug + 'static> ReflectRepeated for RepeatedField<
     }
 }
 
+#[cfg(feature = "with-smallvec")]
+impl<A> ReflectRepeated for ::smallvec::SmallVec<A>
+where
+    A: ::smallvec::Array + Sync + 'static,
+    A::Item: ProtobufValue + fmt::Debug + 'static,
+{
+    fn reflect_iter<'a>(&'a self) -> ReflectRepeatedIter<'a> {
+        ReflectRepeatedIter {
+            imp: Box::new(ReflectRepeatedIterImplSlice::<'a, A::Item> { iter: self.iter() }),
+        }
+    }
+
+    fn len(&self) -> usize {
+        ::smallvec::SmallVec::len(self)
+    }
+
+    fn get(&self, index: usize) -> &ProtobufValue {
+        &self[index]
+    }
+
+    fn set(&mut self, index: usize, value: ReflectValueBox) {
+        let value = value.downcast().expect("wrong type");
+        self[index] = value;
+    }
+
+    fn push(&mut self, value: ReflectValueBox) {
+        let value = value.downcast().expect("wrong type");
+        self.push(value)
+    }
+
+    fn clear(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T: ProtobufType> ReflectRepeated for LazyPacked<T> {
+    fn reflect_iter<'a>(&'a self) -> ReflectRepeatedIter<'a> {
+        ReflectRepeatedIter {
+            imp: Box::new(ReflectRepeatedIterImplSlice::<'a, _> {
+                iter: self.decoded().iter(),
+            }),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.decoded().len()
+    }
+
+    fn get(&self, index: usize) -> &ProtobufValue {
+        &self.decoded()[index]
+    }
+
+    fn set(&mut self, index: usize, value: ReflectValueBox) {
+        let value = value.downcast().expect("wrong type");
+        let mut values = self.decoded().to_vec();
+        values[index] = value;
+        self.set_all(values);
+    }
+
+    fn push(&mut self, value: ReflectValueBox) {
+        let value = value.downcast().expect("wrong type");
+        self.push(value)
+    }
+
+    fn clear(&mut self) {
+        self.clear()
+    }
+}
+
 trait ReflectRepeatedIterTrait<'a> {
     fn next(&mut self) -> Option<&'a ProtobufValue>;
 }
@@ -2,7 +2,10 @@
 //! Should not probably be used directly.
 
 pub use reflect::accessor::map::make_map_accessor;
+pub use reflect::accessor::repeated::make_lazy_packed_accessor;
 pub use reflect::accessor::repeated::make_repeated_field_accessor;
+#[cfg(feature = "with-smallvec")]
+pub use reflect::accessor::repeated::make_small_vec_accessor;
 pub use reflect::accessor::repeated::make_vec_accessor;
 pub use reflect::accessor::singular::make_option_accessor;
 pub use reflect::accessor::singular::make_option_get_copy_accessor;
@@ -12,4 +15,8 @@ pub use reflect::accessor::singular::make_oneof_copy_has_get_set_accessors;
 pub use reflect::accessor::singular::make_oneof_deref_has_get_set_accessor;
 pub use reflect::accessor::singular::make_oneof_message_has_get_mut_set_accessor;
 pub use reflect::accessor::singular::make_option_enum_accessor;
+pub use reflect::accessor::singular::make_lazy_message_accessor;
 pub use reflect::accessor::FieldAccessor;
+pub use reflect::codec::compute_size_reflect;
+pub use reflect::codec::merge_from_reflect;
+pub use reflect::codec::write_to_with_cached_sizes_reflect;
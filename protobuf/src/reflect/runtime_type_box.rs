@@ -1,5 +1,6 @@
 use reflect::EnumDescriptor;
 use reflect::MessageDescriptor;
+use reflect::ReflectValueBox;
 
 pub enum RuntimeTypeBox {
     I32,
@@ -16,3 +17,25 @@ pub enum RuntimeTypeBox {
     Enum(&'static EnumDescriptor),
     Message(&'static MessageDescriptor),
 }
+
+impl RuntimeTypeBox {
+    /// Check if value can be stored in a field of this type.
+    pub(crate) fn is_compatible(&self, value: &ReflectValueBox) -> bool {
+        match (self, value) {
+            (&RuntimeTypeBox::I32, &ReflectValueBox::I32(..))
+            | (&RuntimeTypeBox::I64, &ReflectValueBox::I64(..))
+            | (&RuntimeTypeBox::U32, &ReflectValueBox::U32(..))
+            | (&RuntimeTypeBox::U64, &ReflectValueBox::U64(..))
+            | (&RuntimeTypeBox::F32, &ReflectValueBox::F32(..))
+            | (&RuntimeTypeBox::F64, &ReflectValueBox::F64(..))
+            | (&RuntimeTypeBox::Bool, &ReflectValueBox::Bool(..))
+            | (&RuntimeTypeBox::String, &ReflectValueBox::String(..))
+            | (&RuntimeTypeBox::Chars, &ReflectValueBox::String(..))
+            | (&RuntimeTypeBox::VecU8, &ReflectValueBox::Bytes(..))
+            | (&RuntimeTypeBox::CarllercheBytes, &ReflectValueBox::Bytes(..)) => true,
+            (&RuntimeTypeBox::Enum(e), &ReflectValueBox::Enum(v)) => e == v.enum_descriptor(),
+            (&RuntimeTypeBox::Message(d), &ReflectValueBox::Message(ref m)) => d == m.descriptor(),
+            _ => false,
+        }
+    }
+}
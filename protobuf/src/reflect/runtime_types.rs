@@ -13,6 +13,7 @@ use std::marker;
 #[cfg(feature = "bytes")]
 use Chars;
 use ::{Message, ProtobufEnumOrUnknown};
+use ArcStr;
 use ProtobufEnum;
 use reflect::value::ReflectValueMut;
 
@@ -103,6 +104,8 @@ pub struct RuntimeTypeCarllercheBytes;
 #[cfg(feature = "bytes")]
 #[derive(Debug, Copy, Clone)]
 pub struct RuntimeTypeCarllercheChars;
+#[derive(Debug, Copy, Clone)]
+pub struct RuntimeTypeArcStr;
 
 #[derive(Debug, Copy, Clone)]
 pub struct RuntimeTypeEnum<E: ProtobufEnum>(marker::PhantomData<E>);
@@ -595,6 +598,52 @@ impl RuntimeTypeWithDeref for RuntimeTypeCarllercheChars {
     }
 }
 
+impl RuntimeType for RuntimeTypeArcStr {
+    type Value = ArcStr;
+
+    fn default_value_ref() -> ReflectValueRef<'static> {
+        ReflectValueRef::String("")
+    }
+
+    fn runtime_type_box() -> RuntimeTypeBox
+    where
+        Self: Sized,
+    {
+        RuntimeTypeBox::String
+    }
+
+    fn from_value_box(value_box: ReflectValueBox) -> ArcStr {
+        match value_box {
+            ReflectValueBox::String(v) => v.into(),
+            _ => panic!("wrong type"),
+        }
+    }
+
+    fn into_value_box(value: ArcStr) -> ReflectValueBox {
+        ReflectValueBox::String(value.into())
+    }
+
+    fn as_ref(value: &ArcStr) -> ReflectValueRef {
+        ReflectValueRef::String(&*value)
+    }
+
+    fn is_non_zero(value: &ArcStr) -> bool {
+        !value.is_empty()
+    }
+
+    fn as_mut(_value: &mut Self::Value) -> ReflectValueMut {
+        unimplemented!()
+    }
+}
+
+impl RuntimeTypeWithDeref for RuntimeTypeArcStr {
+    type DerefTarget = str;
+
+    fn defef_as_ref(value: &str) -> ReflectValueRef {
+        ReflectValueRef::String(value)
+    }
+}
+
 impl<E> RuntimeType for RuntimeTypeEnum<E>
 where
     E: ProtobufEnum + ProtobufValue + fmt::Debug,
@@ -0,0 +1,135 @@
+//! Set message fields from strings, e. g. from command line flags
+//! like `--field.subfield=value`.
+
+use std::num::ParseFloatError;
+use std::num::ParseIntError;
+use std::str::ParseBoolError;
+
+use reflect::FieldDescriptor;
+use reflect::ReflectValueBox;
+use reflect::RuntimeFieldType;
+use reflect::RuntimeTypeBox;
+use reflect::RuntimeTypeDynamic;
+use Message;
+
+/// Error returned by `set_field_from_str`.
+#[derive(Debug)]
+pub enum SetFieldFromStrError {
+    /// Message has no field with given name
+    UnknownField(String),
+    /// Field in the middle of the path is not a singular message field
+    NotSingularMessageField(String),
+    /// Message and map fields cannot be set from string
+    UnsupportedFieldType(String),
+    UnknownEnumValueName(String),
+    ParseInt(ParseIntError),
+    ParseFloat(ParseFloatError),
+    ParseBool(ParseBoolError),
+}
+
+impl From<ParseIntError> for SetFieldFromStrError {
+    fn from(e: ParseIntError) -> Self {
+        SetFieldFromStrError::ParseInt(e)
+    }
+}
+
+impl From<ParseFloatError> for SetFieldFromStrError {
+    fn from(e: ParseFloatError) -> Self {
+        SetFieldFromStrError::ParseFloat(e)
+    }
+}
+
+impl From<ParseBoolError> for SetFieldFromStrError {
+    fn from(e: ParseBoolError) -> Self {
+        SetFieldFromStrError::ParseBool(e)
+    }
+}
+
+pub type SetFieldFromStrResult<T> = Result<T, SetFieldFromStrError>;
+
+fn find_field(m: &Message, name: &str) -> SetFieldFromStrResult<&'static FieldDescriptor> {
+    m.descriptor()
+        .field_by_name_or_json_name(name)
+        .ok_or_else(|| SetFieldFromStrError::UnknownField(name.to_owned()))
+}
+
+fn parse_value(
+    field: &FieldDescriptor,
+    t: &RuntimeTypeDynamic,
+    value: &str,
+) -> SetFieldFromStrResult<ReflectValueBox> {
+    Ok(match t.to_box() {
+        RuntimeTypeBox::I32 => ReflectValueBox::I32(value.parse()?),
+        RuntimeTypeBox::I64 => ReflectValueBox::I64(value.parse()?),
+        RuntimeTypeBox::U32 => ReflectValueBox::U32(value.parse()?),
+        RuntimeTypeBox::U64 => ReflectValueBox::U64(value.parse()?),
+        RuntimeTypeBox::F32 => ReflectValueBox::F32(value.parse()?),
+        RuntimeTypeBox::F64 => ReflectValueBox::F64(value.parse()?),
+        RuntimeTypeBox::Bool => ReflectValueBox::Bool(value.parse()?),
+        RuntimeTypeBox::String | RuntimeTypeBox::Chars => ReflectValueBox::from(value),
+        RuntimeTypeBox::VecU8 | RuntimeTypeBox::CarllercheBytes => {
+            ReflectValueBox::from(value.as_bytes())
+        }
+        RuntimeTypeBox::Enum(e) => match e.value_by_name(value) {
+            Some(v) => ReflectValueBox::Enum(v),
+            None => return Err(SetFieldFromStrError::UnknownEnumValueName(value.to_owned())),
+        },
+        RuntimeTypeBox::Message(..) => {
+            return Err(SetFieldFromStrError::UnsupportedFieldType(
+                field.name().to_owned(),
+            ))
+        }
+    })
+}
+
+/// Set a field specified by dotted path like `field.subfield`
+/// to a value parsed from string according to field type.
+///
+/// Path components are field names or JSON names. Intermediate fields
+/// must be singular message fields, they are initialized if unset.
+/// Enum values are parsed by name, `bytes` fields are set
+/// to UTF-8 bytes of the value, and a value is appended to repeated field.
+/// Message and map fields cannot be set.
+pub fn set_field_from_str(m: &mut Message, path: &str, value: &str) -> SetFieldFromStrResult<()> {
+    let mut names: Vec<&str> = path.split('.').collect();
+    let last = names.pop().unwrap();
+
+    let mut m = m;
+    for name in names {
+        let field = find_field(m, name)?;
+        match field.runtime_field_type() {
+            RuntimeFieldType::Singular(t) => match t.to_box() {
+                RuntimeTypeBox::Message(..) => {}
+                _ => {
+                    return Err(SetFieldFromStrError::NotSingularMessageField(
+                        name.to_owned(),
+                    ))
+                }
+            },
+            _ => {
+                return Err(SetFieldFromStrError::NotSingularMessageField(
+                    name.to_owned(),
+                ))
+            }
+        }
+        m = field.mut_message(m);
+    }
+
+    let field = find_field(m, last)?;
+    match field.runtime_field_type() {
+        RuntimeFieldType::Singular(t) => {
+            let value = parse_value(field, t, value)?;
+            field.set_singular_field(m, value);
+        }
+        RuntimeFieldType::Repeated(t) => {
+            let value = parse_value(field, t, value)?;
+            field.mut_repeated(m).push(value);
+        }
+        RuntimeFieldType::Map(..) => {
+            return Err(SetFieldFromStrError::UnsupportedFieldType(
+                field.name().to_owned(),
+            ))
+        }
+    }
+    Ok(())
+}
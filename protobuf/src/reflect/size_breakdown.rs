@@ -0,0 +1,80 @@
+//! Encoded message size by field, e. g. to find which fields
+//! dominate the size of serialized message.
+
+use reflect::ReflectFieldRef;
+use Message;
+
+/// Compute encoded size of each field of the message.
+///
+/// Returns field names with number of bytes the field takes
+/// in serialized message, including field tags and lengths.
+/// Submessage fields are measured as a whole.
+/// Fields not present in the message and unknown fields are omitted.
+///
+/// Size of each field is computed with `compute_size` of a message
+/// which contains only that field, so this function is slow
+/// and should not be used in performance-sensitive code.
+pub fn size_breakdown(m: &Message) -> Vec<(String, usize)> {
+    let descriptor = m.descriptor();
+    let mut r = Vec::new();
+    for field in descriptor.fields() {
+        let mut single = descriptor.new_instance();
+        match field.get_reflect(m) {
+            ReflectFieldRef::Optional(None) => continue,
+            ReflectFieldRef::Optional(Some(v)) => {
+                field.set_singular_field(&mut *single, v.to_box());
+            }
+            ReflectFieldRef::Repeated(repeated) => {
+                if repeated.is_empty() {
+                    continue;
+                }
+                let mut target = field.mut_repeated(&mut *single);
+                for i in 0..repeated.len() {
+                    target.push(repeated.get(i).to_box());
+                }
+            }
+            ReflectFieldRef::Map(map) => {
+                if map.is_empty() {
+                    continue;
+                }
+                let mut target = field.mut_map(&mut *single);
+                for (k, v) in &map {
+                    target.insert(k.to_box(), v.to_box());
+                }
+            }
+        }
+        let size = single.compute_size() as usize;
+        if size != 0 {
+            r.push((field.name().to_owned(), size));
+        }
+    }
+    r
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::DescriptorProto;
+    use descriptor::FieldDescriptorProto;
+
+    #[test]
+    fn breakdown() {
+        let mut m = DescriptorProto::new();
+        m.set_name("Foo".to_owned());
+        for i in 0..10 {
+            let mut field = FieldDescriptorProto::new();
+            field.set_name(format!("field_{}", i));
+            m.field.push(field);
+        }
+
+        let breakdown = size_breakdown(&m);
+        assert_eq!(
+            vec![("name".to_owned(), 5), ("field".to_owned(), 110)],
+            breakdown
+        );
+        let total: usize = breakdown.iter().map(|&(_, s)| s).sum();
+        assert_eq!(m.compute_size() as usize, total);
+        // large repeated field dominates
+        assert!(breakdown[1].1 * 10 > total * 9);
+    }
+}
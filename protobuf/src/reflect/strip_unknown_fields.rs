@@ -0,0 +1,130 @@
+//! Recursively clear unknown fields, e. g. before hashing or comparing messages
+//! which could be parsed from data written by a newer schema.
+
+use reflect::ReflectFieldRef;
+use reflect::ReflectValueBox;
+use reflect::ReflectValueRef;
+use Message;
+use UnknownFields;
+
+fn value_has_unknown_fields(value: &ReflectValueRef) -> bool {
+    match *value {
+        ReflectValueRef::Message(m) => has_unknown_fields(m),
+        _ => false,
+    }
+}
+
+// check before stripping, so repeated and map fields
+// are not rewritten when there is nothing to strip
+fn has_unknown_fields(m: &Message) -> bool {
+    if !m.get_unknown_fields().is_empty() {
+        return true;
+    }
+    m.descriptor()
+        .fields()
+        .iter()
+        .any(|field| match field.get_reflect(m) {
+            ReflectFieldRef::Optional(v) => v.map_or(false, |v| value_has_unknown_fields(&v)),
+            ReflectFieldRef::Repeated(v) => v.into_iter().any(|v| value_has_unknown_fields(&v)),
+            ReflectFieldRef::Map(v) => v.into_iter().any(|(_, v)| value_has_unknown_fields(&v)),
+        })
+}
+
+fn strip_value(value: ReflectValueRef) -> ReflectValueBox {
+    let mut value = value.to_box();
+    if let ReflectValueBox::Message(ref mut m) = value {
+        strip_unknown_fields(&mut **m);
+    }
+    value
+}
+
+// fields are read before modifying the message
+enum StrippedField {
+    Singular,
+    Repeated(Vec<ReflectValueBox>),
+    Map(Vec<(ReflectValueBox, ReflectValueBox)>),
+}
+
+/// Clear unknown fields of the message, and of all its singular,
+/// repeated and map message fields recursively.
+pub fn strip_unknown_fields(m: &mut Message) {
+    // reset rather than clear the map, so stripped message
+    // is equal to the message parsed without unknown fields
+    *m.mut_unknown_fields() = UnknownFields::new();
+    for field in m.descriptor().fields() {
+        let stripped = match field.get_reflect(m) {
+            ReflectFieldRef::Optional(Some(ref v)) if value_has_unknown_fields(v) => {
+                StrippedField::Singular
+            }
+            ReflectFieldRef::Repeated(ref v)
+                if v.into_iter().any(|v| value_has_unknown_fields(&v)) =>
+            {
+                StrippedField::Repeated(v.into_iter().map(strip_value).collect())
+            }
+            ReflectFieldRef::Map(ref v)
+                if v.into_iter().any(|(_, v)| value_has_unknown_fields(&v)) =>
+            {
+                StrippedField::Map(
+                    v.into_iter()
+                        .map(|(k, v)| (k.to_box(), strip_value(v)))
+                        .collect(),
+                )
+            }
+            _ => continue,
+        };
+        match stripped {
+            StrippedField::Singular => strip_unknown_fields(field.mut_message(m)),
+            StrippedField::Repeated(values) => {
+                let mut repeated = field.mut_repeated(m);
+                for (i, v) in values.into_iter().enumerate() {
+                    repeated.set(i, v);
+                }
+            }
+            StrippedField::Map(entries) => {
+                let mut map = field.mut_map(m);
+                for (k, v) in entries {
+                    map.insert(k, v);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::DescriptorProto;
+    use descriptor::FileDescriptorProto;
+    use parse_from_bytes;
+
+    #[test]
+    fn nested() {
+        let mut expected = FileDescriptorProto::new();
+        expected.options.set_default();
+        expected.message_type.push(DescriptorProto::new());
+        expected.message_type.push(DescriptorProto::new());
+        expected.message_type[1].set_name("Foo".to_owned());
+        expected.message_type[1].options.set_default();
+
+        let mut file = expected.clone();
+        file.mut_unknown_fields().add_varint(100, 1);
+        file.options
+            .as_mut()
+            .unwrap()
+            .mut_unknown_fields()
+            .add_varint(101, 2);
+        file.message_type[1]
+            .options
+            .as_mut()
+            .unwrap()
+            .mut_unknown_fields()
+            .add_varint(102, 3);
+        let mut file: FileDescriptorProto =
+            parse_from_bytes(&file.write_to_bytes().unwrap()).unwrap();
+        assert!(has_unknown_fields(&file.message_type[1]));
+
+        strip_unknown_fields(&mut file);
+        assert!(!has_unknown_fields(&file));
+        assert_eq!(expected, file);
+    }
+}
@@ -7,10 +7,12 @@ use bytes::Bytes;
 #[cfg(feature = "bytes")]
 use chars::Chars;
 
+use arc_str::ArcStr;
 use core::Message;
 use enums::ProtobufEnum;
 use error::ProtobufResult;
 use reflect::runtime_types::{RuntimeType, RuntimeTypeEnumOrUnknown};
+use reflect::runtime_types::RuntimeTypeArcStr;
 use reflect::runtime_types::RuntimeTypeBool;
 #[cfg(feature = "bytes")]
 use reflect::runtime_types::RuntimeTypeCarllercheBytes;
@@ -143,6 +145,8 @@ pub struct ProtobufTypeCarllercheBytes;
 #[cfg(feature = "bytes")]
 #[derive(Copy, Clone)]
 pub struct ProtobufTypeCarllercheChars;
+#[derive(Copy, Clone)]
+pub struct ProtobufTypeArcStr;
 
 #[derive(Copy, Clone)]
 pub struct ProtobufTypeEnum<E: ProtobufEnum>(marker::PhantomData<E>);
@@ -683,6 +687,34 @@ impl ProtobufType for ProtobufTypeCarllercheChars {
     }
 }
 
+impl ProtobufType for ProtobufTypeArcStr {
+    type RuntimeType = RuntimeTypeArcStr;
+
+    fn wire_type() -> WireType {
+        ProtobufTypeBytes::wire_type()
+    }
+
+    fn read(is: &mut CodedInputStream) -> ProtobufResult<ArcStr> {
+        is.read_interned_string()
+    }
+
+    fn get_from_unknown(unknown_values: &UnknownValues) -> Option<ArcStr> {
+        ProtobufTypeString::get_from_unknown(unknown_values).map(ArcStr::from)
+    }
+
+    fn compute_size(value: &ArcStr) -> u32 {
+        value.len() as u32
+    }
+
+    fn write_with_cached_size(
+        field_number: u32,
+        value: &ArcStr,
+        os: &mut CodedOutputStream,
+    ) -> ProtobufResult<()> {
+        os.write_string(field_number, &value)
+    }
+}
+
 impl<E: ProtobufEnum + ProtobufValue + fmt::Debug> ProtobufType for ProtobufTypeEnum<E> {
     type RuntimeType = RuntimeTypeEnum<E>;
 
@@ -4,13 +4,16 @@ use bytes::Bytes;
 use chars::Chars;
 
 use super::*;
+use arc_str::ArcStr;
 use core::*;
 use reflect::reflect_deep_eq::ReflectDeepEq;
 use reflect::transmute_eq::transmute_eq;
 use std::hash::Hash;
 use std::hash::Hasher;
 use std::mem;
+use std::num::ParseIntError;
 use std::any::Any;
+use std::any::TypeId;
 
 /// Type implemented by all protobuf singular types
 /// (primitives, string, messages, enums).
@@ -42,6 +45,8 @@ impl ProtobufValue for Bytes {}
 #[cfg(feature = "bytes")]
 impl ProtobufValue for Chars {}
 
+impl ProtobufValue for ArcStr {}
+
 // conflicting implementations, so generated code is used instead
 /*
 impl<E : ProtobufEnum> ProtobufValue for E {
@@ -99,6 +104,30 @@ impl<'a> ReflectValueRef<'a> {
             ReflectValueRef::Message(v) => ReflectValueBox::Message(v.descriptor().clone(v)),
         }
     }
+
+    /// Decimal string of `int64` or `uint64` value,
+    /// as 64-bit integers are represented in JSON.
+    /// Return `None` for values of other types.
+    pub fn to_int64_string(&self) -> Option<String> {
+        match *self {
+            ReflectValueRef::U64(v) => Some(v.to_string()),
+            ReflectValueRef::I64(v) => Some(v.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Clone the value to `V` if this value is of type `V`,
+    /// return `None` otherwise.
+    ///
+    /// Message is cloned only if it is of type `V`.
+    pub fn downcast_clone<V: 'static>(&self) -> Option<V> {
+        if let ReflectValueRef::Message(m) = *self {
+            if Any::type_id(m) != TypeId::of::<V>() {
+                return None;
+            }
+        }
+        self.to_box().downcast().ok()
+    }
 }
 
 impl<'a> ReflectDeepEq for ReflectValueRef<'a> {
@@ -220,6 +249,18 @@ impl From<Vec<u8>> for ReflectValueBox {
     }
 }
 
+impl<'a> From<&'a str> for ReflectValueBox {
+    fn from(v: &'a str) -> Self {
+        ReflectValueBox::String(v.to_owned())
+    }
+}
+
+impl<'a> From<&'a [u8]> for ReflectValueBox {
+    fn from(v: &'a [u8]) -> Self {
+        ReflectValueBox::Bytes(v.to_owned())
+    }
+}
+
 impl From<&'static EnumValueDescriptor> for ReflectValueBox {
     fn from(v: &'static EnumValueDescriptor) -> Self {
         ReflectValueBox::Enum(v)
@@ -264,6 +305,32 @@ impl ReflectValueBox {
         }
     }
 
+    /// Value of enum type with given number,
+    /// `None` if enum has no value with this number.
+    pub fn from_enum(descriptor: &'static EnumDescriptor, value: i32) -> Option<ReflectValueBox> {
+        descriptor.value_by_number(value).map(ReflectValueBox::Enum)
+    }
+
+    /// Convert to value reference which does not borrow this box.
+    ///
+    /// Fail for `string`, `bytes` and message values, returning them unchanged,
+    /// because their references would point into the box.
+    pub fn into_value(self) -> Result<ReflectValueRef<'static>, Self> {
+        match self {
+            ReflectValueBox::U32(v) => Ok(ReflectValueRef::U32(v)),
+            ReflectValueBox::U64(v) => Ok(ReflectValueRef::U64(v)),
+            ReflectValueBox::I32(v) => Ok(ReflectValueRef::I32(v)),
+            ReflectValueBox::I64(v) => Ok(ReflectValueRef::I64(v)),
+            ReflectValueBox::F32(v) => Ok(ReflectValueRef::F32(v)),
+            ReflectValueBox::F64(v) => Ok(ReflectValueRef::F64(v)),
+            ReflectValueBox::Bool(v) => Ok(ReflectValueRef::Bool(v)),
+            ReflectValueBox::Enum(v) => Ok(ReflectValueRef::Enum(v)),
+            v @ ReflectValueBox::String(..)
+            | v @ ReflectValueBox::Bytes(..)
+            | v @ ReflectValueBox::Message(..) => Err(v),
+        }
+    }
+
     pub fn downcast<V: 'static>(self) -> Result<V, Self> {
         match self {
             ReflectValueBox::U32(v) => transmute_eq(v).map_err(ReflectValueBox::U32),
@@ -275,7 +342,10 @@ impl ReflectValueBox {
             ReflectValueBox::Bool(v) => transmute_eq(v).map_err(ReflectValueBox::Bool),
             ReflectValueBox::String(v) => transmute_eq::<String, _>(v)
                 .or_else(|v: String| transmute_eq::<StringOrChars, _>(v.into()))
-                .map_err(|v: StringOrChars| ReflectValueBox::String(v.into())),
+                .or_else(|v: StringOrChars| {
+                    transmute_eq::<ArcStr, _>(ArcStr::from(Into::<String>::into(v)))
+                })
+                .map_err(|v: ArcStr| ReflectValueBox::String(v.into())),
             ReflectValueBox::Bytes(v) => transmute_eq::<Vec<u8>, _>(v)
                 .or_else(|v: Vec<u8>| transmute_eq::<VecU8OrBytes, _>(v.into()))
                 .map_err(|v: VecU8OrBytes| ReflectValueBox::Bytes(v.into())),
@@ -283,6 +353,24 @@ impl ReflectValueBox {
             ReflectValueBox::Message(m) => m.descriptor().cast(m).map_err(ReflectValueBox::Message),
         }
     }
+
+    /// Clone the value to `V` if this value is of type `V`,
+    /// return `None` otherwise.
+    pub fn downcast_clone<V: 'static>(&self) -> Option<V> {
+        self.as_value_ref().downcast_clone()
+    }
+
+    /// Parse `int64` or `uint64` value from decimal string,
+    /// the reverse of `ReflectValueRef::to_int64_string`.
+    ///
+    /// Panic if `t` is not `I64` or `U64`.
+    pub fn int64_from_str(t: &RuntimeTypeBox, s: &str) -> Result<ReflectValueBox, ParseIntError> {
+        match *t {
+            RuntimeTypeBox::U64 => s.parse().map(ReflectValueBox::U64),
+            RuntimeTypeBox::I64 => s.parse().map(ReflectValueBox::I64),
+            _ => panic!("not a 64-bit integer type"),
+        }
+    }
 }
 
 impl<'a> PartialEq for ReflectValueRef<'a> {
@@ -382,6 +470,54 @@ mod test {
         );
     }
 
+    #[test]
+    fn reflect_value_box_into_value() {
+        assert_eq!(Ok(ReflectValueRef::U32(10)), ReflectValueBox::from(10u32).into_value());
+        assert_eq!(Ok(ReflectValueRef::I64(-10)), ReflectValueBox::from(-10i64).into_value());
+        assert_eq!(Ok(ReflectValueRef::F64(0.5)), ReflectValueBox::from(0.5f64).into_value());
+        assert_eq!(Ok(ReflectValueRef::Bool(true)), ReflectValueBox::from(true).into_value());
+        assert_eq!(
+            Err(ReflectValueBox::String("aa".to_owned())),
+            ReflectValueBox::from("aa").into_value()
+        );
+        assert_eq!(
+            Err(ReflectValueBox::Bytes(vec![1, 2])),
+            ReflectValueBox::from(&[1u8, 2][..]).into_value()
+        );
+    }
+
+    #[test]
+    fn int64_string() {
+        let min = ReflectValueRef::I64(i64::min_value()).to_int64_string().unwrap();
+        assert_eq!("-9223372036854775808", min);
+        assert_eq!(
+            ReflectValueBox::I64(i64::min_value()),
+            ReflectValueBox::int64_from_str(&RuntimeTypeBox::I64, &min).unwrap()
+        );
+
+        let max = ReflectValueRef::U64(u64::max_value()).to_int64_string().unwrap();
+        assert_eq!("18446744073709551615", max);
+        assert_eq!(
+            ReflectValueBox::U64(u64::max_value()),
+            ReflectValueBox::int64_from_str(&RuntimeTypeBox::U64, &max).unwrap()
+        );
+
+        assert!(ReflectValueBox::int64_from_str(&RuntimeTypeBox::I64, &max).is_err());
+        assert!(ReflectValueBox::int64_from_str(&RuntimeTypeBox::U64, &min).is_err());
+        assert_eq!(None, ReflectValueRef::I32(1).to_int64_string());
+    }
+
+    #[test]
+    fn reflect_value_ref_downcast_clone() {
+        assert_eq!(Some(10), ReflectValueRef::I32(10).downcast_clone::<i32>());
+        assert_eq!(None, ReflectValueRef::I32(10).downcast_clone::<u32>());
+        assert_eq!(
+            Some("aa".to_owned()),
+            ReflectValueRef::String("aa").downcast_clone::<String>()
+        );
+        assert_eq!(None, ReflectValueRef::String("aa").downcast_clone::<i32>());
+    }
+
     #[test]
     fn reflect_value_box_downcast_chars() {
         assert_eq!(
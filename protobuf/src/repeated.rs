@@ -2,6 +2,7 @@
 use serde;
 
 use std::borrow::Borrow;
+use std::cmp;
 use std::cmp::Ordering;
 use std::default::Default;
 use std::fmt;
@@ -18,9 +19,10 @@ use std::vec;
 
 use clear::Clear;
 
-/// Implemented for both `Vec` and `RepeatedField`.
+/// Implemented for `Vec`, `RepeatedField` and `SmallVec`.
 /// Used to simplify codegen, should not be used directly.
 pub trait VecLike<T> {
+    fn len(&self) -> usize;
     fn push(&mut self, item: T);
     fn push_default(&mut self) -> &mut T
     where
@@ -28,6 +30,10 @@ pub trait VecLike<T> {
 }
 
 impl<T> VecLike<T> for Vec<T> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
     fn push(&mut self, item: T) {
         Vec::push(self, item)
     }
@@ -41,6 +47,25 @@ impl<T> VecLike<T> for Vec<T> {
     }
 }
 
+#[cfg(feature = "with-smallvec")]
+impl<A: smallvec::Array> VecLike<A::Item> for smallvec::SmallVec<A> {
+    fn len(&self) -> usize {
+        smallvec::SmallVec::len(self)
+    }
+
+    fn push(&mut self, item: A::Item) {
+        smallvec::SmallVec::push(self, item)
+    }
+
+    fn push_default(&mut self) -> &mut A::Item
+    where
+        A::Item: Default + Clear,
+    {
+        self.push(Default::default());
+        self.last_mut().unwrap()
+    }
+}
+
 /// Wrapper around vector to avoid deallocations on clear.
 ///
 /// It helps when needed to read lots of messages very quickly.
@@ -61,6 +86,12 @@ impl<T> RepeatedField<T> {
         self.len
     }
 
+    /// Return true if this container has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     /// Clear.
     #[inline]
     pub fn clear(&mut self) {
@@ -69,6 +100,10 @@ impl<T> RepeatedField<T> {
 }
 
 impl<T> VecLike<T> for RepeatedField<T> {
+    fn len(&self) -> usize {
+        RepeatedField::len(self)
+    }
+
     fn push(&mut self, item: T) {
         RepeatedField::push(self, item)
     }
@@ -126,12 +161,25 @@ impl<T> RepeatedField<T> {
         vec
     }
 
+    /// Convert data into boxed slice, dropping elements retained after `clear`.
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.into_vec().into_boxed_slice()
+    }
+
     /// Return current capacity.
     #[inline]
     pub fn capacity(&self) -> usize {
         self.vec.capacity()
     }
 
+    /// Shrink capacity to current length,
+    /// dropping elements retained for reuse after `clear`.
+    pub fn shrink_to_fit(&mut self) {
+        self.vec.truncate(self.len);
+        self.vec.shrink_to_fit();
+    }
+
     /// View data as mutable slice.
     #[inline]
     pub fn as_slice<'a>(&'a self) -> &'a [T] {
@@ -240,10 +288,17 @@ impl<T> RepeatedField<T> {
     }
 
     /// Insert an element at specified position.
+    ///
+    /// Previously allocated and cleared element is reused if available.
     #[inline]
     pub fn insert(&mut self, index: usize, value: T) {
         assert!(index <= self.len);
-        self.vec.insert(index, value);
+        if self.len == self.vec.len() {
+            self.vec.insert(index, value);
+        } else {
+            self.vec[self.len] = value;
+            self.vec[index..self.len + 1].rotate_right(1);
+        }
         self.len += 1;
     }
 
@@ -255,6 +310,19 @@ impl<T> RepeatedField<T> {
         self.vec.remove(index)
     }
 
+    /// Remove an element from specified position and return it,
+    /// replacing it with the last element.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    /// Previously allocated and cleared elements are kept.
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(index < self.len);
+        self.len -= 1;
+        self.vec.swap(index, self.len);
+        self.vec.swap_remove(self.len)
+    }
+
     /// Truncate at specified length.
     #[inline]
     pub fn truncate(&mut self, len: usize) {
@@ -288,6 +356,18 @@ impl<T> RepeatedField<T> {
         self.as_mut_slice().iter_mut()
     }
 
+    /// Iterator over chunks of `chunk_size` elements, last chunk may be shorter.
+    #[inline]
+    pub fn chunks<'a>(&'a self, chunk_size: usize) -> slice::Chunks<'a, T> {
+        self.as_ref().chunks(chunk_size)
+    }
+
+    /// Iterator over all contiguous windows of length `size`.
+    #[inline]
+    pub fn windows<'a>(&'a self, size: usize) -> slice::Windows<'a, T> {
+        self.as_ref().windows(size)
+    }
+
     /// Sort elements with given comparator.
     #[inline]
     pub fn sort_by<F>(&mut self, compare: F)
@@ -297,6 +377,36 @@ impl<T> RepeatedField<T> {
         self.as_mut_slice().sort_by(compare)
     }
 
+    /// Binary search sorted elements for given value.
+    ///
+    /// Elements retained for reuse after `clear` are not searched.
+    #[inline]
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.as_ref().binary_search(x)
+    }
+
+    /// Binary search sorted elements with given comparator.
+    #[inline]
+    pub fn binary_search_by<'a, F>(&'a self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> Ordering,
+    {
+        self.as_ref().binary_search_by(f)
+    }
+
+    /// Binary search elements sorted by given key.
+    #[inline]
+    pub fn binary_search_by_key<'a, B, F>(&'a self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: Ord,
+    {
+        self.as_ref().binary_search_by_key(b, f)
+    }
+
     /// Get data as raw pointer.
     #[inline]
     pub fn as_ptr(&self) -> *const T {
@@ -332,6 +442,13 @@ impl<T> From<Vec<T>> for RepeatedField<T> {
     }
 }
 
+impl<T> From<Box<[T]>> for RepeatedField<T> {
+    #[inline]
+    fn from(values: Box<[T]>) -> RepeatedField<T> {
+        RepeatedField::from_vec(values.into_vec())
+    }
+}
+
 impl<'a, T: Clone> From<&'a [T]> for RepeatedField<T> {
     #[inline]
     fn from(values: &'a [T]) -> RepeatedField<T> {
@@ -374,6 +491,19 @@ impl<T: Clone> Clone for RepeatedField<T> {
             len: self.len(),
         }
     }
+
+    /// Clone into existing field reusing its allocations,
+    /// including elements kept after `clear`.
+    #[inline]
+    fn clone_from(&mut self, source: &RepeatedField<T>) {
+        let source = source.as_ref();
+        let reuse = cmp::min(self.vec.len(), source.len());
+        for (target, source) in self.vec[..reuse].iter_mut().zip(&source[..reuse]) {
+            target.clone_from(source);
+        }
+        self.vec.extend_from_slice(&source[reuse..]);
+        self.len = source.len();
+    }
 }
 
 impl<T> FromIterator<T> for RepeatedField<T> {
@@ -403,6 +533,9 @@ impl<T: Eq> Eq for RepeatedField<T> {}
 
 impl<T: PartialEq> RepeatedField<T> {
     /// True iff this container contains given element.
+    ///
+    /// Only live elements are checked, elements kept for reuse after `clear`
+    /// or `truncate` are ignored.
     #[inline]
     pub fn contains(&self, value: &T) -> bool {
         self.as_ref().contains(value)
@@ -490,6 +623,35 @@ impl<'de, T: serde::Deserialize<'de> + Default> serde::Deserialize<'de> for Repe
     }
 }
 
+#[cfg(feature = "with-arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for RepeatedField<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Vec::arbitrary(u).map(RepeatedField::from)
+    }
+}
+
+/// Provides `par_iter()` over the live elements with `rayon::prelude::*` imported.
+#[cfg(feature = "with-rayon")]
+impl<'a, T: Sync + 'a> rayon::iter::IntoParallelIterator for &'a RepeatedField<T> {
+    type Iter = rayon::slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        rayon::iter::IntoParallelIterator::into_par_iter(self.as_slice())
+    }
+}
+
+/// Provides `par_iter_mut()` over the live elements with `rayon::prelude::*` imported.
+#[cfg(feature = "with-rayon")]
+impl<'a, T: Send + 'a> rayon::iter::IntoParallelIterator for &'a mut RepeatedField<T> {
+    type Iter = rayon::slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        rayon::iter::IntoParallelIterator::into_par_iter(self.as_mut_slice())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::RepeatedField;
@@ -500,11 +662,26 @@ mod test {
         v.push(10);
         v.push(20);
         v.clear();
-        assert_eq!(v.as_mut_slice(), &mut []);
+        assert_eq!(v.as_mut_slice(), &mut [] as &mut [i32]);
         v.push(30);
         assert_eq!(v.as_mut_slice(), &mut [30]);
     }
 
+    #[test]
+    fn binary_search() {
+        let mut v = RepeatedField::new();
+        v.push(100);
+        v.push(200);
+        v.clear();
+        for i in &[10, 20, 30] {
+            v.push(*i);
+        }
+        assert_eq!(Ok(1), v.binary_search(&20));
+        assert_eq!(Err(3), v.binary_search(&100));
+        assert_eq!(Err(0), v.binary_search_by(|x| x.cmp(&5)));
+        assert_eq!(Ok(2), v.binary_search_by_key(&3, |x| x / 10));
+    }
+
     #[test]
     fn push_default() {
         let mut v = RepeatedField::new();
@@ -513,4 +690,120 @@ mod test {
         v.clear();
         assert_eq!("".to_string(), *v.push_default());
     }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut v = RepeatedField::new();
+        for i in 0..100 {
+            v.push(i);
+        }
+        v.clear();
+        v.push(1);
+        let old_capacity = v.capacity();
+        assert!(old_capacity >= 100);
+        v.shrink_to_fit();
+        assert!(v.capacity() < old_capacity);
+        assert!(v.capacity() >= v.len());
+        assert_eq!(&[1], v.as_slice());
+    }
+
+    #[test]
+    fn boxed_slice() {
+        let mut v = RepeatedField::new();
+        v.push("a".to_owned());
+        v.push("b".to_owned());
+        v.push("c".to_owned());
+        v.clear();
+        v.push("d".to_owned());
+        v.push("e".to_owned());
+
+        let b = v.into_boxed_slice();
+        assert_eq!(&["d".to_owned(), "e".to_owned()][..], &b[..]);
+
+        let v = RepeatedField::from(b);
+        assert_eq!(2, v.len());
+        assert_eq!(&["d".to_owned(), "e".to_owned()][..], v.as_slice());
+    }
+
+    #[test]
+    fn contains_ignores_pooled() {
+        let mut v = RepeatedField::new();
+        v.push(10);
+        v.push(20);
+        v.push(30);
+        v.truncate(2);
+        assert!(v.contains(&10));
+        assert!(v.contains(&20));
+        assert!(!v.contains(&30));
+        assert_eq!(Some(1), v.iter().position(|x| *x == 20));
+        assert_eq!(None, v.iter().position(|x| *x == 30));
+
+        v.clear();
+        assert!(!v.contains(&10));
+    }
+
+    #[test]
+    fn swap_remove() {
+        let mut v = RepeatedField::new();
+        for i in 0..5 {
+            v.push(i);
+        }
+        v.truncate(4);
+        assert_eq!(1, v.swap_remove(1));
+        assert_eq!(&[0, 3, 2], v.as_slice());
+        // pooled element is kept
+        assert_eq!(4, v.vec.len());
+        assert_eq!(3, v.swap_remove(1));
+        assert_eq!(&[0, 2], v.as_slice());
+    }
+
+    #[test]
+    fn insert() {
+        let mut v = RepeatedField::new();
+        v.push("a".to_owned());
+        v.push("b".to_owned());
+        v.push("c".to_owned());
+        v.truncate(2);
+        v.insert(0, "x".to_owned());
+        assert_eq!(&["x".to_owned(), "a".to_owned(), "b".to_owned()][..], v.as_slice());
+        // pooled slot is reused
+        assert_eq!(3, v.vec.len());
+        v.insert(3, "y".to_owned());
+        v.insert(1, "z".to_owned());
+        assert_eq!(
+            &["x", "z", "a", "b", "y"][..],
+            &v.iter().map(|s| &s[..]).collect::<Vec<_>>()[..]
+        );
+    }
+
+    #[test]
+    fn chunks_windows() {
+        let mut v = RepeatedField::from_vec(vec![1, 2, 3, 4, 5, 6]);
+        v.truncate(5);
+        let chunks: Vec<&[i32]> = v.chunks(2).collect();
+        assert_eq!(vec![&[1, 2][..], &[3, 4][..], &[5][..]], chunks);
+        assert_eq!(3, v.windows(3).count());
+        assert_eq!(&[3, 4, 5], v.windows(3).last().unwrap());
+    }
+
+    #[test]
+    fn clone_from() {
+        let mut v = RepeatedField::new();
+        v.push("aaaa".to_owned());
+        v.push("bbbb".to_owned());
+        v.push("cccc".to_owned());
+        let ptr = v[0].as_ptr();
+
+        let mut source = RepeatedField::new();
+        source.push("x".to_owned());
+        source.push("y".to_owned());
+        v.clone_from(&source);
+        assert_eq!(&["x".to_owned(), "y".to_owned()][..], v.as_slice());
+        assert_eq!(ptr, v[0].as_ptr());
+
+        source.push("z".to_owned());
+        source.push("w".to_owned());
+        v.clone_from(&source);
+        assert_eq!(source, v);
+    }
 }
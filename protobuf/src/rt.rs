@@ -1,21 +1,31 @@
 //! Functions used by generated protobuf code.
 //! Should not be used by programs written by hands.
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::default::Default;
 use std::hash::Hash;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
 
 #[cfg(feature = "bytes")]
 use bytes::Bytes;
 #[cfg(feature = "bytes")]
 use chars::Chars;
+#[cfg(feature = "with-uuid")]
+use uuid::Uuid;
 
+use arc_str::ArcStr;
 use core::*;
 use enums::ProtobufEnum;
 use error::ProtobufError;
 use error::ProtobufResult;
 use error::WireError;
+use lazy_message::LazyMessage;
+use lazy_packed::LazyPacked;
 use repeated::RepeatedField;
+use singular::OptionLike;
 use singular::SingularField;
 use stream::CodedInputStream;
 use stream::CodedOutputStream;
@@ -32,6 +42,7 @@ use zigzag::*;
 use reflect::runtime_types::RuntimeType;
 use repeated::VecLike;
 use unknown::UnknownFields;
+use view::MessageView;
 
 use prelude::*;
 
@@ -301,6 +312,7 @@ pub fn read_repeated_int32_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_int32_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_int32()?);
             Ok(())
         }
@@ -317,6 +329,7 @@ pub fn read_repeated_int64_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_int64_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_int64()?);
             Ok(())
         }
@@ -333,6 +346,7 @@ pub fn read_repeated_uint32_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_uint32_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_uint32()?);
             Ok(())
         }
@@ -349,6 +363,7 @@ pub fn read_repeated_uint64_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_uint64_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_uint64()?);
             Ok(())
         }
@@ -365,6 +380,7 @@ pub fn read_repeated_sint32_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_sint32_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_sint32()?);
             Ok(())
         }
@@ -381,6 +397,7 @@ pub fn read_repeated_sint64_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_sint64_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_sint64()?);
             Ok(())
         }
@@ -397,6 +414,7 @@ pub fn read_repeated_fixed32_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_fixed32_into(target),
         WireTypeFixed32 => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_fixed32()?);
             Ok(())
         }
@@ -413,6 +431,7 @@ pub fn read_repeated_fixed64_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_fixed64_into(target),
         WireTypeFixed64 => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_fixed64()?);
             Ok(())
         }
@@ -429,6 +448,7 @@ pub fn read_repeated_sfixed32_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_sfixed32_into(target),
         WireTypeFixed32 => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_sfixed32()?);
             Ok(())
         }
@@ -445,6 +465,7 @@ pub fn read_repeated_sfixed64_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_sfixed64_into(target),
         WireTypeFixed64 => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_sfixed64()?);
             Ok(())
         }
@@ -461,6 +482,7 @@ pub fn read_repeated_double_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_double_into(target),
         WireTypeFixed64 => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_double()?);
             Ok(())
         }
@@ -477,6 +499,7 @@ pub fn read_repeated_float_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_float_into(target),
         WireTypeFixed32 => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_float()?);
             Ok(())
         }
@@ -493,6 +516,7 @@ pub fn read_repeated_bool_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_bool_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_bool()?);
             Ok(())
         }
@@ -510,6 +534,7 @@ pub fn read_repeated_enum_into<E: ProtobufEnum>(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_enum_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_enum()?);
             Ok(())
         }
@@ -545,6 +570,7 @@ fn read_repeated_packed_enum_with_unknown_fields_into<E: ProtobufEnum>(
     let len = is.read_raw_varint64()?;
     let old_limit = is.push_limit(len)?;
     while !is.eof()? {
+        is.check_repeated_len(target.len() + 1)?;
         read_enum_with_unknown_fields_into(is, |e| target.push(e), field_number, unknown_fields)?;
     }
     is.pop_limit(old_limit);
@@ -554,11 +580,13 @@ fn read_repeated_packed_enum_with_unknown_fields_into<E: ProtobufEnum>(
 fn read_repeated_packed_enum_or_unknown_into<E: ProtobufEnum>(
     is: &mut CodedInputStream,
     target: &mut Vec<ProtobufEnumOrUnknown<E>>,
+    field: &'static str,
 ) -> ProtobufResult<()> {
     let len = is.read_raw_varint64()?;
     let old_limit = is.push_limit(len)?;
     while !is.eof()? {
-        target.push(is.read_enum_or_unknown()?);
+        is.check_repeated_len(target.len() + 1)?;
+        target.push(is.read_enum_or_unknown_for_field(field)?);
     }
     is.pop_limit(old_limit);
     Ok(())
@@ -585,6 +613,7 @@ pub fn read_repeated_enum_with_unknown_fields_into<E: ProtobufEnum>(
             unknown_fields,
         ),
         WireTypeVarint => {
+            is.check_repeated_len(target.len() + 1)?;
             read_enum_with_unknown_fields_into(is, |e| target.push(e), field_number, unknown_fields)
         }
         _ => Err(unexpected_wire_type(wire_type)),
@@ -601,14 +630,27 @@ pub fn read_repeated_enum_or_unknown_into<E: ProtobufEnum>(
     wire_type: WireType,
     is: &mut CodedInputStream,
     target: &mut Vec<ProtobufEnumOrUnknown<E>>,
+) -> ProtobufResult<()> {
+    read_repeated_enum_or_unknown_field_into(wire_type, is, target, "")
+}
+
+/// Read repeated `enum` field into given vec,
+/// and fail if value is unknown and `CodedInputStream` rejects unknown enum values.
+pub fn read_repeated_enum_or_unknown_field_into<E: ProtobufEnum>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Vec<ProtobufEnumOrUnknown<E>>,
+    field: &'static str,
 ) -> ProtobufResult<()> {
     match wire_type {
         WireTypeLengthDelimited => read_repeated_packed_enum_or_unknown_into(
             is,
             target,
+            field,
         ),
         WireTypeVarint => {
-            target.push(is.read_enum_or_unknown()?);
+            is.check_repeated_len(target.len() + 1)?;
+            target.push(is.read_enum_or_unknown_for_field(field)?);
             Ok(())
         }
         _ => Err(unexpected_wire_type(wire_type)),
@@ -666,6 +708,7 @@ where
 {
     match wire_type {
         WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len() + 1)?;
             let tmp = target.push_default();
             is.read_string_into(tmp)
         }
@@ -685,6 +728,7 @@ where
 {
     match wire_type {
         WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_carllerche_chars()?);
             Ok(())
         }
@@ -692,6 +736,25 @@ where
     }
 }
 
+/// Read repeated `string` field into given vec, interning equal values.
+pub fn read_repeated_interned_string_into<V>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut V,
+) -> ProtobufResult<()>
+where
+    V: VecLike<ArcStr>,
+{
+    match wire_type {
+        WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len() + 1)?;
+            target.push(is.read_interned_string()?);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
 /// Read singular `string` field.
 pub fn read_singular_string_into(
     wire_type: WireType,
@@ -723,6 +786,21 @@ pub fn read_singular_carllerche_string_into(
     }
 }
 
+/// Read singular `string` field, interning equal values.
+pub fn read_singular_interned_string_into(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Option<ArcStr>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            *target = Some(is.read_interned_string()?);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
 /// Read singular `string` field for proto3.
 pub fn read_singular_proto3_string_into(
     wire_type: WireType,
@@ -751,6 +829,21 @@ pub fn read_singular_proto3_carllerche_string_into(
     }
 }
 
+/// Read singular `string` field for proto3, interning equal values.
+pub fn read_singular_proto3_interned_string_into(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut ArcStr,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            *target = is.read_interned_string()?;
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
 /// Read repeated `bytes` field into given vec.
 pub fn read_repeated_bytes_into<V>(
     wire_type: WireType,
@@ -762,6 +855,7 @@ where
 {
     match wire_type {
         WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len() + 1)?;
             let tmp = target.push_default();
             is.read_bytes_into(tmp)
         }
@@ -781,6 +875,7 @@ where
 {
     match wire_type {
         WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len() + 1)?;
             target.push(is.read_carllerche_bytes()?);
             Ok(())
         }
@@ -803,6 +898,170 @@ pub fn read_singular_bytes_into(
     }
 }
 
+/// IP address types which can be stored in `bytes` fields
+/// with `ip_addr` option: 4 bytes for IPv4 and 16 bytes for IPv6.
+pub trait IpAddrBytes: Sized {
+    /// Parse address, `None` if length is incorrect.
+    fn from_bytes(bytes: &[u8]) -> Option<Self>;
+    fn to_bytes(&self) -> Vec<u8>;
+}
+
+impl IpAddrBytes for Ipv4Addr {
+    fn from_bytes(bytes: &[u8]) -> Option<Ipv4Addr> {
+        match bytes.len() {
+            4 => Some(Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3])),
+            _ => None,
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.octets().to_vec()
+    }
+}
+
+impl IpAddrBytes for Ipv6Addr {
+    fn from_bytes(bytes: &[u8]) -> Option<Ipv6Addr> {
+        match bytes.len() {
+            16 => {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(bytes);
+                Some(Ipv6Addr::from(octets))
+            }
+            _ => None,
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.octets().to_vec()
+    }
+}
+
+impl IpAddrBytes for IpAddr {
+    fn from_bytes(bytes: &[u8]) -> Option<IpAddr> {
+        Ipv4Addr::from_bytes(bytes)
+            .map(IpAddr::V4)
+            .or_else(|| Ipv6Addr::from_bytes(bytes).map(IpAddr::V6))
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        match *self {
+            IpAddr::V4(ref a) => a.to_bytes(),
+            IpAddr::V6(ref a) => a.to_bytes(),
+        }
+    }
+}
+
+// Empty value is allowed for singular fields, because it is default value
+fn check_ip_addr_bytes<A: IpAddrBytes>(bytes: &[u8], allow_empty: bool) -> ProtobufResult<()> {
+    if (allow_empty && bytes.is_empty()) || A::from_bytes(bytes).is_some() {
+        Ok(())
+    } else {
+        Err(ProtobufError::WireError(WireError::IncorrectIpAddrLength(bytes.len())))
+    }
+}
+
+/// Read singular `bytes` field with `ip_addr` option.
+pub fn read_singular_ip_addr_into<A: IpAddrBytes>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut SingularField<Vec<u8>>,
+) -> ProtobufResult<()> {
+    read_singular_bytes_into(wire_type, is, target)?;
+    check_ip_addr_bytes::<A>(target.as_ref().unwrap(), true)
+}
+
+/// Read singular `bytes` field with `ip_addr` option for proto3.
+pub fn read_singular_proto3_ip_addr_into<A: IpAddrBytes>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Vec<u8>,
+) -> ProtobufResult<()> {
+    read_singular_proto3_bytes_into(wire_type, is, target)?;
+    check_ip_addr_bytes::<A>(target, true)
+}
+
+/// Read repeated `bytes` field with `ip_addr` option.
+pub fn read_repeated_ip_addr_into<A, V>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut V,
+) -> ProtobufResult<()>
+where
+    A: IpAddrBytes,
+    V: VecLike<Vec<u8>>,
+{
+    match wire_type {
+        WireTypeLengthDelimited => {
+            let bytes = is.read_bytes()?;
+            check_ip_addr_bytes::<A>(&bytes, false)?;
+            is.check_repeated_len(target.len() + 1)?;
+            target.push(bytes);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Parse `bytes` field with `uuid` option, `None` if length is not 16 bytes.
+#[cfg(feature = "with-uuid")]
+pub fn uuid_from_bytes(bytes: &[u8]) -> Option<Uuid> {
+    Uuid::from_slice(bytes).ok()
+}
+
+// Empty value is allowed for singular fields, because it is default value
+#[cfg(feature = "with-uuid")]
+fn check_uuid_bytes(bytes: &[u8], allow_empty: bool) -> ProtobufResult<()> {
+    if (allow_empty && bytes.is_empty()) || uuid_from_bytes(bytes).is_some() {
+        Ok(())
+    } else {
+        Err(ProtobufError::WireError(WireError::IncorrectUuidLength(bytes.len())))
+    }
+}
+
+/// Read singular `bytes` field with `uuid` option.
+#[cfg(feature = "with-uuid")]
+pub fn read_singular_uuid_into(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut SingularField<Vec<u8>>,
+) -> ProtobufResult<()> {
+    read_singular_bytes_into(wire_type, is, target)?;
+    check_uuid_bytes(target.as_ref().unwrap(), true)
+}
+
+/// Read singular `bytes` field with `uuid` option for proto3.
+#[cfg(feature = "with-uuid")]
+pub fn read_singular_proto3_uuid_into(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Vec<u8>,
+) -> ProtobufResult<()> {
+    read_singular_proto3_bytes_into(wire_type, is, target)?;
+    check_uuid_bytes(target, true)
+}
+
+/// Read repeated `bytes` field with `uuid` option.
+#[cfg(feature = "with-uuid")]
+pub fn read_repeated_uuid_into<V>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut V,
+) -> ProtobufResult<()>
+where
+    V: VecLike<Vec<u8>>,
+{
+    match wire_type {
+        WireTypeLengthDelimited => {
+            let bytes = is.read_bytes()?;
+            check_uuid_bytes(&bytes, false)?;
+            is.check_repeated_len(target.len() + 1)?;
+            target.push(bytes);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
 /// Read singular `Bytes` field.
 #[cfg(feature = "bytes")]
 pub fn read_singular_carllerche_bytes_into(
@@ -855,6 +1114,7 @@ pub fn read_repeated_message_into_repeated_field<M: Message + Default>(
 ) -> ProtobufResult<()> {
     match wire_type {
         WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len() + 1)?;
             is.incr_recursion()?;
             let tmp = target.push_default();
             let res = is.merge_message(tmp);
@@ -873,6 +1133,36 @@ pub fn read_repeated_message_into_vec<M: Message + Default>(
 ) -> ProtobufResult<()> {
     match wire_type {
         WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len() + 1)?;
+            is.incr_recursion()?;
+            let res = match is.read_message() {
+                Ok(m) => {
+                    target.push(m);
+                    Ok(())
+                }
+                Err(e) => Err(e),
+            };
+            is.decr_recursion();
+            res
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read repeated `message` field into `SmallVec`.
+#[cfg(feature = "with-smallvec")]
+pub fn read_repeated_message_into_small_vec<M, A>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut ::smallvec::SmallVec<A>,
+) -> ProtobufResult<()>
+where
+    M: Message + Default,
+    A: ::smallvec::Array<Item = M>,
+{
+    match wire_type {
+        WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len() + 1)?;
             is.incr_recursion()?;
             let res = match is.read_message() {
                 Ok(m) => {
@@ -888,6 +1178,35 @@ pub fn read_repeated_message_into_vec<M: Message + Default>(
     }
 }
 
+/// Read repeated numeric or `bool` field (packed or not) into `SmallVec`.
+#[cfg(feature = "with-smallvec")]
+pub fn read_repeated_into_small_vec<T, A>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut ::smallvec::SmallVec<A>,
+) -> ProtobufResult<()>
+where
+    T: ProtobufType,
+    A: ::smallvec::Array<Item = <T::RuntimeType as RuntimeType>::Value>,
+{
+    if wire_type == T::wire_type() {
+        is.check_repeated_len(target.len() + 1)?;
+        target.push(T::read(is)?);
+        Ok(())
+    } else if wire_type == WireTypeLengthDelimited {
+        let len = is.read_raw_varint64()?;
+        let old_limit = is.push_limit(len)?;
+        while !is.eof()? {
+            is.check_repeated_len(target.len() + 1)?;
+            target.push(T::read(is)?);
+        }
+        is.pop_limit(old_limit);
+        Ok(())
+    } else {
+        Err(unexpected_wire_type(wire_type))
+    }
+}
+
 /// Read singular `message` field.
 pub fn read_singular_message_into<M, O>(
     wire_type: WireType,
@@ -910,6 +1229,200 @@ where
     }
 }
 
+/// Read singular lazy `message` field.
+///
+/// Message is not parsed, serialized message is stored in the field.
+pub fn read_singular_lazy_message_into<M>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut LazyMessage<M>,
+) -> ProtobufResult<()>
+where
+    M: Message,
+{
+    match wire_type {
+        WireTypeLengthDelimited => target.merge_from_bytes(&is.read_bytes()?),
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read packed or unpacked repeated field with `lazy` option.
+pub fn read_repeated_lazy_packed_into<T: ProtobufType>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut LazyPacked<T>,
+) -> ProtobufResult<()> {
+    target.read_from(wire_type, is)
+}
+
+/// Read singular `string` field into message view.
+pub fn read_singular_borrowed_string_into<'a>(
+    wire_type: WireType,
+    is: &mut CodedInputStream<'a>,
+    target: &mut Option<&'a str>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            *target = Some(is.read_str_borrowed()?);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read singular `string` field for proto3 into message view.
+pub fn read_singular_proto3_borrowed_string_into<'a>(
+    wire_type: WireType,
+    is: &mut CodedInputStream<'a>,
+    target: &mut &'a str,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            *target = is.read_str_borrowed()?;
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read repeated `string` field into message view.
+pub fn read_repeated_borrowed_string_into<'a>(
+    wire_type: WireType,
+    is: &mut CodedInputStream<'a>,
+    target: &mut Vec<&'a str>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len() + 1)?;
+            target.push(is.read_str_borrowed()?);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read singular `bytes` field into message view.
+pub fn read_singular_borrowed_bytes_into<'a>(
+    wire_type: WireType,
+    is: &mut CodedInputStream<'a>,
+    target: &mut Option<&'a [u8]>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            *target = Some(is.read_bytes_borrowed()?);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read singular `bytes` field for proto3 into message view.
+pub fn read_singular_proto3_borrowed_bytes_into<'a>(
+    wire_type: WireType,
+    is: &mut CodedInputStream<'a>,
+    target: &mut &'a [u8],
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            *target = is.read_bytes_borrowed()?;
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read repeated `bytes` field into message view.
+pub fn read_repeated_borrowed_bytes_into<'a>(
+    wire_type: WireType,
+    is: &mut CodedInputStream<'a>,
+    target: &mut Vec<&'a [u8]>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len() + 1)?;
+            target.push(is.read_bytes_borrowed()?);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read singular `message` field into message view.
+pub fn read_singular_message_view_into<'a, V: MessageView<'a>>(
+    wire_type: WireType,
+    is: &mut CodedInputStream<'a>,
+    target: &mut Option<Box<V>>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            is.incr_recursion()?;
+            let tmp = target.get_or_insert_with(Default::default);
+            let res = is.merge_message_view(&mut **tmp);
+            is.decr_recursion();
+            res
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read repeated `message` field into message view.
+pub fn read_repeated_message_view_into<'a, V: MessageView<'a>>(
+    wire_type: WireType,
+    is: &mut CodedInputStream<'a>,
+    target: &mut Vec<V>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len() + 1)?;
+            is.incr_recursion()?;
+            let mut tmp = V::default();
+            let res = is.merge_message_view(&mut tmp);
+            is.decr_recursion();
+            target.push(tmp);
+            res
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+fn shrink_singular_to_fit<V, O, F>(target: &mut O, shrink: F)
+where
+    O: OptionLike<V> + Default,
+    F: FnOnce(&mut V),
+{
+    if let Some(v) = target.as_option_mut() {
+        shrink(v);
+        return;
+    }
+    // drop value retained after `clear`
+    *target = Default::default();
+}
+
+/// Shrink singular `message` field.
+pub fn shrink_singular_message_to_fit<M, O>(target: &mut O)
+where
+    M: Message,
+    O: OptionLike<M> + Default,
+{
+    shrink_singular_to_fit(target, |m: &mut M| m.shrink_to_fit())
+}
+
+/// Shrink singular `string` field.
+pub fn shrink_singular_string_to_fit<O>(target: &mut O)
+where
+    O: OptionLike<String> + Default,
+{
+    shrink_singular_to_fit(target, |s: &mut String| s.shrink_to_fit())
+}
+
+/// Shrink singular `bytes` field.
+pub fn shrink_singular_bytes_to_fit<O>(target: &mut O)
+where
+    O: OptionLike<Vec<u8>> + Default,
+{
+    shrink_singular_to_fit(target, |b: &mut Vec<u8>| b.shrink_to_fit())
+}
+
 fn skip_group(is: &mut CodedInputStream) -> ProtobufResult<()> {
     loop {
         let (_, wire_type) = is.read_tag_unpack()?;
@@ -932,12 +1445,21 @@ pub fn read_unknown_or_skip_group(
         wire_format::WireTypeStartGroup => skip_group(is),
         _ => {
             let unknown = is.read_unknown(wire_type)?;
+            is.handle_unknown_field(field_number, &unknown);
             unknown_fields.add_value(field_number, unknown);
             Ok(())
         }
     }
 }
 
+/// Skip unknown field in generated message view code.
+pub fn skip_unknown_or_group(wire_type: WireType, is: &mut CodedInputStream) -> ProtobufResult<()> {
+    match wire_type {
+        wire_format::WireTypeStartGroup => skip_group(is),
+        _ => is.skip_field(wire_type),
+    }
+}
+
 /// Create an error for unexpected wire type.
 ///
 /// Function is used in generated code, so error types can be changed,
@@ -946,6 +1468,15 @@ pub fn unexpected_wire_type(wire_type: WireType) -> ProtobufError {
     ProtobufError::WireError(WireError::UnexpectedWireType(wire_type))
 }
 
+/// Unpack tag read with `CodedInputStream::read_raw_varint32`
+/// into pair (field number, wire type).
+pub fn unpack_tag(tag: u32) -> ProtobufResult<(u32, WireType)> {
+    match wire_format::Tag::new(tag) {
+        Some(tag) => Ok(tag.unpack()),
+        None => Err(ProtobufError::WireError(WireError::IncorrectTag(tag))),
+    }
+}
+
 /// Compute serialized size of `map` field and cache nested field sizes.
 pub fn compute_map_size<K, V>(
     field_number: u32,
@@ -1012,6 +1543,34 @@ where
     message.write_to_with_cached_sizes(os)
 }
 
+/// Write lazy message field, with size previously computed by `compute_size`.
+pub fn write_lazy_message_field_with_cached_size<M>(
+    field_number: u32,
+    message: &LazyMessage<M>,
+    os: &mut CodedOutputStream,
+) -> ProtobufResult<()>
+where
+    M: Message,
+{
+    os.write_tag(field_number, WireType::WireTypeLengthDelimited)?;
+    os.write_raw_varint32(message.get_cached_size())?;
+    message.write_to_with_cached_sizes(os)
+}
+
+/// Size of repeated field with `lazy` option, including tag and length.
+pub fn lazy_packed_size<T: ProtobufType>(field_number: u32, field: &LazyPacked<T>) -> u32 {
+    field.compute_size(field_number)
+}
+
+/// Write repeated field with `lazy` option.
+pub fn write_lazy_packed_field<T: ProtobufType>(
+    field_number: u32,
+    field: &LazyPacked<T>,
+    os: &mut CodedOutputStream,
+) -> ProtobufResult<()> {
+    field.write_to(field_number, os)
+}
+
 /// Read `map` field.
 pub fn read_map_into<K, V>(
     wire_type: WireType,
@@ -1057,5 +1616,90 @@ where
 
     target.insert(key, value);
 
-    Ok(())
+    is.check_repeated_len(target.len())
+}
+
+/// Check if value is equal to default value of the type.
+///
+/// Used in generated code with `serde_skip_default` option.
+pub fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// Bits of `f32` hashed in generated code with `derive_hash_floats_by_bits` option.
+///
+/// `-0.0` is hashed as `0.0` because they are equal.
+/// Note `NaN` is not equal to itself, so it is not found when used as a key.
+pub fn f32_hash_bits(value: f32) -> u32 {
+    if value == 0.0 {
+        0
+    } else {
+        value.to_bits()
+    }
+}
+
+/// Bits of `f64` hashed in generated code with `derive_hash_floats_by_bits` option.
+///
+/// `-0.0` is hashed as `0.0` because they are equal.
+/// Note `NaN` is not equal to itself, so it is not found when used as a key.
+pub fn f64_hash_bits(value: f64) -> u64 {
+    if value == 0.0 {
+        0
+    } else {
+        value.to_bits()
+    }
+}
+
+/// `f32` compared with `f32::total_cmp` in generated code with `float_total_cmp` option.
+///
+/// Unlike `==` and `<`, `-0.0` is less than `0.0`, `NaN` is equal to the same `NaN`,
+/// positive `NaN` is greater than positive infinity,
+/// and negative `NaN` is less than negative infinity.
+#[derive(Copy, Clone, Debug)]
+pub struct TotalOrdF32(pub f32);
+
+impl PartialEq for TotalOrdF32 {
+    fn eq(&self, other: &TotalOrdF32) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TotalOrdF32 {}
+
+impl PartialOrd for TotalOrdF32 {
+    fn partial_cmp(&self, other: &TotalOrdF32) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TotalOrdF32 {
+    fn cmp(&self, other: &TotalOrdF32) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// `f64` compared with `f64::total_cmp` in generated code with `float_total_cmp` option.
+///
+/// See `TotalOrdF32` for the order of special values.
+#[derive(Copy, Clone, Debug)]
+pub struct TotalOrdF64(pub f64);
+
+impl PartialEq for TotalOrdF64 {
+    fn eq(&self, other: &TotalOrdF64) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TotalOrdF64 {}
+
+impl PartialOrd for TotalOrdF64 {
+    fn partial_cmp(&self, other: &TotalOrdF64) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TotalOrdF64 {
+    fn cmp(&self, other: &TotalOrdF64) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
 }
@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(deprecated)]
 #![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -17,6 +18,9 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 
+// fails to compile if generated code and protobuf runtime versions differ
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_0_0;
+
 pub mod exts {
     use protobuf::Message as Message_imported_for_functions;
 
@@ -42,8 +46,50 @@ pub mod exts {
 
     pub const serde_derive_cfg_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeString> = ::protobuf::ext::ExtFieldOptional { field_number: 17031, phantom: ::std::marker::PhantomData };
 
+    pub const serde_skip_default_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17032, phantom: ::std::marker::PhantomData };
+
     pub const lite_runtime_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17035, phantom: ::std::marker::PhantomData };
 
+    pub const split_files_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17040, phantom: ::std::marker::PhantomData };
+
+    pub const generate_views_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17044, phantom: ::std::marker::PhantomData };
+
+    pub const json_fromstr_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17046, phantom: ::std::marker::PhantomData };
+
+    pub const flatten_nested_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17047, phantom: ::std::marker::PhantomData };
+
+    pub const generate_services_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17048, phantom: ::std::marker::PhantomData };
+
+    pub const presence_bitset_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17049, phantom: ::std::marker::PhantomData };
+
+    pub const derive_arbitrary_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17050, phantom: ::std::marker::PhantomData };
+
+    pub const json_try_from_value_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17051, phantom: ::std::marker::PhantomData };
+
+    pub const reexport_well_known_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17054, phantom: ::std::marker::PhantomData };
+
+    pub const crate_visibility_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17055, phantom: ::std::marker::PhantomData };
+
+    pub const oneof_allow_large_enum_variant_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17057, phantom: ::std::marker::PhantomData };
+
+    pub const debug_as_text_format_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17058, phantom: ::std::marker::PhantomData };
+
+    pub const small_repeated_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeUint32> = ::protobuf::ext::ExtFieldOptional { field_number: 17059, phantom: ::std::marker::PhantomData };
+
+    pub const derive_hash_floats_by_bits_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17060, phantom: ::std::marker::PhantomData };
+
+    pub const json_serialize_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17061, phantom: ::std::marker::PhantomData };
+
+    pub const generate_into_parts_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17062, phantom: ::std::marker::PhantomData };
+
+    pub const text_format_fromstr_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17064, phantom: ::std::marker::PhantomData };
+
+    pub const generate_append_slice_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17066, phantom: ::std::marker::PhantomData };
+
+    pub const float_total_cmp_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17067, phantom: ::std::marker::PhantomData };
+
+    pub const intern_strings_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17068, phantom: ::std::marker::PhantomData };
+
     pub const expose_oneof: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17001, phantom: ::std::marker::PhantomData };
 
     pub const expose_fields: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17003, phantom: ::std::marker::PhantomData };
@@ -66,6 +112,46 @@ pub mod exts {
 
     pub const serde_derive_cfg: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeString> = ::protobuf::ext::ExtFieldOptional { field_number: 17031, phantom: ::std::marker::PhantomData };
 
+    pub const serde_skip_default: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17032, phantom: ::std::marker::PhantomData };
+
+    pub const numeric_wrapper: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17041, phantom: ::std::marker::PhantomData };
+
+    pub const string_wrapper: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17042, phantom: ::std::marker::PhantomData };
+
+    pub const convertible_to: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeString> = ::protobuf::ext::ExtFieldOptional { field_number: 17043, phantom: ::std::marker::PhantomData };
+
+    pub const generate_views: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17044, phantom: ::std::marker::PhantomData };
+
+    pub const json_fromstr: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17046, phantom: ::std::marker::PhantomData };
+
+    pub const presence_bitset: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17049, phantom: ::std::marker::PhantomData };
+
+    pub const derive_arbitrary: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17050, phantom: ::std::marker::PhantomData };
+
+    pub const json_try_from_value: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17051, phantom: ::std::marker::PhantomData };
+
+    pub const io_write_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeString> = ::protobuf::ext::ExtFieldOptional { field_number: 17056, phantom: ::std::marker::PhantomData };
+
+    pub const oneof_allow_large_enum_variant: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17057, phantom: ::std::marker::PhantomData };
+
+    pub const debug_as_text_format: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17058, phantom: ::std::marker::PhantomData };
+
+    pub const small_repeated: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeUint32> = ::protobuf::ext::ExtFieldOptional { field_number: 17059, phantom: ::std::marker::PhantomData };
+
+    pub const derive_hash_floats_by_bits: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17060, phantom: ::std::marker::PhantomData };
+
+    pub const json_serialize: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17061, phantom: ::std::marker::PhantomData };
+
+    pub const generate_into_parts: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17062, phantom: ::std::marker::PhantomData };
+
+    pub const text_format_fromstr: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17064, phantom: ::std::marker::PhantomData };
+
+    pub const generate_append_slice: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17066, phantom: ::std::marker::PhantomData };
+
+    pub const float_total_cmp: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17067, phantom: ::std::marker::PhantomData };
+
+    pub const intern_strings: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17068, phantom: ::std::marker::PhantomData };
+
     pub const expose_fields_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17003, phantom: ::std::marker::PhantomData };
 
     pub const generate_accessors_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17004, phantom: ::std::marker::PhantomData };
@@ -81,6 +167,22 @@ pub mod exts {
     pub const singular_field_option_box_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17024, phantom: ::std::marker::PhantomData };
 
     pub const singular_field_option_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17025, phantom: ::std::marker::PhantomData };
+
+    pub const ip_addr: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeString> = ::protobuf::ext::ExtFieldOptional { field_number: 17045, phantom: ::std::marker::PhantomData };
+
+    pub const rust_name: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeString> = ::protobuf::ext::ExtFieldOptional { field_number: 17052, phantom: ::std::marker::PhantomData };
+
+    pub const lazy: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17053, phantom: ::std::marker::PhantomData };
+
+    pub const small_repeated_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeUint32> = ::protobuf::ext::ExtFieldOptional { field_number: 17059, phantom: ::std::marker::PhantomData };
+
+    pub const serde_flatten: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17063, phantom: ::std::marker::PhantomData };
+
+    pub const uuid: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17065, phantom: ::std::marker::PhantomData };
+
+    pub const generate_append_slice_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17066, phantom: ::std::marker::PhantomData };
+
+    pub const intern_strings_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17068, phantom: ::std::marker::PhantomData };
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
@@ -137,137 +239,202 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     x_field\x18\x80\x85\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOption\
     sR\x1bsingularFieldOptionBoxField:^\n\x1bsingular_field_option_field\x18\
     \x81\x85\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x18singu\
-    larFieldOptionFieldJ\x94#\n\x06\x12\x04\0\0V\x01\n\x08\n\x01\x0c\x12\x03\
-    \0\0\x12\n\t\n\x02\x03\0\x12\x03\x02\x07)\n\xe5\x01\n\x01\x02\x12\x03\n\
-    \x08\x112^\x20see\x20https://github.com/gogo/protobuf/blob/master/gogopr\
-    oto/gogo.proto\n\x20for\x20the\x20original\x20idea\n2{\x20Generated\x20f\
-    iles\x20can\x20be\x20customized\x20using\x20this\x20proto\n\x20or\x20usi\
-    ng\x20`Customize`\x20struct\x20when\x20codegen\x20is\x20invoked\x20progr\
-    ammatically.\n\n\t\n\x01\x07\x12\x04\x0c\0(\x01\n7\n\x02\x07\0\x12\x03\
-    \x0e\x04+\x1a,\x20When\x20true,\x20oneof\x20field\x20is\x20generated\x20\
-    public\n\n\n\n\x03\x07\0\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\0\x04\x12\
-    \x03\x0e\x04\x0c\n\n\n\x03\x07\0\x05\x12\x03\x0e\r\x11\n\n\n\x03\x07\0\
-    \x01\x12\x03\x0e\x12\"\n\n\n\x03\x07\0\x03\x12\x03\x0e%*\nI\n\x02\x07\
-    \x01\x12\x03\x10\x04,\x1a>\x20When\x20true\x20all\x20fields\x20are\x20pu\
-    blic,\x20and\x20not\x20accessors\x20generated\n\n\n\n\x03\x07\x01\x02\
-    \x12\x03\x0c\x07\"\n\n\n\x03\x07\x01\x04\x12\x03\x10\x04\x0c\n\n\n\x03\
-    \x07\x01\x05\x12\x03\x10\r\x11\n\n\n\x03\x07\x01\x01\x12\x03\x10\x12#\n\
-    \n\n\x03\x07\x01\x03\x12\x03\x10&+\nP\n\x02\x07\x02\x12\x03\x12\x041\x1a\
-    E\x20When\x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20accessors\
-    \x20are\x20not\x20generated\n\n\n\n\x03\x07\x02\x02\x12\x03\x0c\x07\"\n\
-    \n\n\x03\x07\x02\x04\x12\x03\x12\x04\x0c\n\n\n\x03\x07\x02\x05\x12\x03\
-    \x12\r\x11\n\n\n\x03\x07\x02\x01\x12\x03\x12\x12(\n\n\n\x03\x07\x02\x03\
-    \x12\x03\x12+0\nL\n\x02\x07\x03\x12\x03\x14\x04.\x1aA\x20When\x20false,\
-    \x20`get_`\x20is\x20not\x20generated\x20even\x20if\x20`syntax\x20=\x20\"\
-    proto2\"`\n\n\n\n\x03\x07\x03\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x03\
-    \x04\x12\x03\x14\x04\x0c\n\n\n\x03\x07\x03\x05\x12\x03\x14\r\x11\n\n\n\
-    \x03\x07\x03\x01\x12\x03\x14\x12%\n\n\n\x03\x07\x03\x03\x12\x03\x14(-\n2\
-    \n\x02\x07\x04\x12\x03\x16\x049\x1a'\x20Use\x20`bytes::Bytes`\x20for\x20\
-    `bytes`\x20fields\n\n\n\n\x03\x07\x04\x02\x12\x03\x0c\x07\"\n\n\n\x03\
-    \x07\x04\x04\x12\x03\x16\x04\x0c\n\n\n\x03\x07\x04\x05\x12\x03\x16\r\x11\
-    \n\n\n\x03\x07\x04\x01\x12\x03\x16\x120\n\n\n\x03\x07\x04\x03\x12\x03\
-    \x1638\n3\n\x02\x07\x05\x12\x03\x18\x04:\x1a(\x20Use\x20`bytes::Bytes`\
-    \x20for\x20`string`\x20fields\n\n\n\n\x03\x07\x05\x02\x12\x03\x0c\x07\"\
-    \n\n\n\x03\x07\x05\x04\x12\x03\x18\x04\x0c\n\n\n\x03\x07\x05\x05\x12\x03\
-    \x18\r\x11\n\n\n\x03\x07\x05\x01\x12\x03\x18\x121\n\n\n\x03\x07\x05\x03\
-    \x12\x03\x1849\n=\n\x02\x07\x06\x12\x03\x1a\x041\x1a2\x20Use\x20`std::Ve\
-    c`\x20to\x20store\x20repeated\x20messages\x20fields\n\n\n\n\x03\x07\x06\
-    \x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x06\x04\x12\x03\x1a\x04\x0c\n\n\n\
-    \x03\x07\x06\x05\x12\x03\x1a\r\x11\n\n\n\x03\x07\x06\x01\x12\x03\x1a\x12\
-    (\n\n\n\x03\x07\x06\x03\x12\x03\x1a+0\nM\n\x02\x07\x07\x12\x03\x1c\x048\
-    \x1aB\x20Use\x20`std::Option<std::Box<T>>`\x20to\x20store\x20singular\
-    \x20messages\x20fields\n\n\n\n\x03\x07\x07\x02\x12\x03\x0c\x07\"\n\n\n\
-    \x03\x07\x07\x04\x12\x03\x1c\x04\x0c\n\n\n\x03\x07\x07\x05\x12\x03\x1c\r\
-    \x11\n\n\n\x03\x07\x07\x01\x12\x03\x1c\x12/\n\n\n\x03\x07\x07\x03\x12\
-    \x03\x1c27\n\x93\x01\n\x02\x07\x08\x12\x03\x1f\x044\x1a\x87\x01\x20Use\
-    \x20`std::Option<T>`\x20to\x20store\x20singular\x20messages\x20fields.\n\
-    \x20Note,\x20it's\x20not\x20possible\x20to\x20have\x20recursive\x20messa\
-    ges\x20with\x20this\x20option\x20enabled.\n\n\n\n\x03\x07\x08\x02\x12\
-    \x03\x0c\x07\"\n\n\n\x03\x07\x08\x04\x12\x03\x1f\x04\x0c\n\n\n\x03\x07\
-    \x08\x05\x12\x03\x1f\r\x11\n\n\n\x03\x07\x08\x01\x12\x03\x1f\x12+\n\n\n\
-    \x03\x07\x08\x03\x12\x03\x1f.3\nJ\n\x02\x07\t\x12\x03\"\x04+\x1a?\x20Use\
-    \x20`serde_derive`\x20to\x20implement\x20`Serialize`\x20and\x20`Deserial\
-    ize`\n\n\n\n\x03\x07\t\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\t\x04\x12\x03\
-    \"\x04\x0c\n\n\n\x03\x07\t\x05\x12\x03\"\r\x11\n\n\n\x03\x07\t\x01\x12\
-    \x03\"\x12\"\n\n\n\x03\x07\t\x03\x12\x03\"%*\n3\n\x02\x07\n\x12\x03$\x04\
-    1\x1a(\x20Guard\x20serde\x20annotations\x20with\x20cfg\x20attr.\n\n\n\n\
-    \x03\x07\n\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\n\x04\x12\x03$\x04\x0c\n\
-    \n\n\x03\x07\n\x05\x12\x03$\r\x13\n\n\n\x03\x07\n\x01\x12\x03$\x14(\n\n\
-    \n\x03\x07\n\x03\x12\x03$+0\nN\n\x02\x07\x0b\x12\x03'\x04+\x1aC\x20When\
-    \x20true,\x20will\x20only\x20generate\x20codes\x20that\x20works\x20with\
-    \x20lite\x20runtime.\n\n\n\n\x03\x07\x0b\x02\x12\x03\x0c\x07\"\n\n\n\x03\
-    \x07\x0b\x04\x12\x03'\x04\x0c\n\n\n\x03\x07\x0b\x05\x12\x03'\r\x11\n\n\n\
-    \x03\x07\x0b\x01\x12\x03'\x12\"\n\n\n\x03\x07\x0b\x03\x12\x03'%*\n\t\n\
-    \x01\x07\x12\x04*\0B\x01\n7\n\x02\x07\x0c\x12\x03,\x04'\x1a,\x20When\x20\
-    true,\x20oneof\x20field\x20is\x20generated\x20public\n\n\n\n\x03\x07\x0c\
-    \x02\x12\x03*\x07%\n\n\n\x03\x07\x0c\x04\x12\x03,\x04\x0c\n\n\n\x03\x07\
-    \x0c\x05\x12\x03,\r\x11\n\n\n\x03\x07\x0c\x01\x12\x03,\x12\x1e\n\n\n\x03\
-    \x07\x0c\x03\x12\x03,!&\nI\n\x02\x07\r\x12\x03.\x04(\x1a>\x20When\x20tru\
-    e\x20all\x20fields\x20are\x20public,\x20and\x20not\x20accessors\x20gener\
-    ated\n\n\n\n\x03\x07\r\x02\x12\x03*\x07%\n\n\n\x03\x07\r\x04\x12\x03.\
-    \x04\x0c\n\n\n\x03\x07\r\x05\x12\x03.\r\x11\n\n\n\x03\x07\r\x01\x12\x03.\
-    \x12\x1f\n\n\n\x03\x07\r\x03\x12\x03.\"'\nP\n\x02\x07\x0e\x12\x030\x04-\
-    \x1aE\x20When\x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20acces\
-    sors\x20are\x20not\x20generated\n\n\n\n\x03\x07\x0e\x02\x12\x03*\x07%\n\
-    \n\n\x03\x07\x0e\x04\x12\x030\x04\x0c\n\n\n\x03\x07\x0e\x05\x12\x030\r\
-    \x11\n\n\n\x03\x07\x0e\x01\x12\x030\x12$\n\n\n\x03\x07\x0e\x03\x12\x030'\
-    ,\nL\n\x02\x07\x0f\x12\x032\x04*\x1aA\x20When\x20false,\x20`get_`\x20is\
-    \x20not\x20generated\x20even\x20if\x20`syntax\x20=\x20\"proto2\"`\n\n\n\
-    \n\x03\x07\x0f\x02\x12\x03*\x07%\n\n\n\x03\x07\x0f\x04\x12\x032\x04\x0c\
-    \n\n\n\x03\x07\x0f\x05\x12\x032\r\x11\n\n\n\x03\x07\x0f\x01\x12\x032\x12\
-    !\n\n\n\x03\x07\x0f\x03\x12\x032$)\n2\n\x02\x07\x10\x12\x034\x045\x1a'\
+    larFieldOptionField:9\n\x0fsplit_files_all\x18\x90\x85\x01\x20\x01(\x08\
+    \x12\x1c.google.protobuf.FileOptionsB\0:<\n\x0fnumeric_wrapper\x18\x91\
+    \x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsB\0:;\n\x0es\
+    tring_wrapper\x18\x92\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.Messa\
+    geOptionsB\0:@\n\x16serde_skip_default_all\x18\x88\x85\x01\x20\x01(\x08\
+    \x12\x1c.google.protobuf.FileOptionsB\0:?\n\x12serde_skip_default\x18\
+    \x88\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsB\0:;\n\
+    \x0econvertible_to\x18\x93\x85\x01\x20\x01(\t\x12\x1f.google.protobuf.Me\
+    ssageOptionsB\0:<\n\x12generate_views_all\x18\x94\x85\x01\x20\x01(\x08\
+    \x12\x1c.google.protobuf.FileOptionsB\0:;\n\x0egenerate_views\x18\x94\
+    \x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsB\0:2\n\x07i\
+    p_addr\x18\x95\x85\x01\x20\x01(\t\x12\x1d.google.protobuf.FieldOptionsB\
+    \0::\n\x10json_fromstr_all\x18\x96\x85\x01\x20\x01(\x08\x12\x1c.google.p\
+    rotobuf.FileOptionsB\0:9\n\x0cjson_fromstr\x18\x96\x85\x01\x20\x01(\x08\
+    \x12\x1f.google.protobuf.MessageOptionsB\0:<\n\x12flatten_nested_all\x18\
+    \x97\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsB\0:?\n\x15\
+    generate_services_all\x18\x98\x85\x01\x20\x01(\x08\x12\x1c.google.protob\
+    uf.FileOptionsB\0:=\n\x13presence_bitset_all\x18\x99\x85\x01\x20\x01(\
+    \x08\x12\x1c.google.protobuf.FileOptionsB\0:<\n\x0fpresence_bitset\x18\
+    \x99\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsB\0:>\n\
+    \x14derive_arbitrary_all\x18\x9a\x85\x01\x20\x01(\x08\x12\x1c.google.pro\
+    tobuf.FileOptionsB\0:=\n\x10derive_arbitrary\x18\x9a\x85\x01\x20\x01(\
+    \x08\x12\x1f.google.protobuf.MessageOptionsB\0:A\n\x17json_try_from_valu\
+    e_all\x18\x9b\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsB\
+    \0:@\n\x13json_try_from_value\x18\x9b\x85\x01\x20\x01(\x08\x12\x1f.googl\
+    e.protobuf.MessageOptionsB\0:4\n\trust_name\x18\x9c\x85\x01\x20\x01(\t\
+    \x12\x1d.google.protobuf.FieldOptionsB\0:/\n\x04lazy\x18\x9d\x85\x01\x20\
+    \x01(\x08\x12\x1d.google.protobuf.FieldOptionsB\0:A\n\x17reexport_well_k\
+    nown_all\x18\x9e\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOption\
+    sB\0:>\n\x14crate_visibility_all\x18\x9f\x85\x01\x20\x01(\x08\x12\x1c.go\
+    ogle.protobuf.FileOptionsB\0:;\n\x0eio_write_field\x18\xa0\x85\x01\x20\
+    \x01(\t\x12\x1f.google.protobuf.MessageOptionsB\0:L\n\"oneof_allow_large\
+    _enum_variant_all\x18\xa1\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.F\
+    ileOptionsB\0:K\n\x1eoneof_allow_large_enum_variant\x18\xa1\x85\x01\x20\
+    \x01(\x08\x12\x1f.google.protobuf.MessageOptionsB\0:B\n\x18debug_as_text\
+    _format_all\x18\xa2\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOpt\
+    ionsB\0:A\n\x14debug_as_text_format\x18\xa2\x85\x01\x20\x01(\x08\x12\x1f\
+    .google.protobuf.MessageOptionsB\0:<\n\x12small_repeated_all\x18\xa3\x85\
+    \x01\x20\x01(\r\x12\x1c.google.protobuf.FileOptionsB\0:;\n\x0esmall_repe\
+    ated\x18\xa3\x85\x01\x20\x01(\r\x12\x1f.google.protobuf.MessageOptionsB\
+    \0:?\n\x14small_repeated_field\x18\xa3\x85\x01\x20\x01(\r\x12\x1d.google\
+    .protobuf.FieldOptionsB\0:H\n\x1ederive_hash_floats_by_bits_all\x18\xa4\
+    \x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsB\0:G\n\x1aderi\
+    ve_hash_floats_by_bits\x18\xa4\x85\x01\x20\x01(\x08\x12\x1f.google.proto\
+    buf.MessageOptionsB\0:<\n\x12json_serialize_all\x18\xa5\x85\x01\x20\x01(\
+    \x08\x12\x1c.google.protobuf.FileOptionsB\0:;\n\x0ejson_serialize\x18\
+    \xa5\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsB\0:A\n\
+    \x17generate_into_parts_all\x18\xa6\x85\x01\x20\x01(\x08\x12\x1c.google.\
+    protobuf.FileOptionsB\0:@\n\x13generate_into_parts\x18\xa6\x85\x01\x20\
+    \x01(\x08\x12\x1f.google.protobuf.MessageOptionsB\0:8\n\rserde_flatten\
+    \x18\xa7\x85\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsB\0:A\
+    \n\x17text_format_fromstr_all\x18\xa8\x85\x01\x20\x01(\x08\x12\x1c.googl\
+    e.protobuf.FileOptionsB\0:@\n\x13text_format_fromstr\x18\xa8\x85\x01\x20\
+    \x01(\x08\x12\x1f.google.protobuf.MessageOptionsB\0:/\n\x04uuid\x18\xa9\
+    \x85\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsB\0:C\n\x19gen\
+    erate_append_slice_all\x18\xaa\x85\x01\x20\x01(\x08\x12\x1c.google.proto\
+    buf.FileOptionsB\0:B\n\x15generate_append_slice\x18\xaa\x85\x01\x20\x01(\
+    \x08\x12\x1f.google.protobuf.MessageOptionsB\0:F\n\x1bgenerate_append_sl\
+    ice_field\x18\xaa\x85\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOpti\
+    onsB\0:=\n\x13float_total_cmp_all\x18\xab\x85\x01\x20\x01(\x08\x12\x1c.g\
+    oogle.protobuf.FileOptionsB\0:<\n\x0ffloat_total_cmp\x18\xab\x85\x01\x20\
+    \x01(\x08\x12\x1f.google.protobuf.MessageOptionsB\0:<\n\x12intern_string\
+    s_all\x18\xac\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsB\
+    \0:;\n\x0eintern_strings\x18\xac\x85\x01\x20\x01(\x08\x12\x1f.google.pro\
+    tobuf.MessageOptionsB\0:?\n\x14intern_strings_field\x18\xac\x85\x01\x20\
+    \x01(\x08\x12\x1d.google.protobuf.FieldOptionsB\0J\x94#\n\x06\x12\x04\0\
+    \0V\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x02\x07)\
+    \n\xe5\x01\n\x01\x02\x12\x03\n\x08\x112^\x20see\x20https://github.com/go\
+    go/protobuf/blob/master/gogoproto/gogo.proto\n\x20for\x20the\x20original\
+    \x20idea\n2{\x20Generated\x20files\x20can\x20be\x20customized\x20using\
+    \x20this\x20proto\n\x20or\x20using\x20`Customize`\x20struct\x20when\x20c\
+    odegen\x20is\x20invoked\x20programmatically.\n\n\t\n\x01\x07\x12\x04\x0c\
+    \0(\x01\n7\n\x02\x07\0\x12\x03\x0e\x04+\x1a,\x20When\x20true,\x20oneof\
+    \x20field\x20is\x20generated\x20public\n\n\n\n\x03\x07\0\x02\x12\x03\x0c\
+    \x07\"\n\n\n\x03\x07\0\x04\x12\x03\x0e\x04\x0c\n\n\n\x03\x07\0\x05\x12\
+    \x03\x0e\r\x11\n\n\n\x03\x07\0\x01\x12\x03\x0e\x12\"\n\n\n\x03\x07\0\x03\
+    \x12\x03\x0e%*\nI\n\x02\x07\x01\x12\x03\x10\x04,\x1a>\x20When\x20true\
+    \x20all\x20fields\x20are\x20public,\x20and\x20not\x20accessors\x20genera\
+    ted\n\n\n\n\x03\x07\x01\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x01\x04\x12\
+    \x03\x10\x04\x0c\n\n\n\x03\x07\x01\x05\x12\x03\x10\r\x11\n\n\n\x03\x07\
+    \x01\x01\x12\x03\x10\x12#\n\n\n\x03\x07\x01\x03\x12\x03\x10&+\nP\n\x02\
+    \x07\x02\x12\x03\x12\x041\x1aE\x20When\x20false,\x20`get_`,\x20`set_`,\
+    \x20`mut_`\x20etc.\x20accessors\x20are\x20not\x20generated\n\n\n\n\x03\
+    \x07\x02\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x02\x04\x12\x03\x12\x04\x0c\
+    \n\n\n\x03\x07\x02\x05\x12\x03\x12\r\x11\n\n\n\x03\x07\x02\x01\x12\x03\
+    \x12\x12(\n\n\n\x03\x07\x02\x03\x12\x03\x12+0\nL\n\x02\x07\x03\x12\x03\
+    \x14\x04.\x1aA\x20When\x20false,\x20`get_`\x20is\x20not\x20generated\x20\
+    even\x20if\x20`syntax\x20=\x20\"proto2\"`\n\n\n\n\x03\x07\x03\x02\x12\
+    \x03\x0c\x07\"\n\n\n\x03\x07\x03\x04\x12\x03\x14\x04\x0c\n\n\n\x03\x07\
+    \x03\x05\x12\x03\x14\r\x11\n\n\n\x03\x07\x03\x01\x12\x03\x14\x12%\n\n\n\
+    \x03\x07\x03\x03\x12\x03\x14(-\n2\n\x02\x07\x04\x12\x03\x16\x049\x1a'\
     \x20Use\x20`bytes::Bytes`\x20for\x20`bytes`\x20fields\n\n\n\n\x03\x07\
-    \x10\x02\x12\x03*\x07%\n\n\n\x03\x07\x10\x04\x12\x034\x04\x0c\n\n\n\x03\
-    \x07\x10\x05\x12\x034\r\x11\n\n\n\x03\x07\x10\x01\x12\x034\x12,\n\n\n\
-    \x03\x07\x10\x03\x12\x034/4\n3\n\x02\x07\x11\x12\x036\x046\x1a(\x20Use\
-    \x20`bytes::Bytes`\x20for\x20`string`\x20fields\n\n\n\n\x03\x07\x11\x02\
-    \x12\x03*\x07%\n\n\n\x03\x07\x11\x04\x12\x036\x04\x0c\n\n\n\x03\x07\x11\
-    \x05\x12\x036\r\x11\n\n\n\x03\x07\x11\x01\x12\x036\x12-\n\n\n\x03\x07\
-    \x11\x03\x12\x03605\n<\n\x02\x07\x12\x12\x038\x04-\x1a1\x20Use\x20`std::\
-    Vec`\x20to\x20store\x20repeated\x20messages\x20field\n\n\n\n\x03\x07\x12\
-    \x02\x12\x03*\x07%\n\n\n\x03\x07\x12\x04\x12\x038\x04\x0c\n\n\n\x03\x07\
-    \x12\x05\x12\x038\r\x11\n\n\n\x03\x07\x12\x01\x12\x038\x12$\n\n\n\x03\
-    \x07\x12\x03\x12\x038',\nM\n\x02\x07\x13\x12\x03:\x044\x1aB\x20Use\x20`s\
-    td::Option<std::Box<T>>`\x20to\x20store\x20singular\x20messages\x20field\
-    s\n\n\n\n\x03\x07\x13\x02\x12\x03*\x07%\n\n\n\x03\x07\x13\x04\x12\x03:\
-    \x04\x0c\n\n\n\x03\x07\x13\x05\x12\x03:\r\x11\n\n\n\x03\x07\x13\x01\x12\
-    \x03:\x12+\n\n\n\x03\x07\x13\x03\x12\x03:.3\n\x93\x01\n\x02\x07\x14\x12\
-    \x03=\x040\x1a\x87\x01\x20Use\x20`std::Option<T>`\x20to\x20store\x20sing\
-    ular\x20messages\x20fields.\n\x20Note,\x20it's\x20not\x20possible\x20to\
-    \x20have\x20recursive\x20messages\x20with\x20this\x20option\x20enabled.\
-    \n\n\n\n\x03\x07\x14\x02\x12\x03*\x07%\n\n\n\x03\x07\x14\x04\x12\x03=\
-    \x04\x0c\n\n\n\x03\x07\x14\x05\x12\x03=\r\x11\n\n\n\x03\x07\x14\x01\x12\
-    \x03=\x12'\n\n\n\x03\x07\x14\x03\x12\x03=*/\nJ\n\x02\x07\x15\x12\x03?\
-    \x04'\x1a?\x20Use\x20`serde_derive`\x20to\x20implement\x20`Serialize`\
-    \x20and\x20`Deserialize`\n\n\n\n\x03\x07\x15\x02\x12\x03*\x07%\n\n\n\x03\
-    \x07\x15\x04\x12\x03?\x04\x0c\n\n\n\x03\x07\x15\x05\x12\x03?\r\x11\n\n\n\
-    \x03\x07\x15\x01\x12\x03?\x12\x1e\n\n\n\x03\x07\x15\x03\x12\x03?!&\n3\n\
-    \x02\x07\x16\x12\x03A\x04-\x1a(\x20Guard\x20serde\x20annotations\x20with\
-    \x20cfg\x20attr.\n\n\n\n\x03\x07\x16\x02\x12\x03*\x07%\n\n\n\x03\x07\x16\
-    \x04\x12\x03A\x04\x0c\n\n\n\x03\x07\x16\x05\x12\x03A\r\x13\n\n\n\x03\x07\
-    \x16\x01\x12\x03A\x14$\n\n\n\x03\x07\x16\x03\x12\x03A',\n\t\n\x01\x07\
-    \x12\x04D\0V\x01\nI\n\x02\x07\x17\x12\x03F\x04.\x1a>\x20When\x20true\x20\
-    all\x20fields\x20are\x20public,\x20and\x20not\x20accessors\x20generated\
-    \n\n\n\n\x03\x07\x17\x02\x12\x03D\x07#\n\n\n\x03\x07\x17\x04\x12\x03F\
-    \x04\x0c\n\n\n\x03\x07\x17\x05\x12\x03F\r\x11\n\n\n\x03\x07\x17\x01\x12\
-    \x03F\x12%\n\n\n\x03\x07\x17\x03\x12\x03F(-\nP\n\x02\x07\x18\x12\x03H\
-    \x043\x1aE\x20When\x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20\
-    accessors\x20are\x20not\x20generated\n\n\n\n\x03\x07\x18\x02\x12\x03D\
-    \x07#\n\n\n\x03\x07\x18\x04\x12\x03H\x04\x0c\n\n\n\x03\x07\x18\x05\x12\
-    \x03H\r\x11\n\n\n\x03\x07\x18\x01\x12\x03H\x12*\n\n\n\x03\x07\x18\x03\
-    \x12\x03H-2\nL\n\x02\x07\x19\x12\x03J\x040\x1aA\x20When\x20false,\x20`ge\
-    t_`\x20is\x20not\x20generated\x20even\x20if\x20`syntax\x20=\x20\"proto2\
-    \"`\n\n\n\n\x03\x07\x19\x02\x12\x03D\x07#\n\n\n\x03\x07\x19\x04\x12\x03J\
-    \x04\x0c\n\n\n\x03\x07\x19\x05\x12\x03J\r\x11\n\n\n\x03\x07\x19\x01\x12\
-    \x03J\x12'\n\n\n\x03\x07\x19\x03\x12\x03J*/\n2\n\x02\x07\x1a\x12\x03L\
-    \x04;\x1a'\x20Use\x20`bytes::Bytes`\x20for\x20`bytes`\x20fields\n\n\n\n\
-    \x03\x07\x1a\x02\x12\x03D\x07#\n\n\n\x03\x07\x1a\x04\x12\x03L\x04\x0c\n\
-    \n\n\x03\x07\x1a\x05\x12\x03L\r\x11\n\n\n\x03\x07\x1a\x01\x12\x03L\x122\
-    \n\n\n\x03\x07\x1a\x03\x12\x03L5:\n3\n\x02\x07\x1b\x12\x03N\x04<\x1a(\
-    \x20Use\x20`bytes::Bytes`\x20for\x20`string`\x20fields\n\n\n\n\x03\x07\
-    \x1b\x02\x12\x03D\x07#\n\n\n\x03\x07\x1b\x04\x12\x03N\x04\x0c\n\n\n\x03\
-    \x07\x1b\x05\x12\x03N\r\x11\n\n\n\x03\x07\x1b\x01\x12\x03N\x123\n\n\n\
-    \x03\x07\x1b\x03\x12\x03N6;\n<\n\x02\x07\x1c\x12\x03P\x043\x1a1\x20Use\
+    \x04\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x04\x04\x12\x03\x16\x04\x0c\n\n\
+    \n\x03\x07\x04\x05\x12\x03\x16\r\x11\n\n\n\x03\x07\x04\x01\x12\x03\x16\
+    \x120\n\n\n\x03\x07\x04\x03\x12\x03\x1638\n3\n\x02\x07\x05\x12\x03\x18\
+    \x04:\x1a(\x20Use\x20`bytes::Bytes`\x20for\x20`string`\x20fields\n\n\n\n\
+    \x03\x07\x05\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x05\x04\x12\x03\x18\x04\
+    \x0c\n\n\n\x03\x07\x05\x05\x12\x03\x18\r\x11\n\n\n\x03\x07\x05\x01\x12\
+    \x03\x18\x121\n\n\n\x03\x07\x05\x03\x12\x03\x1849\n=\n\x02\x07\x06\x12\
+    \x03\x1a\x041\x1a2\x20Use\x20`std::Vec`\x20to\x20store\x20repeated\x20me\
+    ssages\x20fields\n\n\n\n\x03\x07\x06\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\
+    \x06\x04\x12\x03\x1a\x04\x0c\n\n\n\x03\x07\x06\x05\x12\x03\x1a\r\x11\n\n\
+    \n\x03\x07\x06\x01\x12\x03\x1a\x12(\n\n\n\x03\x07\x06\x03\x12\x03\x1a+0\
+    \nM\n\x02\x07\x07\x12\x03\x1c\x048\x1aB\x20Use\x20`std::Option<std::Box<\
+    T>>`\x20to\x20store\x20singular\x20messages\x20fields\n\n\n\n\x03\x07\
+    \x07\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x07\x04\x12\x03\x1c\x04\x0c\n\n\
+    \n\x03\x07\x07\x05\x12\x03\x1c\r\x11\n\n\n\x03\x07\x07\x01\x12\x03\x1c\
+    \x12/\n\n\n\x03\x07\x07\x03\x12\x03\x1c27\n\x93\x01\n\x02\x07\x08\x12\
+    \x03\x1f\x044\x1a\x87\x01\x20Use\x20`std::Option<T>`\x20to\x20store\x20s\
+    ingular\x20messages\x20fields.\n\x20Note,\x20it's\x20not\x20possible\x20\
+    to\x20have\x20recursive\x20messages\x20with\x20this\x20option\x20enabled\
+    .\n\n\n\n\x03\x07\x08\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x08\x04\x12\
+    \x03\x1f\x04\x0c\n\n\n\x03\x07\x08\x05\x12\x03\x1f\r\x11\n\n\n\x03\x07\
+    \x08\x01\x12\x03\x1f\x12+\n\n\n\x03\x07\x08\x03\x12\x03\x1f.3\nJ\n\x02\
+    \x07\t\x12\x03\"\x04+\x1a?\x20Use\x20`serde_derive`\x20to\x20implement\
+    \x20`Serialize`\x20and\x20`Deserialize`\n\n\n\n\x03\x07\t\x02\x12\x03\
+    \x0c\x07\"\n\n\n\x03\x07\t\x04\x12\x03\"\x04\x0c\n\n\n\x03\x07\t\x05\x12\
+    \x03\"\r\x11\n\n\n\x03\x07\t\x01\x12\x03\"\x12\"\n\n\n\x03\x07\t\x03\x12\
+    \x03\"%*\n3\n\x02\x07\n\x12\x03$\x041\x1a(\x20Guard\x20serde\x20annotati\
+    ons\x20with\x20cfg\x20attr.\n\n\n\n\x03\x07\n\x02\x12\x03\x0c\x07\"\n\n\
+    \n\x03\x07\n\x04\x12\x03$\x04\x0c\n\n\n\x03\x07\n\x05\x12\x03$\r\x13\n\n\
+    \n\x03\x07\n\x01\x12\x03$\x14(\n\n\n\x03\x07\n\x03\x12\x03$+0\nN\n\x02\
+    \x07\x0b\x12\x03'\x04+\x1aC\x20When\x20true,\x20will\x20only\x20generate\
+    \x20codes\x20that\x20works\x20with\x20lite\x20runtime.\n\n\n\n\x03\x07\
+    \x0b\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x0b\x04\x12\x03'\x04\x0c\n\n\n\
+    \x03\x07\x0b\x05\x12\x03'\r\x11\n\n\n\x03\x07\x0b\x01\x12\x03'\x12\"\n\n\
+    \n\x03\x07\x0b\x03\x12\x03'%*\n\t\n\x01\x07\x12\x04*\0B\x01\n7\n\x02\x07\
+    \x0c\x12\x03,\x04'\x1a,\x20When\x20true,\x20oneof\x20field\x20is\x20gene\
+    rated\x20public\n\n\n\n\x03\x07\x0c\x02\x12\x03*\x07%\n\n\n\x03\x07\x0c\
+    \x04\x12\x03,\x04\x0c\n\n\n\x03\x07\x0c\x05\x12\x03,\r\x11\n\n\n\x03\x07\
+    \x0c\x01\x12\x03,\x12\x1e\n\n\n\x03\x07\x0c\x03\x12\x03,!&\nI\n\x02\x07\
+    \r\x12\x03.\x04(\x1a>\x20When\x20true\x20all\x20fields\x20are\x20public,\
+    \x20and\x20not\x20accessors\x20generated\n\n\n\n\x03\x07\r\x02\x12\x03*\
+    \x07%\n\n\n\x03\x07\r\x04\x12\x03.\x04\x0c\n\n\n\x03\x07\r\x05\x12\x03.\
+    \r\x11\n\n\n\x03\x07\r\x01\x12\x03.\x12\x1f\n\n\n\x03\x07\r\x03\x12\x03.\
+    \"'\nP\n\x02\x07\x0e\x12\x030\x04-\x1aE\x20When\x20false,\x20`get_`,\x20\
+    `set_`,\x20`mut_`\x20etc.\x20accessors\x20are\x20not\x20generated\n\n\n\
+    \n\x03\x07\x0e\x02\x12\x03*\x07%\n\n\n\x03\x07\x0e\x04\x12\x030\x04\x0c\
+    \n\n\n\x03\x07\x0e\x05\x12\x030\r\x11\n\n\n\x03\x07\x0e\x01\x12\x030\x12\
+    $\n\n\n\x03\x07\x0e\x03\x12\x030',\nL\n\x02\x07\x0f\x12\x032\x04*\x1aA\
+    \x20When\x20false,\x20`get_`\x20is\x20not\x20generated\x20even\x20if\x20\
+    `syntax\x20=\x20\"proto2\"`\n\n\n\n\x03\x07\x0f\x02\x12\x03*\x07%\n\n\n\
+    \x03\x07\x0f\x04\x12\x032\x04\x0c\n\n\n\x03\x07\x0f\x05\x12\x032\r\x11\n\
+    \n\n\x03\x07\x0f\x01\x12\x032\x12!\n\n\n\x03\x07\x0f\x03\x12\x032$)\n2\n\
+    \x02\x07\x10\x12\x034\x045\x1a'\x20Use\x20`bytes::Bytes`\x20for\x20`byte\
+    s`\x20fields\n\n\n\n\x03\x07\x10\x02\x12\x03*\x07%\n\n\n\x03\x07\x10\x04\
+    \x12\x034\x04\x0c\n\n\n\x03\x07\x10\x05\x12\x034\r\x11\n\n\n\x03\x07\x10\
+    \x01\x12\x034\x12,\n\n\n\x03\x07\x10\x03\x12\x034/4\n3\n\x02\x07\x11\x12\
+    \x036\x046\x1a(\x20Use\x20`bytes::Bytes`\x20for\x20`string`\x20fields\n\
+    \n\n\n\x03\x07\x11\x02\x12\x03*\x07%\n\n\n\x03\x07\x11\x04\x12\x036\x04\
+    \x0c\n\n\n\x03\x07\x11\x05\x12\x036\r\x11\n\n\n\x03\x07\x11\x01\x12\x036\
+    \x12-\n\n\n\x03\x07\x11\x03\x12\x03605\n<\n\x02\x07\x12\x12\x038\x04-\
+    \x1a1\x20Use\x20`std::Vec`\x20to\x20store\x20repeated\x20messages\x20fie\
+    ld\n\n\n\n\x03\x07\x12\x02\x12\x03*\x07%\n\n\n\x03\x07\x12\x04\x12\x038\
+    \x04\x0c\n\n\n\x03\x07\x12\x05\x12\x038\r\x11\n\n\n\x03\x07\x12\x01\x12\
+    \x038\x12$\n\n\n\x03\x07\x12\x03\x12\x038',\nM\n\x02\x07\x13\x12\x03:\
+    \x044\x1aB\x20Use\x20`std::Option<std::Box<T>>`\x20to\x20store\x20singul\
+    ar\x20messages\x20fields\n\n\n\n\x03\x07\x13\x02\x12\x03*\x07%\n\n\n\x03\
+    \x07\x13\x04\x12\x03:\x04\x0c\n\n\n\x03\x07\x13\x05\x12\x03:\r\x11\n\n\n\
+    \x03\x07\x13\x01\x12\x03:\x12+\n\n\n\x03\x07\x13\x03\x12\x03:.3\n\x93\
+    \x01\n\x02\x07\x14\x12\x03=\x040\x1a\x87\x01\x20Use\x20`std::Option<T>`\
+    \x20to\x20store\x20singular\x20messages\x20fields.\n\x20Note,\x20it's\
+    \x20not\x20possible\x20to\x20have\x20recursive\x20messages\x20with\x20th\
+    is\x20option\x20enabled.\n\n\n\n\x03\x07\x14\x02\x12\x03*\x07%\n\n\n\x03\
+    \x07\x14\x04\x12\x03=\x04\x0c\n\n\n\x03\x07\x14\x05\x12\x03=\r\x11\n\n\n\
+    \x03\x07\x14\x01\x12\x03=\x12'\n\n\n\x03\x07\x14\x03\x12\x03=*/\nJ\n\x02\
+    \x07\x15\x12\x03?\x04'\x1a?\x20Use\x20`serde_derive`\x20to\x20implement\
+    \x20`Serialize`\x20and\x20`Deserialize`\n\n\n\n\x03\x07\x15\x02\x12\x03*\
+    \x07%\n\n\n\x03\x07\x15\x04\x12\x03?\x04\x0c\n\n\n\x03\x07\x15\x05\x12\
+    \x03?\r\x11\n\n\n\x03\x07\x15\x01\x12\x03?\x12\x1e\n\n\n\x03\x07\x15\x03\
+    \x12\x03?!&\n3\n\x02\x07\x16\x12\x03A\x04-\x1a(\x20Guard\x20serde\x20ann\
+    otations\x20with\x20cfg\x20attr.\n\n\n\n\x03\x07\x16\x02\x12\x03*\x07%\n\
+    \n\n\x03\x07\x16\x04\x12\x03A\x04\x0c\n\n\n\x03\x07\x16\x05\x12\x03A\r\
+    \x13\n\n\n\x03\x07\x16\x01\x12\x03A\x14$\n\n\n\x03\x07\x16\x03\x12\x03A'\
+    ,\n\t\n\x01\x07\x12\x04D\0V\x01\nI\n\x02\x07\x17\x12\x03F\x04.\x1a>\x20W\
+    hen\x20true\x20all\x20fields\x20are\x20public,\x20and\x20not\x20accessor\
+    s\x20generated\n\n\n\n\x03\x07\x17\x02\x12\x03D\x07#\n\n\n\x03\x07\x17\
+    \x04\x12\x03F\x04\x0c\n\n\n\x03\x07\x17\x05\x12\x03F\r\x11\n\n\n\x03\x07\
+    \x17\x01\x12\x03F\x12%\n\n\n\x03\x07\x17\x03\x12\x03F(-\nP\n\x02\x07\x18\
+    \x12\x03H\x043\x1aE\x20When\x20false,\x20`get_`,\x20`set_`,\x20`mut_`\
+    \x20etc.\x20accessors\x20are\x20not\x20generated\n\n\n\n\x03\x07\x18\x02\
+    \x12\x03D\x07#\n\n\n\x03\x07\x18\x04\x12\x03H\x04\x0c\n\n\n\x03\x07\x18\
+    \x05\x12\x03H\r\x11\n\n\n\x03\x07\x18\x01\x12\x03H\x12*\n\n\n\x03\x07\
+    \x18\x03\x12\x03H-2\nL\n\x02\x07\x19\x12\x03J\x040\x1aA\x20When\x20false\
+    ,\x20`get_`\x20is\x20not\x20generated\x20even\x20if\x20`syntax\x20=\x20\
+    \"proto2\"`\n\n\n\n\x03\x07\x19\x02\x12\x03D\x07#\n\n\n\x03\x07\x19\x04\
+    \x12\x03J\x04\x0c\n\n\n\x03\x07\x19\x05\x12\x03J\r\x11\n\n\n\x03\x07\x19\
+    \x01\x12\x03J\x12'\n\n\n\x03\x07\x19\x03\x12\x03J*/\n2\n\x02\x07\x1a\x12\
+    \x03L\x04;\x1a'\x20Use\x20`bytes::Bytes`\x20for\x20`bytes`\x20fields\n\n\
+    \n\n\x03\x07\x1a\x02\x12\x03D\x07#\n\n\n\x03\x07\x1a\x04\x12\x03L\x04\
+    \x0c\n\n\n\x03\x07\x1a\x05\x12\x03L\r\x11\n\n\n\x03\x07\x1a\x01\x12\x03L\
+    \x122\n\n\n\x03\x07\x1a\x03\x12\x03L5:\n3\n\x02\x07\x1b\x12\x03N\x04<\
+    \x1a(\x20Use\x20`bytes::Bytes`\x20for\x20`string`\x20fields\n\n\n\n\x03\
+    \x07\x1b\x02\x12\x03D\x07#\n\n\n\x03\x07\x1b\x04\x12\x03N\x04\x0c\n\n\n\
+    \x03\x07\x1b\x05\x12\x03N\r\x11\n\n\n\x03\x07\x1b\x01\x12\x03N\x123\n\n\
+    \n\x03\x07\x1b\x03\x12\x03N6;\n<\n\x02\x07\x1c\x12\x03P\x043\x1a1\x20Use\
     \x20`std::Vec`\x20to\x20store\x20repeated\x20messages\x20field\n\n\n\n\
     \x03\x07\x1c\x02\x12\x03D\x07#\n\n\n\x03\x07\x1c\x04\x12\x03P\x04\x0c\n\
     \n\n\x03\x07\x1c\x05\x12\x03P\r\x11\n\n\n\x03\x07\x1c\x01\x12\x03P\x12*\
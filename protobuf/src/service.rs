@@ -0,0 +1,39 @@
+//! Metadata of services generated with `generate_services` option.
+//!
+//! Generated code is transport-agnostic: a trait with a method per RPC,
+//! and a `ServiceInfo` constant describing service methods.
+
+/// Information about service method (RPC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MethodInfo {
+    /// Method name as specified in `.proto` file, e. g. `SayHello`
+    pub name: &'static str,
+    /// Method path like `/package.Service/Method`
+    pub path: &'static str,
+    /// Full name of input message type
+    pub input_type: &'static str,
+    /// Full name of output message type
+    pub output_type: &'static str,
+    /// Client sends a stream of messages
+    pub client_streaming: bool,
+    /// Server returns a stream of messages
+    pub server_streaming: bool,
+}
+
+/// Information about service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServiceInfo {
+    /// Service name as specified in `.proto` file
+    pub name: &'static str,
+    /// Service full name including package
+    pub full_name: &'static str,
+    /// Service methods
+    pub methods: &'static [MethodInfo],
+}
+
+impl ServiceInfo {
+    /// Find method by name as specified in `.proto` file.
+    pub fn method_by_name(&self, name: &str) -> Option<&'static MethodInfo> {
+        self.methods.iter().find(|m| m.name == name)
+    }
+}
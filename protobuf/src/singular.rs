@@ -484,6 +484,17 @@ impl<T: Clone + Default> Clone for SingularField<T> {
             SingularField::none()
         }
     }
+
+    /// Clone into existing value reusing its allocations.
+    #[inline]
+    fn clone_from(&mut self, source: &SingularField<T>) {
+        if source.set {
+            self.value.clone_from(&source.value);
+            self.set = true;
+        } else {
+            self.clear();
+        }
+    }
 }
 
 impl<T: Clone> Clone for SingularPtrField<T> {
@@ -495,6 +506,22 @@ impl<T: Clone> Clone for SingularPtrField<T> {
             SingularPtrField::none()
         }
     }
+
+    /// Clone into existing value reusing its allocations,
+    /// including the value kept after `clear`.
+    #[inline]
+    fn clone_from(&mut self, source: &SingularPtrField<T>) {
+        if source.set {
+            let source_value = source.as_ref().unwrap();
+            match self.value {
+                Some(ref mut value) => (**value).clone_from(source_value),
+                None => self.value = Some(Box::new(source_value.clone())),
+            }
+            self.set = true;
+        } else {
+            self.clear();
+        }
+    }
 }
 
 impl<T: fmt::Debug> fmt::Debug for SingularField<T> {
@@ -680,9 +707,24 @@ impl<'de, T: serde::Deserialize<'de> + Default> serde::Deserialize<'de> for Sing
     }
 }
 
+#[cfg(feature = "with-arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for SingularPtrField<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Option::arbitrary(u).map(SingularPtrField::from)
+    }
+}
+
+#[cfg(feature = "with-arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a> + Default> arbitrary::Arbitrary<'a> for SingularField<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Option::arbitrary(u).map(SingularField::from)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::SingularField;
+    use super::SingularPtrField;
     use clear::Clear;
 
     #[test]
@@ -708,4 +750,22 @@ mod test {
         x.set_default();
         assert_eq!(0, x.as_ref().unwrap().b);
     }
+
+    #[test]
+    fn test_clone_from() {
+        let mut x = SingularField::some("aaaa".to_owned());
+        let ptr = x.as_ref().unwrap().as_ptr();
+        x.clone_from(&SingularField::some("b".to_owned()));
+        assert_eq!("b", x.as_ref().unwrap());
+        assert_eq!(ptr, x.as_ref().unwrap().as_ptr());
+        x.clone_from(&SingularField::none());
+        assert!(x.is_none());
+
+        let mut y = SingularPtrField::some("aaaa".to_owned());
+        let ptr = y.as_ref().unwrap().as_ptr();
+        y.clear();
+        y.clone_from(&SingularPtrField::some("b".to_owned()));
+        assert_eq!("b", y.as_ref().unwrap());
+        assert_eq!(ptr, y.as_ref().unwrap().as_ptr());
+    }
 }
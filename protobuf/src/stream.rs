@@ -1,14 +1,17 @@
+use std::collections::HashSet;
 use std::io;
 use std::io::Write;
 use std::io::{BufRead, Read};
 use std::mem;
 use std::slice;
+use std::str;
 
 #[cfg(feature = "bytes")]
 use bytes::Bytes;
 #[cfg(feature = "bytes")]
 use chars::Chars;
 
+use arc_str::ArcStr;
 use buf_read_iter::BufReadIter;
 use core::Message;
 use enums::ProtobufEnum;
@@ -21,6 +24,7 @@ use unknown::UnknownFields;
 use unknown::UnknownValue;
 use unknown::UnknownValueRef;
 use varint;
+use view::MessageView;
 use wire_format;
 use zigzag::decode_zig_zag_32;
 use zigzag::decode_zig_zag_64;
@@ -56,10 +60,23 @@ const DEFAULT_RECURSION_LIMIT: u32 = 100;
 // Max allocated vec when reading length-delimited from unknown input stream
 const READ_RAW_BYTES_MAX_ALLOC: usize = 10_000_000;
 
+// Width of `uint32` varint placeholder, enough for any value
+const VARINT32_MAX_LEN: usize = 5;
+
 pub struct CodedInputStream<'a> {
     source: BufReadIter<'a>,
     recursion_level: u32,
     recursion_limit: u32,
+    field_allowlist: Option<Vec<u32>>,
+    field_offset_numbers: Vec<u32>,
+    field_offset_collector: Option<&'a mut Vec<(u32, u64, u64)>>,
+    total_bytes_limit: Option<u64>,
+    max_repeated_len: Option<usize>,
+    reject_unknown_enum_values: bool,
+    validate_utf8: bool,
+    unknown_field_handler: Option<&'a mut FnMut(u32, wire_format::WireType, &[u8])>,
+    // created on first interned string read
+    interned_strings: Option<HashSet<ArcStr>>,
 }
 
 impl<'a> CodedInputStream<'a> {
@@ -75,6 +92,12 @@ impl<'a> CodedInputStream<'a> {
         CodedInputStream::from_buf_read_iter(BufReadIter::from_byte_slice(bytes))
     }
 
+    /// Read from `Bytes`.
+    ///
+    /// `Bytes` and `Chars` values read from this stream
+    /// (e. g. when `carllerche_bytes_for_bytes` option is used)
+    /// share memory with given `Bytes` instead of being copied
+    /// (except short values which `Bytes` stores inline).
     #[cfg(feature = "bytes")]
     pub fn from_carllerche_bytes(bytes: &'a Bytes) -> CodedInputStream<'a> {
         CodedInputStream::from_buf_read_iter(BufReadIter::from_bytes(bytes))
@@ -85,6 +108,15 @@ impl<'a> CodedInputStream<'a> {
             source: source,
             recursion_level: 0,
             recursion_limit: DEFAULT_RECURSION_LIMIT,
+            field_allowlist: None,
+            field_offset_numbers: Vec::new(),
+            field_offset_collector: None,
+            total_bytes_limit: None,
+            max_repeated_len: None,
+            reject_unknown_enum_values: false,
+            validate_utf8: true,
+            unknown_field_handler: None,
+            interned_strings: None,
         }
     }
 
@@ -93,6 +125,170 @@ impl<'a> CodedInputStream<'a> {
         self.recursion_limit = limit;
     }
 
+    /// Fail parsing when more than given number of bytes is read from the stream.
+    ///
+    /// Length of length-delimited fields is validated against this limit
+    /// before memory for field is allocated.
+    pub fn set_total_bytes_limit(&mut self, limit: usize) {
+        self.total_bytes_limit = Some(limit as u64);
+    }
+
+    /// Check that `len` more bytes can be read without exceeding total bytes limit
+    #[inline]
+    fn check_total_bytes_limit(&self, len: u64) -> ProtobufResult<()> {
+        match self.total_bytes_limit {
+            Some(limit) if self.pos().saturating_add(len) > limit => Err(
+                ProtobufError::WireError(WireError::TotalBytesLimitExceeded),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fail parsing when repeated or map field has more than given number of elements
+    /// (there is no limit by default).
+    ///
+    /// Lazy packed fields are not checked.
+    pub fn set_max_repeated_len(&mut self, max: usize) {
+        self.max_repeated_len = Some(max);
+    }
+
+    /// Check that repeated field with `len` elements does not exceed
+    /// the limit set with `set_max_repeated_len`
+    #[inline]
+    pub(crate) fn check_repeated_len(&self, len: usize) -> ProtobufResult<()> {
+        match self.max_repeated_len {
+            Some(max) if len > max => Err(ProtobufError::WireError(
+                WireError::RepeatedLenLimitExceeded,
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Parse only fields with given numbers of top-level message,
+    /// all other fields are stored in unknown fields, so they are
+    /// written back when message is serialized.
+    ///
+    /// Nested messages are parsed completely.
+    pub fn set_field_allowlist(&mut self, field_numbers: &[u32]) {
+        self.field_allowlist = Some(field_numbers.to_vec());
+    }
+
+    /// Parse all fields (which is the default).
+    pub fn clear_field_allowlist(&mut self) {
+        self.field_allowlist = None;
+    }
+
+    /// Check if field should be parsed or stored in unknown fields.
+    ///
+    /// Used by generated code.
+    #[inline]
+    pub fn field_allowed(&self, field_number: u32) -> bool {
+        match self.field_allowlist {
+            None => true,
+            Some(ref allowlist) => allowlist.contains(&field_number),
+        }
+    }
+
+    /// Record `(field_number, start, len)` byte ranges of fields with given numbers
+    /// of top-level message into `collector`.
+    ///
+    /// Range covers the whole encoded field including tag,
+    /// positions are relative to the start of the stream (as `pos`).
+    /// Repeated fields get one range per encoded occurrence.
+    pub fn set_field_offset_collector(
+        &mut self,
+        field_numbers: &[u32],
+        collector: &'a mut Vec<(u32, u64, u64)>,
+    ) {
+        self.field_offset_numbers = field_numbers.to_vec();
+        self.field_offset_collector = Some(collector);
+    }
+
+    /// Record range of field which started at `start` and ended at current position
+    /// if offsets of that field are collected.
+    ///
+    /// Used by generated code.
+    #[inline]
+    pub fn record_field_offset(&mut self, field_number: u32, start: u64) {
+        if let Some(ref mut collector) = self.field_offset_collector {
+            if self.field_offset_numbers.contains(&field_number) {
+                let end = self.source.pos();
+                collector.push((field_number, start, end - start));
+            }
+        }
+    }
+
+    /// Fail parsing when enum field contains a value unknown to the enum type
+    /// instead of storing it in `ProtobufEnumOrUnknown` (which is the default).
+    ///
+    /// Map values are not checked.
+    pub fn set_reject_unknown_enum_values(&mut self, reject: bool) {
+        self.reject_unknown_enum_values = reject;
+    }
+
+    /// Check if unknown enum values are rejected.
+    pub fn reject_unknown_enum_values(&self) -> bool {
+        self.reject_unknown_enum_values
+    }
+
+    /// Skip UTF-8 validation of string fields (validation is enabled by default).
+    ///
+    /// # Safety
+    ///
+    /// When validation is disabled, caller must guarantee that all string fields
+    /// in the input are valid UTF-8. Otherwise parsed `String` and `str` values
+    /// contain invalid UTF-8, which is undefined behavior.
+    pub unsafe fn set_validate_utf8(&mut self, validate: bool) {
+        self.validate_utf8 = validate;
+    }
+
+    /// Check if string fields are validated to be UTF-8.
+    pub fn validate_utf8(&self) -> bool {
+        self.validate_utf8
+    }
+
+    /// Call given function for each unknown field read by generated code
+    /// (including unknown fields of nested messages) with field number,
+    /// wire type and encoded value (content for length-delimited fields).
+    ///
+    /// Unknown fields are still stored in `UnknownFields`.
+    /// Skipped groups are not reported.
+    pub fn set_unknown_field_handler(
+        &mut self,
+        handler: &'a mut FnMut(u32, wire_format::WireType, &[u8]),
+    ) {
+        self.unknown_field_handler = Some(handler);
+    }
+
+    // Invoke handler set with `set_unknown_field_handler`
+    pub(crate) fn handle_unknown_field(&mut self, field_number: u32, value: &UnknownValue) {
+        let handler = match self.unknown_field_handler {
+            Some(ref mut handler) => handler,
+            None => return,
+        };
+        let mut buf = [0; 10];
+        let raw: &[u8] = match *value {
+            UnknownValue::Fixed32(v) => {
+                for i in 0..4 {
+                    buf[i] = (v >> (i * 8)) as u8;
+                }
+                &buf[..4]
+            }
+            UnknownValue::Fixed64(v) => {
+                for i in 0..8 {
+                    buf[i] = (v >> (i * 8)) as u8;
+                }
+                &buf[..8]
+            }
+            UnknownValue::Varint(v) => {
+                let len = varint::encode_varint64(v, &mut buf);
+                &buf[..len]
+            }
+            UnknownValue::LengthDelimited(ref bytes) => bytes,
+        };
+        handler(field_number, value.wire_type(), raw);
+    }
+
     #[inline]
     pub(crate) fn incr_recursion(&mut self) -> ProtobufResult<()> {
         if self.recursion_level >= self.recursion_limit {
@@ -123,6 +319,7 @@ impl<'a> CodedInputStream<'a> {
 
     #[cfg(feature = "bytes")]
     fn read_raw_callerche_bytes(&mut self, count: usize) -> ProtobufResult<Bytes> {
+        self.check_total_bytes_limit(count as u64)?;
         self.source.read_exact_bytes(count)
     }
 
@@ -132,6 +329,7 @@ impl<'a> CodedInputStream<'a> {
     }
 
     pub fn push_limit(&mut self, limit: u64) -> ProtobufResult<u64> {
+        self.check_total_bytes_limit(limit)?;
         self.source.push_limit(limit)
     }
 
@@ -139,8 +337,12 @@ impl<'a> CodedInputStream<'a> {
         self.source.pop_limit(old_limit);
     }
 
+    /// Check if end of input or current limit is reached.
+    ///
+    /// Reads more data from underlying `Read` if buffer is exhausted.
     #[inline(always)]
     pub fn eof(&mut self) -> ProtobufResult<bool> {
+        self.check_total_bytes_limit(0)?;
         self.source.eof()
     }
 
@@ -276,6 +478,16 @@ impl<'a> CodedInputStream<'a> {
         self.read_tag().map(|t| t.unpack())
     }
 
+    /// Read tag, return it as pair (field number, wire type),
+    /// or `None` if end of stream or current limit is reached.
+    #[inline]
+    pub fn read_tag_unpacked(&mut self) -> ProtobufResult<Option<(u32, wire_format::WireType)>> {
+        if self.eof()? {
+            return Ok(None);
+        }
+        self.read_tag_unpack().map(Some)
+    }
+
     pub fn read_double(&mut self) -> ProtobufResult<f64> {
         let bits = self.read_raw_little_endian64()?;
         unsafe { Ok(mem::transmute::<u64, f64>(bits)) }
@@ -344,11 +556,32 @@ impl<'a> CodedInputStream<'a> {
         Ok(ProtobufEnumOrUnknown::from_i32(self.read_int32()?))
     }
 
+    /// Read enum value of given field, and fail if value is unknown
+    /// and unknown enum values are rejected.
+    ///
+    /// Used by generated code.
+    pub fn read_enum_or_unknown_for_field<E: ProtobufEnum>(
+        &mut self,
+        field: &'static str,
+    ) -> ProtobufResult<ProtobufEnumOrUnknown<E>> {
+        let value = self.read_enum_or_unknown()?;
+        if self.reject_unknown_enum_values {
+            if let Err(value) = value.enum_value() {
+                return Err(ProtobufError::WireError(WireError::UnknownEnumValue {
+                    field,
+                    value,
+                }));
+            }
+        }
+        Ok(value)
+    }
+
     fn read_repeated_packed_fixed_into<T: ProtobufTypeFixed>(
         &mut self,
         target: &mut Vec<<T::RuntimeType as RuntimeType>::Value>,
     ) -> ProtobufResult<()> {
         let len_bytes = self.read_raw_varint64()?;
+        self.check_total_bytes_limit(len_bytes)?;
 
         let reserve = if len_bytes <= READ_RAW_BYTES_MAX_ALLOC as u64 {
             (len_bytes as usize) / (T::encoded_size() as usize)
@@ -358,10 +591,17 @@ impl<'a> CodedInputStream<'a> {
             READ_RAW_BYTES_MAX_ALLOC / (T::encoded_size() as usize)
         };
 
+        // do not reserve more than allowed by `set_max_repeated_len`
+        let reserve = match self.max_repeated_len {
+            Some(max) if reserve > max => max,
+            _ => reserve,
+        };
+
         target.reserve(reserve);
 
         let old_limit = self.push_limit(len_bytes)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len() + 1)?;
             target.push(T::read(self)?);
         }
         self.pop_limit(old_limit);
@@ -373,6 +613,7 @@ impl<'a> CodedInputStream<'a> {
         target: &mut Vec<<T::RuntimeType as RuntimeType>::Value>,
     ) -> ProtobufResult<()> {
         let len_bytes = self.read_raw_varint64()?;
+        self.check_total_bytes_limit(len_bytes)?;
 
         // value is at least 1 bytes, so this is lower bound of element count
         let reserve = if len_bytes <= READ_RAW_BYTES_MAX_ALLOC as u64 {
@@ -382,10 +623,17 @@ impl<'a> CodedInputStream<'a> {
             READ_RAW_BYTES_MAX_ALLOC
         };
 
+        // do not reserve more than allowed by `set_max_repeated_len`
+        let reserve = match self.max_repeated_len {
+            Some(max) if reserve > max => max,
+            _ => reserve,
+        };
+
         target.reserve(reserve);
 
         let old_limit = self.push_limit(len_bytes)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len() + 1)?;
             target.push(T::read(self)?);
         }
         self.pop_limit(old_limit);
@@ -508,6 +756,8 @@ impl<'a> CodedInputStream<'a> {
     pub fn read_raw_bytes_into(&mut self, count: u32, target: &mut Vec<u8>) -> ProtobufResult<()> {
         let count = count as usize;
 
+        self.check_total_bytes_limit(count as u64)?;
+
         // TODO: also do some limits when reading from unlimited source
         if count as u64 > self.source.bytes_until_limit() {
             return Err(ProtobufError::WireError(WireError::TruncatedMessage));
@@ -585,18 +835,74 @@ impl<'a> CodedInputStream<'a> {
         let mut vec = mem::replace(target, String::new()).into_bytes();
         self.read_bytes_into(&mut vec)?;
 
-        let s = match String::from_utf8(vec) {
-            Ok(t) => t,
-            Err(_) => return Err(ProtobufError::WireError(WireError::Utf8Error)),
+        let s = if self.validate_utf8 {
+            match String::from_utf8(vec) {
+                Ok(t) => t,
+                Err(_) => return Err(ProtobufError::WireError(WireError::Utf8Error)),
+            }
+        } else {
+            // validation was disabled with unsafe `set_validate_utf8`
+            unsafe { String::from_utf8_unchecked(vec) }
         };
         mem::replace(target, s);
         Ok(())
     }
 
-    pub fn merge_message<M: Message>(&mut self, message: &mut M) -> ProtobufResult<()> {
+    /// Read `string` field value, returning shared storage
+    /// if equal string was already read from this stream.
+    pub fn read_interned_string(&mut self) -> ProtobufResult<ArcStr> {
+        let s = self.read_string()?;
+        let interned_strings = self.interned_strings.get_or_insert_with(HashSet::new);
+        if let Some(interned) = interned_strings.get(&*s) {
+            return Ok(interned.clone());
+        }
+        let interned = ArcStr::from(s);
+        interned_strings.insert(interned.clone());
+        Ok(interned)
+    }
+
+    /// Read `bytes` field value pointing into the input buffer.
+    ///
+    /// Only possible when stream is created from byte slice or `Bytes`.
+    pub fn read_bytes_borrowed(&mut self) -> ProtobufResult<&'a [u8]> {
+        let len = self.read_raw_varint32()? as u64;
+        self.check_total_bytes_limit(len)?;
+        if len > self.source.bytes_until_limit() {
+            return Err(ProtobufError::WireError(WireError::TruncatedMessage));
+        }
+        self.source.read_exact_borrowed(len as usize)
+    }
+
+    /// Read `string` field value pointing into the input buffer.
+    ///
+    /// Only possible when stream is created from byte slice or `Bytes`.
+    pub fn read_str_borrowed(&mut self) -> ProtobufResult<&'a str> {
+        let bytes = self.read_bytes_borrowed()?;
+        if !self.validate_utf8 {
+            // validation was disabled with unsafe `set_validate_utf8`
+            return Ok(unsafe { str::from_utf8_unchecked(bytes) });
+        }
+        str::from_utf8(bytes).map_err(|_| ProtobufError::WireError(WireError::Utf8Error))
+    }
+
+    pub fn merge_message<M: Message + ?Sized>(&mut self, message: &mut M) -> ProtobufResult<()> {
+        let len = self.read_raw_varint64()?;
+        let old_limit = self.push_limit(len)?;
+        // field allowlist and offset collector are applied only to top-level message
+        let field_allowlist = self.field_allowlist.take();
+        let field_offset_collector = self.field_offset_collector.take();
+        let r = message.merge_from(self);
+        self.field_allowlist = field_allowlist;
+        self.field_offset_collector = field_offset_collector;
+        r?;
+        self.pop_limit(old_limit);
+        Ok(())
+    }
+
+    pub fn merge_message_view<V: MessageView<'a>>(&mut self, view: &mut V) -> ProtobufResult<()> {
         let len = self.read_raw_varint64()?;
         let old_limit = self.push_limit(len)?;
-        message.merge_from(self)?;
+        view.merge_from(self)?;
         self.pop_limit(old_limit);
         Ok(())
     }
@@ -638,7 +944,7 @@ impl<'a> WithCodedOutputStream for &'a mut (Write + 'a) {
     {
         let mut os = CodedOutputStream::new(self);
         let r = cb(&mut os)?;
-        os.flush()?;
+        os.finish()?;
         Ok(r)
     }
 }
@@ -713,15 +1019,64 @@ impl<'a> WithCodedInputStream for &'a Bytes {
 enum OutputTarget<'a> {
     Write(&'a mut Write, Vec<u8>),
     Vec(&'a mut Vec<u8>),
+    // like `Vec`, cursor position is kept at the end of flushed data;
+    // data after initial position is detached while the stream is alive
+    // (initial position and the detached tail)
+    Cursor(&'a mut io::Cursor<Vec<u8>>, usize, Vec<u8>),
     Bytes,
 }
 
+impl<'a> OutputTarget<'a> {
+    // `Vec` receiving data for `Vec` and `Cursor` targets
+    fn vec_mut(&mut self) -> Option<&mut Vec<u8>> {
+        match *self {
+            OutputTarget::Vec(ref mut vec) => Some(vec),
+            OutputTarget::Cursor(ref mut cursor, ..) => Some(cursor.get_mut()),
+            OutputTarget::Write(..) | OutputTarget::Bytes => None,
+        }
+    }
+
+    // append data after cursor initial position which was not overwritten
+    fn restore_cursor_tail(&mut self) {
+        if let OutputTarget::Cursor(ref mut cursor, start, ref tail) = *self {
+            let written = cursor.get_ref().len() - start;
+            if written < tail.len() {
+                cursor.get_mut().extend_from_slice(&tail[written..]);
+            }
+        }
+    }
+
+    fn update_cursor_position(&mut self) {
+        if let OutputTarget::Cursor(ref mut cursor, ..) = *self {
+            let len = cursor.get_ref().len();
+            cursor.set_position(len as u64);
+        }
+    }
+}
+
+/// Position in `CodedOutputStream` captured by `checkpoint`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    // total number of bytes written to the target
+    position: usize,
+}
+
+/// Space for varint reserved by `reserve_varint32_placeholder`,
+/// filled by `patch_varint32`.
+#[derive(Debug)]
+pub struct Varint32Placeholder {
+    // number of bytes written by this stream before the placeholder
+    position: u64,
+}
+
 pub struct CodedOutputStream<'a> {
     target: OutputTarget<'a>,
     // alias to buf from target
     buffer: &'a mut [u8],
     // within buffer
     position: usize,
+    // bytes written by this stream before `buffer`
+    position_flushed: u64,
 }
 
 impl<'a> CodedOutputStream<'a> {
@@ -739,6 +1094,7 @@ impl<'a> CodedOutputStream<'a> {
             target: OutputTarget::Write(writer, buffer_storage),
             buffer: buffer,
             position: 0,
+            position_flushed: 0,
         }
     }
 
@@ -750,6 +1106,7 @@ impl<'a> CodedOutputStream<'a> {
             target: OutputTarget::Bytes,
             buffer: bytes,
             position: 0,
+            position_flushed: 0,
         }
     }
 
@@ -759,6 +1116,28 @@ impl<'a> CodedOutputStream<'a> {
             target: OutputTarget::Vec(vec),
             buffer: &mut [],
             position: 0,
+            position_flushed: 0,
+        }
+    }
+
+    /// `CodedOutputStream` which writes to `Cursor` at its position.
+    ///
+    /// Like `io::Cursor` writes, data after cursor position is overwritten in place,
+    /// and the part which is not overwritten is kept. Cursor position
+    /// is moved to the end of written data when stream is flushed.
+    pub fn cursor(cursor: &'a mut io::Cursor<Vec<u8>>) -> CodedOutputStream<'a> {
+        let position = cursor.position() as usize;
+        let tail = if position < cursor.get_ref().len() {
+            cursor.get_mut().split_off(position)
+        } else {
+            cursor.get_mut().resize(position, 0);
+            Vec::new()
+        };
+        CodedOutputStream {
+            target: OutputTarget::Cursor(cursor, position, tail),
+            buffer: &mut [],
+            position: 0,
+            position_flushed: 0,
         }
     }
 
@@ -767,26 +1146,37 @@ impl<'a> CodedOutputStream<'a> {
             OutputTarget::Bytes => {
                 assert_eq!(self.buffer.len() as u64, self.position as u64);
             }
-            OutputTarget::Write(..) | OutputTarget::Vec(..) => {
-                panic!("must not be called with Writer or Vec");
+            OutputTarget::Write(..) | OutputTarget::Vec(..) | OutputTarget::Cursor(..) => {
+                panic!("must not be called with Writer, Vec or Cursor");
             }
         }
     }
 
+    /// Total number of bytes written to this stream so far,
+    /// including bytes which are not flushed yet.
+    pub fn total_bytes_written(&self) -> u64 {
+        self.position_flushed + self.position as u64
+    }
+
     fn refresh_buffer(&mut self) -> ProtobufResult<()> {
+        self.position_flushed += self.position as u64;
         match self.target {
             OutputTarget::Write(ref mut write, _) => {
                 write.write_all(&self.buffer[0..self.position as usize])?;
                 self.position = 0;
             }
-            OutputTarget::Vec(ref mut vec) => unsafe {
-                let vec_len = vec.len();
-                assert!(vec_len + self.position <= vec.capacity());
-                vec.set_len(vec_len + self.position);
-                vec.reserve(1);
-                self.buffer = remove_lifetime_mut(remaining_capacity_as_slice_mut(vec));
+            OutputTarget::Vec(..) | OutputTarget::Cursor(..) => {
+                unsafe {
+                    let vec = self.target.vec_mut().unwrap();
+                    let vec_len = vec.len();
+                    assert!(vec_len + self.position <= vec.capacity());
+                    vec.set_len(vec_len + self.position);
+                    vec.reserve(1);
+                    self.buffer = remove_lifetime_mut(remaining_capacity_as_slice_mut(vec));
+                }
                 self.position = 0;
-            },
+                self.target.update_cursor_position();
+            }
             OutputTarget::Bytes => {
                 panic!("refresh_buffer must not be called on CodedOutputStream created from slice");
             }
@@ -802,13 +1192,78 @@ impl<'a> CodedOutputStream<'a> {
     pub fn flush(&mut self) -> ProtobufResult<()> {
         match self.target {
             OutputTarget::Bytes => Ok(()),
-            OutputTarget::Write(..) | OutputTarget::Vec(..) => {
+            OutputTarget::Write(..) | OutputTarget::Vec(..) | OutputTarget::Cursor(..) => {
                 // TODO: must not reserve additional in Vec
                 self.refresh_buffer()
             }
         }
     }
 
+    /// Flush and consume the stream.
+    ///
+    /// Unlike destructor, this function reports flush error to the caller,
+    /// so it should be used to complete writing to `Write`.
+    /// If flush fails, buffered data is discarded.
+    pub fn finish(mut self) -> ProtobufResult<()> {
+        let r = self.flush();
+        // do not flush again in destructor
+        self.position = 0;
+        r
+    }
+
+    /// Capture current position, so output written after it
+    /// could be discarded with `rollback`.
+    pub fn checkpoint(&self) -> Checkpoint {
+        let position = match self.target {
+            OutputTarget::Vec(ref vec) => vec.len() + self.position,
+            OutputTarget::Cursor(ref cursor, ..) => cursor.get_ref().len() + self.position,
+            OutputTarget::Write(..) | OutputTarget::Bytes => self.position,
+        };
+        Checkpoint { position }
+    }
+
+    /// Discard everything written after the checkpoint.
+    ///
+    /// Only supported for streams writing to `Vec`, `Cursor` or slice,
+    /// because `Write` target may have already received the data.
+    pub fn rollback(&mut self, checkpoint: Checkpoint) -> ProtobufResult<()> {
+        let total_bytes_written = self.total_bytes_written();
+        let discarded;
+        match self.target {
+            OutputTarget::Write(..) => {
+                return Err(ProtobufError::IoError(io::Error::new(
+                    io::ErrorKind::Other,
+                    "rollback is not supported for CodedOutputStream writing to Write",
+                )));
+            }
+            OutputTarget::Vec(..) | OutputTarget::Cursor(..) => {
+                {
+                    let vec = self.target.vec_mut().unwrap();
+                    let vec_len = vec.len();
+                    assert!(checkpoint.position <= vec_len + self.position);
+                    discarded = vec_len + self.position - checkpoint.position;
+                    if checkpoint.position >= vec_len {
+                        self.position = checkpoint.position - vec_len;
+                    } else {
+                        vec.truncate(checkpoint.position);
+                        unsafe {
+                            self.buffer = remove_lifetime_mut(remaining_capacity_as_slice_mut(vec));
+                        }
+                        self.position = 0;
+                    }
+                }
+                self.target.update_cursor_position();
+            }
+            OutputTarget::Bytes => {
+                assert!(checkpoint.position <= self.position);
+                discarded = self.position - checkpoint.position;
+                self.position = checkpoint.position;
+            }
+        }
+        self.position_flushed = total_bytes_written - discarded as u64 - self.position as u64;
+        Ok(())
+    }
+
     pub fn write_raw_byte(&mut self, byte: u8) -> ProtobufResult<()> {
         if self.position as usize == self.buffer.len() {
             self.refresh_buffer()?;
@@ -843,17 +1298,88 @@ impl<'a> CodedOutputStream<'a> {
             }
             OutputTarget::Write(ref mut write, _) => {
                 write.write_all(bytes)?;
+                self.position_flushed += bytes.len() as u64;
             }
-            OutputTarget::Vec(ref mut vec) => {
-                vec.extend(bytes);
-                unsafe {
-                    self.buffer = remove_lifetime_mut(remaining_capacity_as_slice_mut(vec));
+            OutputTarget::Vec(..) | OutputTarget::Cursor(..) => {
+                {
+                    let vec = self.target.vec_mut().unwrap();
+                    vec.extend(bytes);
+                    unsafe {
+                        self.buffer = remove_lifetime_mut(remaining_capacity_as_slice_mut(vec));
+                    }
                 }
+                self.position_flushed += bytes.len() as u64;
+                self.target.update_cursor_position();
             }
         }
         Ok(())
     }
 
+    /// Reserve space for `uint32` varint written later with `patch_varint32`,
+    /// e. g. for length of nested message which is not known before the message is written.
+    pub fn reserve_varint32_placeholder(&mut self) -> ProtobufResult<Varint32Placeholder> {
+        let position = self.total_bytes_written();
+        self.write_raw_bytes(&[0; VARINT32_MAX_LEN])?;
+        Ok(Varint32Placeholder { position })
+    }
+
+    /// Write value to the placeholder.
+    ///
+    /// Placeholder has max varint width, so data written after the placeholder
+    /// is moved back when the value is shorter.
+    ///
+    /// When writing to `Write`, placeholder must still be in the stream buffer,
+    /// that is, must not be flushed.
+    pub fn patch_varint32(
+        &mut self,
+        placeholder: Varint32Placeholder,
+        value: u32,
+    ) -> ProtobufResult<()> {
+        let total_bytes_written = self.total_bytes_written();
+        assert!(
+            placeholder.position + VARINT32_MAX_LEN as u64 <= total_bytes_written,
+            "placeholder is rolled back"
+        );
+
+        let mut encoded = [0; VARINT32_MAX_LEN];
+        let len = varint::encode_varint32(value, &mut encoded);
+        let shift = VARINT32_MAX_LEN - len;
+
+        if placeholder.position >= self.position_flushed {
+            let start = (placeholder.position - self.position_flushed) as usize;
+            self.buffer[start..start + len].copy_from_slice(&encoded[..len]);
+            self.buffer[start + len..self.position].rotate_left(shift);
+            self.position -= shift;
+            return Ok(());
+        }
+
+        match self.target {
+            OutputTarget::Write(..) => {
+                return Err(ProtobufError::IoError(io::Error::new(
+                    io::ErrorKind::Other,
+                    "varint placeholder is already flushed to Write",
+                )));
+            }
+            OutputTarget::Vec(..) | OutputTarget::Cursor(..) => {}
+            OutputTarget::Bytes => unreachable!(),
+        }
+
+        // move everything to vec, so placeholder and data after it are contiguous
+        self.refresh_buffer()?;
+        {
+            let vec = self.target.vec_mut().unwrap();
+            let start = vec.len() - (total_bytes_written - placeholder.position) as usize;
+            vec[start..start + len].copy_from_slice(&encoded[..len]);
+            vec.drain(start + len..start + VARINT32_MAX_LEN);
+            unsafe {
+                self.buffer = remove_lifetime_mut(remaining_capacity_as_slice_mut(vec));
+            }
+        }
+        self.position_flushed -= shift as u64;
+        self.target.update_cursor_position();
+        Ok(())
+    }
+
     pub fn write_tag(
         &mut self,
         field_number: u32,
@@ -1106,7 +1632,11 @@ impl<'a> CodedOutputStream<'a> {
         self.write_bytes_no_tag(s.as_bytes())
     }
 
-    pub fn write_message_no_tag<M: Message>(&mut self, msg: &M) -> ProtobufResult<()> {
+    /// Write message size computed with `compute_size` as varint followed by the message,
+    /// without field tag.
+    ///
+    /// `M` can be `dyn Message`.
+    pub fn write_message_no_tag<M: Message + ?Sized>(&mut self, msg: &M) -> ProtobufResult<()> {
         msg.write_length_delimited_to(self)
     }
 
@@ -1122,7 +1652,11 @@ impl<'a> CodedOutputStream<'a> {
         Ok(())
     }
 
-    pub fn write_message<M: Message>(&mut self, field_number: u32, msg: &M) -> ProtobufResult<()> {
+    pub fn write_message<M: Message + ?Sized>(
+        &mut self,
+        field_number: u32,
+        msg: &M,
+    ) -> ProtobufResult<()> {
         self.write_tag(field_number, wire_format::WireTypeLengthDelimited)?;
         self.write_message_no_tag(msg)?;
         Ok(())
@@ -1140,10 +1674,16 @@ impl<'a> Write for CodedOutputStream<'a> {
     }
 }
 
+/// Destructor flushes buffered data.
+///
+/// Flush to `Vec` or to slice cannot fail, but flush to `Write` can,
+/// and destructor panics on error, so `Write` streams should be completed
+/// with `finish` (or `flush`) to handle errors.
 impl<'a> Drop for CodedOutputStream<'a> {
     fn drop(&mut self) {
         // This may panic
         CodedOutputStream::flush(self).expect("failed to flush");
+        self.target.restore_cursor_tail();
     }
 }
 
@@ -1159,6 +1699,7 @@ mod test {
 
     use error::ProtobufError;
     use error::ProtobufResult;
+    use error::WireError;
     use hex::decode_hex;
     use hex::encode_hex;
 
@@ -1167,6 +1708,13 @@ mod test {
     use super::CodedOutputStream;
     use super::READ_RAW_BYTES_MAX_ALLOC;
 
+    use core::parse_from_bytes;
+    use core::parse_from_bytes_with_unknown_handler;
+    use descriptor::DescriptorProto;
+    use descriptor::FileDescriptorProto;
+    use well_known_types::Duration;
+    use Message;
+
     fn test_read_partial<F>(hex: &str, mut callback: F)
     where
         F: FnMut(&mut CodedInputStream),
@@ -1320,6 +1868,154 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_input_stream_read_tag_unpacked() {
+        // field 1 varint, field 2 length-delimited, field 3 fixed32, field 4 fixed64
+        test_read("08 96 01 12 01 aa 1d 01 02 03 04 21 01 02 03 04 05 06 07 08", |is| {
+            assert_eq!(Some((1, wire_format::WireTypeVarint)), is.read_tag_unpacked().unwrap());
+            assert_eq!(150, is.read_uint32().unwrap());
+            assert_eq!(
+                Some((2, wire_format::WireTypeLengthDelimited)),
+                is.read_tag_unpacked().unwrap()
+            );
+            assert_eq!(vec![0xaa], is.read_bytes().unwrap());
+            assert_eq!(Some((3, wire_format::WireTypeFixed32)), is.read_tag_unpacked().unwrap());
+            is.read_fixed32().unwrap();
+            assert_eq!(Some((4, wire_format::WireTypeFixed64)), is.read_tag_unpacked().unwrap());
+            is.read_fixed64().unwrap();
+            assert_eq!(None, is.read_tag_unpacked().unwrap());
+        });
+    }
+
+    #[test]
+    fn test_input_stream_eof_after_message_fields() {
+        // returns one byte per `read` call, so `eof` has to refill the buffer
+        struct ReadByByte<'a>(&'a [u8]);
+
+        impl<'a> Read for ReadByByte<'a> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if self.0.is_empty() || buf.is_empty() {
+                    return Ok(0);
+                }
+                buf[0] = self.0[0];
+                self.0 = &self.0[1..];
+                Ok(1)
+            }
+        }
+
+        let mut d = Duration::new();
+        d.seconds = 150;
+        d.nanos = 7;
+        let bytes = d.write_to_bytes().unwrap();
+
+        let mut read = ReadByByte(&bytes);
+        let mut is = CodedInputStream::new(&mut read);
+        let mut fields = Vec::new();
+        while !is.eof().unwrap() {
+            let (number, wire_type) = is.read_tag_unpack().unwrap();
+            assert_eq!(wire_format::WireTypeVarint, wire_type);
+            fields.push((number, is.read_int64().unwrap()));
+        }
+        assert_eq!(vec![(1, 150), (2, 7)], fields);
+        assert!(is.eof().unwrap());
+    }
+
+    #[test]
+    fn test_input_stream_total_bytes_limit_huge_length() {
+        // field 1, length-delimited, length 0x7fffffff
+        test_read_partial("0a ff ff ff ff 07 aa bb", |is| {
+            is.set_total_bytes_limit(100);
+            assert_eq!((1, wire_format::WireTypeLengthDelimited), is.read_tag_unpack().unwrap());
+            match is.read_bytes() {
+                Err(ProtobufError::WireError(WireError::TotalBytesLimitExceeded)) => {}
+                r => panic!("{:?}", r),
+            }
+        });
+    }
+
+    #[test]
+    fn test_input_stream_total_bytes_limit() {
+        test_read_partial("08 01 08 02 08 03", |is| {
+            is.set_total_bytes_limit(4);
+            for _ in 0..2 {
+                assert!(!is.eof().unwrap());
+                is.read_tag().unwrap();
+                is.read_int32().unwrap();
+            }
+            assert!(!is.eof().unwrap());
+            is.read_tag().unwrap();
+            is.read_int32().unwrap();
+            match is.eof() {
+                Err(ProtobufError::WireError(WireError::TotalBytesLimitExceeded)) => {}
+                r => panic!("{:?}", r),
+            }
+        });
+    }
+
+    #[test]
+    fn test_input_stream_read_borrowed() {
+        let bytes = decode_hex("03 61 62 63 02 ff fe 02 61");
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        let s = is.read_str_borrowed().unwrap();
+        assert_eq!("abc", s);
+        assert_eq!(bytes[1..].as_ptr(), s.as_ptr());
+        match is.read_str_borrowed() {
+            Err(ProtobufError::WireError(WireError::Utf8Error)) => {}
+            r => panic!("{:?}", r),
+        }
+        assert!(is.read_bytes_borrowed().is_err());
+
+        test_read_partial("03 61 62 63", |is| match is.read_bytes_borrowed() {
+            Err(ProtobufError::WireError(WireError::BorrowFromReader)) => {}
+            r => panic!("{:?}", r),
+        });
+    }
+
+    #[test]
+    fn test_input_stream_validate_utf8() {
+        // `FileDescriptorProto` with `name` field containing invalid UTF-8
+        let bytes = decode_hex("0a 02 ff fe");
+
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        assert!(is.validate_utf8());
+        let mut file = FileDescriptorProto::new();
+        match file.merge_from(&mut is) {
+            Err(ProtobufError::WireError(WireError::Utf8Error)) => {}
+            r => panic!("{:?}", r),
+        }
+
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        unsafe {
+            is.set_validate_utf8(false);
+        }
+        let mut file = FileDescriptorProto::new();
+        file.merge_from(&mut is).expect("merge_from");
+        assert_eq!(&[0xff, 0xfe], file.get_name().as_bytes());
+    }
+
+    #[test]
+    fn test_input_stream_unknown_field_handler() {
+        // `FileDescriptorProto` with `name` and unknown fields 20 and 21
+        let bytes = decode_hex("a0 01 96 01 0a 01 61 aa 01 02 62 63");
+        let mut unknown = Vec::new();
+        let file: FileDescriptorProto = {
+            let mut handler = |field_number: u32, wire_type, raw: &[u8]| {
+                unknown.push((field_number, wire_type, raw.to_vec()));
+            };
+            parse_from_bytes_with_unknown_handler(&bytes, &mut handler).unwrap()
+        };
+        assert_eq!("a", file.get_name());
+        assert_eq!(
+            vec![
+                (20, wire_format::WireTypeVarint, vec![0x96, 0x01]),
+                (21, wire_format::WireTypeLengthDelimited, b"bc".to_vec()),
+            ],
+            unknown
+        );
+        // unknown fields are still preserved
+        assert_eq!(bytes.len(), file.write_to_bytes().unwrap().len());
+    }
+
     #[test]
     fn test_input_stream_io_read() {
         test_read("aa bb cc", |is| {
@@ -1438,6 +2134,175 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_output_stream_rollback_vec() {
+        let mut v = vec![11, 22];
+        {
+            let mut os = CodedOutputStream::vec(&mut v);
+            os.write_int32(1, 150).unwrap();
+            let checkpoint = os.checkpoint();
+            os.write_string(2, "abc").unwrap();
+            os.rollback(checkpoint).unwrap();
+            os.flush().unwrap();
+        }
+        assert_eq!("0b 16 08 96 01", encode_hex(&v));
+    }
+
+    #[test]
+    fn test_output_stream_rollback_vec_after_refresh() {
+        let mut v = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut v);
+            os.write_int32(1, 150).unwrap();
+            let checkpoint = os.checkpoint();
+            for _ in 0..2048 {
+                os.write_raw_bytes(&[0x01, 0x02, 0x03, 0x04]).unwrap();
+            }
+            os.flush().unwrap();
+            os.rollback(checkpoint).unwrap();
+            os.write_bool(3, true).unwrap();
+            os.flush().unwrap();
+        }
+        assert_eq!("08 96 01 18 01", encode_hex(&v));
+    }
+
+    #[test]
+    fn test_output_stream_rollback_bytes() {
+        let mut v = vec![0; 3];
+        {
+            let mut os = CodedOutputStream::bytes(&mut v);
+            os.write_int32(1, 150).unwrap();
+            let checkpoint = os.checkpoint();
+            os.rollback(checkpoint).unwrap();
+            os.check_eof();
+        }
+        assert_eq!("08 96 01", encode_hex(&v));
+
+        let mut v = vec![0; 3];
+        {
+            let mut os = CodedOutputStream::bytes(&mut v);
+            let checkpoint = os.checkpoint();
+            os.write_int32(1, 150).unwrap();
+            os.rollback(checkpoint).unwrap();
+            os.write_bool(2, false).unwrap();
+            os.write_raw_byte(0xff).unwrap();
+            os.check_eof();
+        }
+        assert_eq!("10 00 ff", encode_hex(&v));
+    }
+
+    #[test]
+    fn test_output_stream_rollback_write() {
+        let mut v = Vec::new();
+        let mut os = CodedOutputStream::new(&mut v as &mut Write);
+        let checkpoint = os.checkpoint();
+        os.write_int32(1, 150).unwrap();
+        assert!(os.rollback(checkpoint).is_err());
+    }
+
+    #[test]
+    fn test_output_stream_cursor() {
+        let mut cursor = io::Cursor::new(vec![11, 22, 33, 44]);
+        cursor.set_position(2);
+        {
+            let mut os = CodedOutputStream::cursor(&mut cursor);
+            os.write_int32(1, 150).unwrap();
+            os.flush().unwrap();
+        }
+        assert_eq!(5, cursor.position());
+        assert_eq!("0b 16 08 96 01", encode_hex(cursor.get_ref()));
+    }
+
+    #[test]
+    fn test_output_stream_cursor_overwrite() {
+        let mut cursor = io::Cursor::new(vec![11, 22, 33, 44, 55, 66]);
+        cursor.set_position(1);
+        {
+            let mut os = CodedOutputStream::cursor(&mut cursor);
+            os.write_int32(1, 150).unwrap();
+            os.flush().unwrap();
+        }
+        // bytes after written data are kept, like with `io::Write for Cursor`
+        assert_eq!(4, cursor.position());
+        assert_eq!("0b 08 96 01 37 42", encode_hex(cursor.get_ref()));
+
+        let mut cursor = io::Cursor::new(vec![11]);
+        cursor.set_position(3);
+        {
+            let mut os = CodedOutputStream::cursor(&mut cursor);
+            os.write_int32(1, 150).unwrap();
+        }
+        assert_eq!(6, cursor.position());
+        assert_eq!("0b 00 00 08 96 01", encode_hex(cursor.get_ref()));
+    }
+
+    // write `m` as `message_type` field of `FileDescriptorProto`,
+    // patching length after the message is written
+    fn write_message_type_with_placeholder(
+        os: &mut CodedOutputStream,
+        m: &DescriptorProto,
+    ) -> ProtobufResult<()> {
+        os.write_tag(4, wire_format::WireTypeLengthDelimited)?;
+        let placeholder = os.reserve_varint32_placeholder()?;
+        let start = os.total_bytes_written();
+        m.write_to(os)?;
+        let len = os.total_bytes_written() - start;
+        os.patch_varint32(placeholder, len as u32)
+    }
+
+    fn test_varint32_placeholder(m: DescriptorProto) {
+        let mut file = FileDescriptorProto::new();
+        file.message_type.push(m.clone());
+        let expected = file.write_to_bytes().unwrap();
+
+        let mut v = vec![11, 22];
+        {
+            let mut os = CodedOutputStream::vec(&mut v);
+            write_message_type_with_placeholder(&mut os, &m).unwrap();
+            os.write_string(1, "foo.proto").unwrap();
+            os.flush().unwrap();
+        }
+        file.set_name("foo.proto".to_owned());
+        assert_eq!(file, parse_from_bytes(&v[2..]).unwrap());
+
+        let mut cursor = io::Cursor::new(vec![11, 22, 33]);
+        cursor.set_position(1);
+        {
+            let mut os = CodedOutputStream::cursor(&mut cursor);
+            write_message_type_with_placeholder(&mut os, &m).unwrap();
+            os.flush().unwrap();
+        }
+        assert_eq!(1 + expected.len() as u64, cursor.position());
+        assert_eq!(&expected[..], &cursor.get_ref()[1..]);
+    }
+
+    #[test]
+    fn test_output_stream_varint32_placeholder() {
+        let mut m = DescriptorProto::new();
+        m.set_name("Foo".to_owned());
+        test_varint32_placeholder(m.clone());
+
+        let mut v = Vec::new();
+        {
+            let mut os = CodedOutputStream::new(&mut v as &mut Write);
+            write_message_type_with_placeholder(&mut os, &m).unwrap();
+            os.flush().unwrap();
+        }
+        let file: FileDescriptorProto = parse_from_bytes(&v).unwrap();
+        assert_eq!(vec![m], file.message_type.into_vec());
+    }
+
+    #[test]
+    fn test_output_stream_varint32_placeholder_after_refresh() {
+        let mut m = DescriptorProto::new();
+        m.set_name(repeat('a').take(10_000).collect());
+        test_varint32_placeholder(m.clone());
+
+        let mut v = Vec::new();
+        let mut os = CodedOutputStream::new(&mut v as &mut Write);
+        assert!(write_message_type_with_placeholder(&mut os, &m).is_err());
+    }
+
     #[test]
     fn test_output_stream_write_raw_varint32() {
         test_write("96 01", |os| os.write_raw_varint32(150));
@@ -1490,6 +2355,22 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_output_stream_write_message_no_tag() {
+        let mut d = Duration::new();
+        d.seconds = 3;
+        let m: &Message = &d;
+        test_write("02 08 03", |os| os.write_message_no_tag(m));
+        test_write("0a 02 08 03", |os| os.write_message(1, m));
+
+        test_read("02 08 03 02 08 03", |reader| {
+            assert_eq!(d, reader.read_message::<Duration>().unwrap());
+            let mut boxed: Box<Message> = Box::new(Duration::new());
+            reader.merge_message(&mut *boxed).unwrap();
+            assert_eq!(Some(&d), boxed.downcast_ref::<Duration>());
+        });
+    }
+
     #[test]
     fn test_output_stream_io_write() {
         let expected = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
@@ -1529,4 +2410,92 @@ mod test {
             assert_eq!(expected, *v);
         }
     }
+
+    #[test]
+    fn test_output_stream_total_bytes_written() {
+        let mut d = Duration::new();
+        d.seconds = 150;
+        d.nanos = 3;
+
+        let mut v = Vec::new();
+        let mut os = CodedOutputStream::vec(&mut v);
+        assert_eq!(0, os.total_bytes_written());
+        os.write_int64(1, d.seconds).unwrap();
+        os.write_int32(2, d.nanos).unwrap();
+        assert_eq!(d.compute_size() as u64, os.total_bytes_written());
+
+        let checkpoint = os.checkpoint();
+        for _ in 0..2048 {
+            os.write_raw_bytes(&[0x01, 0x02, 0x03, 0x04]).unwrap();
+        }
+        os.write_raw_bytes(&[0; 10000]).unwrap();
+        assert_eq!(
+            d.compute_size() as u64 + 8192 + 10000,
+            os.total_bytes_written()
+        );
+        os.rollback(checkpoint).unwrap();
+        assert_eq!(d.compute_size() as u64, os.total_bytes_written());
+    }
+
+    #[test]
+    fn test_output_stream_total_bytes_written_write() {
+        let mut v = Vec::new();
+        let mut os = CodedOutputStream::new(&mut v as &mut Write);
+        for _ in 0..2048 {
+            os.write_raw_bytes(&[0x01, 0x02, 0x03, 0x04]).unwrap();
+        }
+        os.write_raw_bytes(&[0; 10000]).unwrap();
+        os.write_raw_byte(0).unwrap();
+        assert_eq!(8192 + 10000 + 1, os.total_bytes_written());
+        os.flush().unwrap();
+        assert_eq!(8192 + 10000 + 1, os.total_bytes_written());
+    }
+
+    struct FailingWrite;
+
+    impl Write for FailingWrite {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::Other, "failing write"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_output_stream_finish() {
+        let mut v = Vec::new();
+        {
+            let mut os = CodedOutputStream::new(&mut v as &mut Write);
+            os.write_raw_varint32(150).unwrap();
+            os.finish().unwrap();
+        }
+        assert_eq!("96 01", encode_hex(&v));
+
+        let mut v = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut v);
+            os.write_raw_varint32(150).unwrap();
+            os.finish().unwrap();
+        }
+        assert_eq!("96 01", encode_hex(&v));
+    }
+
+    #[test]
+    fn test_output_stream_finish_error() {
+        let mut w = FailingWrite;
+        let mut os = CodedOutputStream::new(&mut w as &mut Write);
+        os.write_raw_varint32(150).unwrap();
+        // error is returned, and destructor does not panic
+        assert!(os.finish().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to flush")]
+    fn test_output_stream_drop_without_finish_panics_on_error() {
+        let mut w = FailingWrite;
+        let mut os = CodedOutputStream::new(&mut w as &mut Write);
+        os.write_raw_varint32(150).unwrap();
+    }
 }
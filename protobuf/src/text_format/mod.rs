@@ -14,3 +14,6 @@ pub use self::print::print_to_string;
 pub use self::print::quote_escape_bytes;
 
 pub use self::parse::merge_from_str;
+pub use self::parse::parse_from_str;
+pub use self::parse::ParseError;
+pub use self::parse::ParseErrorWithLoc;
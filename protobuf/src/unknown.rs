@@ -1,4 +1,7 @@
 use clear::Clear;
+use error::ProtobufError;
+use error::ProtobufResult;
+use error::WireError;
 use std::collections::hash_map;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
@@ -6,6 +9,7 @@ use std::default::Default;
 use std::hash::Hash;
 use std::hash::Hasher;
 use std::slice;
+use stream::CodedInputStream;
 use wire_format;
 use zigzag::encode_zig_zag_32;
 use zigzag::encode_zig_zag_64;
@@ -131,11 +135,21 @@ impl<'o> Iterator for UnknownValuesIter<'o> {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[derive(Clone, Eq, Debug, Default)]
 pub struct UnknownFields {
     // option is needed, because HashMap constructor performs allocation,
     // and very expensive
     pub fields: Option<Box<HashMap<u32, UnknownValues>>>,
+    // field numbers in order of first insertion, used to write fields back
+    // in the order they were read
+    order: Vec<u32>,
+}
+
+/// Insertion order is not compared.
+impl PartialEq for UnknownFields {
+    fn eq(&self, other: &UnknownFields) -> bool {
+        self.fields == other.fields
+    }
 }
 
 /// Very simple hash implementation of `Hash` for `UnknownFields`.
@@ -173,7 +187,10 @@ impl UnknownFields {
 
         match self.fields.as_mut().unwrap().entry(*number) {
             hash_map::Entry::Occupied(e) => e.into_mut(),
-            hash_map::Entry::Vacant(e) => e.insert(Default::default()),
+            hash_map::Entry::Vacant(e) => {
+                self.order.push(*number);
+                e.insert(Default::default())
+            }
         }
     }
 
@@ -199,9 +216,59 @@ impl UnknownFields {
         self.find_field(&number).add_value(value);
     }
 
+    /// Add a value given as raw wire bytes (without tag, but including
+    /// length prefix for length-delimited values).
+    ///
+    /// Fails if `bytes` is not exactly one encoded value of `wire_type`.
+    pub fn add_bytes(
+        &mut self,
+        number: u32,
+        wire_type: wire_format::WireType,
+        bytes: &[u8],
+    ) -> ProtobufResult<()> {
+        let mut is = CodedInputStream::from_bytes(bytes);
+        let value = is.read_unknown(wire_type)?;
+        if !is.eof()? {
+            return Err(ProtobufError::WireError(WireError::Other));
+        }
+        self.add_value(number, value);
+        Ok(())
+    }
+
+    /// Parse a sequence of tag and value pairs and store them as unknown fields.
+    ///
+    /// Fields are serialized in the order they were first added,
+    /// values of the same field grouped by wire type.
+    pub fn merge_raw(&mut self, bytes: &[u8]) -> ProtobufResult<()> {
+        let mut is = CodedInputStream::from_bytes(bytes);
+        while let Some((number, wire_type)) = is.read_tag_unpacked()? {
+            let value = is.read_unknown(wire_type)?;
+            self.add_value(number, value);
+        }
+        Ok(())
+    }
+
+    /// Iterate fields in the order they were first added.
     pub fn iter<'s>(&'s self) -> UnknownFieldsIter<'s> {
+        let fields = self.fields.as_deref();
+        // fields inserted directly into the map are not in `order`
+        let rest = match fields {
+            Some(map) if map.len() != self.order.len() => Some(map.iter()),
+            _ => None,
+        };
         UnknownFieldsIter {
-            entries: self.fields.as_ref().map(|m| m.iter()),
+            fields,
+            order: &self.order,
+            ordered: self.order.iter(),
+            rest,
+        }
+    }
+
+    /// True if there are no unknown fields.
+    pub fn is_empty(&self) -> bool {
+        match self.fields {
+            Some(ref map) => map.is_empty(),
+            None => true,
         }
     }
 
@@ -211,6 +278,30 @@ impl UnknownFields {
             None => None,
         }
     }
+
+    /// Shrink capacity of all stored containers,
+    /// free the map if it is empty.
+    pub fn shrink_to_fit(&mut self) {
+        let empty = match self.fields {
+            Some(ref mut map) => {
+                map.shrink_to_fit();
+                for values in map.values_mut() {
+                    values.fixed32.shrink_to_fit();
+                    values.fixed64.shrink_to_fit();
+                    values.varint.shrink_to_fit();
+                    values.length_delimited.shrink_to_fit();
+                }
+                map.is_empty()
+            }
+            None => false,
+        };
+        if empty {
+            self.fields = None;
+            self.order = Vec::new();
+        } else {
+            self.order.shrink_to_fit();
+        }
+    }
 }
 
 impl Clear for UnknownFields {
@@ -218,6 +309,7 @@ impl Clear for UnknownFields {
         if let Some(ref mut fields) = self.fields {
             fields.clear();
         }
+        self.order.clear();
     }
 }
 
@@ -231,15 +323,27 @@ impl<'a> IntoIterator for &'a UnknownFields {
 }
 
 pub struct UnknownFieldsIter<'s> {
-    entries: Option<hash_map::Iter<'s, u32, UnknownValues>>,
+    fields: Option<&'s HashMap<u32, UnknownValues>>,
+    order: &'s [u32],
+    ordered: slice::Iter<'s, u32>,
+    rest: Option<hash_map::Iter<'s, u32, UnknownValues>>,
 }
 
 impl<'s> Iterator for UnknownFieldsIter<'s> {
     type Item = (u32, &'s UnknownValues);
 
     fn next(&mut self) -> Option<(u32, &'s UnknownValues)> {
-        match self.entries {
-            Some(ref mut entries) => entries.next().map(|(&number, values)| (number, values)),
+        let fields = self.fields?;
+        for &number in self.ordered.by_ref() {
+            if let Some(values) = fields.get(&number) {
+                return Some((number, values));
+            }
+        }
+        let order = self.order;
+        match self.rest {
+            Some(ref mut rest) => rest
+                .find(|&(number, _)| !order.contains(number))
+                .map(|(&number, values)| (number, values)),
             None => None,
         }
     }
@@ -251,6 +355,18 @@ mod test {
     use std::collections::hash_map::DefaultHasher;
     use std::hash::Hash;
     use std::hash::Hasher;
+    use stream::CodedOutputStream;
+    use wire_format;
+
+    fn write_to_bytes(unknown_fields: &UnknownFields) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            os.write_unknown_fields(unknown_fields).unwrap();
+            os.flush().unwrap();
+        }
+        bytes
+    }
 
     #[test]
     fn unknown_fields_hash() {
@@ -275,4 +391,52 @@ mod test {
 
         assert_eq!(hash(&unknown_fields_1), hash(&unknown_fields_2));
     }
+
+    #[test]
+    fn add_bytes() {
+        let mut unknown_fields = UnknownFields::new();
+        unknown_fields
+            .add_bytes(3, wire_format::WireTypeLengthDelimited, b"\x02ab")
+            .unwrap();
+        unknown_fields
+            .add_bytes(1, wire_format::WireTypeVarint, b"\x96\x01")
+            .unwrap();
+        // written in insertion order
+        assert_eq!(
+            b"\x1a\x02ab\x08\x96\x01".to_vec(),
+            write_to_bytes(&unknown_fields)
+        );
+
+        assert!(unknown_fields
+            .add_bytes(1, wire_format::WireTypeVarint, b"\x01\x02")
+            .is_err());
+        assert!(unknown_fields
+            .add_bytes(1, wire_format::WireTypeFixed32, b"\x01")
+            .is_err());
+    }
+
+    #[test]
+    fn merge_raw() {
+        let bytes = b"\x08\x01\x08\x02\x15\x01\x00\x00\x00\x1a\x02ab\x1a\x00";
+        let mut unknown_fields = UnknownFields::new();
+        unknown_fields.merge_raw(bytes).unwrap();
+        assert_eq!(&[1, 2], &unknown_fields.get(1).unwrap().varint[..]);
+        assert_eq!(bytes.to_vec(), write_to_bytes(&unknown_fields));
+
+        assert!(unknown_fields.merge_raw(b"\x1a\x05ab").is_err());
+    }
+
+    #[test]
+    fn iter_field_inserted_into_map() {
+        let mut unknown_fields = UnknownFields::new();
+        unknown_fields.add_varint(2, 2);
+        unknown_fields
+            .fields
+            .as_mut()
+            .unwrap()
+            .insert(1, Default::default());
+        unknown_fields.add_varint(3, 3);
+        let numbers: Vec<u32> = unknown_fields.iter().map(|(number, _)| number).collect();
+        assert_eq!(vec![2, 3, 1], numbers);
+    }
 }
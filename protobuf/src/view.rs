@@ -0,0 +1,26 @@
+//! Message views: read-only messages borrowing strings and bytes
+//! from the parsed buffer.
+
+use error::ProtobufResult;
+use stream::CodedInputStream;
+
+/// Trait implemented by `FooView<'a>` structs generated with `generate_views` option.
+///
+/// `string` and `bytes` fields of a view point into the input buffer,
+/// so parsing does not copy them. Unknown fields are skipped,
+/// and required fields are not checked.
+pub trait MessageView<'a>: Default {
+    /// Update this view with fields read from given stream.
+    ///
+    /// Stream must be created from byte slice, otherwise
+    /// reading `string` or `bytes` field fails.
+    fn merge_from(&mut self, is: &mut CodedInputStream<'a>) -> ProtobufResult<()>;
+}
+
+/// Parse message view from byte slice.
+pub fn parse_view_from_bytes<'a, V: MessageView<'a>>(bytes: &'a [u8]) -> ProtobufResult<V> {
+    let mut is = CodedInputStream::from_bytes(bytes);
+    let mut view = V::default();
+    view.merge_from(&mut is)?;
+    Ok(view)
+}
@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(deprecated)]
 #![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -17,7 +18,10 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 
-#[derive(PartialEq,Clone,Default)]
+// fails to compile if generated code and protobuf runtime versions differ
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_0_0;
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Any {
     // message fields
@@ -49,7 +53,13 @@ impl ::protobuf::Message for Any {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.type_url)?;
@@ -61,6 +71,7 @@ impl ::protobuf::Message for Any {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -111,6 +122,10 @@ impl ::protobuf::Message for Any {
         Any::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -137,6 +152,12 @@ impl ::protobuf::Message for Any {
         static instance: ::protobuf::rt::Lazy<Any> = ::protobuf::rt::Lazy::INIT;
         instance.get(Any::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.type_url.shrink_to_fit();
+        self.value.shrink_to_fit();
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Any {
@@ -147,6 +168,24 @@ impl ::protobuf::Clear for Any {
     }
 }
 
+impl ::std::clone::Clone for Any {
+    fn clone(&self) -> Any {
+        Any {
+            type_url: ::std::clone::Clone::clone(&self.type_url),
+            value: ::std::clone::Clone::clone(&self.value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Any) {
+        ::std::clone::Clone::clone_from(&mut self.type_url, &source.type_url);
+        ::std::clone::Clone::clone_from(&mut self.value, &source.value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Any {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -156,6 +195,9 @@ impl ::std::fmt::Debug for Any {
 impl ::protobuf::reflect::ProtobufValue for Any {
 }
 
+impl ::protobuf::ProtobufConvertible for Any {
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x19google/protobuf/any.proto\x12\x0fgoogle.protobuf\"6\n\x03Any\x12\
     \x19\n\x08type_url\x18\x01\x20\x01(\tR\x07typeUrl\x12\x14\n\x05value\x18\
@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(deprecated)]
 #![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -17,7 +18,10 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 
-#[derive(PartialEq,Clone,Default)]
+// fails to compile if generated code and protobuf runtime versions differ
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_0_0;
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Api {
     // message fields
@@ -74,7 +78,13 @@ impl ::protobuf::Message for Api {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
@@ -98,12 +108,13 @@ impl ::protobuf::Message for Api {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.syntax = is.read_enum_or_unknown()?;
+                    self.syntax = is.read_enum_or_unknown_for_field("syntax")?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -188,6 +199,10 @@ impl ::protobuf::Message for Api {
         Api::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -239,6 +254,25 @@ impl ::protobuf::Message for Api {
         static instance: ::protobuf::rt::Lazy<Api> = ::protobuf::rt::Lazy::INIT;
         instance.get(Api::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.name.shrink_to_fit();
+        self.methods.shrink_to_fit();
+        for v in self.methods.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.options.shrink_to_fit();
+        for v in self.options.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.version.shrink_to_fit();
+        ::protobuf::rt::shrink_singular_message_to_fit::<::protobuf::well_known_types::SourceContext, _>(&mut self.source_context);
+        self.mixins.shrink_to_fit();
+        for v in self.mixins.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Api {
@@ -254,6 +288,34 @@ impl ::protobuf::Clear for Api {
     }
 }
 
+impl ::std::clone::Clone for Api {
+    fn clone(&self) -> Api {
+        Api {
+            name: ::std::clone::Clone::clone(&self.name),
+            methods: ::std::clone::Clone::clone(&self.methods),
+            options: ::std::clone::Clone::clone(&self.options),
+            version: ::std::clone::Clone::clone(&self.version),
+            source_context: ::std::clone::Clone::clone(&self.source_context),
+            mixins: ::std::clone::Clone::clone(&self.mixins),
+            syntax: ::std::clone::Clone::clone(&self.syntax),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Api) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.methods, &source.methods);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.version, &source.version);
+        ::std::clone::Clone::clone_from(&mut self.source_context, &source.source_context);
+        ::std::clone::Clone::clone_from(&mut self.mixins, &source.mixins);
+        ::std::clone::Clone::clone_from(&mut self.syntax, &source.syntax);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Api {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -263,7 +325,10 @@ impl ::std::fmt::Debug for Api {
 impl ::protobuf::reflect::ProtobufValue for Api {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for Api {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Method {
     // message fields
@@ -305,7 +370,13 @@ impl ::protobuf::Message for Method {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
@@ -335,12 +406,13 @@ impl ::protobuf::Message for Method {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.syntax = is.read_enum_or_unknown()?;
+                    self.syntax = is.read_enum_or_unknown_for_field("syntax")?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -422,6 +494,10 @@ impl ::protobuf::Message for Method {
         Method::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -473,6 +549,17 @@ impl ::protobuf::Message for Method {
         static instance: ::protobuf::rt::Lazy<Method> = ::protobuf::rt::Lazy::INIT;
         instance.get(Method::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.name.shrink_to_fit();
+        self.request_type_url.shrink_to_fit();
+        self.response_type_url.shrink_to_fit();
+        self.options.shrink_to_fit();
+        for v in self.options.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Method {
@@ -488,6 +575,34 @@ impl ::protobuf::Clear for Method {
     }
 }
 
+impl ::std::clone::Clone for Method {
+    fn clone(&self) -> Method {
+        Method {
+            name: ::std::clone::Clone::clone(&self.name),
+            request_type_url: ::std::clone::Clone::clone(&self.request_type_url),
+            request_streaming: ::std::clone::Clone::clone(&self.request_streaming),
+            response_type_url: ::std::clone::Clone::clone(&self.response_type_url),
+            response_streaming: ::std::clone::Clone::clone(&self.response_streaming),
+            options: ::std::clone::Clone::clone(&self.options),
+            syntax: ::std::clone::Clone::clone(&self.syntax),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Method) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.request_type_url, &source.request_type_url);
+        ::std::clone::Clone::clone_from(&mut self.request_streaming, &source.request_streaming);
+        ::std::clone::Clone::clone_from(&mut self.response_type_url, &source.response_type_url);
+        ::std::clone::Clone::clone_from(&mut self.response_streaming, &source.response_streaming);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.syntax, &source.syntax);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Method {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -497,7 +612,10 @@ impl ::std::fmt::Debug for Method {
 impl ::protobuf::reflect::ProtobufValue for Method {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for Method {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Mixin {
     // message fields
@@ -529,7 +647,13 @@ impl ::protobuf::Message for Mixin {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
@@ -541,6 +665,7 @@ impl ::protobuf::Message for Mixin {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -591,6 +716,10 @@ impl ::protobuf::Message for Mixin {
         Mixin::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -617,6 +746,12 @@ impl ::protobuf::Message for Mixin {
         static instance: ::protobuf::rt::Lazy<Mixin> = ::protobuf::rt::Lazy::INIT;
         instance.get(Mixin::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.name.shrink_to_fit();
+        self.root.shrink_to_fit();
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Mixin {
@@ -627,6 +762,24 @@ impl ::protobuf::Clear for Mixin {
     }
 }
 
+impl ::std::clone::Clone for Mixin {
+    fn clone(&self) -> Mixin {
+        Mixin {
+            name: ::std::clone::Clone::clone(&self.name),
+            root: ::std::clone::Clone::clone(&self.root),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Mixin) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.root, &source.root);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Mixin {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -636,6 +789,9 @@ impl ::std::fmt::Debug for Mixin {
 impl ::protobuf::reflect::ProtobufValue for Mixin {
 }
 
+impl ::protobuf::ProtobufConvertible for Mixin {
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x19google/protobuf/api.proto\x12\x0fgoogle.protobuf\x1a$google/protob\
     uf/source_context.proto\x1a\x1agoogle/protobuf/type.proto\"\xc1\x02\n\
@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(deprecated)]
 #![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -17,7 +18,10 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 
-#[derive(PartialEq,Clone,Default)]
+// fails to compile if generated code and protobuf runtime versions differ
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_0_0;
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Empty {
     // special fields
@@ -46,12 +50,19 @@ impl ::protobuf::Message for Empty {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -90,6 +101,10 @@ impl ::protobuf::Message for Empty {
         Empty::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -106,6 +121,10 @@ impl ::protobuf::Message for Empty {
         static instance: ::protobuf::rt::Lazy<Empty> = ::protobuf::rt::Lazy::INIT;
         instance.get(Empty::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Empty {
@@ -114,6 +133,20 @@ impl ::protobuf::Clear for Empty {
     }
 }
 
+impl ::std::clone::Clone for Empty {
+    fn clone(&self) -> Empty {
+        Empty {
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Empty) {
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Empty {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -123,6 +156,9 @@ impl ::std::fmt::Debug for Empty {
 impl ::protobuf::reflect::ProtobufValue for Empty {
 }
 
+impl ::protobuf::ProtobufConvertible for Empty {
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x1bgoogle/protobuf/empty.proto\x12\x0fgoogle.protobuf\"\x07\n\x05Empt\
     yBv\n\x13com.google.protobufB\nEmptyProtoP\x01Z'github.com/golang/protob\
@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(deprecated)]
 #![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -17,7 +18,10 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 
-#[derive(PartialEq,Clone,Default)]
+// fails to compile if generated code and protobuf runtime versions differ
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_0_0;
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct FieldMask {
     // message fields
@@ -48,7 +52,13 @@ impl ::protobuf::Message for FieldMask {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.paths)?;
@@ -57,6 +67,7 @@ impl ::protobuf::Message for FieldMask {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -101,6 +112,10 @@ impl ::protobuf::Message for FieldMask {
         FieldMask::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -122,6 +137,14 @@ impl ::protobuf::Message for FieldMask {
         static instance: ::protobuf::rt::Lazy<FieldMask> = ::protobuf::rt::Lazy::INIT;
         instance.get(FieldMask::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.paths.shrink_to_fit();
+        for v in self.paths.iter_mut() {
+            v.shrink_to_fit();
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for FieldMask {
@@ -131,6 +154,22 @@ impl ::protobuf::Clear for FieldMask {
     }
 }
 
+impl ::std::clone::Clone for FieldMask {
+    fn clone(&self) -> FieldMask {
+        FieldMask {
+            paths: ::std::clone::Clone::clone(&self.paths),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &FieldMask) {
+        ::std::clone::Clone::clone_from(&mut self.paths, &source.paths);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for FieldMask {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -140,6 +179,9 @@ impl ::std::fmt::Debug for FieldMask {
 impl ::protobuf::reflect::ProtobufValue for FieldMask {
 }
 
+impl ::protobuf::ProtobufConvertible for FieldMask {
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x20google/protobuf/field_mask.proto\x12\x0fgoogle.protobuf\"!\n\tFiel\
     dMask\x12\x14\n\x05paths\x18\x01\x20\x03(\tR\x05pathsBN\n\x13com.google.\
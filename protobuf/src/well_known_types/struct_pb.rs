@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(deprecated)]
 #![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -17,7 +18,10 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 
-#[derive(PartialEq,Clone,Default)]
+// fails to compile if generated code and protobuf runtime versions differ
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_0_0;
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Struct {
     // message fields
@@ -48,7 +52,13 @@ impl ::protobuf::Message for Struct {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Value>>(wire_type, is, &mut self.fields)?;
@@ -57,6 +67,7 @@ impl ::protobuf::Message for Struct {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -97,6 +108,10 @@ impl ::protobuf::Message for Struct {
         Struct::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -118,6 +133,14 @@ impl ::protobuf::Message for Struct {
         static instance: ::protobuf::rt::Lazy<Struct> = ::protobuf::rt::Lazy::INIT;
         instance.get(Struct::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.fields.shrink_to_fit();
+        for v in self.fields.values_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Struct {
@@ -127,6 +150,22 @@ impl ::protobuf::Clear for Struct {
     }
 }
 
+impl ::std::clone::Clone for Struct {
+    fn clone(&self) -> Struct {
+        Struct {
+            fields: ::std::clone::Clone::clone(&self.fields),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Struct) {
+        ::std::clone::Clone::clone_from(&mut self.fields, &source.fields);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Struct {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -136,7 +175,10 @@ impl ::std::fmt::Debug for Struct {
 impl ::protobuf::reflect::ProtobufValue for Struct {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for Struct {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Value {
     // message oneof groups
@@ -184,6 +226,10 @@ impl Value {
         self.kind = ::std::option::Option::Some(value::Kind::null_value(::protobuf::ProtobufEnumOrUnknown::new(v)))
     }
 
+    pub fn set_null_value_i32(&mut self, v: i32) {
+        self.kind = ::std::option::Option::Some(value::Kind::null_value(::protobuf::ProtobufEnumOrUnknown::from_i32(v)))
+    }
+
     // double number_value = 2;
 
     pub fn get_number_value(&self) -> f64 {
@@ -380,6 +426,101 @@ impl Value {
             ListValue::new()
         }
     }
+
+    // oneof kind
+
+    pub fn clear_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    // Take oneof value, leaving `None`
+    pub fn take_kind(&mut self) -> ::std::option::Option<value::Kind> {
+        self.kind.take()
+    }
+
+    pub fn is_null_value(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(value::Kind::null_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    pub fn null_value_ref(&self) -> ::std::option::Option<&::protobuf::ProtobufEnumOrUnknown<NullValue>> {
+        match self.kind {
+            ::std::option::Option::Some(value::Kind::null_value(ref v)) => ::std::option::Option::Some(v),
+            _ => ::std::option::Option::None,
+        }
+    }
+
+    pub fn is_number_value(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(value::Kind::number_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    pub fn number_value_ref(&self) -> ::std::option::Option<&f64> {
+        match self.kind {
+            ::std::option::Option::Some(value::Kind::number_value(ref v)) => ::std::option::Option::Some(v),
+            _ => ::std::option::Option::None,
+        }
+    }
+
+    pub fn is_string_value(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(value::Kind::string_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    pub fn string_value_ref(&self) -> ::std::option::Option<&::std::string::String> {
+        match self.kind {
+            ::std::option::Option::Some(value::Kind::string_value(ref v)) => ::std::option::Option::Some(v),
+            _ => ::std::option::Option::None,
+        }
+    }
+
+    pub fn is_bool_value(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(value::Kind::bool_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    pub fn bool_value_ref(&self) -> ::std::option::Option<&bool> {
+        match self.kind {
+            ::std::option::Option::Some(value::Kind::bool_value(ref v)) => ::std::option::Option::Some(v),
+            _ => ::std::option::Option::None,
+        }
+    }
+
+    pub fn is_struct_value(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(value::Kind::struct_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    pub fn struct_value_ref(&self) -> ::std::option::Option<&Struct> {
+        match self.kind {
+            ::std::option::Option::Some(value::Kind::struct_value(ref v)) => ::std::option::Option::Some(v),
+            _ => ::std::option::Option::None,
+        }
+    }
+
+    pub fn is_list_value(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(value::Kind::list_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    pub fn list_value_ref(&self) -> ::std::option::Option<&ListValue> {
+        match self.kind {
+            ::std::option::Option::Some(value::Kind::list_value(ref v)) => ::std::option::Option::Some(v),
+            _ => ::std::option::Option::None,
+        }
+    }
 }
 
 impl ::protobuf::Message for Value {
@@ -399,13 +540,19 @@ impl ::protobuf::Message for Value {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.kind = ::std::option::Option::Some(value::Kind::null_value(is.read_enum_or_unknown()?));
+                    self.kind = ::std::option::Option::Some(value::Kind::null_value(is.read_enum_or_unknown_for_field("null_value")?));
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
@@ -441,6 +588,7 @@ impl ::protobuf::Message for Value {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -525,6 +673,10 @@ impl ::protobuf::Message for Value {
         Value::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -579,6 +731,19 @@ impl ::protobuf::Message for Value {
         static instance: ::protobuf::rt::Lazy<Value> = ::protobuf::rt::Lazy::INIT;
         instance.get(Value::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        if let ::std::option::Option::Some(value::Kind::string_value(ref mut v)) = self.kind {
+            v.shrink_to_fit();
+        }
+        if let ::std::option::Option::Some(value::Kind::struct_value(ref mut v)) = self.kind {
+            ::protobuf::Message::shrink_to_fit(v);
+        }
+        if let ::std::option::Option::Some(value::Kind::list_value(ref mut v)) = self.kind {
+            ::protobuf::Message::shrink_to_fit(v);
+        }
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Value {
@@ -593,6 +758,22 @@ impl ::protobuf::Clear for Value {
     }
 }
 
+impl ::std::clone::Clone for Value {
+    fn clone(&self) -> Value {
+        Value {
+            kind: ::std::clone::Clone::clone(&self.kind),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Value) {
+        ::std::clone::Clone::clone_from(&mut self.kind, &source.kind);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Value {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -602,6 +783,9 @@ impl ::std::fmt::Debug for Value {
 impl ::protobuf::reflect::ProtobufValue for Value {
 }
 
+impl ::protobuf::ProtobufConvertible for Value {
+}
+
 pub mod value {
 
     #[derive(Clone,PartialEq)]
@@ -617,9 +801,15 @@ pub mod value {
 
     impl ::protobuf::Oneof for Kind {
     }
+
+    impl ::std::default::Default for Kind {
+        fn default() -> Self {
+            Kind::null_value(::std::default::Default::default())
+        }
+    }
 }
 
-#[derive(PartialEq,Clone,Default)]
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct ListValue {
     // message fields
@@ -655,7 +845,13 @@ impl ::protobuf::Message for ListValue {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_repeated_message_into_repeated_field(wire_type, is, &mut self.values)?;
@@ -664,6 +860,7 @@ impl ::protobuf::Message for ListValue {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -709,6 +906,10 @@ impl ::protobuf::Message for ListValue {
         ListValue::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -730,6 +931,14 @@ impl ::protobuf::Message for ListValue {
         static instance: ::protobuf::rt::Lazy<ListValue> = ::protobuf::rt::Lazy::INIT;
         instance.get(ListValue::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+        for v in self.values.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for ListValue {
@@ -739,6 +948,22 @@ impl ::protobuf::Clear for ListValue {
     }
 }
 
+impl ::std::clone::Clone for ListValue {
+    fn clone(&self) -> ListValue {
+        ListValue {
+            values: ::std::clone::Clone::clone(&self.values),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &ListValue) {
+        ::std::clone::Clone::clone_from(&mut self.values, &source.values);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for ListValue {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -748,6 +973,9 @@ impl ::std::fmt::Debug for ListValue {
 impl ::protobuf::reflect::ProtobufValue for ListValue {
 }
 
+impl ::protobuf::ProtobufConvertible for ListValue {
+}
+
 #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub enum NullValue {
@@ -766,6 +994,12 @@ impl ::protobuf::ProtobufEnum for NullValue {
         }
     }
 
+    fn name(&self) -> &'static str {
+        match *self {
+            NullValue::NULL_VALUE => "NULL_VALUE",
+        }
+    }
+
     fn values() -> &'static [Self] {
         static values: &'static [NullValue] = &[
             NullValue::NULL_VALUE,
@@ -790,6 +1024,18 @@ impl ::std::default::Default for NullValue {
 impl ::protobuf::reflect::ProtobufValue for NullValue {
 }
 
+impl ::std::convert::From<NullValue> for &'static str {
+    fn from(value: NullValue) -> &'static str {
+        ::protobuf::ProtobufEnum::name(&value)
+    }
+}
+
+impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<NullValue>> for NullValue {
+    fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<NullValue>) -> bool {
+        other == self
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x1cgoogle/protobuf/struct.proto\x12\x0fgoogle.protobuf\"\x98\x01\n\
     \x06Struct\x12;\n\x06fields\x18\x01\x20\x03(\x0b2#.google.protobuf.Struc\
@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(deprecated)]
 #![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -17,7 +18,10 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 
-#[derive(PartialEq,Clone,Default)]
+// fails to compile if generated code and protobuf runtime versions differ
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_0_0;
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Timestamp {
     // message fields
@@ -49,22 +53,42 @@ impl ::protobuf::Message for Timestamp {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
+            let field_start = is.pos();
+            let tag = is.read_raw_varint32()?;
+            match tag {
+                8 if is.field_allowed(1) => {
                     self.seconds = is.read_int64()?;
+                    is.record_field_offset(1, field_start);
                 },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
+                16 if is.field_allowed(2) => {
                     self.nanos = is.read_int32()?;
+                    is.record_field_offset(2, field_start);
                 },
                 _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                    let (field_number, wire_type) = ::protobuf::rt::unpack_tag(tag)?;
+                    if !is.field_allowed(field_number) {
+                        ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        is.record_field_offset(field_number, field_start);
+                        continue;
+                    }
+                    match field_number {
+                        1 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.seconds = is.read_int64()?;
+                        },
+                        2 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.nanos = is.read_int32()?;
+                        },
+                        _ => {
+                            ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        },
+                    };
+                    is.record_field_offset(field_number, field_start);
                 },
             };
         }
@@ -117,6 +141,10 @@ impl ::protobuf::Message for Timestamp {
         Timestamp::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -143,6 +171,10 @@ impl ::protobuf::Message for Timestamp {
         static instance: ::protobuf::rt::Lazy<Timestamp> = ::protobuf::rt::Lazy::INIT;
         instance.get(Timestamp::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Timestamp {
@@ -153,6 +185,24 @@ impl ::protobuf::Clear for Timestamp {
     }
 }
 
+impl ::std::clone::Clone for Timestamp {
+    fn clone(&self) -> Timestamp {
+        Timestamp {
+            seconds: ::std::clone::Clone::clone(&self.seconds),
+            nanos: ::std::clone::Clone::clone(&self.nanos),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Timestamp) {
+        ::std::clone::Clone::clone_from(&mut self.seconds, &source.seconds);
+        ::std::clone::Clone::clone_from(&mut self.nanos, &source.nanos);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Timestamp {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -162,6 +212,9 @@ impl ::std::fmt::Debug for Timestamp {
 impl ::protobuf::reflect::ProtobufValue for Timestamp {
 }
 
+impl ::protobuf::ProtobufConvertible for Timestamp {
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x1fgoogle/protobuf/timestamp.proto\x12\x0fgoogle.protobuf\";\n\tTimes\
     tamp\x12\x18\n\x07seconds\x18\x01\x20\x01(\x03R\x07seconds\x12\x14\n\x05\
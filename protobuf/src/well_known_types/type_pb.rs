@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(deprecated)]
 #![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -17,7 +18,10 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 
-#[derive(PartialEq,Clone,Default)]
+// fails to compile if generated code and protobuf runtime versions differ
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_0_0;
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Type {
     // message fields
@@ -68,7 +72,13 @@ impl ::protobuf::Message for Type {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
@@ -89,12 +99,13 @@ impl ::protobuf::Message for Type {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.syntax = is.read_enum_or_unknown()?;
+                    self.syntax = is.read_enum_or_unknown_for_field("syntax")?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -172,6 +183,10 @@ impl ::protobuf::Message for Type {
         Type::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -218,6 +233,24 @@ impl ::protobuf::Message for Type {
         static instance: ::protobuf::rt::Lazy<Type> = ::protobuf::rt::Lazy::INIT;
         instance.get(Type::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.name.shrink_to_fit();
+        self.fields.shrink_to_fit();
+        for v in self.fields.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.oneofs.shrink_to_fit();
+        for v in self.oneofs.iter_mut() {
+            v.shrink_to_fit();
+        };
+        self.options.shrink_to_fit();
+        for v in self.options.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        ::protobuf::rt::shrink_singular_message_to_fit::<::protobuf::well_known_types::SourceContext, _>(&mut self.source_context);
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Type {
@@ -232,6 +265,32 @@ impl ::protobuf::Clear for Type {
     }
 }
 
+impl ::std::clone::Clone for Type {
+    fn clone(&self) -> Type {
+        Type {
+            name: ::std::clone::Clone::clone(&self.name),
+            fields: ::std::clone::Clone::clone(&self.fields),
+            oneofs: ::std::clone::Clone::clone(&self.oneofs),
+            options: ::std::clone::Clone::clone(&self.options),
+            source_context: ::std::clone::Clone::clone(&self.source_context),
+            syntax: ::std::clone::Clone::clone(&self.syntax),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Type) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.fields, &source.fields);
+        ::std::clone::Clone::clone_from(&mut self.oneofs, &source.oneofs);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.source_context, &source.source_context);
+        ::std::clone::Clone::clone_from(&mut self.syntax, &source.syntax);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Type {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -241,7 +300,10 @@ impl ::std::fmt::Debug for Type {
 impl ::protobuf::reflect::ProtobufValue for Type {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for Type {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Field {
     // message fields
@@ -286,19 +348,25 @@ impl ::protobuf::Message for Field {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.kind = is.read_enum_or_unknown()?;
+                    self.kind = is.read_enum_or_unknown_for_field("kind")?;
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.cardinality = is.read_enum_or_unknown()?;
+                    self.cardinality = is.read_enum_or_unknown_for_field("cardinality")?;
                 },
                 3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
@@ -337,6 +405,7 @@ impl ::protobuf::Message for Field {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -436,6 +505,10 @@ impl ::protobuf::Message for Field {
         Field::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -502,6 +575,18 @@ impl ::protobuf::Message for Field {
         static instance: ::protobuf::rt::Lazy<Field> = ::protobuf::rt::Lazy::INIT;
         instance.get(Field::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.name.shrink_to_fit();
+        self.type_url.shrink_to_fit();
+        self.options.shrink_to_fit();
+        for v in self.options.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.json_name.shrink_to_fit();
+        self.default_value.shrink_to_fit();
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Field {
@@ -520,6 +605,40 @@ impl ::protobuf::Clear for Field {
     }
 }
 
+impl ::std::clone::Clone for Field {
+    fn clone(&self) -> Field {
+        Field {
+            kind: ::std::clone::Clone::clone(&self.kind),
+            cardinality: ::std::clone::Clone::clone(&self.cardinality),
+            number: ::std::clone::Clone::clone(&self.number),
+            name: ::std::clone::Clone::clone(&self.name),
+            type_url: ::std::clone::Clone::clone(&self.type_url),
+            oneof_index: ::std::clone::Clone::clone(&self.oneof_index),
+            packed: ::std::clone::Clone::clone(&self.packed),
+            options: ::std::clone::Clone::clone(&self.options),
+            json_name: ::std::clone::Clone::clone(&self.json_name),
+            default_value: ::std::clone::Clone::clone(&self.default_value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Field) {
+        ::std::clone::Clone::clone_from(&mut self.kind, &source.kind);
+        ::std::clone::Clone::clone_from(&mut self.cardinality, &source.cardinality);
+        ::std::clone::Clone::clone_from(&mut self.number, &source.number);
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.type_url, &source.type_url);
+        ::std::clone::Clone::clone_from(&mut self.oneof_index, &source.oneof_index);
+        ::std::clone::Clone::clone_from(&mut self.packed, &source.packed);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.json_name, &source.json_name);
+        ::std::clone::Clone::clone_from(&mut self.default_value, &source.default_value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Field {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -529,6 +648,9 @@ impl ::std::fmt::Debug for Field {
 impl ::protobuf::reflect::ProtobufValue for Field {
 }
 
+impl ::protobuf::ProtobufConvertible for Field {
+}
+
 pub mod field {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
@@ -584,6 +706,30 @@ pub mod field {
             }
         }
 
+        fn name(&self) -> &'static str {
+            match *self {
+                Kind::TYPE_UNKNOWN => "TYPE_UNKNOWN",
+                Kind::TYPE_DOUBLE => "TYPE_DOUBLE",
+                Kind::TYPE_FLOAT => "TYPE_FLOAT",
+                Kind::TYPE_INT64 => "TYPE_INT64",
+                Kind::TYPE_UINT64 => "TYPE_UINT64",
+                Kind::TYPE_INT32 => "TYPE_INT32",
+                Kind::TYPE_FIXED64 => "TYPE_FIXED64",
+                Kind::TYPE_FIXED32 => "TYPE_FIXED32",
+                Kind::TYPE_BOOL => "TYPE_BOOL",
+                Kind::TYPE_STRING => "TYPE_STRING",
+                Kind::TYPE_GROUP => "TYPE_GROUP",
+                Kind::TYPE_MESSAGE => "TYPE_MESSAGE",
+                Kind::TYPE_BYTES => "TYPE_BYTES",
+                Kind::TYPE_UINT32 => "TYPE_UINT32",
+                Kind::TYPE_ENUM => "TYPE_ENUM",
+                Kind::TYPE_SFIXED32 => "TYPE_SFIXED32",
+                Kind::TYPE_SFIXED64 => "TYPE_SFIXED64",
+                Kind::TYPE_SINT32 => "TYPE_SINT32",
+                Kind::TYPE_SINT64 => "TYPE_SINT64",
+            }
+        }
+
         fn values() -> &'static [Self] {
             static values: &'static [Kind] = &[
                 Kind::TYPE_UNKNOWN,
@@ -626,6 +772,18 @@ pub mod field {
     impl ::protobuf::reflect::ProtobufValue for Kind {
     }
 
+    impl ::std::convert::From<Kind> for &'static str {
+        fn from(value: Kind) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<Kind>> for Kind {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<Kind>) -> bool {
+            other == self
+        }
+    }
+
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
     pub enum Cardinality {
@@ -650,6 +808,15 @@ pub mod field {
             }
         }
 
+        fn name(&self) -> &'static str {
+            match *self {
+                Cardinality::CARDINALITY_UNKNOWN => "CARDINALITY_UNKNOWN",
+                Cardinality::CARDINALITY_OPTIONAL => "CARDINALITY_OPTIONAL",
+                Cardinality::CARDINALITY_REQUIRED => "CARDINALITY_REQUIRED",
+                Cardinality::CARDINALITY_REPEATED => "CARDINALITY_REPEATED",
+            }
+        }
+
         fn values() -> &'static [Self] {
             static values: &'static [Cardinality] = &[
                 Cardinality::CARDINALITY_UNKNOWN,
@@ -676,9 +843,21 @@ pub mod field {
 
     impl ::protobuf::reflect::ProtobufValue for Cardinality {
     }
+
+    impl ::std::convert::From<Cardinality> for &'static str {
+        fn from(value: Cardinality) -> &'static str {
+            ::protobuf::ProtobufEnum::name(&value)
+        }
+    }
+
+    impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<Cardinality>> for Cardinality {
+        fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<Cardinality>) -> bool {
+            other == self
+        }
+    }
 }
 
-#[derive(PartialEq,Clone,Default)]
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Enum {
     // message fields
@@ -728,7 +907,13 @@ impl ::protobuf::Message for Enum {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
@@ -746,12 +931,13 @@ impl ::protobuf::Message for Enum {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.syntax = is.read_enum_or_unknown()?;
+                    self.syntax = is.read_enum_or_unknown_for_field("syntax")?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -823,6 +1009,10 @@ impl ::protobuf::Message for Enum {
         Enum::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -864,6 +1054,20 @@ impl ::protobuf::Message for Enum {
         static instance: ::protobuf::rt::Lazy<Enum> = ::protobuf::rt::Lazy::INIT;
         instance.get(Enum::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.name.shrink_to_fit();
+        self.enumvalue.shrink_to_fit();
+        for v in self.enumvalue.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.options.shrink_to_fit();
+        for v in self.options.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        ::protobuf::rt::shrink_singular_message_to_fit::<::protobuf::well_known_types::SourceContext, _>(&mut self.source_context);
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Enum {
@@ -877,6 +1081,30 @@ impl ::protobuf::Clear for Enum {
     }
 }
 
+impl ::std::clone::Clone for Enum {
+    fn clone(&self) -> Enum {
+        Enum {
+            name: ::std::clone::Clone::clone(&self.name),
+            enumvalue: ::std::clone::Clone::clone(&self.enumvalue),
+            options: ::std::clone::Clone::clone(&self.options),
+            source_context: ::std::clone::Clone::clone(&self.source_context),
+            syntax: ::std::clone::Clone::clone(&self.syntax),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Enum) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.enumvalue, &source.enumvalue);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.source_context, &source.source_context);
+        ::std::clone::Clone::clone_from(&mut self.syntax, &source.syntax);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Enum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -886,7 +1114,10 @@ impl ::std::fmt::Debug for Enum {
 impl ::protobuf::reflect::ProtobufValue for Enum {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for Enum {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct EnumValue {
     // message fields
@@ -924,7 +1155,13 @@ impl ::protobuf::Message for EnumValue {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
@@ -942,6 +1179,7 @@ impl ::protobuf::Message for EnumValue {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -999,6 +1237,10 @@ impl ::protobuf::Message for EnumValue {
         EnumValue::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -1030,6 +1272,15 @@ impl ::protobuf::Message for EnumValue {
         static instance: ::protobuf::rt::Lazy<EnumValue> = ::protobuf::rt::Lazy::INIT;
         instance.get(EnumValue::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.name.shrink_to_fit();
+        self.options.shrink_to_fit();
+        for v in self.options.iter_mut() {
+            ::protobuf::Message::shrink_to_fit(v);
+        };
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for EnumValue {
@@ -1041,6 +1292,26 @@ impl ::protobuf::Clear for EnumValue {
     }
 }
 
+impl ::std::clone::Clone for EnumValue {
+    fn clone(&self) -> EnumValue {
+        EnumValue {
+            name: ::std::clone::Clone::clone(&self.name),
+            number: ::std::clone::Clone::clone(&self.number),
+            options: ::std::clone::Clone::clone(&self.options),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &EnumValue) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.number, &source.number);
+        ::std::clone::Clone::clone_from(&mut self.options, &source.options);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for EnumValue {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -1050,7 +1321,10 @@ impl ::std::fmt::Debug for EnumValue {
 impl ::protobuf::reflect::ProtobufValue for EnumValue {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for EnumValue {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Option {
     // message fields
@@ -1087,7 +1361,13 @@ impl ::protobuf::Message for Option {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
@@ -1099,6 +1379,7 @@ impl ::protobuf::Message for Option {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -1150,6 +1431,10 @@ impl ::protobuf::Message for Option {
         Option::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -1176,6 +1461,12 @@ impl ::protobuf::Message for Option {
         static instance: ::protobuf::rt::Lazy<Option> = ::protobuf::rt::Lazy::INIT;
         instance.get(Option::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.name.shrink_to_fit();
+        ::protobuf::rt::shrink_singular_message_to_fit::<::protobuf::well_known_types::Any, _>(&mut self.value);
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Option {
@@ -1186,6 +1477,24 @@ impl ::protobuf::Clear for Option {
     }
 }
 
+impl ::std::clone::Clone for Option {
+    fn clone(&self) -> Option {
+        Option {
+            name: ::std::clone::Clone::clone(&self.name),
+            value: ::std::clone::Clone::clone(&self.value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Option) {
+        ::std::clone::Clone::clone_from(&mut self.name, &source.name);
+        ::std::clone::Clone::clone_from(&mut self.value, &source.value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Option {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -1195,6 +1504,9 @@ impl ::std::fmt::Debug for Option {
 impl ::protobuf::reflect::ProtobufValue for Option {
 }
 
+impl ::protobuf::ProtobufConvertible for Option {
+}
+
 #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub enum Syntax {
@@ -1215,6 +1527,13 @@ impl ::protobuf::ProtobufEnum for Syntax {
         }
     }
 
+    fn name(&self) -> &'static str {
+        match *self {
+            Syntax::SYNTAX_PROTO2 => "SYNTAX_PROTO2",
+            Syntax::SYNTAX_PROTO3 => "SYNTAX_PROTO3",
+        }
+    }
+
     fn values() -> &'static [Self] {
         static values: &'static [Syntax] = &[
             Syntax::SYNTAX_PROTO2,
@@ -1240,6 +1559,18 @@ impl ::std::default::Default for Syntax {
 impl ::protobuf::reflect::ProtobufValue for Syntax {
 }
 
+impl ::std::convert::From<Syntax> for &'static str {
+    fn from(value: Syntax) -> &'static str {
+        ::protobuf::ProtobufEnum::name(&value)
+    }
+}
+
+impl ::std::cmp::PartialEq<::protobuf::ProtobufEnumOrUnknown<Syntax>> for Syntax {
+    fn eq(&self, other: &::protobuf::ProtobufEnumOrUnknown<Syntax>) -> bool {
+        other == self
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x1agoogle/protobuf/type.proto\x12\x0fgoogle.protobuf\x1a\x19google/pr\
     otobuf/any.proto\x1a$google/protobuf/source_context.proto\"\x8d\x02\n\
@@ -9,6 +9,7 @@
 
 #![allow(box_pointers)]
 #![allow(dead_code)]
+#![allow(deprecated)]
 #![allow(missing_docs)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -17,7 +18,10 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 
-#[derive(PartialEq,Clone,Default)]
+// fails to compile if generated code and protobuf runtime versions differ
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_0_0;
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct DoubleValue {
     // message fields
@@ -48,16 +52,32 @@ impl ::protobuf::Message for DoubleValue {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
+            let field_start = is.pos();
+            let tag = is.read_raw_varint32()?;
+            match tag {
+                9 if is.field_allowed(1) => {
                     self.value = is.read_double()?;
+                    is.record_field_offset(1, field_start);
                 },
                 _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                    let (field_number, wire_type) = ::protobuf::rt::unpack_tag(tag)?;
+                    if !is.field_allowed(field_number) {
+                        ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        is.record_field_offset(field_number, field_start);
+                        continue;
+                    }
+                    match field_number {
+                        1 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.value = is.read_double()?;
+                        },
+                        _ => {
+                            ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        },
+                    };
+                    is.record_field_offset(field_number, field_start);
                 },
             };
         }
@@ -104,6 +124,10 @@ impl ::protobuf::Message for DoubleValue {
         DoubleValue::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -125,6 +149,10 @@ impl ::protobuf::Message for DoubleValue {
         static instance: ::protobuf::rt::Lazy<DoubleValue> = ::protobuf::rt::Lazy::INIT;
         instance.get(DoubleValue::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for DoubleValue {
@@ -134,6 +162,22 @@ impl ::protobuf::Clear for DoubleValue {
     }
 }
 
+impl ::std::clone::Clone for DoubleValue {
+    fn clone(&self) -> DoubleValue {
+        DoubleValue {
+            value: ::std::clone::Clone::clone(&self.value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &DoubleValue) {
+        ::std::clone::Clone::clone_from(&mut self.value, &source.value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for DoubleValue {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -143,7 +187,10 @@ impl ::std::fmt::Debug for DoubleValue {
 impl ::protobuf::reflect::ProtobufValue for DoubleValue {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for DoubleValue {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct FloatValue {
     // message fields
@@ -174,16 +221,32 @@ impl ::protobuf::Message for FloatValue {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
+            let field_start = is.pos();
+            let tag = is.read_raw_varint32()?;
+            match tag {
+                13 if is.field_allowed(1) => {
                     self.value = is.read_float()?;
+                    is.record_field_offset(1, field_start);
                 },
                 _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                    let (field_number, wire_type) = ::protobuf::rt::unpack_tag(tag)?;
+                    if !is.field_allowed(field_number) {
+                        ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        is.record_field_offset(field_number, field_start);
+                        continue;
+                    }
+                    match field_number {
+                        1 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.value = is.read_float()?;
+                        },
+                        _ => {
+                            ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        },
+                    };
+                    is.record_field_offset(field_number, field_start);
                 },
             };
         }
@@ -230,6 +293,10 @@ impl ::protobuf::Message for FloatValue {
         FloatValue::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -251,6 +318,10 @@ impl ::protobuf::Message for FloatValue {
         static instance: ::protobuf::rt::Lazy<FloatValue> = ::protobuf::rt::Lazy::INIT;
         instance.get(FloatValue::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for FloatValue {
@@ -260,6 +331,22 @@ impl ::protobuf::Clear for FloatValue {
     }
 }
 
+impl ::std::clone::Clone for FloatValue {
+    fn clone(&self) -> FloatValue {
+        FloatValue {
+            value: ::std::clone::Clone::clone(&self.value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &FloatValue) {
+        ::std::clone::Clone::clone_from(&mut self.value, &source.value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for FloatValue {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -269,7 +356,10 @@ impl ::std::fmt::Debug for FloatValue {
 impl ::protobuf::reflect::ProtobufValue for FloatValue {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for FloatValue {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Int64Value {
     // message fields
@@ -300,16 +390,32 @@ impl ::protobuf::Message for Int64Value {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
+            let field_start = is.pos();
+            let tag = is.read_raw_varint32()?;
+            match tag {
+                8 if is.field_allowed(1) => {
                     self.value = is.read_int64()?;
+                    is.record_field_offset(1, field_start);
                 },
                 _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                    let (field_number, wire_type) = ::protobuf::rt::unpack_tag(tag)?;
+                    if !is.field_allowed(field_number) {
+                        ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        is.record_field_offset(field_number, field_start);
+                        continue;
+                    }
+                    match field_number {
+                        1 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.value = is.read_int64()?;
+                        },
+                        _ => {
+                            ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        },
+                    };
+                    is.record_field_offset(field_number, field_start);
                 },
             };
         }
@@ -356,6 +462,10 @@ impl ::protobuf::Message for Int64Value {
         Int64Value::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -377,6 +487,10 @@ impl ::protobuf::Message for Int64Value {
         static instance: ::protobuf::rt::Lazy<Int64Value> = ::protobuf::rt::Lazy::INIT;
         instance.get(Int64Value::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Int64Value {
@@ -386,6 +500,22 @@ impl ::protobuf::Clear for Int64Value {
     }
 }
 
+impl ::std::clone::Clone for Int64Value {
+    fn clone(&self) -> Int64Value {
+        Int64Value {
+            value: ::std::clone::Clone::clone(&self.value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Int64Value) {
+        ::std::clone::Clone::clone_from(&mut self.value, &source.value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Int64Value {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -395,7 +525,10 @@ impl ::std::fmt::Debug for Int64Value {
 impl ::protobuf::reflect::ProtobufValue for Int64Value {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for Int64Value {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct UInt64Value {
     // message fields
@@ -426,16 +559,32 @@ impl ::protobuf::Message for UInt64Value {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
+            let field_start = is.pos();
+            let tag = is.read_raw_varint32()?;
+            match tag {
+                8 if is.field_allowed(1) => {
                     self.value = is.read_uint64()?;
+                    is.record_field_offset(1, field_start);
                 },
                 _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                    let (field_number, wire_type) = ::protobuf::rt::unpack_tag(tag)?;
+                    if !is.field_allowed(field_number) {
+                        ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        is.record_field_offset(field_number, field_start);
+                        continue;
+                    }
+                    match field_number {
+                        1 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.value = is.read_uint64()?;
+                        },
+                        _ => {
+                            ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        },
+                    };
+                    is.record_field_offset(field_number, field_start);
                 },
             };
         }
@@ -482,6 +631,10 @@ impl ::protobuf::Message for UInt64Value {
         UInt64Value::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -503,6 +656,10 @@ impl ::protobuf::Message for UInt64Value {
         static instance: ::protobuf::rt::Lazy<UInt64Value> = ::protobuf::rt::Lazy::INIT;
         instance.get(UInt64Value::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for UInt64Value {
@@ -512,6 +669,22 @@ impl ::protobuf::Clear for UInt64Value {
     }
 }
 
+impl ::std::clone::Clone for UInt64Value {
+    fn clone(&self) -> UInt64Value {
+        UInt64Value {
+            value: ::std::clone::Clone::clone(&self.value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &UInt64Value) {
+        ::std::clone::Clone::clone_from(&mut self.value, &source.value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for UInt64Value {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -521,7 +694,10 @@ impl ::std::fmt::Debug for UInt64Value {
 impl ::protobuf::reflect::ProtobufValue for UInt64Value {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for UInt64Value {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct Int32Value {
     // message fields
@@ -552,16 +728,32 @@ impl ::protobuf::Message for Int32Value {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
+            let field_start = is.pos();
+            let tag = is.read_raw_varint32()?;
+            match tag {
+                8 if is.field_allowed(1) => {
                     self.value = is.read_int32()?;
+                    is.record_field_offset(1, field_start);
                 },
                 _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                    let (field_number, wire_type) = ::protobuf::rt::unpack_tag(tag)?;
+                    if !is.field_allowed(field_number) {
+                        ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        is.record_field_offset(field_number, field_start);
+                        continue;
+                    }
+                    match field_number {
+                        1 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.value = is.read_int32()?;
+                        },
+                        _ => {
+                            ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        },
+                    };
+                    is.record_field_offset(field_number, field_start);
                 },
             };
         }
@@ -608,6 +800,10 @@ impl ::protobuf::Message for Int32Value {
         Int32Value::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -629,6 +825,10 @@ impl ::protobuf::Message for Int32Value {
         static instance: ::protobuf::rt::Lazy<Int32Value> = ::protobuf::rt::Lazy::INIT;
         instance.get(Int32Value::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for Int32Value {
@@ -638,6 +838,22 @@ impl ::protobuf::Clear for Int32Value {
     }
 }
 
+impl ::std::clone::Clone for Int32Value {
+    fn clone(&self) -> Int32Value {
+        Int32Value {
+            value: ::std::clone::Clone::clone(&self.value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Int32Value) {
+        ::std::clone::Clone::clone_from(&mut self.value, &source.value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for Int32Value {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -647,7 +863,10 @@ impl ::std::fmt::Debug for Int32Value {
 impl ::protobuf::reflect::ProtobufValue for Int32Value {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for Int32Value {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct UInt32Value {
     // message fields
@@ -678,16 +897,32 @@ impl ::protobuf::Message for UInt32Value {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
+            let field_start = is.pos();
+            let tag = is.read_raw_varint32()?;
+            match tag {
+                8 if is.field_allowed(1) => {
                     self.value = is.read_uint32()?;
+                    is.record_field_offset(1, field_start);
                 },
                 _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                    let (field_number, wire_type) = ::protobuf::rt::unpack_tag(tag)?;
+                    if !is.field_allowed(field_number) {
+                        ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        is.record_field_offset(field_number, field_start);
+                        continue;
+                    }
+                    match field_number {
+                        1 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.value = is.read_uint32()?;
+                        },
+                        _ => {
+                            ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        },
+                    };
+                    is.record_field_offset(field_number, field_start);
                 },
             };
         }
@@ -734,6 +969,10 @@ impl ::protobuf::Message for UInt32Value {
         UInt32Value::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -755,6 +994,10 @@ impl ::protobuf::Message for UInt32Value {
         static instance: ::protobuf::rt::Lazy<UInt32Value> = ::protobuf::rt::Lazy::INIT;
         instance.get(UInt32Value::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for UInt32Value {
@@ -764,6 +1007,22 @@ impl ::protobuf::Clear for UInt32Value {
     }
 }
 
+impl ::std::clone::Clone for UInt32Value {
+    fn clone(&self) -> UInt32Value {
+        UInt32Value {
+            value: ::std::clone::Clone::clone(&self.value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &UInt32Value) {
+        ::std::clone::Clone::clone_from(&mut self.value, &source.value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for UInt32Value {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -773,7 +1032,10 @@ impl ::std::fmt::Debug for UInt32Value {
 impl ::protobuf::reflect::ProtobufValue for UInt32Value {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for UInt32Value {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct BoolValue {
     // message fields
@@ -804,16 +1066,32 @@ impl ::protobuf::Message for BoolValue {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
+            let field_start = is.pos();
+            let tag = is.read_raw_varint32()?;
+            match tag {
+                8 if is.field_allowed(1) => {
                     self.value = is.read_bool()?;
+                    is.record_field_offset(1, field_start);
                 },
                 _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                    let (field_number, wire_type) = ::protobuf::rt::unpack_tag(tag)?;
+                    if !is.field_allowed(field_number) {
+                        ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        is.record_field_offset(field_number, field_start);
+                        continue;
+                    }
+                    match field_number {
+                        1 => {
+                            if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                                return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                            }
+                            self.value = is.read_bool()?;
+                        },
+                        _ => {
+                            ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                        },
+                    };
+                    is.record_field_offset(field_number, field_start);
                 },
             };
         }
@@ -860,6 +1138,10 @@ impl ::protobuf::Message for BoolValue {
         BoolValue::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -881,6 +1163,10 @@ impl ::protobuf::Message for BoolValue {
         static instance: ::protobuf::rt::Lazy<BoolValue> = ::protobuf::rt::Lazy::INIT;
         instance.get(BoolValue::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for BoolValue {
@@ -890,6 +1176,22 @@ impl ::protobuf::Clear for BoolValue {
     }
 }
 
+impl ::std::clone::Clone for BoolValue {
+    fn clone(&self) -> BoolValue {
+        BoolValue {
+            value: ::std::clone::Clone::clone(&self.value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &BoolValue) {
+        ::std::clone::Clone::clone_from(&mut self.value, &source.value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for BoolValue {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -899,7 +1201,10 @@ impl ::std::fmt::Debug for BoolValue {
 impl ::protobuf::reflect::ProtobufValue for BoolValue {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for BoolValue {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct StringValue {
     // message fields
@@ -930,7 +1235,13 @@ impl ::protobuf::Message for StringValue {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
@@ -939,6 +1250,7 @@ impl ::protobuf::Message for StringValue {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -983,6 +1295,10 @@ impl ::protobuf::Message for StringValue {
         StringValue::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -1004,6 +1320,11 @@ impl ::protobuf::Message for StringValue {
         static instance: ::protobuf::rt::Lazy<StringValue> = ::protobuf::rt::Lazy::INIT;
         instance.get(StringValue::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.value.shrink_to_fit();
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for StringValue {
@@ -1013,6 +1334,22 @@ impl ::protobuf::Clear for StringValue {
     }
 }
 
+impl ::std::clone::Clone for StringValue {
+    fn clone(&self) -> StringValue {
+        StringValue {
+            value: ::std::clone::Clone::clone(&self.value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &StringValue) {
+        ::std::clone::Clone::clone_from(&mut self.value, &source.value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for StringValue {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -1022,7 +1359,10 @@ impl ::std::fmt::Debug for StringValue {
 impl ::protobuf::reflect::ProtobufValue for StringValue {
 }
 
-#[derive(PartialEq,Clone,Default)]
+impl ::protobuf::ProtobufConvertible for StringValue {
+}
+
+#[derive(PartialEq,Default)]
 #[cfg_attr(serde, derive(Serialize, Deserialize))]
 pub struct BytesValue {
     // message fields
@@ -1053,7 +1393,13 @@ impl ::protobuf::Message for BytesValue {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
+            let field_start = is.pos();
             let (field_number, wire_type) = is.read_tag_unpack()?;
+            if !is.field_allowed(field_number) {
+                ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                is.record_field_offset(field_number, field_start);
+                continue;
+            }
             match field_number {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
@@ -1062,6 +1408,7 @@ impl ::protobuf::Message for BytesValue {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
+            is.record_field_offset(field_number, field_start);
         }
         ::std::result::Result::Ok(())
     }
@@ -1106,6 +1453,10 @@ impl ::protobuf::Message for BytesValue {
         BytesValue::new()
     }
 
+    fn clone_box(&self) -> ::std::boxed::Box<::protobuf::Message> {
+        ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+    }
+
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::INIT;
         descriptor.get(|| {
@@ -1127,6 +1478,11 @@ impl ::protobuf::Message for BytesValue {
         static instance: ::protobuf::rt::Lazy<BytesValue> = ::protobuf::rt::Lazy::INIT;
         instance.get(BytesValue::new)
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.value.shrink_to_fit();
+        self.unknown_fields.shrink_to_fit();
+    }
 }
 
 impl ::protobuf::Clear for BytesValue {
@@ -1136,6 +1492,22 @@ impl ::protobuf::Clear for BytesValue {
     }
 }
 
+impl ::std::clone::Clone for BytesValue {
+    fn clone(&self) -> BytesValue {
+        BytesValue {
+            value: ::std::clone::Clone::clone(&self.value),
+            unknown_fields: ::std::clone::Clone::clone(&self.unknown_fields),
+            cached_size: ::std::clone::Clone::clone(&self.cached_size),
+        }
+    }
+
+    fn clone_from(&mut self, source: &BytesValue) {
+        ::std::clone::Clone::clone_from(&mut self.value, &source.value);
+        ::std::clone::Clone::clone_from(&mut self.unknown_fields, &source.unknown_fields);
+        ::std::clone::Clone::clone_from(&mut self.cached_size, &source.cached_size);
+    }
+}
+
 impl ::std::fmt::Debug for BytesValue {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
@@ -1145,6 +1517,9 @@ impl ::std::fmt::Debug for BytesValue {
 impl ::protobuf::reflect::ProtobufValue for BytesValue {
 }
 
+impl ::protobuf::ProtobufConvertible for BytesValue {
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x1egoogle/protobuf/wrappers.proto\x12\x0fgoogle.protobuf\"#\n\x0bDoub\
     leValue\x12\x14\n\x05value\x18\x01\x20\x01(\x01R\x05value\"\"\n\nFloatVa\
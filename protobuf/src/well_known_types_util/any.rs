@@ -0,0 +1,86 @@
+use core::parse_from_bytes;
+use core::Message;
+use error::ProtobufResult;
+use reflect::MessageDescriptor;
+use well_known_types::Any;
+
+impl Any {
+    fn type_url(type_url_prefix: &str, descriptor: &MessageDescriptor) -> String {
+        format!("{}/{}", type_url_prefix, descriptor.full_name())
+    }
+
+    fn get_type_name_from_type_url(type_url: &str) -> Option<&str> {
+        match type_url.rfind('/') {
+            Some(i) => Some(&type_url[i + 1..]),
+            None => None,
+        }
+    }
+
+    /// Pack any message into `well_known_types::Any` value.
+    ///
+    /// Type URL is set to `type.googleapis.com/<full_name>`.
+    pub fn pack<M: Message>(message: &M) -> ProtobufResult<Any> {
+        Any::pack_dyn(message)
+    }
+
+    /// Same as `pack`, but works with `dyn Message`.
+    pub fn pack_dyn(message: &Message) -> ProtobufResult<Any> {
+        Ok(Any {
+            type_url: Any::type_url("type.googleapis.com", message.descriptor()),
+            value: message.write_to_bytes()?,
+            ..Default::default()
+        })
+    }
+
+    /// Check if `Any` contains a message of given type.
+    pub fn is<M: Message>(&self) -> bool {
+        self.is_dyn(M::descriptor_static())
+    }
+
+    /// Check if `Any` contains a message of given type.
+    pub fn is_dyn(&self, descriptor: &MessageDescriptor) -> bool {
+        match Any::get_type_name_from_type_url(&self.type_url) {
+            Some(type_name) => type_name == descriptor.full_name(),
+            None => false,
+        }
+    }
+
+    /// Extract a message from this `Any`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(None)` when message type mismatch
+    /// * `Err` when parse failed
+    pub fn unpack<M: Message>(&self) -> ProtobufResult<Option<M>> {
+        if !self.is::<M>() {
+            return Ok(None);
+        }
+        Ok(Some(parse_from_bytes(&self.value)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use well_known_types::Any;
+    use well_known_types::Duration;
+    use well_known_types::Timestamp;
+
+    #[test]
+    fn pack_unpack() {
+        let mut duration = Duration::new();
+        duration.seconds = 10;
+        duration.nanos = 20;
+
+        let any = Any::pack(&duration).unwrap();
+        assert_eq!("type.googleapis.com/google.protobuf.Duration", any.type_url);
+        assert!(any.is::<Duration>());
+        assert_eq!(Some(duration), any.unpack::<Duration>().unwrap());
+    }
+
+    #[test]
+    fn unpack_mismatch() {
+        let any = Any::pack(&Duration::new()).unwrap();
+        assert!(!any.is::<Timestamp>());
+        assert_eq!(None, any.unpack::<Timestamp>().unwrap());
+    }
+}
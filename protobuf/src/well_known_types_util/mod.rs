@@ -0,0 +1,4 @@
+//! Hand-written functions for well known types.
+
+mod any;
+mod struct_pb;
@@ -0,0 +1,129 @@
+use std::ops::Index;
+
+use well_known_types::value;
+use well_known_types::ListValue;
+use well_known_types::NullValue;
+use well_known_types::Struct;
+use well_known_types::Value;
+use ProtobufEnumOrUnknown;
+
+impl Struct {
+    /// Get field value by key.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.fields.get(key)
+    }
+
+    /// Insert field value, returning the previous value for this key.
+    pub fn insert<K: Into<String>>(&mut self, key: K, value: Value) -> Option<Value> {
+        self.fields.insert(key.into(), value)
+    }
+}
+
+/// Get field value by key.
+///
+/// # Panics
+///
+/// If the key is not present in the `Struct`.
+impl<'a> Index<&'a str> for Struct {
+    type Output = Value;
+
+    fn index(&self, key: &str) -> &Value {
+        self.get(key).expect("key not found in Struct")
+    }
+}
+
+impl Value {
+    /// `null` value.
+    pub fn null() -> Value {
+        Value::from(value::Kind::null_value(ProtobufEnumOrUnknown::new(
+            NullValue::NULL_VALUE,
+        )))
+    }
+}
+
+impl From<value::Kind> for Value {
+    fn from(kind: value::Kind) -> Value {
+        Value {
+            kind: Some(kind),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<f64> for Value {
+    fn from(n: f64) -> Value {
+        Value::from(value::Kind::number_value(n))
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Value {
+        Value::from(value::Kind::bool_value(b))
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Value {
+        Value::from(value::Kind::string_value(s))
+    }
+}
+
+impl<'a> From<&'a str> for Value {
+    fn from(s: &'a str) -> Value {
+        Value::from(s.to_owned())
+    }
+}
+
+impl From<Struct> for Value {
+    fn from(s: Struct) -> Value {
+        Value::from(value::Kind::struct_value(s))
+    }
+}
+
+impl From<ListValue> for Value {
+    fn from(l: ListValue) -> Value {
+        Value::from(value::Kind::list_value(l))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use well_known_types::ListValue;
+    use well_known_types::Struct;
+    use well_known_types::Value;
+
+    #[test]
+    fn mixed_values() {
+        let mut list = ListValue::new();
+        list.values.push(Value::from(1.0));
+        list.values.push(Value::null());
+
+        let mut s = Struct::new();
+        assert_eq!(None, s.insert("number", Value::from(10.5)));
+        s.insert("string", Value::from("abc"));
+        s.insert("bool".to_owned(), Value::from(true));
+        s.insert("list", Value::from(list.clone()));
+        s.insert("null", Value::null());
+
+        assert_eq!(10.5, s["number"].get_number_value());
+        assert_eq!("abc", s["string"].get_string_value());
+        assert!(s["bool"].get_bool_value());
+        assert_eq!(&list, s["list"].get_list_value());
+        assert!(s["null"].is_null_value());
+        assert_eq!(None, s.get("missing"));
+
+        assert_eq!(Some(Value::from(true)), s.insert("bool", Value::from(false)));
+        assert!(!s["bool"].get_bool_value());
+
+        let mut outer = Struct::new();
+        outer.insert("inner", Value::from(s.clone()));
+        assert_eq!(&s, outer["inner"].get_struct_value());
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_missing() {
+        let s = Struct::new();
+        let _ = &s["missing"];
+    }
+}
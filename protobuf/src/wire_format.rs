@@ -1,6 +1,9 @@
 // TODO: temporary
 pub use self::WireType::*;
 
+use error::ProtobufResult;
+use stream::CodedInputStream;
+
 pub const TAG_TYPE_BITS: u32 = 3;
 pub const TAG_TYPE_MASK: u32 = (1u32 << TAG_TYPE_BITS as usize) - 1;
 // max possible tag number
@@ -81,3 +84,76 @@ impl Tag {
         self.field_number
     }
 }
+
+/// Field read from the input without schema, see [`tokenize`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WireToken<'a> {
+    pub field_number: u32,
+    pub wire_type: WireType,
+    /// Encoded value: varint bytes, 4 or 8 bytes of fixed value,
+    /// content (without length) of length-delimited value,
+    /// or empty slice for group start and end.
+    pub raw_value: &'a [u8],
+}
+
+/// Split input into fields without schema.
+///
+/// Length-delimited values are not parsed recursively,
+/// and group start and end are returned as separate tokens.
+pub fn tokenize(bytes: &[u8]) -> ProtobufResult<Vec<WireToken>> {
+    let mut is = CodedInputStream::from_bytes(bytes);
+    let mut r = Vec::new();
+    while !is.eof()? {
+        let (field_number, wire_type) = is.read_tag_unpack()?;
+        let raw_value = match wire_type {
+            WireTypeLengthDelimited => is.read_bytes_borrowed()?,
+            WireTypeStartGroup | WireTypeEndGroup => &bytes[0..0],
+            _ => {
+                let start = is.pos() as usize;
+                is.skip_field(wire_type)?;
+                &bytes[start..is.pos() as usize]
+            }
+        };
+        r.push(WireToken {
+            field_number,
+            wire_type,
+            raw_value,
+        });
+    }
+    Ok(r)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hex::decode_hex;
+
+    #[test]
+    fn test_tokenize() {
+        // 1: varint 150, 2: "ab", 3: fixed32, 4: group { 5: fixed64 }
+        let bytes = decode_hex(
+            "08 96 01 12 02 61 62 1d 01 02 03 04 23 29 01 02 03 04 05 06 07 08 24",
+        );
+        let tokens = tokenize(&bytes).unwrap();
+        let unpacked: Vec<_> = tokens
+            .iter()
+            .map(|t| (t.field_number, t.wire_type, t.raw_value))
+            .collect();
+        assert_eq!(
+            vec![
+                (1, WireTypeVarint, &[0x96, 0x01][..]),
+                (2, WireTypeLengthDelimited, &b"ab"[..]),
+                (3, WireTypeFixed32, &[1, 2, 3, 4][..]),
+                (4, WireTypeStartGroup, &[][..]),
+                (5, WireTypeFixed64, &[1, 2, 3, 4, 5, 6, 7, 8][..]),
+                (4, WireTypeEndGroup, &[][..]),
+            ],
+            unpacked
+        );
+    }
+
+    #[test]
+    fn test_tokenize_truncated() {
+        assert!(tokenize(&decode_hex("12 05 61 62")).is_err());
+    }
+}